@@ -0,0 +1,17 @@
+use std::{env, path::PathBuf};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Use the bundled protoc unless one is provided
+    if env::var_os("PROTOC").is_none() {
+        env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+    }
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR")?);
+
+    tonic_build::configure()
+        .build_client(false)
+        .file_descriptor_set_path(out_dir.join("descriptor.bin"))
+        .compile_protos(&["proto/product.proto", "proto/user.proto"], &["proto"])?;
+
+    Ok(())
+}
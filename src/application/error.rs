@@ -1,13 +1,22 @@
-use crate::domain::repositories::RepositoryError;
+use crate::domain::{
+    gateways::{BlobStorageError, PaymentGatewayError},
+    repositories::RepositoryError,
+};
 
 #[derive(thiserror::Error, Debug)]
 pub enum ApplicationError {
     #[error("Repository error: {0}")]
     Repository(#[from] RepositoryError),
+    #[error("Payment error: {0}")]
+    Payment(#[from] PaymentGatewayError),
+    #[error("Storage error: {0}")]
+    Storage(#[from] BlobStorageError),
     #[error("Validation error: {0}")]
     Validation(String),
     #[error("Not found")]
     NotFound,
     #[error("Unauthorized")]
     Unauthorized,
+    #[error("Internal error: {0}")]
+    Internal(String),
 }
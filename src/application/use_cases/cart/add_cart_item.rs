@@ -0,0 +1,64 @@
+use super::pricing::{cart_currency, price_cart, resolve_cart};
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::cart::{AddCartItemDto, CartOwner, PricedCart},
+    repositories::{CartRepository, ProductRepository},
+};
+use async_trait::async_trait;
+use tracing::instrument;
+
+pub struct AddCartItemUseCase<C: CartRepository, P: ProductRepository> {
+    carts: C,
+    products: P,
+}
+
+impl<C: CartRepository, P: ProductRepository> AddCartItemUseCase<C, P> {
+    pub fn new(carts: C, products: P) -> Self {
+        Self { carts, products }
+    }
+}
+
+#[async_trait]
+impl<C: CartRepository + Send + Sync, P: ProductRepository + Send + Sync>
+    UseCase<(CartOwner, AddCartItemDto), PricedCart, ApplicationError>
+    for AddCartItemUseCase<C, P>
+{
+    #[instrument(name = "AddCartItemUseCase::execute", skip_all)]
+    async fn execute(
+        &self,
+        input: (CartOwner, AddCartItemDto),
+    ) -> Result<PricedCart, ApplicationError> {
+        let (owner, item) = input;
+
+        // Validate input
+        if item.quantity <= 0 {
+            return Err(ApplicationError::Validation(
+                "Quantity must be greater than zero".to_string(),
+            ));
+        }
+
+        let product = self
+            .products
+            .find_by_id(item.product_id)
+            .await?
+            .ok_or_else(|| ApplicationError::Validation("Product does not exist".to_string()))?;
+
+        // A cart is paid in one currency, that of its first product
+        let cart = resolve_cart(&self.carts, owner).await?;
+        if let Some(currency) = cart_currency(&self.carts, &self.products, cart.id).await? {
+            if currency != product.currency {
+                return Err(ApplicationError::Validation(format!(
+                    "Cart is priced in {currency}, product is priced in {}",
+                    product.currency
+                )));
+            }
+        }
+
+        // Add item at the current product price
+        self.carts
+            .add_item(cart.id, product.id, item.quantity, product.price)
+            .await?;
+
+        price_cart(&self.carts, &self.products, cart).await
+    }
+}
@@ -0,0 +1,26 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{entities::cart::PricedCart, repositories::CartRepository};
+use async_trait::async_trait;
+use tracing::instrument;
+
+pub struct CreateCartUseCase<C: CartRepository> {
+    carts: C,
+}
+
+impl<C: CartRepository> CreateCartUseCase<C> {
+    pub fn new(carts: C) -> Self {
+        Self { carts }
+    }
+}
+
+#[async_trait]
+impl<C: CartRepository + Send + Sync> UseCase<(), PricedCart, ApplicationError>
+    for CreateCartUseCase<C>
+{
+    #[instrument(name = "CreateCartUseCase::execute", skip_all)]
+    async fn execute(&self, _: ()) -> Result<PricedCart, ApplicationError> {
+        // Create an empty anonymous cart
+        let cart = self.carts.create(None).await?;
+        Ok(PricedCart::new(cart, Vec::new()))
+    }
+}
@@ -0,0 +1,30 @@
+use super::pricing::{price_cart, resolve_cart};
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::cart::{CartOwner, PricedCart},
+    repositories::{CartRepository, ProductRepository},
+};
+use async_trait::async_trait;
+use tracing::instrument;
+
+pub struct GetCartUseCase<C: CartRepository, P: ProductRepository> {
+    carts: C,
+    products: P,
+}
+
+impl<C: CartRepository, P: ProductRepository> GetCartUseCase<C, P> {
+    pub fn new(carts: C, products: P) -> Self {
+        Self { carts, products }
+    }
+}
+
+#[async_trait]
+impl<C: CartRepository + Send + Sync, P: ProductRepository + Send + Sync>
+    UseCase<CartOwner, PricedCart, ApplicationError> for GetCartUseCase<C, P>
+{
+    #[instrument(name = "GetCartUseCase::execute", skip_all)]
+    async fn execute(&self, owner: CartOwner) -> Result<PricedCart, ApplicationError> {
+        let cart = resolve_cart(&self.carts, owner).await?;
+        price_cart(&self.carts, &self.products, cart).await
+    }
+}
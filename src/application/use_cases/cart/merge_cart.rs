@@ -0,0 +1,61 @@
+use super::pricing::{cart_currency, price_cart, resolve_cart};
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::cart::{CartOwner, MergeCartDto, PricedCart},
+    repositories::{CartRepository, ProductRepository},
+};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// Merges an anonymous cart into a user's cart, typically right after login
+pub struct MergeCartUseCase<C: CartRepository, P: ProductRepository> {
+    carts: C,
+    products: P,
+}
+
+impl<C: CartRepository, P: ProductRepository> MergeCartUseCase<C, P> {
+    pub fn new(carts: C, products: P) -> Self {
+        Self { carts, products }
+    }
+}
+
+#[async_trait]
+impl<C: CartRepository + Send + Sync, P: ProductRepository + Send + Sync>
+    UseCase<(Uuid, MergeCartDto), PricedCart, ApplicationError> for MergeCartUseCase<C, P>
+{
+    #[instrument(name = "MergeCartUseCase::execute", skip_all)]
+    async fn execute(&self, input: (Uuid, MergeCartDto)) -> Result<PricedCart, ApplicationError> {
+        let (user_id, merge_dto) = input;
+
+        // Check the anonymous cart exists and is not owned by anyone
+        let source = self
+            .carts
+            .find_by_token(merge_dto.cart_token)
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+
+        if source.user_id.is_some() {
+            return Err(ApplicationError::Validation(
+                "Only anonymous carts can be merged".to_string(),
+            ));
+        }
+
+        // Move items into the user's cart
+        let target = resolve_cart(&self.carts, CartOwner::User(user_id)).await?;
+        let currencies = (
+            cart_currency(&self.carts, &self.products, source.id).await?,
+            cart_currency(&self.carts, &self.products, target.id).await?,
+        );
+        if let (Some(source_currency), Some(target_currency)) = currencies {
+            if source_currency != target_currency {
+                return Err(ApplicationError::Validation(format!(
+                    "Cannot merge a cart priced in {source_currency} into one priced in {target_currency}"
+                )));
+            }
+        }
+        self.carts.merge(source.id, target.id).await?;
+
+        price_cart(&self.carts, &self.products, target).await
+    }
+}
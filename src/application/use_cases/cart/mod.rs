@@ -0,0 +1,16 @@
+pub mod add_cart_item;
+pub mod create_cart;
+pub mod get_cart;
+pub mod merge_cart;
+pub mod pricing;
+pub mod quote_cart;
+pub mod remove_cart_item;
+pub mod update_cart_item;
+
+pub use add_cart_item::AddCartItemUseCase;
+pub use create_cart::CreateCartUseCase;
+pub use get_cart::GetCartUseCase;
+pub use merge_cart::MergeCartUseCase;
+pub use quote_cart::QuoteCartUseCase;
+pub use remove_cart_item::RemoveCartItemUseCase;
+pub use update_cart_item::UpdateCartItemUseCase;
@@ -0,0 +1,125 @@
+use crate::application::error::ApplicationError;
+use crate::domain::{
+    entities::{
+        cart::{Cart, CartOwner, PricedCart, PricedCartItem},
+        exchange_rate::DEFAULT_CURRENCY,
+    },
+    repositories::{CartRepository, ProductRepository, PromotionRepository, RepositoryError},
+    services::promotion_engine::{evaluate, PricingContext, PricingLine, PromotionOutcome},
+};
+use chrono::Utc;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Looks up the cart for `owner`, creating a user's cart on first access
+pub async fn resolve_cart<C: CartRepository>(
+    carts: &C,
+    owner: CartOwner,
+) -> Result<Cart, ApplicationError> {
+    match owner {
+        CartOwner::Anonymous(token) => carts
+            .find_by_token(token)
+            .await?
+            .ok_or(ApplicationError::NotFound),
+        CartOwner::User(user_id) => {
+            if let Some(cart) = carts.find_by_user(user_id).await? {
+                return Ok(cart);
+            }
+
+            match carts.create(Some(user_id)).await {
+                Ok(cart) => Ok(cart),
+                // Another request created the cart concurrently
+                Err(RepositoryError::DuplicateEntry) => carts
+                    .find_by_user(user_id)
+                    .await?
+                    .ok_or(ApplicationError::NotFound),
+                Err(RepositoryError::NotFound) => Err(ApplicationError::NotFound),
+                Err(e) => Err(e.into()),
+            }
+        }
+    }
+}
+
+/// Currency the cart's items are priced in, absent while it is empty
+pub async fn cart_currency<C: CartRepository, P: ProductRepository>(
+    carts: &C,
+    products: &P,
+    cart_id: Uuid,
+) -> Result<Option<String>, ApplicationError> {
+    let Some(item) = carts.list_items(cart_id).await?.into_iter().next() else {
+        return Ok(None);
+    };
+    let product = products.find_by_id(item.product_id).await?;
+
+    Ok(product.map(|product| product.currency))
+}
+
+/// Prices every item against the current product price, persisting any price changes
+pub async fn price_cart<C: CartRepository, P: ProductRepository>(
+    carts: &C,
+    products: &P,
+    cart: Cart,
+) -> Result<PricedCart, ApplicationError> {
+    let items = carts.list_items(cart.id).await?;
+    let product_ids: Vec<_> = items.iter().map(|item| item.product_id).collect();
+    let catalog = products.find_by_ids(&product_ids).await?;
+
+    let mut priced_items = Vec::with_capacity(items.len());
+    for item in &items {
+        let Some(product) = catalog.iter().find(|p| p.id == item.product_id) else {
+            continue;
+        };
+
+        let priced = PricedCartItem::new(item, product);
+        if priced.previous_unit_price.is_some() {
+            carts
+                .update_item_price(cart.id, item.product_id, priced.unit_price)
+                .await?;
+        }
+        priced_items.push(priced);
+    }
+
+    Ok(PricedCart::new(cart, priced_items))
+}
+
+/// Evaluates the running promotions and the entered code against a priced cart
+pub async fn apply_promotions<M: PromotionRepository>(
+    promotions: &M,
+    priced: &PricedCart,
+    code: Option<&str>,
+) -> Result<PromotionOutcome, ApplicationError> {
+    // Amounts in different currencies cannot be added up
+    if priced.has_mixed_currencies() {
+        return Err(ApplicationError::Validation(
+            "Cart contains products priced in different currencies".to_string(),
+        ));
+    }
+
+    let now = Utc::now();
+    let code = code
+        .map(|code| code.trim().to_ascii_uppercase())
+        .filter(|code| !code.is_empty());
+    let candidates = promotions.find_applicable(now, code.as_deref()).await?;
+
+    let user_id = priced.cart.user_id;
+    let redemptions = match user_id {
+        Some(user_id) => {
+            let ids: Vec<_> = candidates.iter().map(|promotion| promotion.id).collect();
+            promotions.count_redemptions(user_id, &ids).await?
+        }
+        None => HashMap::new(),
+    };
+
+    let lines: Vec<PricingLine> = priced.items.iter().map(PricingLine::from).collect();
+    Ok(evaluate(
+        &candidates,
+        &PricingContext {
+            lines: &lines,
+            currency: priced.currency.as_deref().unwrap_or(DEFAULT_CURRENCY),
+            user_id,
+            code: code.as_deref(),
+            now,
+            redemptions: &redemptions,
+        },
+    ))
+}
@@ -0,0 +1,49 @@
+use super::pricing::{apply_promotions, price_cart, resolve_cart};
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::cart::{CartOwner, PricedCart},
+    repositories::{CartRepository, ProductRepository, PromotionRepository},
+    services::promotion_engine::PromotionOutcome,
+};
+use async_trait::async_trait;
+use tracing::instrument;
+
+/// Prices a cart including promotions and an optional discount code
+pub struct QuoteCartUseCase<C: CartRepository, P: ProductRepository, M: PromotionRepository> {
+    carts: C,
+    products: P,
+    promotions: M,
+}
+
+impl<C: CartRepository, P: ProductRepository, M: PromotionRepository> QuoteCartUseCase<C, P, M> {
+    pub fn new(carts: C, products: P, promotions: M) -> Self {
+        Self {
+            carts,
+            products,
+            promotions,
+        }
+    }
+}
+
+#[async_trait]
+impl<C, P, M> UseCase<(CartOwner, Option<String>), (PricedCart, PromotionOutcome), ApplicationError>
+    for QuoteCartUseCase<C, P, M>
+where
+    C: CartRepository + Send + Sync,
+    P: ProductRepository + Send + Sync,
+    M: PromotionRepository + Send + Sync,
+{
+    #[instrument(name = "QuoteCartUseCase::execute", skip_all)]
+    async fn execute(
+        &self,
+        input: (CartOwner, Option<String>),
+    ) -> Result<(PricedCart, PromotionOutcome), ApplicationError> {
+        let (owner, code) = input;
+
+        let cart = resolve_cart(&self.carts, owner).await?;
+        let priced = price_cart(&self.carts, &self.products, cart).await?;
+        let outcome = apply_promotions(&self.promotions, &priced, code.as_deref()).await?;
+
+        Ok((priced, outcome))
+    }
+}
@@ -0,0 +1,40 @@
+use super::pricing::{price_cart, resolve_cart};
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::cart::{CartOwner, PricedCart},
+    repositories::{CartRepository, ProductRepository, RepositoryError},
+};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct RemoveCartItemUseCase<C: CartRepository, P: ProductRepository> {
+    carts: C,
+    products: P,
+}
+
+impl<C: CartRepository, P: ProductRepository> RemoveCartItemUseCase<C, P> {
+    pub fn new(carts: C, products: P) -> Self {
+        Self { carts, products }
+    }
+}
+
+#[async_trait]
+impl<C: CartRepository + Send + Sync, P: ProductRepository + Send + Sync>
+    UseCase<(CartOwner, Uuid), PricedCart, ApplicationError> for RemoveCartItemUseCase<C, P>
+{
+    #[instrument(name = "RemoveCartItemUseCase::execute", skip_all)]
+    async fn execute(&self, input: (CartOwner, Uuid)) -> Result<PricedCart, ApplicationError> {
+        let (owner, product_id) = input;
+
+        // Remove item from cart
+        let cart = resolve_cart(&self.carts, owner).await?;
+        match self.carts.remove_item(cart.id, product_id).await {
+            Ok(()) => {}
+            Err(RepositoryError::NotFound) => return Err(ApplicationError::NotFound),
+            Err(e) => return Err(e.into()),
+        }
+
+        price_cart(&self.carts, &self.products, cart).await
+    }
+}
@@ -0,0 +1,55 @@
+use super::pricing::{price_cart, resolve_cart};
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::cart::{CartOwner, PricedCart, UpdateCartItemDto},
+    repositories::{CartRepository, ProductRepository, RepositoryError},
+};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct UpdateCartItemUseCase<C: CartRepository, P: ProductRepository> {
+    carts: C,
+    products: P,
+}
+
+impl<C: CartRepository, P: ProductRepository> UpdateCartItemUseCase<C, P> {
+    pub fn new(carts: C, products: P) -> Self {
+        Self { carts, products }
+    }
+}
+
+#[async_trait]
+impl<C: CartRepository + Send + Sync, P: ProductRepository + Send + Sync>
+    UseCase<(CartOwner, Uuid, UpdateCartItemDto), PricedCart, ApplicationError>
+    for UpdateCartItemUseCase<C, P>
+{
+    #[instrument(name = "UpdateCartItemUseCase::execute", skip_all)]
+    async fn execute(
+        &self,
+        input: (CartOwner, Uuid, UpdateCartItemDto),
+    ) -> Result<PricedCart, ApplicationError> {
+        let (owner, product_id, update_dto) = input;
+
+        // Validate input
+        if update_dto.quantity <= 0 {
+            return Err(ApplicationError::Validation(
+                "Quantity must be greater than zero".to_string(),
+            ));
+        }
+
+        // Update item quantity
+        let cart = resolve_cart(&self.carts, owner).await?;
+        match self
+            .carts
+            .update_item(cart.id, product_id, update_dto.quantity)
+            .await
+        {
+            Ok(_) => {}
+            Err(RepositoryError::NotFound) => return Err(ApplicationError::NotFound),
+            Err(e) => return Err(e.into()),
+        }
+
+        price_cart(&self.carts, &self.products, cart).await
+    }
+}
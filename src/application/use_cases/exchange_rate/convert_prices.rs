@@ -0,0 +1,97 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::{
+        exchange_rate::{is_currency_code, ExchangeRate, DEFAULT_CURRENCY},
+        product::Product,
+    },
+    repositories::ExchangeRateRepository,
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use tracing::instrument;
+
+/// Converts product prices into the requested currency
+pub struct ConvertPricesUseCase<X: ExchangeRateRepository> {
+    rates: X,
+}
+
+impl<X: ExchangeRateRepository> ConvertPricesUseCase<X> {
+    pub fn new(rates: X) -> Self {
+        Self { rates }
+    }
+
+    /// Looks up the rate for the pair, either as stored or as the inverse of the opposite pair
+    async fn direct_rate(
+        &self,
+        base: &str,
+        quote: &str,
+    ) -> Result<Option<ExchangeRate>, ApplicationError> {
+        if let Some(rate) = self.rates.find(base, quote).await? {
+            return Ok(Some(rate));
+        }
+
+        Ok(self
+            .rates
+            .find(quote, base)
+            .await?
+            .map(|rate| rate.inverse()))
+    }
+
+    /// Looks up the rate for the pair, crossing through the default currency when
+    /// the pair has no rate of its own
+    async fn rate(&self, base: &str, quote: &str) -> Result<ExchangeRate, ApplicationError> {
+        if let Some(rate) = self.direct_rate(base, quote).await? {
+            return Ok(rate);
+        }
+
+        if base != DEFAULT_CURRENCY && quote != DEFAULT_CURRENCY {
+            let first = self.direct_rate(base, DEFAULT_CURRENCY).await?;
+            let second = self.direct_rate(DEFAULT_CURRENCY, quote).await?;
+            if let (Some(first), Some(second)) = (first, second) {
+                return Ok(first.then(&second));
+            }
+        }
+
+        Err(ApplicationError::Validation(format!(
+            "No exchange rate from {base} to {quote}"
+        )))
+    }
+}
+
+#[async_trait]
+impl<X: ExchangeRateRepository + Send + Sync>
+    UseCase<(Vec<Product>, String), Vec<Product>, ApplicationError> for ConvertPricesUseCase<X>
+{
+    #[instrument(name = "ConvertPricesUseCase::execute", skip_all)]
+    async fn execute(
+        &self,
+        input: (Vec<Product>, String),
+    ) -> Result<Vec<Product>, ApplicationError> {
+        let (mut products, currency) = input;
+
+        // Validate input
+        if !is_currency_code(&currency) {
+            return Err(ApplicationError::Validation(
+                "Currency must be an ISO 4217 code".to_string(),
+            ));
+        }
+
+        // Look up each source currency once
+        let mut rates: HashMap<String, ExchangeRate> = HashMap::new();
+        for product in products.iter_mut() {
+            if product.currency == currency {
+                continue;
+            }
+
+            if !rates.contains_key(&product.currency) {
+                let rate = self.rate(&product.currency, &currency).await?;
+                rates.insert(product.currency.clone(), rate);
+            }
+
+            product.price = rates[&product.currency].convert(product.price);
+            product.currency = currency.clone();
+        }
+
+        Ok(products)
+    }
+}
@@ -0,0 +1,33 @@
+use super::validate_pair;
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::repositories::{ExchangeRateRepository, RepositoryError};
+use async_trait::async_trait;
+use tracing::instrument;
+
+pub struct DeleteExchangeRateUseCase<X: ExchangeRateRepository> {
+    rates: X,
+}
+
+impl<X: ExchangeRateRepository> DeleteExchangeRateUseCase<X> {
+    pub fn new(rates: X) -> Self {
+        Self { rates }
+    }
+}
+
+#[async_trait]
+impl<X: ExchangeRateRepository + Send + Sync> UseCase<(String, String), (), ApplicationError>
+    for DeleteExchangeRateUseCase<X>
+{
+    #[instrument(name = "DeleteExchangeRateUseCase::execute", skip_all)]
+    async fn execute(&self, input: (String, String)) -> Result<(), ApplicationError> {
+        let (base, quote) = input;
+
+        validate_pair(&base, &quote)?;
+
+        match self.rates.delete(&base, &quote).await {
+            Ok(()) => Ok(()),
+            Err(RepositoryError::NotFound) => Err(ApplicationError::NotFound),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
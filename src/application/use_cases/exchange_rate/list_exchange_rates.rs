@@ -0,0 +1,25 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{entities::exchange_rate::ExchangeRate, repositories::ExchangeRateRepository};
+use async_trait::async_trait;
+use tracing::instrument;
+
+pub struct ListExchangeRatesUseCase<X: ExchangeRateRepository> {
+    rates: X,
+}
+
+impl<X: ExchangeRateRepository> ListExchangeRatesUseCase<X> {
+    pub fn new(rates: X) -> Self {
+        Self { rates }
+    }
+}
+
+#[async_trait]
+impl<X: ExchangeRateRepository + Send + Sync> UseCase<(), Vec<ExchangeRate>, ApplicationError>
+    for ListExchangeRatesUseCase<X>
+{
+    #[instrument(name = "ListExchangeRatesUseCase::execute", skip_all)]
+    async fn execute(&self, _: ()) -> Result<Vec<ExchangeRate>, ApplicationError> {
+        let rates = self.rates.list().await?;
+        Ok(rates)
+    }
+}
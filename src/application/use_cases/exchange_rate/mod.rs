@@ -0,0 +1,30 @@
+pub mod convert_prices;
+pub mod delete_exchange_rate;
+pub mod list_exchange_rates;
+pub mod set_exchange_rate;
+
+pub use convert_prices::ConvertPricesUseCase;
+pub use delete_exchange_rate::DeleteExchangeRateUseCase;
+pub use list_exchange_rates::ListExchangeRatesUseCase;
+pub use set_exchange_rate::SetExchangeRateUseCase;
+
+use crate::application::error::ApplicationError;
+
+/// Validates a currency pair given as ISO 4217 codes
+fn validate_pair(base: &str, quote: &str) -> Result<(), ApplicationError> {
+    use crate::domain::entities::exchange_rate::is_currency_code;
+
+    if !is_currency_code(base) || !is_currency_code(quote) {
+        return Err(ApplicationError::Validation(
+            "Currencies must be ISO 4217 codes".to_string(),
+        ));
+    }
+
+    if base == quote {
+        return Err(ApplicationError::Validation(
+            "Base and quote currency must differ".to_string(),
+        ));
+    }
+
+    Ok(())
+}
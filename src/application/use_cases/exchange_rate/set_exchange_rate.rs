@@ -0,0 +1,45 @@
+use super::validate_pair;
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::exchange_rate::{ExchangeRate, SetExchangeRateDto},
+    repositories::ExchangeRateRepository,
+};
+use async_trait::async_trait;
+use rust_decimal_macros::dec;
+use tracing::instrument;
+
+pub struct SetExchangeRateUseCase<X: ExchangeRateRepository> {
+    rates: X,
+}
+
+impl<X: ExchangeRateRepository> SetExchangeRateUseCase<X> {
+    pub fn new(rates: X) -> Self {
+        Self { rates }
+    }
+}
+
+#[async_trait]
+impl<X: ExchangeRateRepository + Send + Sync>
+    UseCase<(String, String, SetExchangeRateDto), ExchangeRate, ApplicationError>
+    for SetExchangeRateUseCase<X>
+{
+    #[instrument(name = "SetExchangeRateUseCase::execute", skip_all)]
+    async fn execute(
+        &self,
+        input: (String, String, SetExchangeRateDto),
+    ) -> Result<ExchangeRate, ApplicationError> {
+        let (base, quote, dto) = input;
+
+        // Validate input
+        validate_pair(&base, &quote)?;
+
+        if dto.rate <= dec!(0) {
+            return Err(ApplicationError::Validation(
+                "Rate must be positive".to_string(),
+            ));
+        }
+
+        let rate = self.rates.set(&base, &quote, dto.rate).await?;
+        Ok(rate)
+    }
+}
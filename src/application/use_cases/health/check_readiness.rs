@@ -0,0 +1,69 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::health::{HealthCheckResult, HealthReport, HealthStatus},
+    gateways::HealthCheck,
+};
+use async_trait::async_trait;
+use futures::future::join_all;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::instrument;
+
+/// Runs every dependency check concurrently, input being whether the server is
+/// shutting down. A check slower than the timeout counts as down
+pub struct CheckReadinessUseCase {
+    checks: Vec<Arc<dyn HealthCheck>>,
+    timeout: Duration,
+}
+
+impl CheckReadinessUseCase {
+    pub fn new(checks: Vec<Arc<dyn HealthCheck>>, timeout: Duration) -> Self {
+        Self { checks, timeout }
+    }
+
+    async fn run(&self, check: &dyn HealthCheck) -> HealthCheckResult {
+        let started = Instant::now();
+        let outcome = tokio::time::timeout(self.timeout, check.check()).await;
+        let duration_ms = started.elapsed().as_secs_f64() * 1000.0;
+
+        let (status, details, error) = match outcome {
+            Ok(Ok(details)) => (HealthStatus::Up, details, None),
+            Ok(Err(e)) => (HealthStatus::Down, None, Some(e.to_string())),
+            Err(_) => (
+                HealthStatus::Down,
+                None,
+                Some(format!("Timed out after {}ms", self.timeout.as_millis())),
+            ),
+        };
+
+        HealthCheckResult {
+            name: check.name().to_string(),
+            status,
+            duration_ms,
+            details,
+            error,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<bool, HealthReport, ApplicationError> for CheckReadinessUseCase {
+    #[instrument(name = "CheckReadinessUseCase::execute", skip_all)]
+    async fn execute(&self, shutting_down: bool) -> Result<HealthReport, ApplicationError> {
+        let started = Instant::now();
+        let checks = join_all(self.checks.iter().map(|check| self.run(check.as_ref()))).await;
+
+        let status = if shutting_down || checks.iter().any(|c| c.status == HealthStatus::Down) {
+            HealthStatus::Down
+        } else {
+            HealthStatus::Up
+        };
+
+        Ok(HealthReport {
+            status,
+            shutting_down,
+            duration_ms: started.elapsed().as_secs_f64() * 1000.0,
+            checks,
+        })
+    }
+}
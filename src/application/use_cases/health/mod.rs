@@ -0,0 +1,3 @@
+pub mod check_readiness;
+
+pub use check_readiness::CheckReadinessUseCase;
@@ -0,0 +1,31 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::job::Job,
+    repositories::{JobRepository, RepositoryError},
+};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// Cancels a job that has not started yet
+pub struct CancelJobUseCase<R: JobRepository> {
+    repository: R,
+}
+
+impl<R: JobRepository> CancelJobUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: JobRepository + Send + Sync> UseCase<Uuid, Job, ApplicationError> for CancelJobUseCase<R> {
+    #[instrument(name = "CancelJobUseCase::execute", skip_all)]
+    async fn execute(&self, id: Uuid) -> Result<Job, ApplicationError> {
+        match self.repository.cancel(id).await {
+            Ok(job) => Ok(job),
+            Err(RepositoryError::NotFound) => Err(ApplicationError::NotFound),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
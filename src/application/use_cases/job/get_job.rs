@@ -0,0 +1,26 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{entities::job::Job, repositories::JobRepository};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct GetJobUseCase<R: JobRepository> {
+    repository: R,
+}
+
+impl<R: JobRepository> GetJobUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: JobRepository + Send + Sync> UseCase<Uuid, Job, ApplicationError> for GetJobUseCase<R> {
+    #[instrument(name = "GetJobUseCase::execute", skip_all)]
+    async fn execute(&self, id: Uuid) -> Result<Job, ApplicationError> {
+        self.repository
+            .find_by_id(id)
+            .await?
+            .ok_or(ApplicationError::NotFound)
+    }
+}
@@ -0,0 +1,45 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::job::{Job, JobFilter},
+    repositories::JobRepository,
+};
+use async_trait::async_trait;
+use tracing::instrument;
+
+/// Jobs returned when the caller asks for no particular number
+pub const DEFAULT_JOB_LIMIT: i64 = 50;
+/// Most jobs returned at once
+pub const MAX_JOB_LIMIT: i64 = 200;
+
+/// The newest jobs matching the filter, a zero limit meaning the default
+pub struct ListJobsUseCase<R: JobRepository> {
+    repository: R,
+}
+
+impl<R: JobRepository> ListJobsUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: JobRepository + Send + Sync> UseCase<JobFilter, Vec<Job>, ApplicationError>
+    for ListJobsUseCase<R>
+{
+    #[instrument(name = "ListJobsUseCase::execute", skip_all)]
+    async fn execute(&self, mut filter: JobFilter) -> Result<Vec<Job>, ApplicationError> {
+        // Validate input
+        if filter.limit == 0 {
+            filter.limit = DEFAULT_JOB_LIMIT;
+        }
+        if !(1..=MAX_JOB_LIMIT).contains(&filter.limit) {
+            return Err(ApplicationError::Validation(format!(
+                "Limit must be between 1 and {}",
+                MAX_JOB_LIMIT
+            )));
+        }
+
+        let jobs = self.repository.list(filter).await?;
+        Ok(jobs)
+    }
+}
@@ -0,0 +1,11 @@
+pub mod cancel_job;
+pub mod get_job;
+pub mod list_jobs;
+pub mod prune_jobs;
+pub mod retry_job;
+
+pub use cancel_job::CancelJobUseCase;
+pub use get_job::GetJobUseCase;
+pub use list_jobs::ListJobsUseCase;
+pub use prune_jobs::PruneJobsUseCase;
+pub use retry_job::RetryJobUseCase;
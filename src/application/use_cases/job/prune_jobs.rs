@@ -0,0 +1,31 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::repositories::JobRepository;
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use tracing::instrument;
+
+/// Drops succeeded and cancelled jobs older than the retention period, dead jobs
+/// stay until retried or cancelled by hand
+pub struct PruneJobsUseCase<R: JobRepository> {
+    repository: R,
+}
+
+impl<R: JobRepository> PruneJobsUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: JobRepository + Send + Sync> UseCase<Duration, u64, ApplicationError>
+    for PruneJobsUseCase<R>
+{
+    #[instrument(name = "PruneJobsUseCase::execute", skip_all)]
+    async fn execute(&self, retention: Duration) -> Result<u64, ApplicationError> {
+        let pruned = self
+            .repository
+            .prune_finished(Utc::now() - retention)
+            .await?;
+        Ok(pruned)
+    }
+}
@@ -0,0 +1,31 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::job::Job,
+    repositories::{JobRepository, RepositoryError},
+};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// Queues a dead or cancelled job again with fresh attempts
+pub struct RetryJobUseCase<R: JobRepository> {
+    repository: R,
+}
+
+impl<R: JobRepository> RetryJobUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: JobRepository + Send + Sync> UseCase<Uuid, Job, ApplicationError> for RetryJobUseCase<R> {
+    #[instrument(name = "RetryJobUseCase::execute", skip_all)]
+    async fn execute(&self, id: Uuid) -> Result<Job, ApplicationError> {
+        match self.repository.retry(id).await {
+            Ok(job) => Ok(job),
+            Err(RepositoryError::NotFound) => Err(ApplicationError::NotFound),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
@@ -1,5 +1,17 @@
 pub mod base;
+pub mod cart;
+pub mod exchange_rate;
+pub mod health;
+pub mod job;
+pub mod order;
+pub mod outbox;
+pub mod payment;
 pub mod product;
+pub mod product_event;
+pub mod product_image;
+pub mod promotion;
+pub mod scheduler;
 pub mod user;
+pub mod webhook;
 
 pub use base::UseCase;
@@ -0,0 +1,131 @@
+use crate::application::{
+    error::ApplicationError,
+    use_cases::{
+        cart::pricing::{apply_promotions, price_cart, resolve_cart},
+        UseCase,
+    },
+};
+use crate::domain::{
+    entities::{
+        cart::CartOwner,
+        domain_event::DomainEvent,
+        live_update::LiveUpdate,
+        order::{CheckoutDto, Order, OrderWithItems},
+    },
+    gateways::UpdateBus,
+    repositories::{
+        CartRepository, OrderRepository, ProductRepository, PromotionRepository, RepositoryError,
+    },
+};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// Turns a user's cart into an order awaiting payment, or a paid one when promotions cover it
+pub struct CheckoutCartUseCase<
+    C: CartRepository,
+    P: ProductRepository,
+    O: OrderRepository,
+    M: PromotionRepository,
+    B: UpdateBus,
+> {
+    carts: C,
+    products: P,
+    orders: O,
+    promotions: M,
+    updates: B,
+}
+
+impl<
+        C: CartRepository,
+        P: ProductRepository,
+        O: OrderRepository,
+        M: PromotionRepository,
+        B: UpdateBus,
+    > CheckoutCartUseCase<C, P, O, M, B>
+{
+    pub fn new(carts: C, products: P, orders: O, promotions: M, updates: B) -> Self {
+        Self {
+            carts,
+            products,
+            orders,
+            promotions,
+            updates,
+        }
+    }
+}
+
+#[async_trait]
+impl<C, P, O, M, B> UseCase<(Uuid, CheckoutDto), OrderWithItems, ApplicationError>
+    for CheckoutCartUseCase<C, P, O, M, B>
+where
+    C: CartRepository + Send + Sync,
+    P: ProductRepository + Send + Sync,
+    O: OrderRepository + Send + Sync,
+    M: PromotionRepository + Send + Sync,
+    B: UpdateBus,
+{
+    #[instrument(name = "CheckoutCartUseCase::execute", skip_all)]
+    async fn execute(
+        &self,
+        input: (Uuid, CheckoutDto),
+    ) -> Result<OrderWithItems, ApplicationError> {
+        let (user_id, checkout) = input;
+
+        // Price the cart against the current catalog
+        let cart = resolve_cart(&self.carts, CartOwner::User(user_id)).await?;
+        let priced = price_cart(&self.carts, &self.products, cart).await?;
+
+        // Only empty carts have no currency
+        let Some(currency) = priced.currency.clone() else {
+            return Err(ApplicationError::Validation("Cart is empty".to_string()));
+        };
+
+        if let Some(item) = priced
+            .items
+            .iter()
+            .find(|item| item.stock_warning.is_some())
+        {
+            return Err(ApplicationError::Validation(format!(
+                "Insufficient stock for {}",
+                item.product_name
+            )));
+        }
+
+        // Apply promotions, refusing a code that does not apply
+        let pricing = apply_promotions(&self.promotions, &priced, checkout.code.as_deref()).await?;
+        if let Some(rejection) = &pricing.code_rejection {
+            return Err(ApplicationError::Validation(format!(
+                "Discount code rejected: {rejection}"
+            )));
+        }
+
+        // Place the order
+        let order: Order = match self
+            .orders
+            .create_from_cart(
+                user_id,
+                priced.cart.id,
+                &priced.items,
+                &currency,
+                &pricing,
+                |product| DomainEvent::ProductUpdated(product.clone()),
+            )
+            .await
+        {
+            Ok(order) => order,
+            Err(RepositoryError::Conflict(msg)) => return Err(ApplicationError::Validation(msg)),
+            Err(e) => return Err(e.into()),
+        };
+
+        let items = self.orders.list_items(order.id).await?;
+
+        // Let subscribers know about the order, the stock it took reaches them through
+        // the outbox
+        self.updates.publish(LiveUpdate::OrderPlaced {
+            order: order.clone(),
+        });
+
+        Ok(OrderWithItems { order, items })
+    }
+}
@@ -0,0 +1,33 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{entities::order::OrderWithItems, repositories::OrderRepository};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct GetOrderUseCase<O: OrderRepository> {
+    orders: O,
+}
+
+impl<O: OrderRepository> GetOrderUseCase<O> {
+    pub fn new(orders: O) -> Self {
+        Self { orders }
+    }
+}
+
+#[async_trait]
+impl<O: OrderRepository + Send + Sync> UseCase<Uuid, OrderWithItems, ApplicationError>
+    for GetOrderUseCase<O>
+{
+    #[instrument(name = "GetOrderUseCase::execute", skip_all)]
+    async fn execute(&self, id: Uuid) -> Result<OrderWithItems, ApplicationError> {
+        // Fetch order and its items
+        let order = self
+            .orders
+            .find_by_id(id)
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+        let items = self.orders.list_items(order.id).await?;
+
+        Ok(OrderWithItems { order, items })
+    }
+}
@@ -0,0 +1,5 @@
+pub mod checkout_cart;
+pub mod get_order;
+
+pub use checkout_cart::CheckoutCartUseCase;
+pub use get_order::GetOrderUseCase;
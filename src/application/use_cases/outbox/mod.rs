@@ -0,0 +1,5 @@
+pub mod prune_outbox;
+pub mod relay_outbox;
+
+pub use prune_outbox::PruneOutboxUseCase;
+pub use relay_outbox::RelayOutboxUseCase;
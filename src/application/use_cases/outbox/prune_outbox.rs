@@ -0,0 +1,30 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::repositories::OutboxRepository;
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use tracing::instrument;
+
+/// Drops published outbox events older than the retention period
+pub struct PruneOutboxUseCase<R: OutboxRepository> {
+    repository: R,
+}
+
+impl<R: OutboxRepository> PruneOutboxUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: OutboxRepository + Send + Sync> UseCase<Duration, u64, ApplicationError>
+    for PruneOutboxUseCase<R>
+{
+    #[instrument(name = "PruneOutboxUseCase::execute", skip_all)]
+    async fn execute(&self, retention: Duration) -> Result<u64, ApplicationError> {
+        let pruned = self
+            .repository
+            .prune_published(Utc::now() - retention)
+            .await?;
+        Ok(pruned)
+    }
+}
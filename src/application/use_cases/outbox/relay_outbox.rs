@@ -0,0 +1,83 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::domain_event::OutboxEvent, gateways::EventSink, repositories::OutboxRepository,
+};
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use log::{error, warn};
+use tracing::instrument;
+
+/// Events relayed per run
+const BATCH_SIZE: i64 = 100;
+/// How long claimed events are hidden from other relays
+const LEASE: Duration = Duration::seconds(60);
+/// Wait after the first failed attempt, doubled after every further one
+const FIRST_RETRY_DELAY: Duration = Duration::seconds(1);
+const MAX_RETRY_DELAY: Duration = Duration::minutes(5);
+
+/// When to retry an event whose `attempts`-th relay attempt failed. Events are never
+/// given up on, a sink that is down only delays them
+fn retry_delay(attempts: i32) -> Duration {
+    let factor = 2_i32.saturating_pow(attempts.saturating_sub(1).min(16) as u32);
+    (FIRST_RETRY_DELAY * factor).min(MAX_RETRY_DELAY)
+}
+
+/// Publishes pending outbox events to every sink, returning how many were claimed.
+/// An event is marked published once all sinks accepted it, so after a failure the
+/// sinks that already had it see it again under the same id
+pub struct RelayOutboxUseCase<R: OutboxRepository> {
+    repository: R,
+    sinks: Vec<Box<dyn EventSink>>,
+}
+
+impl<R: OutboxRepository> RelayOutboxUseCase<R> {
+    pub fn new(repository: R, sinks: Vec<Box<dyn EventSink>>) -> Self {
+        Self { repository, sinks }
+    }
+
+    /// Hands the event to the sinks in order, stopping at the first failure
+    async fn publish(&self, event: &OutboxEvent) -> Result<(), String> {
+        for sink in &self.sinks {
+            sink.publish(event)
+                .await
+                .map_err(|e| format!("{}: {}", sink.name(), e))?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<R: OutboxRepository + Send + Sync> UseCase<(), usize, ApplicationError>
+    for RelayOutboxUseCase<R>
+{
+    #[instrument(name = "RelayOutboxUseCase::execute", skip_all)]
+    async fn execute(&self, _: ()) -> Result<usize, ApplicationError> {
+        let events = self
+            .repository
+            .claim_pending(BATCH_SIZE, Utc::now() + LEASE)
+            .await?;
+
+        // One at a time, so sinks receive events in the order they were recorded
+        for event in &events {
+            // A lost result only means the event is relayed again once its lease ends
+            let recorded = match self.publish(event).await {
+                Ok(()) => self.repository.mark_published(event.id).await,
+                Err(reason) => {
+                    warn!(
+                        "Error relaying {} event {} (attempt {}): {}",
+                        event.event_type, event.id, event.attempts, reason
+                    );
+                    let retry_at = Utc::now() + retry_delay(event.attempts);
+                    self.repository
+                        .mark_failed(event.id, &reason, retry_at)
+                        .await
+                }
+            };
+            if let Err(e) = recorded {
+                error!("Error recording relay of event {}: {:?}", event.id, e);
+            }
+        }
+
+        Ok(events.len())
+    }
+}
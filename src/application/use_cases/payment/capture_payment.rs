@@ -0,0 +1,85 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::{
+        live_update::LiveUpdate,
+        payment::{Payment, PaymentStatus},
+    },
+    gateways::{PaymentGateway, PaymentGatewayError, UpdateBus},
+    repositories::PaymentRepository,
+};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct CapturePaymentUseCase<R: PaymentRepository, G: PaymentGateway, B: UpdateBus> {
+    payments: R,
+    gateway: G,
+    updates: B,
+}
+
+impl<R: PaymentRepository, G: PaymentGateway, B: UpdateBus> CapturePaymentUseCase<R, G, B> {
+    pub fn new(payments: R, gateway: G, updates: B) -> Self {
+        Self {
+            payments,
+            gateway,
+            updates,
+        }
+    }
+}
+
+#[async_trait]
+impl<R: PaymentRepository + Send + Sync, G: PaymentGateway + Send + Sync, B: UpdateBus>
+    UseCase<Uuid, Payment, ApplicationError> for CapturePaymentUseCase<R, G, B>
+{
+    #[instrument(name = "CapturePaymentUseCase::execute", skip_all)]
+    async fn execute(&self, id: Uuid) -> Result<Payment, ApplicationError> {
+        let payment = self
+            .payments
+            .find_by_id(id)
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+
+        if payment.status != PaymentStatus::RequiresCapture {
+            return Err(ApplicationError::Validation(
+                "Payment is not awaiting capture".to_string(),
+            ));
+        }
+
+        // Capture funds with the provider, a declined capture fails the payment
+        match self.gateway.capture(&payment.intent_id).await {
+            Ok(intent) => self.record(&payment, intent.status).await?,
+            Err(PaymentGatewayError::Declined(reason)) => {
+                self.record(&payment, PaymentStatus::Failed).await?;
+                return Err(PaymentGatewayError::Declined(reason).into());
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        self.payments
+            .find_by_id(id)
+            .await?
+            .ok_or(ApplicationError::NotFound)
+    }
+}
+
+impl<R: PaymentRepository, G: PaymentGateway, B: UpdateBus> CapturePaymentUseCase<R, G, B> {
+    /// Moves the payment out of `requires_capture` and its order along with it
+    async fn record(
+        &self,
+        payment: &Payment,
+        status: PaymentStatus,
+    ) -> Result<(), ApplicationError> {
+        let order_status = status.order_status();
+        let applied = self
+            .payments
+            .transition(payment.id, payment.status, status, order_status, None)
+            .await?;
+        if let (true, Some(status)) = (applied, order_status) {
+            self.updates.publish(LiveUpdate::OrderStatusChanged {
+                order_id: payment.order_id,
+                status,
+            });
+        }
+        Ok(())
+    }
+}
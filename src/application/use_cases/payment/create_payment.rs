@@ -0,0 +1,97 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::{
+        live_update::LiveUpdate,
+        payment::{CreatePaymentDto, Payment},
+    },
+    gateways::{payment_gateway::PaymentIntentRequest, PaymentGateway, UpdateBus},
+    repositories::{OrderRepository, PaymentRepository},
+};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct CreatePaymentUseCase<
+    O: OrderRepository,
+    R: PaymentRepository,
+    G: PaymentGateway,
+    B: UpdateBus,
+> {
+    orders: O,
+    payments: R,
+    gateway: G,
+    updates: B,
+}
+
+impl<O: OrderRepository, R: PaymentRepository, G: PaymentGateway, B: UpdateBus>
+    CreatePaymentUseCase<O, R, G, B>
+{
+    pub fn new(orders: O, payments: R, gateway: G, updates: B) -> Self {
+        Self {
+            orders,
+            payments,
+            gateway,
+            updates,
+        }
+    }
+}
+
+#[async_trait]
+impl<O, R, G, B> UseCase<Uuid, Payment, ApplicationError> for CreatePaymentUseCase<O, R, G, B>
+where
+    O: OrderRepository + Send + Sync,
+    R: PaymentRepository + Send + Sync,
+    G: PaymentGateway + Send + Sync,
+    B: UpdateBus,
+{
+    #[instrument(name = "CreatePaymentUseCase::execute", skip_all)]
+    async fn execute(&self, order_id: Uuid) -> Result<Payment, ApplicationError> {
+        let order = self
+            .orders
+            .find_by_id(order_id)
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+
+        if !order.status.accepts_payment() {
+            return Err(ApplicationError::Validation(
+                "Order is not awaiting payment".to_string(),
+            ));
+        }
+
+        // Open a payment intent with the provider
+        let payment_id = Uuid::new_v4();
+        let intent = self
+            .gateway
+            .create_intent(PaymentIntentRequest {
+                payment_id,
+                amount: order.total,
+                currency: order.currency.clone(),
+            })
+            .await?;
+
+        let order_status = intent.status.order_status();
+        let payment = self
+            .payments
+            .create(
+                CreatePaymentDto {
+                    id: payment_id,
+                    order_id: order.id,
+                    provider: self.gateway.provider().to_string(),
+                    intent_id: intent.intent_id,
+                    amount: order.total,
+                    currency: order.currency,
+                    status: intent.status,
+                },
+                order_status,
+            )
+            .await?;
+        if let Some(status) = order_status {
+            self.updates.publish(LiveUpdate::OrderStatusChanged {
+                order_id: order.id,
+                status,
+            });
+        }
+
+        Ok(payment)
+    }
+}
@@ -0,0 +1,223 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::live_update::LiveUpdate,
+    gateways::{PaymentGateway, UpdateBus},
+    repositories::PaymentRepository,
+};
+use async_trait::async_trait;
+use log::{info, warn};
+use tracing::instrument;
+
+/// Raw webhook request as received from the provider
+pub struct PaymentWebhookInput {
+    pub payload: Vec<u8>,
+    pub signature: String,
+}
+
+/// Applies provider callbacks to payments and their orders.
+///
+/// Redelivered events and transitions that are no longer valid are acknowledged
+/// without changing anything, so providers can retry safely.
+pub struct HandlePaymentWebhookUseCase<R: PaymentRepository, G: PaymentGateway, B: UpdateBus> {
+    payments: R,
+    gateway: G,
+    updates: B,
+}
+
+impl<R: PaymentRepository, G: PaymentGateway, B: UpdateBus> HandlePaymentWebhookUseCase<R, G, B> {
+    pub fn new(payments: R, gateway: G, updates: B) -> Self {
+        Self {
+            payments,
+            gateway,
+            updates,
+        }
+    }
+}
+
+#[async_trait]
+impl<R: PaymentRepository + Send + Sync, G: PaymentGateway + Send + Sync, B: UpdateBus>
+    UseCase<PaymentWebhookInput, (), ApplicationError> for HandlePaymentWebhookUseCase<R, G, B>
+{
+    #[instrument(name = "HandlePaymentWebhookUseCase::execute", skip_all)]
+    async fn execute(&self, input: PaymentWebhookInput) -> Result<(), ApplicationError> {
+        // Verify and decode the callback
+        let event = self
+            .gateway
+            .parse_webhook(&input.payload, &input.signature)?;
+
+        let payment = self
+            .payments
+            .find_by_intent_id(&event.intent_id)
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+
+        if !payment.status.can_transition_to(event.status) {
+            info!(
+                "Ignoring webhook {} for payment {}: {:?} -> {:?}",
+                event.event_id, payment.id, payment.status, event.status
+            );
+            return Ok(());
+        }
+
+        let applied = self
+            .payments
+            .transition(
+                payment.id,
+                payment.status,
+                event.status,
+                event.status.order_status(),
+                Some(&event.event_id),
+            )
+            .await?;
+
+        if !applied {
+            warn!(
+                "Webhook {} for payment {} was already processed or superseded",
+                event.event_id, payment.id
+            );
+        } else if let Some(status) = event.status.order_status() {
+            self.updates.publish(LiveUpdate::OrderStatusChanged {
+                order_id: payment.order_id,
+                status,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        entities::{
+            domain_event::DomainEvent,
+            order::OrderStatus,
+            payment::{CreatePaymentDto, PaymentStatus},
+            user::{CreateUserDto, User},
+        },
+        repositories::UserRepository,
+    };
+    use crate::infrastructure::{
+        events::BroadcastUpdateBus,
+        payments::{FakeOutcome, FakePaymentGateway},
+        persistence::postgres::{PostgresPaymentRepository, PostgresUserRepository},
+    };
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+    use sqlx::PgPool;
+    use uuid::Uuid;
+
+    /// A payment waiting for a 3-D Secure challenge, with its order and user
+    async fn pending_payment(pool: &PgPool) -> (Uuid, Uuid, String) {
+        let name = Uuid::new_v4().simple().to_string();
+        let user = PostgresUserRepository::new(pool.clone())
+            .create(
+                CreateUserDto {
+                    email: format!("{name}@example.com"),
+                    username: name,
+                    password: "secret".to_string(),
+                },
+                |user: &User| DomainEvent::UserCreated(user.clone()),
+            )
+            .await
+            .unwrap();
+        let order_id: Uuid = sqlx::query_scalar(
+            "INSERT INTO orders (user_id, total, currency) VALUES ($1, 10, 'USD') RETURNING id",
+        )
+        .bind(user.id)
+        .fetch_one(pool)
+        .await
+        .unwrap();
+
+        let payment_id = Uuid::new_v4();
+        let intent_id = format!("fake_pi_{}", payment_id.simple());
+        PostgresPaymentRepository::new(pool.clone())
+            .create(
+                CreatePaymentDto {
+                    id: payment_id,
+                    order_id,
+                    provider: "fake".to_string(),
+                    intent_id: intent_id.clone(),
+                    amount: dec!(10),
+                    currency: "USD".to_string(),
+                    status: PaymentStatus::RequiresAction,
+                },
+                None,
+            )
+            .await
+            .unwrap();
+
+        (payment_id, order_id, intent_id)
+    }
+
+    async fn statuses(pool: &PgPool, payment_id: Uuid) -> (PaymentStatus, OrderStatus) {
+        sqlx::query_as(
+            r#"
+            SELECT p.status, o.status FROM payments p JOIN orders o ON o.id = p.order_id
+            WHERE p.id = $1
+            "#,
+        )
+        .bind(payment_id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Postgres at DATABASE_URL"]
+    async fn ignores_redelivered_events_and_illegal_transitions() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        let pool = PgPool::connect(&url)
+            .await
+            .expect("Database is not reachable");
+        let (payment_id, order_id, intent_id) = pending_payment(&pool).await;
+        let gateway = FakePaymentGateway::new(FakeOutcome::Succeed, "whsec_fake");
+        let webhook = |event_id: &str, event_type: &str| {
+            let payload = json!({ "id": event_id, "type": event_type, "intent_id": intent_id })
+                .to_string()
+                .into_bytes();
+            let signature = gateway.sign(&payload);
+            PaymentWebhookInput { payload, signature }
+        };
+        let updates = BroadcastUpdateBus::new();
+        let mut published = updates.subscribe();
+        let use_case = HandlePaymentWebhookUseCase::new(
+            PostgresPaymentRepository::new(pool.clone()),
+            FakePaymentGateway::new(FakeOutcome::Succeed, "whsec_fake"),
+            updates,
+        );
+
+        let event_id = format!("evt_{}", Uuid::new_v4().simple());
+        use_case
+            .execute(webhook(&event_id, "payment_intent.succeeded"))
+            .await
+            .unwrap();
+        assert_eq!(
+            statuses(&pool, payment_id).await,
+            (PaymentStatus::Succeeded, OrderStatus::Paid)
+        );
+        assert!(matches!(
+            published.try_recv().unwrap().as_ref(),
+            LiveUpdate::OrderStatusChanged { order_id: id, status: OrderStatus::Paid } if *id == order_id
+        ));
+
+        // The same event id again, even for a transition that would be allowed now
+        use_case
+            .execute(webhook(&event_id, "charge.refunded"))
+            .await
+            .unwrap();
+        // A payment that succeeded cannot fail anymore
+        let failed_id = format!("evt_{}", Uuid::new_v4().simple());
+        use_case
+            .execute(webhook(&failed_id, "payment_intent.payment_failed"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            statuses(&pool, payment_id).await,
+            (PaymentStatus::Succeeded, OrderStatus::Paid)
+        );
+        assert!(published.try_recv().is_err());
+    }
+}
@@ -0,0 +1,9 @@
+pub mod capture_payment;
+pub mod create_payment;
+pub mod handle_payment_webhook;
+pub mod refund_payment;
+
+pub use capture_payment::CapturePaymentUseCase;
+pub use create_payment::CreatePaymentUseCase;
+pub use handle_payment_webhook::{HandlePaymentWebhookUseCase, PaymentWebhookInput};
+pub use refund_payment::RefundPaymentUseCase;
@@ -0,0 +1,76 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::{
+        live_update::LiveUpdate,
+        payment::{Payment, PaymentStatus},
+    },
+    gateways::{PaymentGateway, UpdateBus},
+    repositories::PaymentRepository,
+};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct RefundPaymentUseCase<R: PaymentRepository, G: PaymentGateway, B: UpdateBus> {
+    payments: R,
+    gateway: G,
+    updates: B,
+}
+
+impl<R: PaymentRepository, G: PaymentGateway, B: UpdateBus> RefundPaymentUseCase<R, G, B> {
+    pub fn new(payments: R, gateway: G, updates: B) -> Self {
+        Self {
+            payments,
+            gateway,
+            updates,
+        }
+    }
+}
+
+#[async_trait]
+impl<R: PaymentRepository + Send + Sync, G: PaymentGateway + Send + Sync, B: UpdateBus>
+    UseCase<Uuid, Payment, ApplicationError> for RefundPaymentUseCase<R, G, B>
+{
+    #[instrument(name = "RefundPaymentUseCase::execute", skip_all)]
+    async fn execute(&self, id: Uuid) -> Result<Payment, ApplicationError> {
+        let payment = self
+            .payments
+            .find_by_id(id)
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+
+        if payment.status != PaymentStatus::Succeeded {
+            return Err(ApplicationError::Validation(
+                "Only succeeded payments can be refunded".to_string(),
+            ));
+        }
+
+        // Refund the full amount with the provider
+        let intent = self
+            .gateway
+            .refund(&payment.intent_id, payment.amount)
+            .await?;
+        let order_status = intent.status.order_status();
+        let applied = self
+            .payments
+            .transition(
+                payment.id,
+                payment.status,
+                intent.status,
+                order_status,
+                None,
+            )
+            .await?;
+        if let (true, Some(status)) = (applied, order_status) {
+            self.updates.publish(LiveUpdate::OrderStatusChanged {
+                order_id: payment.order_id,
+                status,
+            });
+        }
+
+        self.payments
+            .find_by_id(id)
+            .await?
+            .ok_or(ApplicationError::NotFound)
+    }
+}
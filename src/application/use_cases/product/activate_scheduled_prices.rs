@@ -0,0 +1,32 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{entities::domain_event::DomainEvent, repositories::ProductPriceRepository};
+use async_trait::async_trait;
+use chrono::Utc;
+use tracing::instrument;
+
+/// Brings product prices in line with the price history, starting and ending scheduled prices
+pub struct ActivateScheduledPricesUseCase<H: ProductPriceRepository> {
+    prices: H,
+}
+
+impl<H: ProductPriceRepository> ActivateScheduledPricesUseCase<H> {
+    pub fn new(prices: H) -> Self {
+        Self { prices }
+    }
+}
+
+#[async_trait]
+impl<H: ProductPriceRepository + Send + Sync> UseCase<(), u64, ApplicationError>
+    for ActivateScheduledPricesUseCase<H>
+{
+    #[instrument(name = "ActivateScheduledPricesUseCase::execute", skip_all)]
+    async fn execute(&self, _: ()) -> Result<u64, ApplicationError> {
+        let activated = self
+            .prices
+            .activate_due(Utc::now(), |product| {
+                DomainEvent::ProductUpdated(product.clone())
+            })
+            .await?;
+        Ok(activated)
+    }
+}
@@ -0,0 +1,89 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::{
+        domain_event::DomainEvent,
+        product::CreateProductDto,
+        product_bulk::{BulkItemResult, BulkReport},
+    },
+    repositories::ProductRepository,
+};
+use async_trait::async_trait;
+use tracing::instrument;
+
+use super::create_product::validate_product;
+
+/// Most items a single bulk request may carry
+pub const BULK_MAX_ITEMS: usize = 1000;
+
+pub fn validate_bulk_size(len: usize) -> Result<(), ApplicationError> {
+    if len == 0 {
+        return Err(ApplicationError::Validation(
+            "At least one item is required".to_string(),
+        ));
+    }
+
+    if len > BULK_MAX_ITEMS {
+        return Err(ApplicationError::Validation(format!(
+            "At most {} items can be sent at once",
+            BULK_MAX_ITEMS
+        )));
+    }
+
+    Ok(())
+}
+
+pub struct BulkCreateProductsUseCase<R: ProductRepository> {
+    repository: R,
+}
+
+impl<R: ProductRepository> BulkCreateProductsUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: ProductRepository + Send + Sync>
+    UseCase<(Vec<CreateProductDto>, bool), BulkReport, ApplicationError>
+    for BulkCreateProductsUseCase<R>
+{
+    #[instrument(name = "BulkCreateProductsUseCase::execute", skip_all)]
+    async fn execute(
+        &self,
+        input: (Vec<CreateProductDto>, bool),
+    ) -> Result<BulkReport, ApplicationError> {
+        let (products, atomic) = input;
+
+        // Validate input
+        validate_bulk_size(products.len())?;
+        let mut results = Vec::new();
+        let mut valid = Vec::with_capacity(products.len());
+        for (index, product) in products.into_iter().enumerate() {
+            match validate_product(&product) {
+                Ok(()) => valid.push((index, product)),
+                Err(ApplicationError::Validation(reason)) => {
+                    results.push(BulkItemResult::failed(index, None, reason))
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        if atomic && !results.is_empty() {
+            results.extend(
+                valid
+                    .into_iter()
+                    .map(|(index, _)| BulkItemResult::not_applied(index, None)),
+            );
+        } else if !valid.is_empty() {
+            results.extend(
+                self.repository
+                    .bulk_create(valid, atomic, |product| {
+                        DomainEvent::ProductCreated(product.clone())
+                    })
+                    .await?,
+            );
+        }
+
+        Ok(BulkReport::new(atomic, results))
+    }
+}
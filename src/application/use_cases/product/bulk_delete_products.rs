@@ -0,0 +1,67 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::{
+        domain_event::DomainEvent,
+        product_bulk::{BulkItemResult, BulkReport},
+    },
+    repositories::ProductRepository,
+};
+use async_trait::async_trait;
+use std::collections::HashSet;
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::bulk_create_products::validate_bulk_size;
+
+pub struct BulkDeleteProductsUseCase<R: ProductRepository> {
+    repository: R,
+}
+
+impl<R: ProductRepository> BulkDeleteProductsUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: ProductRepository + Send + Sync> UseCase<(Vec<Uuid>, bool), BulkReport, ApplicationError>
+    for BulkDeleteProductsUseCase<R>
+{
+    #[instrument(name = "BulkDeleteProductsUseCase::execute", skip_all)]
+    async fn execute(&self, input: (Vec<Uuid>, bool)) -> Result<BulkReport, ApplicationError> {
+        let (ids, atomic) = input;
+
+        // Validate input
+        validate_bulk_size(ids.len())?;
+        let mut results = Vec::new();
+        let mut valid = Vec::with_capacity(ids.len());
+        let mut seen = HashSet::new();
+        for (index, id) in ids.into_iter().enumerate() {
+            if seen.insert(id) {
+                valid.push((index, id));
+            } else {
+                results.push(BulkItemResult::failed(
+                    index,
+                    Some(id),
+                    "Product appears more than once",
+                ));
+            }
+        }
+
+        if atomic && !results.is_empty() {
+            results.extend(
+                valid
+                    .into_iter()
+                    .map(|(index, id)| BulkItemResult::not_applied(index, Some(id))),
+            );
+        } else {
+            results.extend(
+                self.repository
+                    .bulk_delete(valid, atomic, |id| DomainEvent::ProductDeleted(*id))
+                    .await?,
+            );
+        }
+
+        Ok(BulkReport::new(atomic, results))
+    }
+}
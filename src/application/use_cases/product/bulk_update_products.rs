@@ -0,0 +1,87 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::{
+        domain_event::DomainEvent,
+        product_bulk::{BulkItemResult, BulkReport, BulkUpdateProductDto},
+    },
+    repositories::{ProductRepository, RepositoryError},
+};
+use async_trait::async_trait;
+use std::collections::HashSet;
+use tracing::instrument;
+
+use super::{bulk_create_products::validate_bulk_size, update_product::validate_product_update};
+
+pub struct BulkUpdateProductsUseCase<R: ProductRepository> {
+    repository: R,
+}
+
+impl<R: ProductRepository> BulkUpdateProductsUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: ProductRepository + Send + Sync>
+    UseCase<(Vec<BulkUpdateProductDto>, bool), BulkReport, ApplicationError>
+    for BulkUpdateProductsUseCase<R>
+{
+    #[instrument(name = "BulkUpdateProductsUseCase::execute", skip_all)]
+    async fn execute(
+        &self,
+        input: (Vec<BulkUpdateProductDto>, bool),
+    ) -> Result<BulkReport, ApplicationError> {
+        let (updates, atomic) = input;
+
+        // Validate input
+        validate_bulk_size(updates.len())?;
+        let mut results = Vec::new();
+        let mut valid = Vec::with_capacity(updates.len());
+        let mut seen = HashSet::new();
+        for (index, update) in updates.into_iter().enumerate() {
+            if !seen.insert(update.id) {
+                results.push(BulkItemResult::failed(
+                    index,
+                    Some(update.id),
+                    "Product appears more than once",
+                ));
+                continue;
+            }
+            match validate_product_update(&update.changes) {
+                Ok(()) => valid.push((index, update)),
+                Err(ApplicationError::Validation(reason)) => {
+                    results.push(BulkItemResult::failed(index, Some(update.id), reason))
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        if atomic && !results.is_empty() {
+            results.extend(
+                valid
+                    .into_iter()
+                    .map(|(index, update)| BulkItemResult::not_applied(index, Some(update.id))),
+            );
+        } else if !valid.is_empty() {
+            match self
+                .repository
+                .bulk_update(valid, atomic, |product| {
+                    DomainEvent::ProductUpdated(product.clone())
+                })
+                .await
+            {
+                Ok(updated) => results.extend(updated),
+                // Lost a race for a SKU after the repository checked it
+                Err(RepositoryError::DuplicateEntry) => {
+                    return Err(ApplicationError::Validation(
+                        "A product with this SKU already exists".to_string(),
+                    ))
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        Ok(BulkReport::new(atomic, results))
+    }
+}
@@ -1,10 +1,15 @@
 use crate::application::{error::ApplicationError, use_cases::UseCase};
 use crate::domain::{
-    entities::product::{CreateProductDto, Product},
-    repositories::ProductRepository,
+    entities::{
+        domain_event::DomainEvent,
+        exchange_rate::is_currency_code,
+        product::{CreateProductDto, Product},
+    },
+    repositories::{ProductRepository, RepositoryError},
 };
 use async_trait::async_trait;
 use rust_decimal_macros::dec;
+use tracing::instrument;
 
 pub struct CreateProductUseCase<R: ProductRepository> {
     repository: R,
@@ -20,28 +25,69 @@ impl<R: ProductRepository> CreateProductUseCase<R> {
 impl<R: ProductRepository + Send + Sync> UseCase<CreateProductDto, Product, ApplicationError>
     for CreateProductUseCase<R>
 {
+    #[instrument(name = "CreateProductUseCase::execute", skip_all)]
     async fn execute(&self, input: CreateProductDto) -> Result<Product, ApplicationError> {
         // Validate input
-        if input.name.is_empty() {
-            return Err(ApplicationError::Validation(
-                "Product name is required".to_string(),
-            ));
-        }
+        validate_product(&input)?;
 
-        if input.price < dec!(0) {
-            return Err(ApplicationError::Validation(
-                "Price cannot be negative".to_string(),
-            ));
+        // Create product
+        match self
+            .repository
+            .create(input, |product| {
+                DomainEvent::ProductCreated(product.clone())
+            })
+            .await
+        {
+            Ok(product) => Ok(product),
+            Err(RepositoryError::DuplicateEntry) => Err(ApplicationError::Validation(
+                "A product with this SKU already exists".to_string(),
+            )),
+            Err(e) => Err(e.into()),
         }
+    }
+}
 
-        if input.stock < 0 {
+/// Rules every new product must satisfy, shared with the CSV import
+pub fn validate_product(input: &CreateProductDto) -> Result<(), ApplicationError> {
+    if let Some(sku) = &input.sku {
+        validate_sku(sku)?;
+    }
+
+    if input.name.is_empty() {
+        return Err(ApplicationError::Validation(
+            "Product name is required".to_string(),
+        ));
+    }
+
+    if input.price < dec!(0) {
+        return Err(ApplicationError::Validation(
+            "Price cannot be negative".to_string(),
+        ));
+    }
+
+    if let Some(currency) = &input.currency {
+        if !is_currency_code(currency) {
             return Err(ApplicationError::Validation(
-                "Stock cannot be negative".to_string(),
+                "Currency must be an ISO 4217 code".to_string(),
             ));
         }
+    }
 
-        // Create product
-        let product = self.repository.create(input).await?;
-        Ok(product)
+    if input.stock < 0 {
+        return Err(ApplicationError::Validation(
+            "Stock cannot be negative".to_string(),
+        ));
     }
+
+    Ok(())
+}
+
+pub fn validate_sku(sku: &str) -> Result<(), ApplicationError> {
+    if sku.trim().is_empty() || sku.len() > 64 {
+        return Err(ApplicationError::Validation(
+            "SKU must be between 1 and 64 characters".to_string(),
+        ));
+    }
+
+    Ok(())
 }
@@ -1,6 +1,7 @@
 use crate::application::{error::ApplicationError, use_cases::UseCase};
-use crate::domain::repositories::ProductRepository;
+use crate::domain::{entities::domain_event::DomainEvent, repositories::ProductRepository};
 use async_trait::async_trait;
+use tracing::instrument;
 use uuid::Uuid;
 
 pub struct DeleteProductUseCase<R: ProductRepository> {
@@ -17,6 +18,7 @@ impl<R: ProductRepository> DeleteProductUseCase<R> {
 impl<R: ProductRepository + Send + Sync> UseCase<Uuid, (), ApplicationError>
     for DeleteProductUseCase<R>
 {
+    #[instrument(name = "DeleteProductUseCase::execute", skip_all)]
     async fn execute(&self, id: Uuid) -> Result<(), ApplicationError> {
         // Check if product exists
         if self.repository.find_by_id(id).await?.is_none() {
@@ -24,7 +26,9 @@ impl<R: ProductRepository + Send + Sync> UseCase<Uuid, (), ApplicationError>
         }
 
         // Delete product
-        self.repository.delete(id).await?;
+        self.repository
+            .delete(id, |id| DomainEvent::ProductDeleted(*id))
+            .await?;
         Ok(())
     }
 }
@@ -0,0 +1,34 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{entities::product::Product, repositories::ProductRepository};
+use async_trait::async_trait;
+use futures::{stream::BoxStream, StreamExt};
+use tracing::instrument;
+
+/// Streams every product for exports too large to collect in memory
+pub struct ExportProductsUseCase<R: ProductRepository> {
+    repository: R,
+}
+
+impl<R: ProductRepository> ExportProductsUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: ProductRepository + Send + Sync>
+    UseCase<(), BoxStream<'static, Result<Product, ApplicationError>>, ApplicationError>
+    for ExportProductsUseCase<R>
+{
+    #[instrument(name = "ExportProductsUseCase::execute", skip_all)]
+    async fn execute(
+        &self,
+        _: (),
+    ) -> Result<BoxStream<'static, Result<Product, ApplicationError>>, ApplicationError> {
+        Ok(self
+            .repository
+            .stream()
+            .map(|row| row.map_err(ApplicationError::from))
+            .boxed())
+    }
+}
@@ -0,0 +1,36 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::product_price::ProductPrice,
+    repositories::{ProductPriceRepository, ProductRepository},
+};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct GetPriceHistoryUseCase<R: ProductRepository, H: ProductPriceRepository> {
+    repository: R,
+    prices: H,
+}
+
+impl<R: ProductRepository, H: ProductPriceRepository> GetPriceHistoryUseCase<R, H> {
+    pub fn new(repository: R, prices: H) -> Self {
+        Self { repository, prices }
+    }
+}
+
+#[async_trait]
+impl<R: ProductRepository + Send + Sync, H: ProductPriceRepository + Send + Sync>
+    UseCase<Uuid, Vec<ProductPrice>, ApplicationError> for GetPriceHistoryUseCase<R, H>
+{
+    #[instrument(name = "GetPriceHistoryUseCase::execute", skip_all)]
+    async fn execute(&self, id: Uuid) -> Result<Vec<ProductPrice>, ApplicationError> {
+        // Check if product exists
+        if self.repository.find_by_id(id).await?.is_none() {
+            return Err(ApplicationError::NotFound);
+        }
+
+        // Fetch past, current and scheduled prices
+        let prices = self.prices.list(id).await?;
+        Ok(prices)
+    }
+}
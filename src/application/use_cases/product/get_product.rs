@@ -1,6 +1,7 @@
 use crate::application::{error::ApplicationError, use_cases::UseCase};
 use crate::domain::{entities::product::Product, repositories::ProductRepository};
 use async_trait::async_trait;
+use tracing::instrument;
 use uuid::Uuid;
 
 pub struct GetProductUseCase<R: ProductRepository> {
@@ -17,6 +18,7 @@ impl<R: ProductRepository> GetProductUseCase<R> {
 impl<R: ProductRepository + Send + Sync> UseCase<Uuid, Product, ApplicationError>
     for GetProductUseCase<R>
 {
+    #[instrument(name = "GetProductUseCase::execute", skip_all)]
     async fn execute(&self, id: Uuid) -> Result<Product, ApplicationError> {
         // Fetch product by ID
         let product = self
@@ -0,0 +1,45 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::product::Product,
+    repositories::{ProductPriceRepository, ProductRepository},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tracing::instrument;
+use uuid::Uuid;
+
+/// Fetches a product with the price that was valid at a given moment
+pub struct GetProductAtUseCase<R: ProductRepository, H: ProductPriceRepository> {
+    repository: R,
+    prices: H,
+}
+
+impl<R: ProductRepository, H: ProductPriceRepository> GetProductAtUseCase<R, H> {
+    pub fn new(repository: R, prices: H) -> Self {
+        Self { repository, prices }
+    }
+}
+
+#[async_trait]
+impl<R: ProductRepository + Send + Sync, H: ProductPriceRepository + Send + Sync>
+    UseCase<(Uuid, DateTime<Utc>), Product, ApplicationError> for GetProductAtUseCase<R, H>
+{
+    #[instrument(name = "GetProductAtUseCase::execute", skip_all)]
+    async fn execute(&self, input: (Uuid, DateTime<Utc>)) -> Result<Product, ApplicationError> {
+        let (id, at) = input;
+
+        let mut product = self
+            .repository
+            .find_by_id(id)
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+
+        // Replace the current price with the one in effect at `at`
+        let price = self.prices.price_at(id, at).await?.ok_or_else(|| {
+            ApplicationError::Validation(format!("No price was in effect at {at}"))
+        })?;
+        product.price = price.price;
+
+        Ok(product)
+    }
+}
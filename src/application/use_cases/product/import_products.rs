@@ -0,0 +1,312 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::{
+        domain_event::DomainEvent,
+        product::CreateProductDto,
+        product_import::{ImportReport, ImportRowResult, ProductImportRow},
+    },
+    repositories::ProductRepository,
+};
+use async_trait::async_trait;
+use csv_async::{AsyncReaderBuilder, StringRecord, Trim};
+use log::error;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use tokio::io::AsyncRead;
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::create_product::validate_product;
+
+/// Rows the import saves per transaction
+pub const IMPORT_BATCH_SIZE: usize = 500;
+
+/// Columns that must appear in the header of an import file
+const IMPORT_REQUIRED_COLUMNS: [&str; 3] = ["name", "price", "stock"];
+
+/// One row of a product import CSV. Columns are matched by header name, unknown
+/// columns are ignored and empty cells count as absent
+#[derive(Debug, Deserialize)]
+struct ProductCsvRecord {
+    id: Option<Uuid>,
+    sku: Option<String>,
+    name: String,
+    description: Option<String>,
+    category: Option<String>,
+    price: Decimal,
+    currency: Option<String>,
+    stock: i32,
+}
+
+impl ProductCsvRecord {
+    fn into_row(self, line: u64) -> ProductImportRow {
+        ProductImportRow {
+            line,
+            id: self.id,
+            product: CreateProductDto {
+                sku: self.sku,
+                name: self.name,
+                description: self.description.unwrap_or_default(),
+                category: self.category,
+                price: self.price,
+                currency: self.currency,
+                stock: self.stock,
+            },
+        }
+    }
+}
+
+/// Human readable reason a CSV row could not be read
+fn describe_csv_error(e: &csv_async::Error, headers: &StringRecord) -> String {
+    match e.kind() {
+        csv_async::ErrorKind::Deserialize { err, .. } => {
+            let column = err
+                .field()
+                .and_then(|index| headers.get(index as usize))
+                .unwrap_or("unknown");
+            format!("Invalid value in column '{}': {}", column, err.kind())
+        }
+        csv_async::ErrorKind::UnequalLengths {
+            expected_len, len, ..
+        } => format!("Expected {} columns, found {}", expected_len, len),
+        _ => e.to_string(),
+    }
+}
+
+/// A CSV file to import, read as it arrives
+pub struct ImportProductsInput<S> {
+    pub csv: S,
+    pub dry_run: bool,
+}
+
+/// Creates or updates products from a CSV file, row by row and in batches so the file
+/// is never held in memory.
+///
+/// Rows that cannot be read or break the product rules are reported as failed. When a
+/// batch cannot be saved or the file breaks off the import stops, and the report covers
+/// the rows before the line it stopped at.
+pub struct ImportProductsUseCase<R: ProductRepository> {
+    repository: R,
+}
+
+impl<R: ProductRepository> ImportProductsUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+
+    /// Upserts one batch of rows, rejecting rows that break the product rules
+    async fn import_batch(
+        &self,
+        rows: Vec<ProductImportRow>,
+        dry_run: bool,
+    ) -> Result<Vec<ImportRowResult>, ApplicationError> {
+        // Validate input
+        let mut results = Vec::new();
+        let mut valid = Vec::with_capacity(rows.len());
+        for row in rows {
+            match validate_product(&row.product) {
+                Ok(()) => valid.push(row),
+                Err(ApplicationError::Validation(reason)) => {
+                    results.push(ImportRowResult::failed(row.line, row.product.sku, reason))
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        if !valid.is_empty() {
+            results.extend(
+                self.repository
+                    .import_batch(
+                        valid,
+                        dry_run,
+                        |product| DomainEvent::ProductCreated(product.clone()),
+                        |product| DomainEvent::ProductUpdated(product.clone()),
+                    )
+                    .await?,
+            );
+        }
+
+        Ok(results)
+    }
+
+    /// Records a saved batch, or stops the report at its first line
+    async fn save(
+        &self,
+        report: &mut ImportReport,
+        rows: Vec<ProductImportRow>,
+        dry_run: bool,
+    ) -> bool {
+        let first_line = rows.first().map(|row| row.line).unwrap_or_default();
+        match self.import_batch(rows, dry_run).await {
+            Ok(results) => {
+                report.record(results);
+                true
+            }
+            Err(e) => {
+                error!("Error importing products from line {}: {:?}", first_line, e);
+                report.interrupt(first_line, "Internal server error");
+                false
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<R, S> UseCase<ImportProductsInput<S>, ImportReport, ApplicationError>
+    for ImportProductsUseCase<R>
+where
+    R: ProductRepository + Send + Sync,
+    S: AsyncRead + Unpin + Send + 'static,
+{
+    #[instrument(name = "ImportProductsUseCase::execute", skip_all)]
+    async fn execute(
+        &self,
+        input: ImportProductsInput<S>,
+    ) -> Result<ImportReport, ApplicationError> {
+        let ImportProductsInput { csv, dry_run } = input;
+
+        // Validate the header
+        let mut reader = AsyncReaderBuilder::new().trim(Trim::All).create_reader(csv);
+        let headers = reader
+            .headers()
+            .await
+            .map_err(|e| ApplicationError::Validation(e.to_string()))?
+            .clone();
+        if let Some(missing) = IMPORT_REQUIRED_COLUMNS
+            .iter()
+            .find(|column| !headers.iter().any(|header| header == **column))
+        {
+            return Err(ApplicationError::Validation(format!(
+                "Missing required column '{}'",
+                missing
+            )));
+        }
+        let sku_index = headers.iter().position(|header| header == "sku");
+
+        let mut report = ImportReport::new(dry_run);
+        let mut batch = Vec::with_capacity(IMPORT_BATCH_SIZE);
+        let mut record = StringRecord::new();
+
+        loop {
+            let parsed = match reader.read_record(&mut record).await {
+                Ok(false) => break,
+                Ok(true) => record.deserialize::<ProductCsvRecord>(Some(&headers)),
+                Err(e) => Err(e),
+            };
+            match parsed {
+                Ok(product) => {
+                    let line = record.position().map(|pos| pos.line()).unwrap_or_default();
+                    batch.push(product.into_row(line));
+                }
+                Err(e) => {
+                    // Bad rows are reported, only an unreadable stream stops the import
+                    let position = match e.kind() {
+                        csv_async::ErrorKind::Io(_) => None,
+                        _ => e.position().or(record.position()),
+                    };
+                    let Some(line) = position.map(|pos| pos.line()) else {
+                        let rows = std::mem::take(&mut batch);
+                        if self.save(&mut report, rows, dry_run).await {
+                            report.interrupt(reader.position().line(), e.to_string());
+                        }
+                        return Ok(report.finish());
+                    };
+                    let sku = sku_index
+                        .and_then(|index| record.get(index))
+                        .filter(|sku| !sku.is_empty())
+                        .map(str::to_string);
+                    report.record([ImportRowResult::failed(
+                        line,
+                        sku,
+                        describe_csv_error(&e, &headers),
+                    )]);
+                }
+            }
+
+            if batch.len() >= IMPORT_BATCH_SIZE {
+                let rows = std::mem::take(&mut batch);
+                if !self.save(&mut report, rows, dry_run).await {
+                    return Ok(report.finish());
+                }
+            }
+        }
+
+        if !batch.is_empty() {
+            self.save(&mut report, batch, dry_run).await;
+        }
+
+        Ok(report.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::product_import::ImportRowStatus;
+    use crate::infrastructure::persistence::postgres::PostgresProductRepository;
+    use actix_web::web::Bytes;
+    use futures::stream;
+    use sqlx::PgPool;
+    use tokio_util::io::StreamReader;
+
+    async fn pool() -> PgPool {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        PgPool::connect(&url)
+            .await
+            .expect("Database is not reachable")
+    }
+
+    fn csv(
+        chunks: Vec<std::io::Result<Bytes>>,
+    ) -> ImportProductsInput<impl AsyncRead + Unpin + Send + 'static> {
+        ImportProductsInput {
+            csv: StreamReader::new(stream::iter(chunks)),
+            dry_run: true,
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Postgres at DATABASE_URL"]
+    async fn reports_the_rows_read_before_the_file_breaks_off() {
+        let use_case = ImportProductsUseCase::new(PostgresProductRepository::new(pool().await));
+
+        let report = use_case
+            .execute(csv(vec![
+                Ok(Bytes::from("name,price,stock\nLamp,9.99,3\nDesk,cheap,1\n")),
+                Err(std::io::Error::other("connection reset")),
+            ]))
+            .await
+            .unwrap();
+
+        let rows: Vec<_> = report
+            .rows
+            .iter()
+            .map(|row| (row.line, row.status))
+            .collect();
+        assert_eq!(
+            rows,
+            [(2, ImportRowStatus::Created), (3, ImportRowStatus::Failed)]
+        );
+        let interrupted = report.interrupted.expect("the import stopped early");
+        assert_eq!(interrupted.line, 4);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Postgres at DATABASE_URL"]
+    async fn stops_at_the_first_line_of_a_batch_that_cannot_be_saved() {
+        let pool = pool().await;
+        pool.close().await;
+        let use_case = ImportProductsUseCase::new(PostgresProductRepository::new(pool));
+
+        let report = use_case
+            .execute(csv(vec![Ok(Bytes::from(
+                "name,price,stock\nLamp,9.99,3\nDesk,120,1\n",
+            ))]))
+            .await
+            .unwrap();
+
+        assert!(report.rows.is_empty());
+        let interrupted = report.interrupted.expect("the import stopped early");
+        assert_eq!(interrupted.line, 2);
+    }
+}
@@ -0,0 +1,40 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{entities::product_price::ProductPrice, repositories::ProductPriceRepository};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// Loads the price histories of several products at once, grouped by product
+pub struct ListPriceHistoriesUseCase<H: ProductPriceRepository> {
+    prices: H,
+}
+
+impl<H: ProductPriceRepository> ListPriceHistoriesUseCase<H> {
+    pub fn new(prices: H) -> Self {
+        Self { prices }
+    }
+}
+
+#[async_trait]
+impl<H: ProductPriceRepository + Send + Sync>
+    UseCase<Vec<Uuid>, HashMap<Uuid, Vec<ProductPrice>>, ApplicationError>
+    for ListPriceHistoriesUseCase<H>
+{
+    #[instrument(name = "ListPriceHistoriesUseCase::execute", skip_all)]
+    async fn execute(
+        &self,
+        product_ids: Vec<Uuid>,
+    ) -> Result<HashMap<Uuid, Vec<ProductPrice>>, ApplicationError> {
+        let mut grouped: HashMap<Uuid, Vec<ProductPrice>> = HashMap::new();
+        if product_ids.is_empty() {
+            return Ok(grouped);
+        }
+
+        for price in self.prices.list_for_products(&product_ids).await? {
+            grouped.entry(price.product_id).or_default().push(price);
+        }
+
+        Ok(grouped)
+    }
+}
@@ -1,6 +1,7 @@
 use crate::application::{error::ApplicationError, use_cases::UseCase};
 use crate::domain::{entities::product::Product, repositories::ProductRepository};
 use async_trait::async_trait;
+use tracing::instrument;
 
 pub struct ListProductsUseCase<R: ProductRepository> {
     repository: R,
@@ -16,6 +17,7 @@ impl<R: ProductRepository> ListProductsUseCase<R> {
 impl<R: ProductRepository + Send + Sync> UseCase<(), Vec<Product>, ApplicationError>
     for ListProductsUseCase<R>
 {
+    #[instrument(name = "ListProductsUseCase::execute", skip_all)]
     async fn execute(&self, _: ()) -> Result<Vec<Product>, ApplicationError> {
         // Fetch all products
         let products = self.repository.list().await?;
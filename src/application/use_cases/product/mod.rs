@@ -1,11 +1,31 @@
+pub mod activate_scheduled_prices;
+pub mod bulk_create_products;
+pub mod bulk_delete_products;
+pub mod bulk_update_products;
 pub mod create_product;
 pub mod delete_product;
+pub mod export_products;
+pub mod get_price_history;
 pub mod get_product;
+pub mod get_product_at;
+pub mod import_products;
+pub mod list_price_histories;
 pub mod list_products;
+pub mod schedule_price;
 pub mod update_product;
 
+pub use activate_scheduled_prices::ActivateScheduledPricesUseCase;
+pub use bulk_create_products::BulkCreateProductsUseCase;
+pub use bulk_delete_products::BulkDeleteProductsUseCase;
+pub use bulk_update_products::BulkUpdateProductsUseCase;
 pub use create_product::CreateProductUseCase;
 pub use delete_product::DeleteProductUseCase;
+pub use export_products::ExportProductsUseCase;
+pub use get_price_history::GetPriceHistoryUseCase;
 pub use get_product::GetProductUseCase;
+pub use get_product_at::GetProductAtUseCase;
+pub use import_products::{ImportProductsInput, ImportProductsUseCase};
+pub use list_price_histories::ListPriceHistoriesUseCase;
 pub use list_products::ListProductsUseCase;
+pub use schedule_price::SchedulePriceUseCase;
 pub use update_product::UpdateProductUseCase;
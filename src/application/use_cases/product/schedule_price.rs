@@ -0,0 +1,65 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::product_price::{ProductPrice, SchedulePriceDto},
+    repositories::{ProductPriceRepository, ProductRepository},
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use rust_decimal_macros::dec;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct SchedulePriceUseCase<R: ProductRepository, H: ProductPriceRepository> {
+    repository: R,
+    prices: H,
+}
+
+impl<R: ProductRepository, H: ProductPriceRepository> SchedulePriceUseCase<R, H> {
+    pub fn new(repository: R, prices: H) -> Self {
+        Self { repository, prices }
+    }
+}
+
+#[async_trait]
+impl<R: ProductRepository + Send + Sync, H: ProductPriceRepository + Send + Sync>
+    UseCase<(Uuid, SchedulePriceDto), ProductPrice, ApplicationError>
+    for SchedulePriceUseCase<R, H>
+{
+    #[instrument(name = "SchedulePriceUseCase::execute", skip_all)]
+    async fn execute(
+        &self,
+        input: (Uuid, SchedulePriceDto),
+    ) -> Result<ProductPrice, ApplicationError> {
+        let (id, schedule_dto) = input;
+
+        // Validate input
+        if schedule_dto.price < dec!(0) {
+            return Err(ApplicationError::Validation(
+                "Price cannot be negative".to_string(),
+            ));
+        }
+
+        if schedule_dto.effective_from <= Utc::now() {
+            return Err(ApplicationError::Validation(
+                "Scheduled prices must start in the future".to_string(),
+            ));
+        }
+
+        if let Some(effective_to) = schedule_dto.effective_to {
+            if effective_to <= schedule_dto.effective_from {
+                return Err(ApplicationError::Validation(
+                    "effective_to must be after effective_from".to_string(),
+                ));
+            }
+        }
+
+        // Check if product exists
+        if self.repository.find_by_id(id).await?.is_none() {
+            return Err(ApplicationError::NotFound);
+        }
+
+        // Schedule price
+        let price = self.prices.schedule(id, schedule_dto).await?;
+        Ok(price)
+    }
+}
@@ -1,10 +1,15 @@
+use super::create_product::validate_sku;
 use crate::application::{error::ApplicationError, use_cases::UseCase};
 use crate::domain::{
-    entities::product::{Product, UpdateProductDto},
-    repositories::ProductRepository,
+    entities::{
+        domain_event::DomainEvent,
+        product::{Product, UpdateProductDto},
+    },
+    repositories::{ProductRepository, RepositoryError},
 };
 use async_trait::async_trait;
 use rust_decimal_macros::dec;
+use tracing::instrument;
 use uuid::Uuid;
 
 pub struct UpdateProductUseCase<R: ProductRepository> {
@@ -21,49 +26,72 @@ impl<R: ProductRepository> UpdateProductUseCase<R> {
 impl<R: ProductRepository + Send + Sync>
     UseCase<(Uuid, UpdateProductDto), Product, ApplicationError> for UpdateProductUseCase<R>
 {
+    #[instrument(name = "UpdateProductUseCase::execute", skip_all)]
     async fn execute(&self, input: (Uuid, UpdateProductDto)) -> Result<Product, ApplicationError> {
         let (id, update_dto) = input;
 
         // Validate input
-        if let Some(name) = &update_dto.name {
-            if name.is_empty() {
-                return Err(ApplicationError::Validation(
-                    "Name cannot be empty".to_string(),
-                ));
-            }
+        validate_product_update(&update_dto)?;
+
+        // Check if product exists
+        if self.repository.find_by_id(id).await?.is_none() {
+            return Err(ApplicationError::NotFound);
         }
 
-        if let Some(description) = &update_dto.description {
-            if description.is_empty() {
-                return Err(ApplicationError::Validation(
-                    "Description cannot be empty".to_string(),
-                ));
-            }
+        // Update product
+        match self
+            .repository
+            .update(id, update_dto, |product| {
+                DomainEvent::ProductUpdated(product.clone())
+            })
+            .await
+        {
+            Ok(product) => Ok(product),
+            Err(RepositoryError::DuplicateEntry) => Err(ApplicationError::Validation(
+                "A product with this SKU already exists".to_string(),
+            )),
+            Err(e) => Err(e.into()),
         }
+    }
+}
+
+/// Checks the fields an update sets against the product rules
+pub fn validate_product_update(input: &UpdateProductDto) -> Result<(), ApplicationError> {
+    if let Some(sku) = &input.sku {
+        validate_sku(sku)?;
+    }
 
-        if let Some(price) = update_dto.price {
-            if price < dec!(0) {
-                return Err(ApplicationError::Validation(
-                    "Price cannot be negative".to_string(),
-                ));
-            }
+    if let Some(name) = &input.name {
+        if name.is_empty() {
+            return Err(ApplicationError::Validation(
+                "Name cannot be empty".to_string(),
+            ));
         }
+    }
 
-        if let Some(stock) = update_dto.stock {
-            if stock < 0 {
-                return Err(ApplicationError::Validation(
-                    "Stock cannot be negative".to_string(),
-                ));
-            }
+    if let Some(description) = &input.description {
+        if description.is_empty() {
+            return Err(ApplicationError::Validation(
+                "Description cannot be empty".to_string(),
+            ));
         }
+    }
 
-        // Check if product exists
-        if self.repository.find_by_id(id).await?.is_none() {
-            return Err(ApplicationError::NotFound);
+    if let Some(price) = input.price {
+        if price < dec!(0) {
+            return Err(ApplicationError::Validation(
+                "Price cannot be negative".to_string(),
+            ));
         }
+    }
 
-        // Update product
-        let product = self.repository.update(id, update_dto).await?;
-        Ok(product)
+    if let Some(stock) = input.stock {
+        if stock < 0 {
+            return Err(ApplicationError::Validation(
+                "Stock cannot be negative".to_string(),
+            ));
+        }
     }
+
+    Ok(())
 }
@@ -0,0 +1,30 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{entities::product_event::ProductEvent, repositories::ProductEventRepository};
+use async_trait::async_trait;
+use tracing::instrument;
+
+pub struct GetProductEventUseCase<R: ProductEventRepository> {
+    repository: R,
+}
+
+impl<R: ProductEventRepository> GetProductEventUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: ProductEventRepository + Send + Sync> UseCase<i64, ProductEvent, ApplicationError>
+    for GetProductEventUseCase<R>
+{
+    #[instrument(name = "GetProductEventUseCase::execute", skip_all)]
+    async fn execute(&self, id: i64) -> Result<ProductEvent, ApplicationError> {
+        let event = self
+            .repository
+            .find_by_id(id)
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+
+        Ok(event)
+    }
+}
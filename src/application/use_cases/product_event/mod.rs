@@ -0,0 +1,7 @@
+pub mod get_product_event;
+pub mod prune_product_events;
+pub mod replay_product_events;
+
+pub use get_product_event::GetProductEventUseCase;
+pub use prune_product_events::PruneProductEventsUseCase;
+pub use replay_product_events::ReplayProductEventsUseCase;
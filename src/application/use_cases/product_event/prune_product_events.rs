@@ -0,0 +1,27 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::repositories::ProductEventRepository;
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use tracing::instrument;
+
+/// Drops product events older than the retention period
+pub struct PruneProductEventsUseCase<R: ProductEventRepository> {
+    repository: R,
+}
+
+impl<R: ProductEventRepository> PruneProductEventsUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: ProductEventRepository + Send + Sync> UseCase<Duration, u64, ApplicationError>
+    for PruneProductEventsUseCase<R>
+{
+    #[instrument(name = "PruneProductEventsUseCase::execute", skip_all)]
+    async fn execute(&self, retention: Duration) -> Result<u64, ApplicationError> {
+        let pruned = self.repository.prune(Utc::now() - retention).await?;
+        Ok(pruned)
+    }
+}
@@ -0,0 +1,47 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{entities::product_event::ProductEvent, repositories::ProductEventRepository};
+use async_trait::async_trait;
+use tracing::instrument;
+
+/// Events recorded after the last one a client saw, plus the `lookback` ids before
+/// it, `None` when some of them were already pruned and the client has to start over
+pub struct ReplayProductEventsUseCase<R: ProductEventRepository> {
+    repository: R,
+}
+
+impl<R: ProductEventRepository> ReplayProductEventsUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: ProductEventRepository + Send + Sync>
+    UseCase<(i64, i64, i64), Option<Vec<ProductEvent>>, ApplicationError>
+    for ReplayProductEventsUseCase<R>
+{
+    #[instrument(name = "ReplayProductEventsUseCase::execute", skip_all)]
+    async fn execute(
+        &self,
+        input: (i64, i64, i64),
+    ) -> Result<Option<Vec<ProductEvent>>, ApplicationError> {
+        let (after_id, lookback, limit) = input;
+
+        // Validate input
+        if after_id < 0 || lookback < 0 || limit <= 0 {
+            return Err(ApplicationError::Validation("Invalid event id".to_string()));
+        }
+
+        // Events between the client's last one and the oldest retained were pruned
+        if let Some(oldest) = self.repository.oldest_id().await? {
+            if after_id.saturating_add(1) < oldest {
+                return Ok(None);
+            }
+        }
+
+        // Ids are taken before commit, so events below the last one may have committed since
+        let from_id = after_id.saturating_sub(lookback).max(0);
+        let events = self.repository.list_after(from_id, limit).await?;
+        Ok(Some(events))
+    }
+}
@@ -0,0 +1,44 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::{job::NewJob, product_image::RemoveImageFilesJob},
+    repositories::{ProductImageRepository, RepositoryError},
+};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct DeleteProductImageUseCase<I: ProductImageRepository> {
+    images: I,
+}
+
+impl<I: ProductImageRepository> DeleteProductImageUseCase<I> {
+    pub fn new(images: I) -> Self {
+        Self { images }
+    }
+}
+
+#[async_trait]
+impl<I: ProductImageRepository + Send + Sync> UseCase<(Uuid, Uuid), (), ApplicationError>
+    for DeleteProductImageUseCase<I>
+{
+    #[instrument(name = "DeleteProductImageUseCase::execute", skip_all)]
+    async fn execute(&self, input: (Uuid, Uuid)) -> Result<(), ApplicationError> {
+        let (product_id, image_id) = input;
+
+        // The image is gone from the catalog, its files are removed in the background
+        // by a job committed with the delete
+        match self
+            .images
+            .delete(product_id, image_id, |image| {
+                NewJob::new(&RemoveImageFilesJob {
+                    keys: image.storage_keys(),
+                })
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(RepositoryError::NotFound) => Err(ApplicationError::NotFound),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
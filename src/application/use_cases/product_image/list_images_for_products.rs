@@ -0,0 +1,40 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{entities::product_image::ProductImage, repositories::ProductImageRepository};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// Loads the images of several products at once, grouped by product
+pub struct ListImagesForProductsUseCase<I: ProductImageRepository> {
+    images: I,
+}
+
+impl<I: ProductImageRepository> ListImagesForProductsUseCase<I> {
+    pub fn new(images: I) -> Self {
+        Self { images }
+    }
+}
+
+#[async_trait]
+impl<I: ProductImageRepository + Send + Sync>
+    UseCase<Vec<Uuid>, HashMap<Uuid, Vec<ProductImage>>, ApplicationError>
+    for ListImagesForProductsUseCase<I>
+{
+    #[instrument(name = "ListImagesForProductsUseCase::execute", skip_all)]
+    async fn execute(
+        &self,
+        product_ids: Vec<Uuid>,
+    ) -> Result<HashMap<Uuid, Vec<ProductImage>>, ApplicationError> {
+        let mut grouped: HashMap<Uuid, Vec<ProductImage>> = HashMap::new();
+        if product_ids.is_empty() {
+            return Ok(grouped);
+        }
+
+        for image in self.images.list_for_products(&product_ids).await? {
+            grouped.entry(image.product_id).or_default().push(image);
+        }
+
+        Ok(grouped)
+    }
+}
@@ -0,0 +1,34 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::product_image::ProductImage,
+    repositories::{ProductImageRepository, ProductRepository},
+};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct ListProductImagesUseCase<R: ProductRepository, I: ProductImageRepository> {
+    products: R,
+    images: I,
+}
+
+impl<R: ProductRepository, I: ProductImageRepository> ListProductImagesUseCase<R, I> {
+    pub fn new(products: R, images: I) -> Self {
+        Self { products, images }
+    }
+}
+
+#[async_trait]
+impl<R: ProductRepository + Send + Sync, I: ProductImageRepository + Send + Sync>
+    UseCase<Uuid, Vec<ProductImage>, ApplicationError> for ListProductImagesUseCase<R, I>
+{
+    #[instrument(name = "ListProductImagesUseCase::execute", skip_all)]
+    async fn execute(&self, product_id: Uuid) -> Result<Vec<ProductImage>, ApplicationError> {
+        if self.products.find_by_id(product_id).await?.is_none() {
+            return Err(ApplicationError::NotFound);
+        }
+
+        let images = self.images.list(product_id).await?;
+        Ok(images)
+    }
+}
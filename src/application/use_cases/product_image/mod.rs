@@ -0,0 +1,13 @@
+pub mod delete_product_image;
+pub mod list_images_for_products;
+pub mod list_product_images;
+pub mod reorder_product_images;
+pub mod set_primary_product_image;
+pub mod upload_product_image;
+
+pub use delete_product_image::DeleteProductImageUseCase;
+pub use list_images_for_products::ListImagesForProductsUseCase;
+pub use list_product_images::ListProductImagesUseCase;
+pub use reorder_product_images::ReorderProductImagesUseCase;
+pub use set_primary_product_image::SetPrimaryProductImageUseCase;
+pub use upload_product_image::{UploadProductImageInput, UploadProductImageUseCase};
@@ -0,0 +1,56 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::product_image::{ProductImage, ReorderProductImagesDto},
+    repositories::{ProductImageRepository, ProductRepository},
+};
+use async_trait::async_trait;
+use std::collections::HashSet;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct ReorderProductImagesUseCase<R: ProductRepository, I: ProductImageRepository> {
+    products: R,
+    images: I,
+}
+
+impl<R: ProductRepository, I: ProductImageRepository> ReorderProductImagesUseCase<R, I> {
+    pub fn new(products: R, images: I) -> Self {
+        Self { products, images }
+    }
+}
+
+#[async_trait]
+impl<R: ProductRepository + Send + Sync, I: ProductImageRepository + Send + Sync>
+    UseCase<(Uuid, ReorderProductImagesDto), Vec<ProductImage>, ApplicationError>
+    for ReorderProductImagesUseCase<R, I>
+{
+    #[instrument(name = "ReorderProductImagesUseCase::execute", skip_all)]
+    async fn execute(
+        &self,
+        input: (Uuid, ReorderProductImagesDto),
+    ) -> Result<Vec<ProductImage>, ApplicationError> {
+        let (product_id, order) = input;
+
+        if self.products.find_by_id(product_id).await?.is_none() {
+            return Err(ApplicationError::NotFound);
+        }
+
+        // The new order must name every image exactly once
+        let current: HashSet<Uuid> = self
+            .images
+            .list(product_id)
+            .await?
+            .into_iter()
+            .map(|image| image.id)
+            .collect();
+        let requested: HashSet<Uuid> = order.image_ids.iter().copied().collect();
+        if requested.len() != order.image_ids.len() || requested != current {
+            return Err(ApplicationError::Validation(
+                "Image order must list every image of the product exactly once".to_string(),
+            ));
+        }
+
+        let images = self.images.reorder(product_id, &order.image_ids).await?;
+        Ok(images)
+    }
+}
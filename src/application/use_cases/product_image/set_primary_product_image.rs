@@ -0,0 +1,34 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::product_image::ProductImage,
+    repositories::{ProductImageRepository, RepositoryError},
+};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct SetPrimaryProductImageUseCase<I: ProductImageRepository> {
+    images: I,
+}
+
+impl<I: ProductImageRepository> SetPrimaryProductImageUseCase<I> {
+    pub fn new(images: I) -> Self {
+        Self { images }
+    }
+}
+
+#[async_trait]
+impl<I: ProductImageRepository + Send + Sync> UseCase<(Uuid, Uuid), ProductImage, ApplicationError>
+    for SetPrimaryProductImageUseCase<I>
+{
+    #[instrument(name = "SetPrimaryProductImageUseCase::execute", skip_all)]
+    async fn execute(&self, input: (Uuid, Uuid)) -> Result<ProductImage, ApplicationError> {
+        let (product_id, image_id) = input;
+
+        match self.images.set_primary(product_id, image_id).await {
+            Ok(image) => Ok(image),
+            Err(RepositoryError::NotFound) => Err(ApplicationError::NotFound),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
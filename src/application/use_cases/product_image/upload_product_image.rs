@@ -0,0 +1,133 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::product_image::{image_key, NewProductImage, ProductImage},
+    gateways::BlobStorage,
+    repositories::{ProductImageRepository, ProductRepository},
+    services::image_processing::{process, ProcessedImage},
+};
+use async_trait::async_trait;
+use log::warn;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct UploadProductImageInput {
+    pub product_id: Uuid,
+    /// Raw file contents
+    pub data: Vec<u8>,
+    /// Make the image the product's primary image
+    pub is_primary: bool,
+}
+
+/// Validates an uploaded image, stores it with its thumbnails and attaches it to the product
+pub struct UploadProductImageUseCase<
+    R: ProductRepository,
+    I: ProductImageRepository,
+    S: BlobStorage,
+> {
+    products: R,
+    images: I,
+    storage: S,
+}
+
+impl<R: ProductRepository, I: ProductImageRepository, S: BlobStorage>
+    UploadProductImageUseCase<R, I, S>
+{
+    pub fn new(products: R, images: I, storage: S) -> Self {
+        Self {
+            products,
+            images,
+            storage,
+        }
+    }
+
+    /// Best effort removal of blobs that did not make it into the catalog
+    async fn discard(&self, keys: &[String]) {
+        for key in keys {
+            if let Err(e) = self.storage.delete(key).await {
+                warn!("Failed to remove orphaned image {}: {}", key, e);
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<R, I, S> UseCase<UploadProductImageInput, ProductImage, ApplicationError>
+    for UploadProductImageUseCase<R, I, S>
+where
+    R: ProductRepository + Send + Sync,
+    I: ProductImageRepository + Send + Sync,
+    S: BlobStorage + Send + Sync,
+{
+    #[instrument(name = "UploadProductImageUseCase::execute", skip_all)]
+    async fn execute(
+        &self,
+        input: UploadProductImageInput,
+    ) -> Result<ProductImage, ApplicationError> {
+        // Validate input
+        if input.data.is_empty() {
+            return Err(ApplicationError::Validation(
+                "Image file is empty".to_string(),
+            ));
+        }
+
+        if self.products.find_by_id(input.product_id).await?.is_none() {
+            return Err(ApplicationError::NotFound);
+        }
+
+        // Decoding and resizing are CPU bound
+        let data = input.data;
+        let (data, processed): (Vec<u8>, ProcessedImage) =
+            tokio::task::spawn_blocking(move || process(&data).map(|processed| (data, processed)))
+                .await
+                .map_err(|e| ApplicationError::Internal(format!("Image processing failed: {e}")))?
+                .map_err(|e| ApplicationError::Validation(e.to_string()))?;
+
+        // Store the files before the row so a listed image always has its files
+        let id = Uuid::new_v4();
+        let size_bytes = data.len() as i64;
+        let mut stored = Vec::new();
+        let original_key = image_key(input.product_id, id, processed.content_type, None);
+        let uploads = std::iter::once((original_key, processed.content_type, data)).chain(
+            processed.thumbnails.into_iter().map(|thumbnail| {
+                (
+                    image_key(
+                        input.product_id,
+                        id,
+                        processed.content_type,
+                        Some(thumbnail.variant),
+                    ),
+                    thumbnail.content_type,
+                    thumbnail.data,
+                )
+            }),
+        );
+        for (key, content_type, data) in uploads {
+            if let Err(e) = self.storage.put(&key, content_type, data).await {
+                self.discard(&stored).await;
+                return Err(e.into());
+            }
+            stored.push(key);
+        }
+
+        let created = self
+            .images
+            .create(NewProductImage {
+                id,
+                product_id: input.product_id,
+                content_type: processed.content_type.to_string(),
+                width: processed.width as i32,
+                height: processed.height as i32,
+                size_bytes,
+                is_primary: input.is_primary,
+            })
+            .await;
+
+        match created {
+            Ok(image) => Ok(image),
+            Err(e) => {
+                self.discard(&stored).await;
+                Err(e.into())
+            }
+        }
+    }
+}
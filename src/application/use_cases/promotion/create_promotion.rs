@@ -0,0 +1,40 @@
+use super::validate_promotion;
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::promotion::{CreatePromotionDto, Promotion},
+    repositories::{PromotionRepository, RepositoryError},
+};
+use async_trait::async_trait;
+use tracing::instrument;
+
+pub struct CreatePromotionUseCase<M: PromotionRepository> {
+    repository: M,
+}
+
+impl<M: PromotionRepository> CreatePromotionUseCase<M> {
+    pub fn new(repository: M) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<M: PromotionRepository + Send + Sync> UseCase<CreatePromotionDto, Promotion, ApplicationError>
+    for CreatePromotionUseCase<M>
+{
+    #[instrument(name = "CreatePromotionUseCase::execute", skip_all)]
+    async fn execute(&self, mut input: CreatePromotionDto) -> Result<Promotion, ApplicationError> {
+        // Codes are matched case-insensitively
+        input.code = input.code.map(|code| code.trim().to_ascii_uppercase());
+
+        // Validate input
+        validate_promotion(&input)?;
+
+        match self.repository.create(input).await {
+            Ok(promotion) => Ok(promotion),
+            Err(RepositoryError::DuplicateEntry) => Err(ApplicationError::Validation(
+                "A promotion with this code already exists".to_string(),
+            )),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::repositories::{PromotionRepository, RepositoryError};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct DeletePromotionUseCase<M: PromotionRepository> {
+    repository: M,
+}
+
+impl<M: PromotionRepository> DeletePromotionUseCase<M> {
+    pub fn new(repository: M) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<M: PromotionRepository + Send + Sync> UseCase<Uuid, (), ApplicationError>
+    for DeletePromotionUseCase<M>
+{
+    #[instrument(name = "DeletePromotionUseCase::execute", skip_all)]
+    async fn execute(&self, id: Uuid) -> Result<(), ApplicationError> {
+        match self.repository.delete(id).await {
+            Ok(()) => Ok(()),
+            Err(RepositoryError::NotFound) => Err(ApplicationError::NotFound),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{entities::promotion::Promotion, repositories::PromotionRepository};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct GetPromotionUseCase<M: PromotionRepository> {
+    repository: M,
+}
+
+impl<M: PromotionRepository> GetPromotionUseCase<M> {
+    pub fn new(repository: M) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<M: PromotionRepository + Send + Sync> UseCase<Uuid, Promotion, ApplicationError>
+    for GetPromotionUseCase<M>
+{
+    #[instrument(name = "GetPromotionUseCase::execute", skip_all)]
+    async fn execute(&self, id: Uuid) -> Result<Promotion, ApplicationError> {
+        self.repository
+            .find_by_id(id)
+            .await?
+            .ok_or(ApplicationError::NotFound)
+    }
+}
@@ -0,0 +1,25 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{entities::promotion::Promotion, repositories::PromotionRepository};
+use async_trait::async_trait;
+use tracing::instrument;
+
+pub struct ListPromotionsUseCase<M: PromotionRepository> {
+    repository: M,
+}
+
+impl<M: PromotionRepository> ListPromotionsUseCase<M> {
+    pub fn new(repository: M) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<M: PromotionRepository + Send + Sync> UseCase<(), Vec<Promotion>, ApplicationError>
+    for ListPromotionsUseCase<M>
+{
+    #[instrument(name = "ListPromotionsUseCase::execute", skip_all)]
+    async fn execute(&self, _: ()) -> Result<Vec<Promotion>, ApplicationError> {
+        let promotions = self.repository.list().await?;
+        Ok(promotions)
+    }
+}
@@ -0,0 +1,84 @@
+pub mod create_promotion;
+pub mod delete_promotion;
+pub mod get_promotion;
+pub mod list_promotions;
+pub mod update_promotion;
+
+pub use create_promotion::CreatePromotionUseCase;
+pub use delete_promotion::DeletePromotionUseCase;
+pub use get_promotion::GetPromotionUseCase;
+pub use list_promotions::ListPromotionsUseCase;
+pub use update_promotion::UpdatePromotionUseCase;
+
+use crate::application::error::ApplicationError;
+use crate::domain::entities::{
+    exchange_rate::is_currency_code,
+    promotion::{CreatePromotionDto, PromotionKind},
+};
+use rust_decimal_macros::dec;
+
+/// Checks that the promotion's rules are consistent
+fn validate_promotion(promotion: &CreatePromotionDto) -> Result<(), ApplicationError> {
+    let invalid = |msg: &str| Err(ApplicationError::Validation(msg.to_string()));
+
+    if promotion.name.is_empty() {
+        return invalid("Promotion name is required");
+    }
+
+    if let Some(code) = &promotion.code {
+        if code.is_empty() || !code.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+            return invalid("Code must contain only letters, digits and dashes");
+        }
+    }
+
+    if promotion.value <= dec!(0) {
+        return invalid("Value must be positive");
+    }
+
+    match promotion.kind {
+        PromotionKind::Percentage if promotion.value > dec!(100) => {
+            return invalid("Percentage cannot exceed 100");
+        }
+        PromotionKind::BuyXGetY => {
+            if promotion.value > dec!(100) {
+                return invalid("Percentage cannot exceed 100");
+            }
+            if promotion.buy_quantity.unwrap_or(0) <= 0 || promotion.get_quantity.unwrap_or(0) <= 0
+            {
+                return invalid("Buy X get Y promotions need positive buy and get quantities");
+            }
+        }
+        _ => {}
+    }
+
+    if promotion.min_order_value.is_some_and(|min| min < dec!(0)) {
+        return invalid("Minimum order value cannot be negative");
+    }
+
+    match &promotion.currency {
+        Some(currency) if !is_currency_code(currency) => {
+            return invalid("Currency must be a three-letter ISO 4217 code");
+        }
+        None if promotion.kind == PromotionKind::FixedAmount
+            || promotion.min_order_value.is_some() =>
+        {
+            return invalid("Currency is required for fixed amounts and minimum order values");
+        }
+        _ => {}
+    }
+
+    if promotion
+        .usage_limit_per_user
+        .is_some_and(|limit| limit <= 0)
+    {
+        return invalid("Usage limit must be positive");
+    }
+
+    if let (Some(starts_at), Some(ends_at)) = (promotion.starts_at, promotion.ends_at) {
+        if ends_at <= starts_at {
+            return invalid("Promotion must end after it starts");
+        }
+    }
+
+    Ok(())
+}
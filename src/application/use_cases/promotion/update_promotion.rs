@@ -0,0 +1,52 @@
+use super::validate_promotion;
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::promotion::{Promotion, UpdatePromotionDto},
+    repositories::{PromotionRepository, RepositoryError},
+};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct UpdatePromotionUseCase<M: PromotionRepository> {
+    repository: M,
+}
+
+impl<M: PromotionRepository> UpdatePromotionUseCase<M> {
+    pub fn new(repository: M) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<M: PromotionRepository + Send + Sync>
+    UseCase<(Uuid, UpdatePromotionDto), Promotion, ApplicationError> for UpdatePromotionUseCase<M>
+{
+    #[instrument(name = "UpdatePromotionUseCase::execute", skip_all)]
+    async fn execute(
+        &self,
+        input: (Uuid, UpdatePromotionDto),
+    ) -> Result<Promotion, ApplicationError> {
+        let (id, mut update_dto) = input;
+        update_dto.code = update_dto.code.map(|code| code.trim().to_ascii_uppercase());
+
+        // Check if promotion exists
+        let current = self
+            .repository
+            .find_by_id(id)
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+
+        // Validate the promotion as it will be after the update
+        validate_promotion(&current.merged_with(&update_dto))?;
+
+        match self.repository.update(id, update_dto).await {
+            Ok(promotion) => Ok(promotion),
+            Err(RepositoryError::NotFound) => Err(ApplicationError::NotFound),
+            Err(RepositoryError::DuplicateEntry) => Err(ApplicationError::Validation(
+                "A promotion with this code already exists".to_string(),
+            )),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::scheduled_task::ScheduledTask, repositories::ScheduledTaskRepository,
+};
+use async_trait::async_trait;
+use tracing::instrument;
+
+/// Cron tasks with their next and last run
+pub struct ListScheduledTasksUseCase<R: ScheduledTaskRepository> {
+    repository: R,
+}
+
+impl<R: ScheduledTaskRepository> ListScheduledTasksUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: ScheduledTaskRepository + Send + Sync> UseCase<(), Vec<ScheduledTask>, ApplicationError>
+    for ListScheduledTasksUseCase<R>
+{
+    #[instrument(name = "ListScheduledTasksUseCase::execute", skip_all)]
+    async fn execute(&self, _: ()) -> Result<Vec<ScheduledTask>, ApplicationError> {
+        let tasks = self.repository.list().await?;
+        Ok(tasks)
+    }
+}
@@ -0,0 +1,3 @@
+pub mod list_scheduled_tasks;
+
+pub use list_scheduled_tasks::ListScheduledTasksUseCase;
@@ -1,10 +1,14 @@
 use crate::application::error::ApplicationError;
 use crate::application::use_cases::UseCase;
 use crate::domain::{
-    entities::user::{CreateUserDto, User},
+    entities::{
+        domain_event::DomainEvent,
+        user::{CreateUserDto, User},
+    },
     repositories::UserRepository,
 };
 use async_trait::async_trait;
+use tracing::instrument;
 
 pub struct CreateUserUseCase<R: UserRepository> {
     repository: R,
@@ -20,6 +24,7 @@ impl<R: UserRepository> CreateUserUseCase<R> {
 impl<R: UserRepository + Send + Sync> UseCase<CreateUserDto, User, ApplicationError>
     for CreateUserUseCase<R>
 {
+    #[instrument(name = "CreateUserUseCase::execute", skip_all)]
     async fn execute(&self, input: CreateUserDto) -> Result<User, ApplicationError> {
         // Validate input
         if input.email.is_empty() || input.username.is_empty() || input.password.is_empty() {
@@ -36,7 +41,10 @@ impl<R: UserRepository + Send + Sync> UseCase<CreateUserDto, User, ApplicationEr
         }
 
         // Create user
-        let user = self.repository.create(input).await?;
+        let user = self
+            .repository
+            .create(input, |user| DomainEvent::UserCreated(user.clone()))
+            .await?;
         Ok(user)
     }
 }
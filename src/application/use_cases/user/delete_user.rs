@@ -1,6 +1,7 @@
 use crate::application::{error::ApplicationError, use_cases::UseCase};
-use crate::domain::repositories::UserRepository;
+use crate::domain::{entities::domain_event::DomainEvent, repositories::UserRepository};
 use async_trait::async_trait;
+use tracing::instrument;
 use uuid::Uuid;
 
 pub struct DeleteUserUseCase<R: UserRepository> {
@@ -15,6 +16,7 @@ impl<R: UserRepository> DeleteUserUseCase<R> {
 
 #[async_trait]
 impl<R: UserRepository + Send + Sync> UseCase<Uuid, (), ApplicationError> for DeleteUserUseCase<R> {
+    #[instrument(name = "DeleteUserUseCase::execute", skip_all)]
     async fn execute(&self, id: Uuid) -> Result<(), ApplicationError> {
         // Check if user exists
         if self.repository.find_by_id(id).await?.is_none() {
@@ -22,7 +24,9 @@ impl<R: UserRepository + Send + Sync> UseCase<Uuid, (), ApplicationError> for De
         }
 
         // Delete user
-        self.repository.delete(id).await?;
+        self.repository
+            .delete(id, |id| DomainEvent::UserDeleted(*id))
+            .await?;
         Ok(())
     }
 }
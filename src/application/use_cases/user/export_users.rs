@@ -0,0 +1,34 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{entities::user::User, repositories::UserRepository};
+use async_trait::async_trait;
+use futures::{stream::BoxStream, StreamExt};
+use tracing::instrument;
+
+/// Streams every user for exports too large to collect in memory
+pub struct ExportUsersUseCase<R: UserRepository> {
+    repository: R,
+}
+
+impl<R: UserRepository> ExportUsersUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: UserRepository + Send + Sync>
+    UseCase<(), BoxStream<'static, Result<User, ApplicationError>>, ApplicationError>
+    for ExportUsersUseCase<R>
+{
+    #[instrument(name = "ExportUsersUseCase::execute", skip_all)]
+    async fn execute(
+        &self,
+        _: (),
+    ) -> Result<BoxStream<'static, Result<User, ApplicationError>>, ApplicationError> {
+        Ok(self
+            .repository
+            .stream()
+            .map(|row| row.map_err(ApplicationError::from))
+            .boxed())
+    }
+}
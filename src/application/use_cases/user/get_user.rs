@@ -1,6 +1,7 @@
 use crate::application::{error::ApplicationError, use_cases::UseCase};
 use crate::domain::{entities::user::User, repositories::UserRepository};
 use async_trait::async_trait;
+use tracing::instrument;
 use uuid::Uuid;
 
 pub struct GetUserUseCase<R: UserRepository> {
@@ -15,6 +16,7 @@ impl<R: UserRepository> GetUserUseCase<R> {
 
 #[async_trait]
 impl<R: UserRepository + Send + Sync> UseCase<Uuid, User, ApplicationError> for GetUserUseCase<R> {
+    #[instrument(name = "GetUserUseCase::execute", skip_all)]
     async fn execute(&self, id: Uuid) -> Result<User, ApplicationError> {
         // Fetch user by ID
         let user = self
@@ -1,6 +1,7 @@
 use crate::application::{error::ApplicationError, use_cases::UseCase};
 use crate::domain::{entities::user::User, repositories::UserRepository};
 use async_trait::async_trait;
+use tracing::instrument;
 
 pub struct ListUsersUseCase<R: UserRepository> {
     repository: R,
@@ -16,6 +17,7 @@ impl<R: UserRepository> ListUsersUseCase<R> {
 impl<R: UserRepository + Send + Sync> UseCase<(), Vec<User>, ApplicationError>
     for ListUsersUseCase<R>
 {
+    #[instrument(name = "ListUsersUseCase::execute", skip_all)]
     async fn execute(&self, _: ()) -> Result<Vec<User>, ApplicationError> {
         // Fetch all users
         let users = self.repository.list().await?;
@@ -1,11 +1,13 @@
 pub mod create_user;
 pub mod delete_user;
+pub mod export_users;
 pub mod get_user;
 pub mod list_users;
 pub mod update_user;
 
 pub use create_user::CreateUserUseCase;
 pub use delete_user::DeleteUserUseCase;
+pub use export_users::ExportUsersUseCase;
 pub use get_user::GetUserUseCase;
 pub use list_users::ListUsersUseCase;
 pub use update_user::UpdateUserUseCase;
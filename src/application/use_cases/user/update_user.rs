@@ -1,9 +1,13 @@
 use crate::application::{error::ApplicationError, use_cases::UseCase};
 use crate::domain::{
-    entities::user::{UpdateUserDto, User},
+    entities::{
+        domain_event::DomainEvent,
+        user::{UpdateUserDto, User},
+    },
     repositories::UserRepository,
 };
 use async_trait::async_trait;
+use tracing::instrument;
 use uuid::Uuid;
 
 pub struct UpdateUserUseCase<R: UserRepository> {
@@ -20,6 +24,7 @@ impl<R: UserRepository> UpdateUserUseCase<R> {
 impl<R: UserRepository + Send + Sync> UseCase<(Uuid, UpdateUserDto), User, ApplicationError>
     for UpdateUserUseCase<R>
 {
+    #[instrument(name = "UpdateUserUseCase::execute", skip_all)]
     async fn execute(&self, input: (Uuid, UpdateUserDto)) -> Result<User, ApplicationError> {
         let (id, update_dto) = input;
 
@@ -54,7 +59,12 @@ impl<R: UserRepository + Send + Sync> UseCase<(Uuid, UpdateUserDto), User, Appli
         }
 
         // Update user
-        let user = self.repository.update(id, update_dto).await?;
+        let user = self
+            .repository
+            .update(id, update_dto, |user| {
+                DomainEvent::UserUpdated(user.clone())
+            })
+            .await?;
         Ok(user)
     }
 }
@@ -0,0 +1,51 @@
+use super::{validate_event_types, validate_secret, validate_url};
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::webhook::{CreateWebhookSubscriptionDto, WebhookSubscription},
+    repositories::WebhookRepository,
+};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct CreateWebhookSubscriptionUseCase<R: WebhookRepository> {
+    repository: R,
+}
+
+impl<R: WebhookRepository> CreateWebhookSubscriptionUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: WebhookRepository + Send + Sync>
+    UseCase<CreateWebhookSubscriptionDto, WebhookSubscription, ApplicationError>
+    for CreateWebhookSubscriptionUseCase<R>
+{
+    #[instrument(name = "CreateWebhookSubscriptionUseCase::execute", skip_all)]
+    async fn execute(
+        &self,
+        mut input: CreateWebhookSubscriptionDto,
+    ) -> Result<WebhookSubscription, ApplicationError> {
+        input.event_types.sort();
+        input.event_types.dedup();
+
+        // Validate input
+        validate_url(&input.url)?;
+        validate_event_types(&input.event_types)?;
+        match &input.secret {
+            Some(secret) => validate_secret(secret)?,
+            None => {
+                input.secret = Some(format!(
+                    "whsec_{}{}",
+                    Uuid::new_v4().simple(),
+                    Uuid::new_v4().simple()
+                ))
+            }
+        }
+
+        let subscription = self.repository.create_subscription(input).await?;
+        Ok(subscription)
+    }
+}
@@ -0,0 +1,30 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::repositories::{RepositoryError, WebhookRepository};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct DeleteWebhookSubscriptionUseCase<R: WebhookRepository> {
+    repository: R,
+}
+
+impl<R: WebhookRepository> DeleteWebhookSubscriptionUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: WebhookRepository + Send + Sync> UseCase<Uuid, (), ApplicationError>
+    for DeleteWebhookSubscriptionUseCase<R>
+{
+    #[instrument(name = "DeleteWebhookSubscriptionUseCase::execute", skip_all)]
+    async fn execute(&self, id: Uuid) -> Result<(), ApplicationError> {
+        // Pending deliveries go with the subscription
+        match self.repository.delete_subscription(id).await {
+            Ok(()) => Ok(()),
+            Err(RepositoryError::NotFound) => Err(ApplicationError::NotFound),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
@@ -0,0 +1,358 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::webhook::{DueWebhookDelivery, WebhookAttemptOutcome},
+    gateways::{webhook_sender::WebhookRequest, WebhookSender},
+    repositories::WebhookDeliveryQueue,
+    services::webhook_signature::{sign, SIGNATURE_HEADER, TIMESTAMP_HEADER},
+};
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use futures::future::join_all;
+use log::{error, warn};
+use tracing::instrument;
+
+/// Deliveries attempted per run
+const BATCH_SIZE: i64 = 20;
+/// How long a claimed delivery is hidden from other workers
+const LEASE: Duration = Duration::seconds(60);
+/// Attempts before a delivery is given up on
+pub const MAX_ATTEMPTS: i32 = 10;
+/// Wait after the first failed attempt, doubled after every further one
+const FIRST_RETRY_DELAY: Duration = Duration::seconds(30);
+const MAX_RETRY_DELAY: Duration = Duration::hours(1);
+/// Failed attempts in a row after which a subscription is disabled
+pub const DISABLE_AFTER_FAILURES: i32 = 20;
+
+/// When to retry a delivery that failed its `attempts`-th attempt, if at all
+fn retry_delay(attempts: i32) -> Option<Duration> {
+    if attempts >= MAX_ATTEMPTS {
+        return None;
+    }
+
+    let factor = 2_i32.saturating_pow(attempts.saturating_sub(1) as u32);
+    Some((FIRST_RETRY_DELAY * factor).min(MAX_RETRY_DELAY))
+}
+
+/// Attempts the due deliveries once each, returning how many were attempted
+pub struct DeliverWebhooksUseCase<R: WebhookDeliveryQueue, S: WebhookSender> {
+    repository: R,
+    sender: S,
+}
+
+impl<R: WebhookDeliveryQueue, S: WebhookSender> DeliverWebhooksUseCase<R, S> {
+    pub fn new(repository: R, sender: S) -> Self {
+        Self { repository, sender }
+    }
+
+    async fn attempt(&self, due: &DueWebhookDelivery) -> WebhookAttemptOutcome {
+        let delivery = &due.delivery;
+        let body = serde_json::to_vec(&delivery.payload).unwrap_or_default();
+        let timestamp = Utc::now().timestamp();
+        let request = WebhookRequest {
+            url: &due.url,
+            headers: vec![
+                ("Content-Type", "application/json".to_string()),
+                ("X-Webhook-Id", delivery.event_id.to_string()),
+                ("X-Webhook-Delivery", delivery.id.to_string()),
+                ("X-Webhook-Event", delivery.event_type.clone()),
+                (TIMESTAMP_HEADER, timestamp.to_string()),
+                (SIGNATURE_HEADER, sign(&due.secret, timestamp, &body)),
+            ],
+            body,
+        };
+
+        let (response_status, error) = match self.sender.send(request).await {
+            Ok(status) if (200..300).contains(&status) => {
+                return WebhookAttemptOutcome::Succeeded {
+                    response_status: status.into(),
+                }
+            }
+            Ok(status) => (Some(status.into()), format!("Endpoint answered {}", status)),
+            Err(e) => (None, e.to_string()),
+        };
+
+        let retry_at = retry_delay(delivery.attempts + 1).map(|delay| Utc::now() + delay);
+        if retry_at.is_none() {
+            warn!(
+                "Giving up on webhook delivery {} to {} after {} attempts: {}",
+                delivery.id,
+                due.url,
+                delivery.attempts + 1,
+                error
+            );
+        }
+        WebhookAttemptOutcome::Failed {
+            response_status,
+            error,
+            retry_at,
+        }
+    }
+}
+
+#[async_trait]
+impl<R, S> UseCase<(), usize, ApplicationError> for DeliverWebhooksUseCase<R, S>
+where
+    R: WebhookDeliveryQueue + Send + Sync,
+    S: WebhookSender + Send + Sync,
+{
+    #[instrument(name = "DeliverWebhooksUseCase::execute", skip_all)]
+    async fn execute(&self, _: ()) -> Result<usize, ApplicationError> {
+        let due = self
+            .repository
+            .claim_due(BATCH_SIZE, Utc::now() + LEASE)
+            .await?;
+
+        let outcomes = join_all(due.iter().map(|delivery| self.attempt(delivery))).await;
+        for (delivery, outcome) in due.iter().zip(outcomes) {
+            // A lost result only means the delivery is retried once its lease ends
+            match self
+                .repository
+                .record_attempt(delivery.delivery.id, outcome, DISABLE_AFTER_FAILURES)
+                .await
+            {
+                Ok(true) => warn!(
+                    "Disabled webhook subscription {} after {} failed attempts in a row",
+                    delivery.delivery.subscription_id, DISABLE_AFTER_FAILURES
+                ),
+                Ok(false) => {}
+                Err(e) => error!(
+                    "Error recording webhook delivery {}: {:?}",
+                    delivery.delivery.id, e
+                ),
+            }
+        }
+
+        Ok(due.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        entities::webhook::{WebhookDelivery, WebhookDeliveryStatus},
+        repositories::RepositoryError,
+    };
+    use crate::infrastructure::webhooks::HttpWebhookSender;
+    use actix_web::{web, App, HttpRequest, HttpResponse, HttpServer};
+    use chrono::DateTime;
+    use serde_json::json;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    const SECRET: &str = "whsec_test";
+
+    /// Hands out one delivery and keeps the attempts recorded for it
+    #[derive(Default)]
+    struct FakeRepository {
+        due: Mutex<Vec<DueWebhookDelivery>>,
+        recorded: Mutex<Vec<(Uuid, WebhookAttemptOutcome, i32)>>,
+    }
+
+    #[async_trait]
+    impl WebhookDeliveryQueue for FakeRepository {
+        async fn claim_due(
+            &self,
+            _: i64,
+            _: DateTime<Utc>,
+        ) -> Result<Vec<DueWebhookDelivery>, RepositoryError> {
+            Ok(std::mem::take(&mut *self.due.lock().unwrap()))
+        }
+
+        async fn record_attempt(
+            &self,
+            id: Uuid,
+            outcome: WebhookAttemptOutcome,
+            disable_after: i32,
+        ) -> Result<bool, RepositoryError> {
+            self.recorded
+                .lock()
+                .unwrap()
+                .push((id, outcome, disable_after));
+            Ok(false)
+        }
+    }
+
+    /// A request the local endpoint received
+    struct Received {
+        headers: Vec<(String, String)>,
+        body: Vec<u8>,
+    }
+
+    impl Received {
+        fn header(&self, name: &str) -> &str {
+            self.headers
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_str())
+                .unwrap_or_default()
+        }
+    }
+
+    /// Starts an endpoint on a free local port answering every request with `status`
+    fn endpoint(status: u16) -> (String, web::Data<Mutex<Vec<Received>>>) {
+        let received = web::Data::new(Mutex::new(Vec::<Received>::new()));
+        let log = received.clone();
+        let server = HttpServer::new(move || {
+            App::new().app_data(log.clone()).default_service(web::to(
+                move |req: HttpRequest, body: web::Bytes, log: web::Data<Mutex<Vec<Received>>>| {
+                    log.lock().unwrap().push(Received {
+                        headers: req
+                            .headers()
+                            .iter()
+                            .map(|(name, value)| {
+                                (name.to_string(), value.to_str().unwrap().to_string())
+                            })
+                            .collect(),
+                        body: body.to_vec(),
+                    });
+                    async move {
+                        HttpResponse::build(actix_web::http::StatusCode::from_u16(status).unwrap())
+                            .finish()
+                    }
+                },
+            ))
+        })
+        .workers(1)
+        .bind(("127.0.0.1", 0))
+        .unwrap();
+        let address = server.addrs()[0];
+        actix_web::rt::spawn(server.run());
+
+        (format!("http://{address}/hooks"), received)
+    }
+
+    fn due(url: &str, attempts: i32) -> DueWebhookDelivery {
+        DueWebhookDelivery {
+            delivery: WebhookDelivery {
+                id: Uuid::new_v4(),
+                subscription_id: Uuid::new_v4(),
+                event_id: Uuid::new_v4(),
+                event_type: "product.updated".to_string(),
+                payload: json!({ "type": "product.updated", "data": { "name": "A" } }),
+                status: WebhookDeliveryStatus::Pending,
+                attempts,
+                next_attempt_at: Utc::now(),
+                last_attempt_at: None,
+                response_status: None,
+                last_error: None,
+                created_at: Utc::now(),
+            },
+            url: url.to_string(),
+            secret: SECRET.to_string(),
+        }
+    }
+
+    /// Runs one delivery pass and returns what was recorded for the delivery
+    async fn deliver(delivery: DueWebhookDelivery) -> (WebhookAttemptOutcome, i32) {
+        let repository = FakeRepository::default();
+        repository.due.lock().unwrap().push(delivery);
+        let use_case = DeliverWebhooksUseCase::new(repository, HttpWebhookSender::new());
+
+        assert_eq!(use_case.execute(()).await.unwrap(), 1);
+        let (_, outcome, disable_after) = use_case.repository.recorded.lock().unwrap().remove(0);
+        (outcome, disable_after)
+    }
+
+    fn retry_in(outcome: &WebhookAttemptOutcome) -> Option<Duration> {
+        match outcome {
+            WebhookAttemptOutcome::Failed { retry_at, .. } => {
+                retry_at.map(|retry_at| retry_at - Utc::now())
+            }
+            WebhookAttemptOutcome::Succeeded { .. } => panic!("Delivery should have failed"),
+        }
+    }
+
+    fn assert_about(actual: Duration, expected: Duration) {
+        let slack = Duration::seconds(5);
+        assert!(
+            actual <= expected && actual > expected - slack,
+            "Expected about {expected}, got {actual}"
+        );
+    }
+
+    #[actix_web::test]
+    async fn sends_signed_and_timestamped_payloads() {
+        let (url, received) = endpoint(204);
+        let delivery = due(&url, 0);
+        let (delivery_id, event_id) = (delivery.delivery.id, delivery.delivery.event_id);
+
+        let (outcome, _) = deliver(delivery).await;
+        assert!(matches!(
+            outcome,
+            WebhookAttemptOutcome::Succeeded {
+                response_status: 204
+            }
+        ));
+
+        let received = received.lock().unwrap();
+        let request = &received[0];
+        let timestamp: i64 = request.header(TIMESTAMP_HEADER).parse().unwrap();
+        assert!((Utc::now().timestamp() - timestamp).abs() <= 5);
+        assert_eq!(
+            request.header(SIGNATURE_HEADER),
+            sign(SECRET, timestamp, &request.body)
+        );
+        assert_eq!(request.header("X-Webhook-Event"), "product.updated");
+        assert_eq!(request.header("X-Webhook-Id"), event_id.to_string());
+        assert_eq!(
+            request.header("X-Webhook-Delivery"),
+            delivery_id.to_string()
+        );
+        assert_eq!(request.header("Content-Type"), "application/json");
+        let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+        assert_eq!(body["data"]["name"], "A");
+    }
+
+    #[actix_web::test]
+    async fn backs_off_exponentially_up_to_an_hour() {
+        let (url, _) = endpoint(503);
+
+        let (outcome, _) = deliver(due(&url, 0)).await;
+        assert!(matches!(
+            outcome,
+            WebhookAttemptOutcome::Failed {
+                response_status: Some(503),
+                ..
+            }
+        ));
+        assert_about(retry_in(&outcome).unwrap(), Duration::seconds(30));
+
+        let (outcome, _) = deliver(due(&url, 3)).await;
+        assert_about(retry_in(&outcome).unwrap(), Duration::minutes(4));
+
+        let (outcome, _) = deliver(due(&url, 8)).await;
+        assert_about(retry_in(&outcome).unwrap(), Duration::hours(1));
+    }
+
+    #[actix_web::test]
+    async fn gives_up_after_the_last_attempt() {
+        let (url, _) = endpoint(500);
+
+        let (outcome, _) = deliver(due(&url, MAX_ATTEMPTS - 2)).await;
+        assert!(retry_in(&outcome).is_some());
+
+        let (outcome, _) = deliver(due(&url, MAX_ATTEMPTS - 1)).await;
+        assert_eq!(retry_in(&outcome), None);
+    }
+
+    #[actix_web::test]
+    async fn counts_unreachable_endpoints_as_failures() {
+        // Nothing listens on a port that was just released
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+
+        let (outcome, disable_after) = deliver(due(&format!("http://127.0.0.1:{port}"), 0)).await;
+        assert!(matches!(
+            outcome,
+            WebhookAttemptOutcome::Failed {
+                response_status: None,
+                ..
+            }
+        ));
+        assert_eq!(disable_after, DISABLE_AFTER_FAILURES);
+    }
+}
@@ -0,0 +1,28 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{entities::webhook::WebhookSubscription, repositories::WebhookRepository};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct GetWebhookSubscriptionUseCase<R: WebhookRepository> {
+    repository: R,
+}
+
+impl<R: WebhookRepository> GetWebhookSubscriptionUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: WebhookRepository + Send + Sync> UseCase<Uuid, WebhookSubscription, ApplicationError>
+    for GetWebhookSubscriptionUseCase<R>
+{
+    #[instrument(name = "GetWebhookSubscriptionUseCase::execute", skip_all)]
+    async fn execute(&self, id: Uuid) -> Result<WebhookSubscription, ApplicationError> {
+        self.repository
+            .find_subscription(id)
+            .await?
+            .ok_or(ApplicationError::NotFound)
+    }
+}
@@ -0,0 +1,59 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{entities::webhook::WebhookDelivery, repositories::WebhookRepository};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// Deliveries returned when the caller asks for no particular number
+pub const DEFAULT_DELIVERY_LIMIT: i64 = 50;
+/// Most deliveries returned at once
+pub const MAX_DELIVERY_LIMIT: i64 = 200;
+
+/// The delivery log of a subscription, newest first
+pub struct ListWebhookDeliveriesUseCase<R: WebhookRepository> {
+    repository: R,
+}
+
+impl<R: WebhookRepository> ListWebhookDeliveriesUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: WebhookRepository + Send + Sync>
+    UseCase<(Uuid, Option<i64>), Vec<WebhookDelivery>, ApplicationError>
+    for ListWebhookDeliveriesUseCase<R>
+{
+    #[instrument(name = "ListWebhookDeliveriesUseCase::execute", skip_all)]
+    async fn execute(
+        &self,
+        input: (Uuid, Option<i64>),
+    ) -> Result<Vec<WebhookDelivery>, ApplicationError> {
+        let (subscription_id, limit) = input;
+
+        // Validate input
+        let limit = limit.unwrap_or(DEFAULT_DELIVERY_LIMIT);
+        if !(1..=MAX_DELIVERY_LIMIT).contains(&limit) {
+            return Err(ApplicationError::Validation(format!(
+                "Limit must be between 1 and {}",
+                MAX_DELIVERY_LIMIT
+            )));
+        }
+
+        if self
+            .repository
+            .find_subscription(subscription_id)
+            .await?
+            .is_none()
+        {
+            return Err(ApplicationError::NotFound);
+        }
+
+        let deliveries = self
+            .repository
+            .list_deliveries(subscription_id, limit)
+            .await?;
+        Ok(deliveries)
+    }
+}
@@ -0,0 +1,25 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{entities::webhook::WebhookSubscription, repositories::WebhookRepository};
+use async_trait::async_trait;
+use tracing::instrument;
+
+pub struct ListWebhookSubscriptionsUseCase<R: WebhookRepository> {
+    repository: R,
+}
+
+impl<R: WebhookRepository> ListWebhookSubscriptionsUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: WebhookRepository + Send + Sync> UseCase<(), Vec<WebhookSubscription>, ApplicationError>
+    for ListWebhookSubscriptionsUseCase<R>
+{
+    #[instrument(name = "ListWebhookSubscriptionsUseCase::execute", skip_all)]
+    async fn execute(&self, _: ()) -> Result<Vec<WebhookSubscription>, ApplicationError> {
+        let subscriptions = self.repository.list_subscriptions().await?;
+        Ok(subscriptions)
+    }
+}
@@ -0,0 +1,70 @@
+pub mod create_webhook_subscription;
+pub mod delete_webhook_subscription;
+pub mod deliver_webhooks;
+pub mod get_webhook_subscription;
+pub mod list_webhook_deliveries;
+pub mod list_webhook_subscriptions;
+pub mod redeliver_webhook;
+pub mod update_webhook_subscription;
+
+pub use create_webhook_subscription::CreateWebhookSubscriptionUseCase;
+pub use delete_webhook_subscription::DeleteWebhookSubscriptionUseCase;
+pub use deliver_webhooks::DeliverWebhooksUseCase;
+pub use get_webhook_subscription::GetWebhookSubscriptionUseCase;
+pub use list_webhook_deliveries::ListWebhookDeliveriesUseCase;
+pub use list_webhook_subscriptions::ListWebhookSubscriptionsUseCase;
+pub use redeliver_webhook::RedeliverWebhookUseCase;
+pub use update_webhook_subscription::UpdateWebhookSubscriptionUseCase;
+
+use crate::application::error::ApplicationError;
+use crate::domain::entities::webhook::WEBHOOK_EVENT_TYPES;
+
+fn validate_url(url: &str) -> Result<(), ApplicationError> {
+    let host = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or_default();
+
+    if host.is_empty()
+        || host.starts_with('/')
+        || url.len() > 2048
+        || url.contains(char::is_whitespace)
+    {
+        return Err(ApplicationError::Validation(
+            "URL must be an absolute http or https URL".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_event_types(event_types: &[String]) -> Result<(), ApplicationError> {
+    if event_types.is_empty() {
+        return Err(ApplicationError::Validation(
+            "At least one event type is required".to_string(),
+        ));
+    }
+
+    if let Some(unknown) = event_types
+        .iter()
+        .find(|event_type| !WEBHOOK_EVENT_TYPES.contains(&event_type.as_str()))
+    {
+        return Err(ApplicationError::Validation(format!(
+            "Unknown event type '{}', expected one of {}",
+            unknown,
+            WEBHOOK_EVENT_TYPES.join(", ")
+        )));
+    }
+
+    Ok(())
+}
+
+fn validate_secret(secret: &str) -> Result<(), ApplicationError> {
+    if secret.len() < 16 || secret.len() > 256 {
+        return Err(ApplicationError::Validation(
+            "Secret must be between 16 and 256 characters".to_string(),
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{entities::webhook::WebhookDelivery, repositories::WebhookRepository};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// Queues a logged delivery again, input being the subscription and delivery ids
+pub struct RedeliverWebhookUseCase<R: WebhookRepository> {
+    repository: R,
+}
+
+impl<R: WebhookRepository> RedeliverWebhookUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: WebhookRepository + Send + Sync> UseCase<(Uuid, Uuid), WebhookDelivery, ApplicationError>
+    for RedeliverWebhookUseCase<R>
+{
+    #[instrument(name = "RedeliverWebhookUseCase::execute", skip_all)]
+    async fn execute(&self, input: (Uuid, Uuid)) -> Result<WebhookDelivery, ApplicationError> {
+        let (subscription_id, delivery_id) = input;
+
+        let subscription = self
+            .repository
+            .find_subscription(subscription_id)
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+        if !subscription.active {
+            return Err(ApplicationError::Validation(
+                "Subscription is disabled, reactivate it first".to_string(),
+            ));
+        }
+
+        let delivery = self
+            .repository
+            .find_delivery(subscription_id, delivery_id)
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+
+        let delivery = self.repository.redeliver(delivery.id).await?;
+        Ok(delivery)
+    }
+}
@@ -0,0 +1,52 @@
+use super::{validate_event_types, validate_secret, validate_url};
+use crate::application::{error::ApplicationError, use_cases::UseCase};
+use crate::domain::{
+    entities::webhook::{UpdateWebhookSubscriptionDto, WebhookSubscription},
+    repositories::{RepositoryError, WebhookRepository},
+};
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct UpdateWebhookSubscriptionUseCase<R: WebhookRepository> {
+    repository: R,
+}
+
+impl<R: WebhookRepository> UpdateWebhookSubscriptionUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: WebhookRepository + Send + Sync>
+    UseCase<(Uuid, UpdateWebhookSubscriptionDto), WebhookSubscription, ApplicationError>
+    for UpdateWebhookSubscriptionUseCase<R>
+{
+    #[instrument(name = "UpdateWebhookSubscriptionUseCase::execute", skip_all)]
+    async fn execute(
+        &self,
+        input: (Uuid, UpdateWebhookSubscriptionDto),
+    ) -> Result<WebhookSubscription, ApplicationError> {
+        let (id, mut update_dto) = input;
+
+        // Validate input
+        if let Some(url) = &update_dto.url {
+            validate_url(url)?;
+        }
+        if let Some(event_types) = &mut update_dto.event_types {
+            event_types.sort();
+            event_types.dedup();
+            validate_event_types(event_types)?;
+        }
+        if let Some(secret) = &update_dto.secret {
+            validate_secret(secret)?;
+        }
+
+        match self.repository.update_subscription(id, update_dto).await {
+            Ok(subscription) => Ok(subscription),
+            Err(RepositoryError::NotFound) => Err(ApplicationError::NotFound),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
@@ -1,9 +1,20 @@
-use super::{DatabaseConfig, Environment, LoggerConfig};
+use super::{
+    AuthConfig, DatabaseConfig, Environment, HealthConfig, LoggerConfig, MediaConfig, OutboxConfig,
+    PaymentConfig, ShutdownConfig, TelemetryConfig,
+};
+use crate::infrastructure::telemetry::Telemetry;
 use log::info;
 
 pub struct AppConfig {
     pub env: Environment,
     pub db: DatabaseConfig,
+    pub payment: PaymentConfig,
+    pub media: MediaConfig,
+    pub auth: AuthConfig,
+    pub outbox: OutboxConfig,
+    pub health: HealthConfig,
+    pub shutdown: ShutdownConfig,
+    pub telemetry: Telemetry,
 }
 
 impl AppConfig {
@@ -14,13 +25,41 @@ impl AppConfig {
         // Initialize environment
         let env = Environment::new();
 
-        // Initialize logger
-        LoggerConfig::init(&env.rust_log);
+        // Initialize logging and trace export
+        let telemetry = Telemetry::init(&LoggerConfig::new(&env), &TelemetryConfig::new());
 
         info!("Initializing database connection...");
         // Initialize database
         let db = DatabaseConfig::init_pool(&env.database_url).await;
 
-        Self { env, db }
+        // Initialize payment provider settings
+        let payment = PaymentConfig::new(env.is_production());
+
+        // Initialize product image storage settings
+        let media = MediaConfig::new();
+
+        // Initialize bearer token validation
+        let auth = AuthConfig::new();
+
+        // Initialize domain event sinks
+        let outbox = OutboxConfig::new();
+
+        // Initialize readiness checks
+        let health = HealthConfig::new();
+
+        // Initialize shutdown timing
+        let shutdown = ShutdownConfig::new(env.is_production());
+
+        Self {
+            env,
+            db,
+            payment,
+            media,
+            auth,
+            outbox,
+            health,
+            shutdown,
+            telemetry,
+        }
     }
 }
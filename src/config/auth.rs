@@ -0,0 +1,17 @@
+use std::env;
+
+#[derive(Debug, Clone)]
+pub struct AuthConfig {
+    /// HS256 secret bearer tokens are signed with, every request is anonymous without it
+    pub jwt_secret: Option<String>,
+}
+
+impl AuthConfig {
+    pub fn new() -> Self {
+        Self {
+            jwt_secret: env::var("AUTH_JWT_SECRET")
+                .ok()
+                .filter(|secret| !secret.is_empty()),
+        }
+    }
+}
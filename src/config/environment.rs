@@ -5,6 +5,7 @@ pub struct Environment {
     pub database_url: String,
     pub server_host: String,
     pub server_port: u16,
+    pub grpc_port: u16,
     pub rust_log: String,
     pub environment: EnvironmentType,
 }
@@ -25,6 +26,10 @@ impl Environment {
                 .unwrap_or_else(|_| "8080".to_string())
                 .parse()
                 .expect("SERVER_PORT must be a valid number"),
+            grpc_port: env::var("GRPC_PORT")
+                .unwrap_or_else(|_| "50051".to_string())
+                .parse()
+                .expect("GRPC_PORT must be a valid number"),
             rust_log: env::var("RUST_LOG").unwrap_or_else(|_| "debug".to_string()),
             environment: match env::var("ENVIRONMENT").as_deref() {
                 Ok("production") => EnvironmentType::Production,
@@ -0,0 +1,20 @@
+use std::env;
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct HealthConfig {
+    /// Longest a readiness check may take before it counts as down
+    pub check_timeout: Duration,
+}
+
+impl HealthConfig {
+    pub fn new() -> Self {
+        Self {
+            check_timeout: Duration::from_millis(
+                env::var("HEALTH_CHECK_TIMEOUT_MS")
+                    .map(|v| v.parse().expect("HEALTH_CHECK_TIMEOUT_MS must be a number"))
+                    .unwrap_or(2000),
+            ),
+        }
+    }
+}
@@ -1,12 +1,36 @@
-use env_logger::{Builder, Env};
+use std::env;
 
-pub struct LoggerConfig;
+use super::Environment;
+
+/// How log lines are written to stdout
+#[derive(Debug, Clone, PartialEq)]
+pub enum LogFormat {
+    /// One JSON object per line, for log aggregation
+    Json,
+    /// Human-readable lines with the enclosing spans, for local development
+    Pretty,
+}
+
+#[derive(Debug, Clone)]
+pub struct LoggerConfig {
+    /// `RUST_LOG` directives, e.g. `info,sqlx=warn`
+    pub filter: String,
+    pub format: LogFormat,
+}
 
 impl LoggerConfig {
-    pub fn init(rust_log: &str) {
-        Builder::from_env(Env::default().default_filter_or(rust_log))
-            .format_timestamp_millis()
-            .format_module_path(true)
-            .init();
+    pub fn new(env: &Environment) -> Self {
+        let format = match env::var("LOG_FORMAT").as_deref() {
+            Ok("json") => LogFormat::Json,
+            Ok("pretty") => LogFormat::Pretty,
+            Ok(other) => panic!("Unknown LOG_FORMAT {other}, expected json or pretty"),
+            Err(_) if env.is_production() => LogFormat::Json,
+            Err(_) => LogFormat::Pretty,
+        };
+
+        Self {
+            filter: env.rust_log.clone(),
+            format,
+        }
     }
 }
@@ -0,0 +1,78 @@
+use std::env;
+
+/// Maximum accepted upload size when `MEDIA_MAX_IMAGE_BYTES` is not set
+const DEFAULT_MAX_IMAGE_BYTES: usize = 5 * 1024 * 1024;
+
+/// Where product images are stored
+#[derive(Debug, Clone)]
+pub enum MediaBackend {
+    /// Files on the local disk, served by the application itself
+    Local { dir: String },
+    /// An S3-compatible bucket
+    #[cfg(feature = "s3")]
+    S3(S3Config),
+}
+
+#[cfg(feature = "s3")]
+#[derive(Debug, Clone)]
+pub struct S3Config {
+    pub endpoint: String,
+    pub bucket: String,
+    pub region: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct MediaConfig {
+    pub backend: MediaBackend,
+    /// Prefix of the public image URLs
+    pub base_url: String,
+    pub max_image_bytes: usize,
+}
+
+impl MediaConfig {
+    pub fn new() -> Self {
+        let backend = match env::var("MEDIA_STORAGE")
+            .unwrap_or_else(|_| "local".to_string())
+            .as_str()
+        {
+            "local" => MediaBackend::Local {
+                dir: env::var("MEDIA_LOCAL_DIR").unwrap_or_else(|_| "./uploads".to_string()),
+            },
+            #[cfg(feature = "s3")]
+            "s3" => MediaBackend::S3(S3Config {
+                endpoint: required("S3_ENDPOINT"),
+                bucket: required("S3_BUCKET"),
+                region: env::var("S3_REGION").unwrap_or_else(|_| "us-east-1".to_string()),
+                access_key_id: required("S3_ACCESS_KEY_ID"),
+                secret_access_key: required("S3_SECRET_ACCESS_KEY"),
+            }),
+            #[cfg(not(feature = "s3"))]
+            "s3" => panic!("MEDIA_STORAGE=s3 requires building with the `s3` feature"),
+            other => panic!("MEDIA_STORAGE must be local or s3, got {other}"),
+        };
+
+        let base_url = match &backend {
+            MediaBackend::Local { .. } => {
+                env::var("MEDIA_BASE_URL").unwrap_or_else(|_| "/media".to_string())
+            }
+            #[cfg(feature = "s3")]
+            MediaBackend::S3(s3) => env::var("MEDIA_BASE_URL")
+                .unwrap_or_else(|_| format!("{}/{}", s3.endpoint.trim_end_matches('/'), s3.bucket)),
+        };
+
+        Self {
+            backend,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            max_image_bytes: env::var("MEDIA_MAX_IMAGE_BYTES")
+                .map(|v| v.parse().expect("MEDIA_MAX_IMAGE_BYTES must be a number"))
+                .unwrap_or(DEFAULT_MAX_IMAGE_BYTES),
+        }
+    }
+}
+
+#[cfg(feature = "s3")]
+fn required(name: &str) -> String {
+    env::var(name).unwrap_or_else(|_| panic!("{name} must be set when MEDIA_STORAGE=s3"))
+}
@@ -1,9 +1,23 @@
 pub mod app;
+pub mod auth;
 pub mod database;
 pub mod environment;
+pub mod health;
 pub mod logger;
+pub mod media;
+pub mod outbox;
+pub mod payment;
+pub mod shutdown;
+pub mod telemetry;
 
 pub use app::AppConfig;
+pub use auth::AuthConfig;
 pub use database::DatabaseConfig;
 pub use environment::Environment;
+pub use health::HealthConfig;
 pub use logger::LoggerConfig;
+pub use media::MediaConfig;
+pub use outbox::OutboxConfig;
+pub use payment::PaymentConfig;
+pub use shutdown::ShutdownConfig;
+pub use telemetry::TelemetryConfig;
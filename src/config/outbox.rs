@@ -0,0 +1,37 @@
+use std::env;
+
+/// Where the outbox relay publishes domain events
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutboxSink {
+    /// The in-process event bus
+    Bus,
+    /// Deliveries to the matching webhook subscriptions
+    Webhooks,
+    /// The application log
+    Log,
+}
+
+#[derive(Debug, Clone)]
+pub struct OutboxConfig {
+    /// Sinks in publishing order
+    pub sinks: Vec<OutboxSink>,
+}
+
+impl OutboxConfig {
+    pub fn new() -> Self {
+        let sinks = env::var("OUTBOX_SINKS")
+            .unwrap_or_else(|_| "bus,webhooks".to_string())
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(|name| match name {
+                "bus" => OutboxSink::Bus,
+                "webhooks" => OutboxSink::Webhooks,
+                "log" => OutboxSink::Log,
+                other => panic!("OUTBOX_SINKS entries must be bus, webhooks or log, got {other}"),
+            })
+            .collect();
+
+        Self { sinks }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::infrastructure::payments::FakeOutcome;
+use std::env;
+
+#[derive(Debug, Clone)]
+pub struct PaymentConfig {
+    pub webhook_secret: String,
+    pub fake_outcome: FakeOutcome,
+}
+
+impl PaymentConfig {
+    pub fn new(is_production: bool) -> Self {
+        let webhook_secret = match env::var("PAYMENT_WEBHOOK_SECRET") {
+            Ok(secret) => secret,
+            Err(_) if !is_production => "dev-webhook-secret".to_string(),
+            Err(_) => panic!("PAYMENT_WEBHOOK_SECRET must be set in production"),
+        };
+
+        Self {
+            webhook_secret,
+            fake_outcome: env::var("PAYMENT_FAKE_OUTCOME")
+                .unwrap_or_else(|_| "succeed".to_string())
+                .parse()
+                .expect("PAYMENT_FAKE_OUTCOME must be one of succeed, fail, requires_action"),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use std::env;
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct ShutdownConfig {
+    /// How long readiness fails before the server stops accepting connections,
+    /// giving load balancers time to notice
+    pub readiness_delay: Duration,
+    /// Longest in-flight requests may take to finish once the server stopped
+    /// accepting connections
+    pub drain_timeout: Duration,
+    /// Longest background tasks may take to finish their current work
+    pub task_timeout: Duration,
+}
+
+impl ShutdownConfig {
+    pub fn new(is_production: bool) -> Self {
+        Self {
+            readiness_delay: seconds(
+                "SHUTDOWN_READINESS_DELAY_SECS",
+                if is_production { 5 } else { 0 },
+            ),
+            drain_timeout: seconds("SHUTDOWN_DRAIN_TIMEOUT_SECS", 30),
+            task_timeout: seconds("SHUTDOWN_TASK_TIMEOUT_SECS", 30),
+        }
+    }
+}
+
+fn seconds(name: &str, default: u64) -> Duration {
+    Duration::from_secs(
+        env::var(name)
+            .map(|v| {
+                v.parse()
+                    .unwrap_or_else(|_| panic!("{name} must be a number of seconds"))
+            })
+            .unwrap_or(default),
+    )
+}
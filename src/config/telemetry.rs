@@ -0,0 +1,51 @@
+use std::env;
+
+/// Where finished spans are sent
+#[derive(Debug, Clone, PartialEq)]
+pub enum TraceExporter {
+    /// Tracing is off, no spans are recorded
+    None,
+    /// An OpenTelemetry collector over OTLP/gRPC
+    Otlp { endpoint: String },
+    /// One JSON line per span on stdout, for local debugging
+    Stdout,
+}
+
+#[derive(Debug, Clone)]
+pub struct TelemetryConfig {
+    pub exporter: TraceExporter,
+    pub service_name: String,
+    /// Share of new traces recorded, traces started upstream follow the caller's decision
+    pub sample_ratio: f64,
+}
+
+impl TelemetryConfig {
+    pub fn new() -> Self {
+        let exporter = match env::var("OTEL_TRACES_EXPORTER")
+            .unwrap_or_else(|_| "none".to_string())
+            .as_str()
+        {
+            "none" => TraceExporter::None,
+            "otlp" => TraceExporter::Otlp {
+                endpoint: env::var("OTEL_EXPORTER_OTLP_ENDPOINT")
+                    .unwrap_or_else(|_| "http://localhost:4317".to_string()),
+            },
+            "stdout" => TraceExporter::Stdout,
+            other => panic!("Unknown OTEL_TRACES_EXPORTER {other}, expected none, otlp or stdout"),
+        };
+
+        Self {
+            exporter,
+            service_name: env::var("OTEL_SERVICE_NAME")
+                .unwrap_or_else(|_| env!("CARGO_PKG_NAME").to_string()),
+            sample_ratio: env::var("OTEL_TRACES_SAMPLER_ARG")
+                .map(|v| {
+                    v.parse()
+                        .ok()
+                        .filter(|ratio| (0.0..=1.0).contains(ratio))
+                        .expect("OTEL_TRACES_SAMPLER_ARG must be a ratio between 0 and 1")
+                })
+                .unwrap_or(1.0),
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::product::Product;
+
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct Cart {
+    /// The unique identifier for the cart
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub id: Uuid,
+    /// Owner of the cart, empty for anonymous carts
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub user_id: Option<Uuid>,
+    /// Token identifying an anonymous cart
+    #[schema(example = "5f0c6b1e-8f4a-4d6e-9a4b-2c1d3e4f5a6b")]
+    pub token: Uuid,
+    /// When the cart was created
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub created_at: DateTime<Utc>,
+    /// Last update timestamp
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct CartItem {
+    /// The cart this item belongs to
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub cart_id: Uuid,
+    /// The product in the cart
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub product_id: Uuid,
+    /// Requested quantity
+    #[schema(example = "2")]
+    pub quantity: i32,
+    /// Unit price of the product when the cart was last priced
+    #[schema(example = "999.99")]
+    pub unit_price: Decimal,
+    /// When the item was added
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub created_at: DateTime<Utc>,
+    /// Last update timestamp
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Identifies whose cart an operation applies to
+#[derive(Debug, Clone, Copy)]
+pub enum CartOwner {
+    /// A registered user's cart, keyed by user ID
+    User(Uuid),
+    /// An anonymous cart, keyed by its cart token
+    Anonymous(Uuid),
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AddCartItemDto {
+    /// The product to add
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub product_id: Uuid,
+    /// Quantity to add to the cart
+    #[schema(example = "1")]
+    pub quantity: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateCartItemDto {
+    /// New quantity for the item
+    #[schema(example = "3")]
+    pub quantity: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MergeCartDto {
+    /// Token of the anonymous cart to merge into the user's cart
+    #[schema(example = "5f0c6b1e-8f4a-4d6e-9a4b-2c1d3e4f5a6b")]
+    pub cart_token: Uuid,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case", tag = "kind")]
+pub enum StockWarning {
+    /// The product is no longer in stock
+    OutOfStock,
+    /// Fewer units are available than requested
+    InsufficientStock { available: i32 },
+}
+
+/// A cart item priced against the current product catalog
+#[derive(Debug)]
+pub struct PricedCartItem {
+    pub product_id: Uuid,
+    pub product_name: String,
+    pub category: Option<String>,
+    pub quantity: i32,
+    pub unit_price: Decimal,
+    /// Currency of the unit price, that of the product
+    pub currency: String,
+    /// Price the item was previously held at, if the product price changed since
+    pub previous_unit_price: Option<Decimal>,
+    pub line_total: Decimal,
+    pub stock_warning: Option<StockWarning>,
+}
+
+/// A cart with all items re-priced against current product prices
+#[derive(Debug)]
+pub struct PricedCart {
+    pub cart: Cart,
+    pub items: Vec<PricedCartItem>,
+    pub subtotal: Decimal,
+    /// Currency of the subtotal, absent while the cart is empty
+    pub currency: Option<String>,
+}
+
+impl PricedCartItem {
+    pub fn new(item: &CartItem, product: &Product) -> Self {
+        let previous_unit_price = (item.unit_price != product.price).then_some(item.unit_price);
+
+        let stock_warning = if product.stock <= 0 {
+            Some(StockWarning::OutOfStock)
+        } else if item.quantity > product.stock {
+            Some(StockWarning::InsufficientStock {
+                available: product.stock,
+            })
+        } else {
+            None
+        };
+
+        Self {
+            product_id: item.product_id,
+            product_name: product.name.clone(),
+            category: product.category.clone(),
+            quantity: item.quantity,
+            unit_price: product.price,
+            currency: product.currency.clone(),
+            previous_unit_price,
+            line_total: product.price * Decimal::from(item.quantity),
+            stock_warning,
+        }
+    }
+}
+
+impl PricedCart {
+    pub fn new(cart: Cart, items: Vec<PricedCartItem>) -> Self {
+        let subtotal = items.iter().map(|item| item.line_total).sum();
+        let currency = items.first().map(|item| item.currency.clone());
+        Self {
+            cart,
+            items,
+            subtotal,
+            currency,
+        }
+    }
+
+    /// Whether the items are priced in more than one currency, which only carts
+    /// filled before adding items checked their currency can be
+    pub fn has_mixed_currencies(&self) -> bool {
+        self.items
+            .iter()
+            .any(|item| Some(&item.currency) != self.currency.as_ref())
+    }
+}
@@ -0,0 +1,84 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::{json, Value};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::{product::Product, user::User};
+
+/// Something that happened in the domain, announced by the use case that caused it
+#[derive(Debug, Clone)]
+pub enum DomainEvent {
+    ProductCreated(Product),
+    /// Any change of a product, stock reservations and scheduled prices included
+    ProductUpdated(Product),
+    ProductDeleted(Uuid),
+    UserCreated(User),
+    UserUpdated(User),
+    UserDeleted(Uuid),
+}
+
+/// Builds the event for the outcome of a write. Repositories call it inside the write's
+/// transaction, so the event is stored in the outbox if and only if the write commits
+pub type EventFactory<T> = fn(&T) -> DomainEvent;
+
+impl DomainEvent {
+    /// Name of the event, also used as webhook event type
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            DomainEvent::ProductCreated(_) => "product.created",
+            DomainEvent::ProductUpdated(_) => "product.updated",
+            DomainEvent::ProductDeleted(_) => "product.deleted",
+            DomainEvent::UserCreated(_) => "user.created",
+            DomainEvent::UserUpdated(_) => "user.updated",
+            DomainEvent::UserDeleted(_) => "user.deleted",
+        }
+    }
+
+    /// The product or user the event is about
+    pub fn aggregate_id(&self) -> Uuid {
+        match self {
+            DomainEvent::ProductCreated(product) | DomainEvent::ProductUpdated(product) => {
+                product.id
+            }
+            DomainEvent::UserCreated(user) | DomainEvent::UserUpdated(user) => user.id,
+            DomainEvent::ProductDeleted(id) | DomainEvent::UserDeleted(id) => *id,
+        }
+    }
+
+    /// What sinks receive as the event's data. Password hashes never leave the database
+    pub fn payload(&self) -> Value {
+        match self {
+            DomainEvent::ProductCreated(product) | DomainEvent::ProductUpdated(product) => {
+                serde_json::to_value(product).unwrap_or_default()
+            }
+            DomainEvent::UserCreated(user) | DomainEvent::UserUpdated(user) => json!({
+                "id": user.id,
+                "email": user.email,
+                "username": user.username,
+                "created_at": user.created_at,
+                "updated_at": user.updated_at,
+            }),
+            DomainEvent::ProductDeleted(id) | DomainEvent::UserDeleted(id) => json!({ "id": id }),
+        }
+    }
+}
+
+/// A domain event stored in the outbox, waiting for or done with relaying
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct OutboxEvent {
+    /// Unique per event and kept across redeliveries, sinks deduplicate on it
+    pub id: Uuid,
+    /// Order in which the events were recorded
+    pub sequence: i64,
+    pub event_type: String,
+    pub aggregate_id: Uuid,
+    pub payload: Value,
+    pub occurred_at: DateTime<Utc>,
+    /// Relay attempts so far
+    pub attempts: i32,
+    /// When the event may be relayed (again)
+    pub available_at: DateTime<Utc>,
+    pub published_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+}
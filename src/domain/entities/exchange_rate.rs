@@ -0,0 +1,78 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+/// Currency used for products created without one
+pub const DEFAULT_CURRENCY: &str = "USD";
+
+/// Whether `code` has the shape of an ISO 4217 alphabetic code
+pub fn is_currency_code(code: &str) -> bool {
+    code.len() == 3 && code.bytes().all(|b| b.is_ascii_uppercase())
+}
+
+/// Number of decimal places used by the currency's minor unit (ISO 4217)
+pub fn minor_units(code: &str) -> u32 {
+    match code {
+        "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF" | "UGX"
+        | "UYI" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+        "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+        _ => 2,
+    }
+}
+
+/// How many units of `quote_currency` one unit of `base_currency` buys
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct ExchangeRate {
+    /// Currency converted from
+    #[schema(example = "USD")]
+    pub base_currency: String,
+    /// Currency converted to
+    #[schema(example = "EUR")]
+    pub quote_currency: String,
+    /// Units of the quote currency per unit of the base currency
+    #[schema(example = "0.92")]
+    pub rate: Decimal,
+    /// When the rate was last set
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ExchangeRate {
+    /// The rate for the opposite direction
+    pub fn inverse(&self) -> Self {
+        Self {
+            base_currency: self.quote_currency.clone(),
+            quote_currency: self.base_currency.clone(),
+            rate: Decimal::ONE / self.rate,
+            updated_at: self.updated_at,
+        }
+    }
+
+    /// The rate of converting with this rate and then with `next`
+    pub fn then(&self, next: &ExchangeRate) -> Self {
+        Self {
+            base_currency: self.base_currency.clone(),
+            quote_currency: next.quote_currency.clone(),
+            rate: self.rate * next.rate,
+            updated_at: self.updated_at.min(next.updated_at),
+        }
+    }
+
+    /// Converts an amount in the base currency, rounding half to even to the
+    /// quote currency's minor unit
+    pub fn convert(&self, amount: Decimal) -> Decimal {
+        (amount * self.rate).round_dp_with_strategy(
+            minor_units(&self.quote_currency),
+            RoundingStrategy::MidpointNearestEven,
+        )
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SetExchangeRateDto {
+    /// Units of the quote currency per unit of the base currency
+    #[schema(example = "0.92")]
+    pub rate: Decimal,
+}
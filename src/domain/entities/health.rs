@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    Up,
+    Down,
+}
+
+/// The outcome of one dependency check
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct HealthCheckResult {
+    #[schema(example = "database")]
+    pub name: String,
+    pub status: HealthStatus,
+    /// How long the check took
+    #[schema(example = 1.7)]
+    pub duration_ms: f64,
+    /// What the check measured, such as pool usage
+    #[schema(value_type = Option<Object>)]
+    pub details: Option<serde_json::Value>,
+    /// Why the check failed
+    #[schema(example = "pool timed out while waiting for an open connection")]
+    pub error: Option<String>,
+}
+
+/// All dependency checks, up only when every one of them is
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    /// Set once the server started shutting down, which fails readiness on its own
+    pub shutting_down: bool,
+    /// How long all checks took together, they run concurrently
+    #[schema(example = 2.3)]
+    pub duration_ms: f64,
+    pub checks: Vec<HealthCheckResult>,
+}
@@ -0,0 +1,103 @@
+use chrono::{DateTime, Utc};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Queue of jobs that do not name one
+pub const DEFAULT_QUEUE: &str = "default";
+
+/// The payload of a kind of background job. Handlers receive it deserialized
+pub trait JobPayload: Serialize + DeserializeOwned + Send + Sync + 'static {
+    /// Identifies the handler, stored with every job
+    const KIND: &'static str;
+    const QUEUE: &'static str = DEFAULT_QUEUE;
+    /// Attempts before the job is dead-lettered
+    const MAX_ATTEMPTS: i32 = 5;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "job_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    /// Waiting for `run_at`, first run or retry
+    Queued,
+    Running,
+    Succeeded,
+    /// Gave up after its last attempt or a permanent failure, until retried by hand
+    Dead,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct Job {
+    /// The unique identifier for the job
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub id: Uuid,
+    /// Queue the job runs on, each with its own concurrency limit
+    #[schema(example = "media")]
+    pub queue: String,
+    /// Handler of the job
+    #[schema(example = "product_image.remove_files")]
+    pub kind: String,
+    #[schema(value_type = Object)]
+    pub payload: serde_json::Value,
+    pub status: JobStatus,
+    /// Attempts started so far
+    #[schema(example = 1)]
+    pub attempts: i32,
+    #[schema(example = 5)]
+    pub max_attempts: i32,
+    /// Earliest time of the next attempt
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub run_at: DateTime<Utc>,
+    /// Until when a worker holds the running job, after which it is taken over
+    #[schema(example = "2024-02-16T00:05:00Z")]
+    pub locked_until: Option<DateTime<Utc>>,
+    /// Why the last attempt failed
+    #[schema(example = "Storage unavailable")]
+    pub last_error: Option<String>,
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub created_at: DateTime<Utc>,
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub updated_at: DateTime<Utc>,
+    /// When the job succeeded, died or was cancelled
+    #[schema(example = "2024-02-16T00:00:01Z")]
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+/// A job to enqueue
+#[derive(Debug, Clone)]
+pub struct NewJob {
+    pub queue: &'static str,
+    pub kind: &'static str,
+    pub payload: serde_json::Value,
+    pub run_at: DateTime<Utc>,
+    pub max_attempts: i32,
+}
+
+impl NewJob {
+    /// A job running the payload's handler as soon as possible
+    pub fn new<P: JobPayload>(payload: &P) -> Self {
+        Self {
+            queue: P::QUEUE,
+            kind: P::KIND,
+            payload: serde_json::to_value(payload).unwrap_or_default(),
+            run_at: Utc::now(),
+            max_attempts: P::MAX_ATTEMPTS,
+        }
+    }
+}
+
+/// Builds the job following up a write. Repositories call it inside the write's
+/// transaction, so the job is enqueued if and only if the write commits
+pub type JobFactory<T> = fn(&T) -> NewJob;
+
+/// Which jobs to list
+#[derive(Debug, Clone, Default)]
+pub struct JobFilter {
+    pub status: Option<JobStatus>,
+    pub queue: Option<String>,
+    pub kind: Option<String>,
+    pub limit: i64,
+}
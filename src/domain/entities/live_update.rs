@@ -0,0 +1,90 @@
+use serde::Serialize;
+use std::{fmt, str::FromStr};
+use uuid::Uuid;
+
+use super::{
+    domain_event::OutboxEvent,
+    order::{Order, OrderStatus},
+    product::Product,
+};
+
+/// What live update subscribers listen to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Topic {
+    /// Changes to one product, including its stock
+    Product(Uuid),
+    /// Placed orders and order status changes
+    Orders,
+}
+
+impl fmt::Display for Topic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Product(id) => write!(f, "product:{}", id),
+            Self::Orders => f.write_str("orders"),
+        }
+    }
+}
+
+impl FromStr for Topic {
+    type Err = String;
+
+    fn from_str(topic: &str) -> Result<Self, Self::Err> {
+        match topic.split_once(':') {
+            None if topic == "orders" => Ok(Self::Orders),
+            Some(("product", id)) => Uuid::parse_str(id)
+                .map(Self::Product)
+                .map_err(|_| format!("Invalid product id in topic {topic:?}")),
+            _ => Err(format!("Unknown topic {topic:?}")),
+        }
+    }
+}
+
+/// A change published to live subscribers. Product changes come from committed domain
+/// events, orders from their write paths
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LiveUpdate {
+    ProductCreated {
+        product: Product,
+    },
+    /// The product changed, stock included
+    ProductUpdated {
+        product: Product,
+    },
+    ProductDeleted {
+        product_id: Uuid,
+    },
+    OrderPlaced {
+        order: Order,
+    },
+    OrderStatusChanged {
+        order_id: Uuid,
+        status: OrderStatus,
+    },
+}
+
+impl LiveUpdate {
+    /// The update for a committed product event, `None` for other events
+    pub fn from_event(event: &OutboxEvent) -> Option<Self> {
+        let product = || serde_json::from_value::<Product>(event.payload.clone()).ok();
+        match event.event_type.as_str() {
+            "product.created" => product().map(|product| Self::ProductCreated { product }),
+            "product.updated" => product().map(|product| Self::ProductUpdated { product }),
+            "product.deleted" => Some(Self::ProductDeleted {
+                product_id: event.aggregate_id,
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn topic(&self) -> Topic {
+        match self {
+            Self::ProductCreated { product } | Self::ProductUpdated { product } => {
+                Topic::Product(product.id)
+            }
+            Self::ProductDeleted { product_id } => Topic::Product(*product_id),
+            Self::OrderPlaced { .. } | Self::OrderStatusChanged { .. } => Topic::Orders,
+        }
+    }
+}
@@ -1,5 +1,28 @@
+pub mod cart;
+pub mod domain_event;
+pub mod exchange_rate;
+pub mod health;
+pub mod job;
+pub mod live_update;
+pub mod order;
+pub mod payment;
 pub mod product;
+pub mod product_bulk;
+pub mod product_event;
+pub mod product_image;
+pub mod product_import;
+pub mod product_price;
+pub mod promotion;
+pub mod scheduled_task;
 pub mod user;
+pub mod webhook;
 
+pub use cart::Cart;
+pub use exchange_rate::ExchangeRate;
+pub use order::Order;
+pub use payment::Payment;
 pub use product::Product;
+pub use product_image::ProductImage;
+pub use product_price::ProductPrice;
+pub use promotion::Promotion;
 pub use user::User;
@@ -0,0 +1,92 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "order_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum OrderStatus {
+    PendingPayment,
+    Paid,
+    PaymentFailed,
+    Refunded,
+}
+
+impl OrderStatus {
+    /// Status of a newly placed order, already paid when promotions leave nothing to pay
+    pub fn placed(total: Decimal) -> Self {
+        if total > Decimal::ZERO {
+            Self::PendingPayment
+        } else {
+            Self::Paid
+        }
+    }
+
+    /// Whether a new payment may be started for an order in this status
+    pub fn accepts_payment(self) -> bool {
+        matches!(self, Self::PendingPayment | Self::PaymentFailed)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct Order {
+    /// The unique identifier for the order
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub id: Uuid,
+    /// The user who placed the order
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub user_id: Uuid,
+    /// Current order status
+    pub status: OrderStatus,
+    /// Discount granted by promotions
+    #[schema(example = "10.00")]
+    pub discount: Decimal,
+    /// Total amount to be paid
+    #[schema(example = "1999.98")]
+    pub total: Decimal,
+    /// ISO 4217 currency of every amount in the order
+    #[schema(example = "USD")]
+    pub currency: String,
+    /// When the order was placed
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub created_at: DateTime<Utc>,
+    /// Last update timestamp
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct OrderItem {
+    /// The order this item belongs to
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub order_id: Uuid,
+    /// The ordered product
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub product_id: Uuid,
+    /// Product name at the time of ordering
+    #[schema(example = "iPhone 14 Pro")]
+    pub product_name: String,
+    /// Ordered quantity
+    #[schema(example = "2")]
+    pub quantity: i32,
+    /// Unit price at the time of ordering
+    #[schema(example = "999.99")]
+    pub unit_price: Decimal,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct CheckoutDto {
+    /// Discount code to apply to the order
+    #[schema(example = "SUMMER10")]
+    pub code: Option<String>,
+}
+
+/// An order together with its line items
+#[derive(Debug)]
+pub struct OrderWithItems {
+    pub order: Order,
+    pub items: Vec<OrderItem>,
+}
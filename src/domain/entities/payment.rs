@@ -0,0 +1,85 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::order::OrderStatus;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "payment_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentStatus {
+    /// Waiting for the customer to complete an authentication step (e.g. 3-D Secure)
+    RequiresAction,
+    /// Authorized and waiting to be captured
+    RequiresCapture,
+    Succeeded,
+    Failed,
+    Refunded,
+}
+
+impl PaymentStatus {
+    pub fn can_transition_to(self, next: Self) -> bool {
+        use PaymentStatus::*;
+
+        matches!(
+            (self, next),
+            (RequiresAction, RequiresCapture | Succeeded | Failed)
+                | (RequiresCapture, Succeeded | Failed)
+                | (Succeeded, Refunded)
+        )
+    }
+
+    /// The order status implied by a payment reaching this status, if any
+    pub fn order_status(self) -> Option<OrderStatus> {
+        match self {
+            Self::Succeeded => Some(OrderStatus::Paid),
+            Self::Failed => Some(OrderStatus::PaymentFailed),
+            Self::Refunded => Some(OrderStatus::Refunded),
+            Self::RequiresAction | Self::RequiresCapture => None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct Payment {
+    /// The unique identifier for the payment
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub id: Uuid,
+    /// The order being paid
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub order_id: Uuid,
+    /// Payment provider handling the payment
+    #[schema(example = "fake")]
+    pub provider: String,
+    /// Payment intent identifier assigned by the provider
+    #[schema(example = "fake_pi_123e4567e89b12d3a456426614174000")]
+    pub intent_id: String,
+    /// Amount to be charged
+    #[schema(example = "1999.98")]
+    pub amount: Decimal,
+    /// ISO 4217 currency of the amount
+    #[schema(example = "USD")]
+    pub currency: String,
+    /// Current payment status
+    pub status: PaymentStatus,
+    /// When the payment was created
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub created_at: DateTime<Utc>,
+    /// Last update timestamp
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug)]
+pub struct CreatePaymentDto {
+    pub id: Uuid,
+    pub order_id: Uuid,
+    pub provider: String,
+    pub intent_id: String,
+    pub amount: Decimal,
+    pub currency: String,
+    pub status: PaymentStatus,
+}
@@ -5,20 +5,29 @@ use sqlx::FromRow;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct Product {
     /// The unique identifier for the product
     #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
     pub id: Uuid,
+    /// Merchant stock keeping unit, unique when present
+    #[schema(example = "IPH-14-PRO-128")]
+    pub sku: Option<String>,
     /// The name of the product
     #[schema(example = "iPhone 14 Pro")]
     pub name: String,
     /// Detailed description of the product
     #[schema(example = "Latest iPhone model with dynamic island")]
     pub description: String,
+    /// Category the product is listed under
+    #[schema(example = "smartphones")]
+    pub category: Option<String>,
     /// The price of the product
     #[schema(example = "999.99")]
     pub price: Decimal,
+    /// ISO 4217 currency of the price
+    #[schema(example = "USD")]
+    pub currency: String,
     /// Current stock quantity
     #[schema(example = "100")]
     pub stock: i32,
@@ -32,15 +41,25 @@ pub struct Product {
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CreateProductDto {
+    /// Merchant stock keeping unit, unique when present
+    #[schema(example = "IPH-14-PRO-128")]
+    pub sku: Option<String>,
     /// The name of the product
     #[schema(example = "iPhone 14 Pro")]
     pub name: String,
     /// Detailed description of the product
     #[schema(example = "Latest iPhone model with dynamic island")]
     pub description: String,
+    /// Category the product is listed under
+    #[schema(example = "smartphones")]
+    pub category: Option<String>,
     /// The price of the product
     #[schema(example = "999.99")]
     pub price: Decimal,
+    /// ISO 4217 currency of the price, USD when absent. Fixed once the product exists
+    /// so its price history stays in one currency
+    #[schema(example = "USD")]
+    pub currency: Option<String>,
     /// Initial stock quantity
     #[schema(example = "100")]
     pub stock: i32,
@@ -48,12 +67,18 @@ pub struct CreateProductDto {
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UpdateProductDto {
+    /// Updated stock keeping unit
+    #[schema(example = "IPH-14-PRO-MAX-256")]
+    pub sku: Option<String>,
     /// Updated name of the product
     #[schema(example = "iPhone 14 Pro Max")]
     pub name: Option<String>,
     /// Updated description of the product
     #[schema(example = "Updated description for iPhone")]
     pub description: Option<String>,
+    /// Updated category of the product
+    #[schema(example = "smartphones")]
+    pub category: Option<String>,
     /// Updated price of the product
     #[schema(example = "1099.99")]
     pub price: Option<Decimal>,
@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::product::UpdateProductDto;
+
+/// Changes for one product of a bulk update
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BulkUpdateProductDto {
+    /// Product to update
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub id: Uuid,
+    #[serde(flatten)]
+    pub changes: UpdateProductDto,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkItemStatus {
+    Created,
+    Updated,
+    Deleted,
+    Failed,
+    /// Would have succeeded, but another item failed an all-or-nothing request
+    RolledBack,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BulkItemResult {
+    /// Position of the item in the request
+    #[schema(example = 0)]
+    pub index: usize,
+    pub status: BulkItemStatus,
+    /// The affected product
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub id: Option<Uuid>,
+    /// Why the item was rejected
+    #[schema(example = "Product not found")]
+    pub error: Option<String>,
+}
+
+impl BulkItemResult {
+    pub fn succeeded(index: usize, status: BulkItemStatus, id: Uuid) -> Self {
+        Self {
+            index,
+            status,
+            id: Some(id),
+            error: None,
+        }
+    }
+
+    pub fn failed(index: usize, id: Option<Uuid>, error: impl Into<String>) -> Self {
+        Self {
+            index,
+            status: BulkItemStatus::Failed,
+            id,
+            error: Some(error.into()),
+        }
+    }
+
+    /// An item left alone because another item failed an all-or-nothing request
+    pub fn not_applied(index: usize, id: Option<Uuid>) -> Self {
+        Self {
+            index,
+            status: BulkItemStatus::RolledBack,
+            id,
+            error: Some("Not applied, another item failed".to_string()),
+        }
+    }
+
+    /// Reports a successful item as undone, failed items keep their reason
+    pub fn rolled_back(self) -> Self {
+        match self.status {
+            BulkItemStatus::Failed => self,
+            // The id of a product that was never kept means nothing to the client
+            BulkItemStatus::Created => Self::not_applied(self.index, None),
+            _ => Self::not_applied(self.index, self.id),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BulkReport {
+    /// Whether the request was all-or-nothing
+    #[schema(example = false)]
+    pub atomic: bool,
+    /// Items that were applied
+    #[schema(example = 10)]
+    pub succeeded: u64,
+    /// Items that were rejected or rolled back
+    #[schema(example = 1)]
+    pub failed: u64,
+    /// Outcome of every item, in request order
+    pub items: Vec<BulkItemResult>,
+}
+
+impl BulkReport {
+    pub fn new(atomic: bool, mut items: Vec<BulkItemResult>) -> Self {
+        items.sort_by_key(|item| item.index);
+        let failed = items
+            .iter()
+            .filter(|item| {
+                matches!(
+                    item.status,
+                    BulkItemStatus::Failed | BulkItemStatus::RolledBack
+                )
+            })
+            .count() as u64;
+
+        Self {
+            atomic,
+            succeeded: items.len() as u64 - failed,
+            failed,
+            items,
+        }
+    }
+}
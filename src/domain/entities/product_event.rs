@@ -0,0 +1,35 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{types::Json, FromRow};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::product::Product;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "product_event_kind", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ProductEventKind {
+    Created,
+    Updated,
+    Deleted,
+    /// Only the stock changed
+    StockChanged,
+}
+
+/// A change to a product, recorded by a trigger on the `products` table
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct ProductEvent {
+    /// Increasing event id, used as the SSE event id
+    #[schema(example = 42)]
+    pub id: i64,
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub product_id: Uuid,
+    pub kind: ProductEventKind,
+    /// The product after the change, or before it for deletions
+    #[schema(value_type = Product)]
+    pub product: Json<Product>,
+    /// When the change was committed
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub created_at: DateTime<Utc>,
+}
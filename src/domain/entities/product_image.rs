@@ -0,0 +1,119 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::job::JobPayload;
+
+/// Thumbnail variants generated for every image, as (name, bounding box in pixels)
+pub const THUMBNAIL_SIZES: [(&str, u32); 3] = [("small", 150), ("medium", 400), ("large", 800)];
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct ProductImage {
+    /// The unique identifier for the image
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub id: Uuid,
+    /// The product the image belongs to
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub product_id: Uuid,
+    /// Display order, starting at 0
+    #[schema(example = 0)]
+    pub position: i32,
+    /// Whether this is the product's main image
+    #[schema(example = true)]
+    pub is_primary: bool,
+    /// Sniffed MIME type of the original
+    #[schema(example = "image/jpeg")]
+    pub content_type: String,
+    /// Width of the original in pixels
+    #[schema(example = 1200)]
+    pub width: i32,
+    /// Height of the original in pixels
+    #[schema(example = 800)]
+    pub height: i32,
+    /// Size of the original in bytes
+    #[schema(example = 245760)]
+    pub size_bytes: i64,
+    /// When the image was uploaded
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl ProductImage {
+    /// Storage key of the original (`None`) or of a thumbnail variant
+    pub fn storage_key(&self, variant: Option<&str>) -> String {
+        image_key(self.product_id, self.id, &self.content_type, variant)
+    }
+
+    /// Storage keys of the original and all thumbnails
+    pub fn storage_keys(&self) -> Vec<String> {
+        std::iter::once(self.storage_key(None))
+            .chain(
+                THUMBNAIL_SIZES
+                    .iter()
+                    .map(|(name, _)| self.storage_key(Some(name))),
+            )
+            .collect()
+    }
+}
+
+/// Background job removing the files of a deleted image from storage
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemoveImageFilesJob {
+    pub keys: Vec<String>,
+}
+
+impl JobPayload for RemoveImageFilesJob {
+    const KIND: &'static str = "product_image.remove_files";
+    const QUEUE: &'static str = "media";
+}
+
+/// MIME type thumbnails of an original are encoded as. JPEG stays JPEG, everything
+/// else becomes PNG to keep transparency
+pub fn thumbnail_content_type(original: &str) -> &'static str {
+    match original {
+        "image/jpeg" => "image/jpeg",
+        _ => "image/png",
+    }
+}
+
+/// Storage key of an original (`variant` is `None`) or one of its thumbnails
+pub fn image_key(
+    product_id: Uuid,
+    image_id: Uuid,
+    content_type: &str,
+    variant: Option<&str>,
+) -> String {
+    let (name, content_type) = match variant {
+        Some(variant) => (variant, thumbnail_content_type(content_type)),
+        None => ("original", content_type),
+    };
+    let extension = match content_type {
+        "image/png" => "png",
+        "image/gif" => "gif",
+        "image/webp" => "webp",
+        _ => "jpg",
+    };
+
+    format!("products/{product_id}/{image_id}/{name}.{extension}")
+}
+
+/// Metadata of a freshly processed upload
+#[derive(Debug)]
+pub struct NewProductImage {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub content_type: String,
+    pub width: i32,
+    pub height: i32,
+    pub size_bytes: i64,
+    /// Make this the primary image, the first image always is
+    pub is_primary: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReorderProductImagesDto {
+    /// Every image of the product, in the desired order
+    pub image_ids: Vec<Uuid>,
+}
@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::product::CreateProductDto;
+
+/// One data row of an import file. The product is matched by `id` when given,
+/// otherwise by `product.sku`, and created when nothing matches
+#[derive(Debug)]
+pub struct ProductImportRow {
+    /// Line number in the file, the header being line 1
+    pub line: u64,
+    pub id: Option<Uuid>,
+    pub product: CreateProductDto,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportRowStatus {
+    Created,
+    Updated,
+    Failed,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ImportRowResult {
+    /// Line number in the file, the header being line 1
+    #[schema(example = 2)]
+    pub line: u64,
+    pub status: ImportRowStatus,
+    /// The created or updated product
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub id: Option<Uuid>,
+    #[schema(example = "IPH-14-PRO-128")]
+    pub sku: Option<String>,
+    /// Why the row was rejected
+    #[schema(example = "Price cannot be negative")]
+    pub error: Option<String>,
+}
+
+impl ImportRowResult {
+    pub fn failed(line: u64, sku: Option<String>, error: impl Into<String>) -> Self {
+        Self {
+            line,
+            status: ImportRowStatus::Failed,
+            id: None,
+            sku,
+            error: Some(error.into()),
+        }
+    }
+}
+
+/// Where and why an import stopped before the end of the file
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ImportInterruption {
+    /// First line that was not imported, rows before it are in the report
+    #[schema(example = 1002)]
+    pub line: u64,
+    #[schema(example = "Internal server error")]
+    pub error: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct ImportReport {
+    /// Nothing was saved, the counts show what the import would do
+    #[schema(example = false)]
+    pub dry_run: bool,
+    #[schema(example = 10)]
+    pub created: u64,
+    #[schema(example = 5)]
+    pub updated: u64,
+    #[schema(example = 1)]
+    pub failed: u64,
+    /// Outcome of every data row, in file order
+    pub rows: Vec<ImportRowResult>,
+    /// Set when the import stopped early, nothing from `line` on was imported
+    pub interrupted: Option<ImportInterruption>,
+}
+
+impl ImportReport {
+    pub fn new(dry_run: bool) -> Self {
+        Self {
+            dry_run,
+            ..Self::default()
+        }
+    }
+
+    pub fn record(&mut self, results: impl IntoIterator<Item = ImportRowResult>) {
+        for result in results {
+            match result.status {
+                ImportRowStatus::Created => self.created += 1,
+                ImportRowStatus::Updated => self.updated += 1,
+                ImportRowStatus::Failed => self.failed += 1,
+            }
+            self.rows.push(result);
+        }
+    }
+
+    /// Marks the import as stopped at `line`
+    pub fn interrupt(&mut self, line: u64, error: impl Into<String>) {
+        self.interrupted = Some(ImportInterruption {
+            line,
+            error: error.into(),
+        });
+    }
+
+    /// Puts rows back in file order once all batches are recorded
+    pub fn finish(mut self) -> Self {
+        self.rows.sort_by_key(|row| row.line);
+        self
+    }
+}
@@ -0,0 +1,42 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// A product price valid during `[effective_from, effective_to)`
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct ProductPrice {
+    /// The unique identifier for the price entry
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub id: Uuid,
+    /// The product this price applies to
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub product_id: Uuid,
+    /// The price during this period
+    #[schema(example = "999.99")]
+    pub price: Decimal,
+    /// Start of the period (inclusive)
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub effective_from: DateTime<Utc>,
+    /// End of the period (exclusive), open-ended when absent
+    #[schema(example = "2024-03-01T00:00:00Z")]
+    pub effective_to: Option<DateTime<Utc>>,
+    /// When the price entry was recorded
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SchedulePriceDto {
+    /// The price to apply
+    #[schema(example = "899.99")]
+    pub price: Decimal,
+    /// When the price takes effect, must be in the future
+    #[schema(example = "2024-11-29T00:00:00Z")]
+    pub effective_from: DateTime<Utc>,
+    /// When the price ends and the previous price resumes, open-ended when absent
+    #[schema(example = "2024-12-02T00:00:00Z")]
+    pub effective_to: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,193 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "promotion_kind", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PromotionKind {
+    /// `value` percent off the eligible items
+    Percentage,
+    /// `value` off the eligible items, never more than they cost
+    FixedAmount,
+    /// For every `buy_quantity` units bought, `get_quantity` more units are
+    /// `value` percent off (100 makes them free)
+    BuyXGetY,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct Promotion {
+    /// The unique identifier for the promotion
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub id: Uuid,
+    /// Name shown to customers
+    #[schema(example = "Summer sale")]
+    pub name: String,
+    /// Discount code to enter, automatic promotions have none
+    #[schema(example = "SUMMER10")]
+    pub code: Option<String>,
+    /// How the discount is calculated
+    pub kind: PromotionKind,
+    /// Percentage or amount, depending on the kind
+    #[schema(example = "10")]
+    pub value: Decimal,
+    /// Units to buy before the free units apply (buy X get Y)
+    #[schema(example = "2")]
+    pub buy_quantity: Option<i32>,
+    /// Units discounted per `buy_quantity` units bought (buy X get Y)
+    #[schema(example = "1")]
+    pub get_quantity: Option<i32>,
+    /// Cart subtotal required before the promotion applies
+    #[schema(example = "50.00")]
+    pub min_order_value: Option<Decimal>,
+    /// ISO 4217 currency of the fixed amount and minimum order value, required
+    /// when either is set
+    #[schema(example = "USD")]
+    pub currency: Option<String>,
+    /// Products the promotion applies to
+    pub product_ids: Vec<Uuid>,
+    /// Product categories the promotion applies to, every item is eligible when
+    /// neither products nor categories are given
+    #[schema(example = json!(["smartphones"]))]
+    pub categories: Vec<String>,
+    /// Times a single user may redeem the promotion
+    #[schema(example = "1")]
+    pub usage_limit_per_user: Option<i32>,
+    /// Start of the validity window
+    #[schema(example = "2024-06-01T00:00:00Z")]
+    pub starts_at: Option<DateTime<Utc>>,
+    /// End of the validity window (exclusive)
+    #[schema(example = "2024-09-01T00:00:00Z")]
+    pub ends_at: Option<DateTime<Utc>>,
+    /// Whether the promotion can be applied at all
+    #[schema(example = true)]
+    pub active: bool,
+    /// When the promotion was created
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub created_at: DateTime<Utc>,
+    /// Last update timestamp
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreatePromotionDto {
+    /// Name shown to customers
+    #[schema(example = "Summer sale")]
+    pub name: String,
+    /// Discount code to enter, omit for an automatic promotion
+    #[schema(example = "SUMMER10")]
+    pub code: Option<String>,
+    /// How the discount is calculated
+    pub kind: PromotionKind,
+    /// Percentage or amount, depending on the kind
+    #[schema(example = "10")]
+    pub value: Decimal,
+    /// Units to buy before the free units apply (buy X get Y)
+    #[schema(example = "2")]
+    pub buy_quantity: Option<i32>,
+    /// Units discounted per `buy_quantity` units bought (buy X get Y)
+    #[schema(example = "1")]
+    pub get_quantity: Option<i32>,
+    /// Cart subtotal required before the promotion applies
+    #[schema(example = "50.00")]
+    pub min_order_value: Option<Decimal>,
+    /// ISO 4217 currency of the fixed amount and minimum order value, required
+    /// when either is set
+    #[schema(example = "USD")]
+    pub currency: Option<String>,
+    /// Products the promotion applies to
+    #[serde(default)]
+    pub product_ids: Vec<Uuid>,
+    /// Product categories the promotion applies to
+    #[serde(default)]
+    #[schema(example = json!(["smartphones"]))]
+    pub categories: Vec<String>,
+    /// Times a single user may redeem the promotion
+    #[schema(example = "1")]
+    pub usage_limit_per_user: Option<i32>,
+    /// Start of the validity window
+    #[schema(example = "2024-06-01T00:00:00Z")]
+    pub starts_at: Option<DateTime<Utc>>,
+    /// End of the validity window (exclusive)
+    #[schema(example = "2024-09-01T00:00:00Z")]
+    pub ends_at: Option<DateTime<Utc>>,
+    /// Whether the promotion can be applied, defaults to true
+    #[schema(example = true)]
+    pub active: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdatePromotionDto {
+    /// Updated name
+    #[schema(example = "Summer sale")]
+    pub name: Option<String>,
+    /// Updated discount code
+    #[schema(example = "SUMMER15")]
+    pub code: Option<String>,
+    /// Updated kind
+    pub kind: Option<PromotionKind>,
+    /// Updated percentage or amount
+    #[schema(example = "15")]
+    pub value: Option<Decimal>,
+    /// Updated buy quantity
+    #[schema(example = "3")]
+    pub buy_quantity: Option<i32>,
+    /// Updated get quantity
+    #[schema(example = "1")]
+    pub get_quantity: Option<i32>,
+    /// Updated minimum order value
+    #[schema(example = "75.00")]
+    pub min_order_value: Option<Decimal>,
+    /// Updated currency of the amounts
+    #[schema(example = "EUR")]
+    pub currency: Option<String>,
+    /// Updated eligible products
+    pub product_ids: Option<Vec<Uuid>>,
+    /// Updated eligible categories
+    #[schema(example = json!(["smartphones", "tablets"]))]
+    pub categories: Option<Vec<String>>,
+    /// Updated per-user usage limit
+    #[schema(example = "2")]
+    pub usage_limit_per_user: Option<i32>,
+    /// Updated start of the validity window
+    #[schema(example = "2024-06-01T00:00:00Z")]
+    pub starts_at: Option<DateTime<Utc>>,
+    /// Updated end of the validity window
+    #[schema(example = "2024-09-15T00:00:00Z")]
+    pub ends_at: Option<DateTime<Utc>>,
+    /// Enable or disable the promotion
+    #[schema(example = false)]
+    pub active: Option<bool>,
+}
+
+impl Promotion {
+    /// The promotion's rules with `update` applied on top
+    pub fn merged_with(&self, update: &UpdatePromotionDto) -> CreatePromotionDto {
+        CreatePromotionDto {
+            name: update.name.clone().unwrap_or_else(|| self.name.clone()),
+            code: update.code.clone().or_else(|| self.code.clone()),
+            kind: update.kind.unwrap_or(self.kind),
+            value: update.value.unwrap_or(self.value),
+            buy_quantity: update.buy_quantity.or(self.buy_quantity),
+            get_quantity: update.get_quantity.or(self.get_quantity),
+            min_order_value: update.min_order_value.or(self.min_order_value),
+            currency: update.currency.clone().or_else(|| self.currency.clone()),
+            product_ids: update
+                .product_ids
+                .clone()
+                .unwrap_or_else(|| self.product_ids.clone()),
+            categories: update
+                .categories
+                .clone()
+                .unwrap_or_else(|| self.categories.clone()),
+            usage_limit_per_user: update.usage_limit_per_user.or(self.usage_limit_per_user),
+            starts_at: update.starts_at.or(self.starts_at),
+            ends_at: update.ends_at.or(self.ends_at),
+            active: Some(update.active.unwrap_or(self.active)),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "task_run_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum TaskRunStatus {
+    Running,
+    Succeeded,
+    Failed,
+}
+
+/// A cron task of the scheduler with its last run on any replica
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct ScheduledTask {
+    #[schema(example = "jobs.prune")]
+    pub name: String,
+    /// Cron expression with seconds, in UTC
+    #[schema(example = "0 30 3 * * *")]
+    pub schedule: String,
+    #[schema(example = "2024-02-17T03:30:00Z")]
+    pub next_run_at: Option<DateTime<Utc>>,
+    /// Fire time the last run was started for
+    #[schema(example = "2024-02-16T03:30:00Z")]
+    pub last_scheduled_for: Option<DateTime<Utc>>,
+    #[schema(example = "2024-02-16T03:30:00Z")]
+    pub last_started_at: Option<DateTime<Utc>>,
+    #[schema(example = "2024-02-16T03:30:01Z")]
+    pub last_finished_at: Option<DateTime<Utc>>,
+    pub last_status: Option<TaskRunStatus>,
+    /// Why the last run failed
+    #[schema(example = "Database error: connection refused")]
+    pub last_error: Option<String>,
+    #[schema(example = 120)]
+    pub last_duration_ms: Option<i64>,
+    #[schema(example = "2024-02-16T03:30:01Z")]
+    pub updated_at: DateTime<Utc>,
+}
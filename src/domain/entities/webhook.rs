@@ -0,0 +1,145 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Events a webhook subscription can listen to
+pub const WEBHOOK_EVENT_TYPES: [&str; 6] = [
+    "product.created",
+    "product.updated",
+    "product.deleted",
+    "user.created",
+    "user.updated",
+    "user.deleted",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct WebhookSubscription {
+    /// The unique identifier for the subscription
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub id: Uuid,
+    /// Endpoint the events are posted to
+    #[schema(example = "https://partner.example.com/hooks/catalog")]
+    pub url: String,
+    /// Events delivered to the endpoint
+    #[schema(example = json!(["product.created", "product.updated"]))]
+    pub event_types: Vec<String>,
+    /// Key the payloads are signed with
+    #[serde(skip_serializing)]
+    pub secret: String,
+    /// Whether events are delivered, cleared after too many failed attempts in a row
+    #[schema(example = true)]
+    pub active: bool,
+    /// Failed attempts since the last successful delivery
+    #[schema(example = 0)]
+    pub consecutive_failures: i32,
+    /// When the subscription was disabled for failing
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub disabled_at: Option<DateTime<Utc>>,
+    /// When the subscription was created
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub created_at: DateTime<Utc>,
+    /// Last update timestamp
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreateWebhookSubscriptionDto {
+    /// Endpoint the events are posted to
+    #[schema(example = "https://partner.example.com/hooks/catalog")]
+    pub url: String,
+    /// Events to deliver
+    #[schema(example = json!(["product.created", "product.updated"]))]
+    pub event_types: Vec<String>,
+    /// Key to sign payloads with, generated when omitted
+    #[schema(example = "whsec_2f1c9a7e4b")]
+    pub secret: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct UpdateWebhookSubscriptionDto {
+    /// Endpoint the events are posted to
+    #[schema(example = "https://partner.example.com/hooks/catalog")]
+    pub url: Option<String>,
+    /// Events to deliver
+    #[schema(example = json!(["product.deleted"]))]
+    pub event_types: Option<Vec<String>>,
+    /// New signing key
+    #[schema(example = "whsec_2f1c9a7e4b")]
+    pub secret: Option<String>,
+    /// Pause or resume deliveries, resuming also clears the failure count
+    #[schema(example = true)]
+    pub active: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "webhook_delivery_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    /// Waiting for its first or next attempt
+    Pending,
+    Succeeded,
+    /// Gave up after the last retry
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct WebhookDelivery {
+    /// The unique identifier for the delivery
+    #[schema(example = "3f2b8c1e-9d4a-4e6b-8f7a-1c2d3e4f5a6b")]
+    pub id: Uuid,
+    /// Subscription the event is delivered to
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub subscription_id: Uuid,
+    /// The event, the same for every delivery of it
+    #[schema(example = "9a8b7c6d-5e4f-4a3b-2c1d-0e9f8a7b6c5d")]
+    pub event_id: Uuid,
+    #[schema(example = "product.updated")]
+    pub event_type: String,
+    /// Body posted to the endpoint
+    #[schema(value_type = Object)]
+    pub payload: serde_json::Value,
+    pub status: WebhookDeliveryStatus,
+    /// Attempts made so far
+    #[schema(example = 1)]
+    pub attempts: i32,
+    /// When the next attempt is due, while pending
+    #[schema(example = "2024-02-16T00:00:30Z")]
+    pub next_attempt_at: DateTime<Utc>,
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    /// HTTP status of the last attempt, absent when the endpoint could not be reached
+    #[schema(example = 200)]
+    pub response_status: Option<i32>,
+    /// Why the last attempt failed
+    #[schema(example = "Endpoint answered 503")]
+    pub last_error: Option<String>,
+    /// When the event was queued
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// A delivery claimed for an attempt, with where to send it
+#[derive(Debug, FromRow)]
+pub struct DueWebhookDelivery {
+    #[sqlx(flatten)]
+    pub delivery: WebhookDelivery,
+    pub url: String,
+    pub secret: String,
+}
+
+/// How an attempt ended
+#[derive(Debug)]
+pub enum WebhookAttemptOutcome {
+    Succeeded {
+        response_status: i32,
+    },
+    /// Retried at `retry_at`, or given up on without one
+    Failed {
+        response_status: Option<i32>,
+        error: String,
+        retry_at: Option<DateTime<Utc>>,
+    },
+}
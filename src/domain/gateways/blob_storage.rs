@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+
+#[derive(thiserror::Error, Debug)]
+pub enum BlobStorageError {
+    #[error("Blob not found")]
+    NotFound,
+    #[error("Storage backend error: {0}")]
+    Backend(String),
+}
+
+/// Stores binary objects such as product images under slash separated keys
+#[async_trait]
+pub trait BlobStorage: Send + Sync {
+    async fn put(
+        &self,
+        key: &str,
+        content_type: &str,
+        data: Vec<u8>,
+    ) -> Result<(), BlobStorageError>;
+    async fn delete(&self, key: &str) -> Result<(), BlobStorageError>;
+    /// Verifies the backend is reachable and accepts writes
+    async fn check(&self) -> Result<(), BlobStorageError>;
+    /// Public URL the object is served from
+    fn url(&self, key: &str) -> String;
+}
@@ -0,0 +1,18 @@
+use crate::domain::entities::domain_event::OutboxEvent;
+use async_trait::async_trait;
+
+#[derive(thiserror::Error, Debug)]
+pub enum EventSinkError {
+    #[error("Sink unavailable: {0}")]
+    Unavailable(String),
+}
+
+/// A destination the outbox relay publishes events to
+#[async_trait]
+pub trait EventSink: Send + Sync {
+    /// Short name for logs and relay errors
+    fn name(&self) -> &'static str;
+    /// Hands the event on. An event is published at least once, so a sink may see the
+    /// same id again after a failure and must treat it as a duplicate
+    async fn publish(&self, event: &OutboxEvent) -> Result<(), EventSinkError>;
+}
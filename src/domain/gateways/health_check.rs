@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+#[derive(thiserror::Error, Debug)]
+pub enum HealthCheckError {
+    #[error("{0}")]
+    Unhealthy(String),
+}
+
+/// A dependency the server needs to serve traffic
+#[async_trait]
+pub trait HealthCheck: Send + Sync {
+    /// Short name shown in the health report
+    fn name(&self) -> &'static str;
+    /// Probes the dependency, returning what was measured
+    async fn check(&self) -> Result<Option<serde_json::Value>, HealthCheckError>;
+}
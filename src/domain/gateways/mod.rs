@@ -0,0 +1,13 @@
+pub mod blob_storage;
+pub mod event_sink;
+pub mod health_check;
+pub mod payment_gateway;
+pub mod update_bus;
+pub mod webhook_sender;
+
+pub use blob_storage::{BlobStorage, BlobStorageError};
+pub use event_sink::{EventSink, EventSinkError};
+pub use health_check::{HealthCheck, HealthCheckError};
+pub use payment_gateway::{PaymentGateway, PaymentGatewayError};
+pub use update_bus::UpdateBus;
+pub use webhook_sender::{WebhookSender, WebhookSenderError};
@@ -0,0 +1,62 @@
+use crate::domain::entities::payment::PaymentStatus;
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+#[derive(thiserror::Error, Debug)]
+pub enum PaymentGatewayError {
+    #[error("Payment declined: {0}")]
+    Declined(String),
+    #[error("Invalid webhook signature")]
+    InvalidSignature,
+    #[error("Invalid webhook payload: {0}")]
+    InvalidPayload(String),
+    #[error("Provider error: {0}")]
+    Provider(String),
+}
+
+#[derive(Debug)]
+pub struct PaymentIntentRequest {
+    /// Our payment ID, used by providers as idempotency key
+    pub payment_id: Uuid,
+    pub amount: Decimal,
+    /// ISO 4217 currency of the amount
+    pub currency: String,
+}
+
+#[derive(Debug)]
+pub struct PaymentIntent {
+    pub intent_id: String,
+    pub status: PaymentStatus,
+}
+
+/// A verified provider callback reporting a payment status change
+#[derive(Debug)]
+pub struct PaymentWebhookEvent {
+    /// Provider event ID, used to deduplicate redeliveries
+    pub event_id: String,
+    pub intent_id: String,
+    pub status: PaymentStatus,
+}
+
+#[async_trait]
+pub trait PaymentGateway: Send + Sync {
+    /// Short provider name stored alongside each payment
+    fn provider(&self) -> &'static str;
+    async fn create_intent(
+        &self,
+        request: PaymentIntentRequest,
+    ) -> Result<PaymentIntent, PaymentGatewayError>;
+    async fn capture(&self, intent_id: &str) -> Result<PaymentIntent, PaymentGatewayError>;
+    async fn refund(
+        &self,
+        intent_id: &str,
+        amount: Decimal,
+    ) -> Result<PaymentIntent, PaymentGatewayError>;
+    /// Verifies the webhook signature and decodes the provider payload
+    fn parse_webhook(
+        &self,
+        payload: &[u8],
+        signature: &str,
+    ) -> Result<PaymentWebhookEvent, PaymentGatewayError>;
+}
@@ -0,0 +1,7 @@
+use crate::domain::entities::live_update::LiveUpdate;
+
+/// Fans live updates out to whoever is subscribed in the process
+pub trait UpdateBus: Send + Sync {
+    /// Publishes without waiting, updates nobody subscribed to are dropped
+    fn publish(&self, update: LiveUpdate);
+}
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+#[derive(thiserror::Error, Debug)]
+pub enum WebhookSenderError {
+    #[error("Endpoint did not answer in time")]
+    Timeout,
+    #[error("Endpoint unreachable: {0}")]
+    Unreachable(String),
+}
+
+/// A signed webhook request
+#[derive(Debug)]
+pub struct WebhookRequest<'a> {
+    pub url: &'a str,
+    pub headers: Vec<(&'static str, String)>,
+    pub body: Vec<u8>,
+}
+
+#[async_trait]
+pub trait WebhookSender: Send + Sync {
+    /// Posts the request, returning the HTTP status the endpoint answered with
+    async fn send(&self, request: WebhookRequest<'_>) -> Result<u16, WebhookSenderError>;
+}
@@ -1,2 +1,4 @@
 pub mod entities;
+pub mod gateways;
 pub mod repositories;
+pub mod services;
@@ -0,0 +1,36 @@
+use super::RepositoryError;
+use crate::domain::entities::cart::{Cart, CartItem};
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait CartRepository: Send + Sync {
+    async fn find_by_token(&self, token: Uuid) -> Result<Option<Cart>, RepositoryError>;
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Option<Cart>, RepositoryError>;
+    async fn create(&self, user_id: Option<Uuid>) -> Result<Cart, RepositoryError>;
+    async fn list_items(&self, cart_id: Uuid) -> Result<Vec<CartItem>, RepositoryError>;
+    /// Adds `quantity` units of a product, incrementing the quantity if already present
+    async fn add_item(
+        &self,
+        cart_id: Uuid,
+        product_id: Uuid,
+        quantity: i32,
+        unit_price: Decimal,
+    ) -> Result<CartItem, RepositoryError>;
+    async fn update_item(
+        &self,
+        cart_id: Uuid,
+        product_id: Uuid,
+        quantity: i32,
+    ) -> Result<CartItem, RepositoryError>;
+    async fn update_item_price(
+        &self,
+        cart_id: Uuid,
+        product_id: Uuid,
+        unit_price: Decimal,
+    ) -> Result<(), RepositoryError>;
+    async fn remove_item(&self, cart_id: Uuid, product_id: Uuid) -> Result<(), RepositoryError>;
+    /// Moves all items of `source_id` into `target_id` and deletes the source cart
+    async fn merge(&self, source_id: Uuid, target_id: Uuid) -> Result<(), RepositoryError>;
+}
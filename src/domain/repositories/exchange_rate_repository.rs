@@ -0,0 +1,18 @@
+use super::RepositoryError;
+use crate::domain::entities::exchange_rate::ExchangeRate;
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+
+#[async_trait]
+pub trait ExchangeRateRepository: Send + Sync {
+    async fn list(&self) -> Result<Vec<ExchangeRate>, RepositoryError>;
+    async fn find(&self, base: &str, quote: &str) -> Result<Option<ExchangeRate>, RepositoryError>;
+    /// Creates the rate or replaces the existing one for the pair
+    async fn set(
+        &self,
+        base: &str,
+        quote: &str,
+        rate: Decimal,
+    ) -> Result<ExchangeRate, RepositoryError>;
+    async fn delete(&self, base: &str, quote: &str) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,37 @@
+use super::RepositoryError;
+use crate::domain::entities::job::{Job, JobFilter};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[async_trait]
+pub trait JobRepository: Send + Sync {
+    /// Takes up to `limit` due jobs of the kinds on the queue and marks them running
+    /// until `lease_until`. Running jobs whose lease ended are taken over, or
+    /// dead-lettered when they have no attempts left
+    async fn claim(
+        &self,
+        queue: &str,
+        kinds: &[&'static str],
+        limit: i64,
+        lease_until: DateTime<Utc>,
+    ) -> Result<Vec<Job>, RepositoryError>;
+    async fn complete(&self, id: Uuid) -> Result<(), RepositoryError>;
+    /// Records a failed attempt, queueing the job again at `retry_at` or
+    /// dead-lettering it without one
+    async fn fail(
+        &self,
+        id: Uuid,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), RepositoryError>;
+    /// The newest jobs matching the filter
+    async fn list(&self, filter: JobFilter) -> Result<Vec<Job>, RepositoryError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Job>, RepositoryError>;
+    /// Queues a dead or cancelled job again with fresh attempts, `Conflict` otherwise
+    async fn retry(&self, id: Uuid) -> Result<Job, RepositoryError>;
+    /// Cancels a queued job, `Conflict` otherwise
+    async fn cancel(&self, id: Uuid) -> Result<Job, RepositoryError>;
+    /// Removes succeeded and cancelled jobs finished before `before`, returning how many
+    async fn prune_finished(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError>;
+}
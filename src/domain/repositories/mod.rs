@@ -1,8 +1,32 @@
+pub mod cart_repository;
+pub mod exchange_rate_repository;
+pub mod job_repository;
+pub mod order_repository;
+pub mod outbox_repository;
+pub mod payment_repository;
+pub mod product_event_repository;
+pub mod product_image_repository;
+pub mod product_price_repository;
 pub mod product_repository;
+pub mod promotion_repository;
+pub mod scheduled_task_repository;
 pub mod user_repository;
+pub mod webhook_repository;
 
+pub use cart_repository::CartRepository;
+pub use exchange_rate_repository::ExchangeRateRepository;
+pub use job_repository::JobRepository;
+pub use order_repository::OrderRepository;
+pub use outbox_repository::OutboxRepository;
+pub use payment_repository::PaymentRepository;
+pub use product_event_repository::ProductEventRepository;
+pub use product_image_repository::ProductImageRepository;
+pub use product_price_repository::ProductPriceRepository;
 pub use product_repository::ProductRepository;
+pub use promotion_repository::PromotionRepository;
+pub use scheduled_task_repository::ScheduledTaskRepository;
 pub use user_repository::UserRepository;
+pub use webhook_repository::{WebhookDeliveryQueue, WebhookRepository};
 
 #[derive(thiserror::Error, Debug)]
 pub enum RepositoryError {
@@ -12,4 +36,6 @@ pub enum RepositoryError {
     NotFound,
     #[error("Duplicate entry")]
     DuplicateEntry,
+    #[error("Conflict: {0}")]
+    Conflict(String),
 }
@@ -0,0 +1,30 @@
+use super::RepositoryError;
+use crate::domain::{
+    entities::{
+        cart::PricedCartItem,
+        domain_event::EventFactory,
+        order::{Order, OrderItem},
+        product::Product,
+    },
+    services::promotion_engine::PromotionOutcome,
+};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait OrderRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Order>, RepositoryError>;
+    async fn list_items(&self, order_id: Uuid) -> Result<Vec<OrderItem>, RepositoryError>;
+    /// Places an order for the cart items, all priced in `currency`, reserving stock,
+    /// redeeming the applied promotions and emptying the cart. `stock_event` is
+    /// recorded for every product whose stock was reserved
+    async fn create_from_cart(
+        &self,
+        user_id: Uuid,
+        cart_id: Uuid,
+        items: &[PricedCartItem],
+        currency: &str,
+        pricing: &PromotionOutcome,
+        stock_event: EventFactory<Product>,
+    ) -> Result<Order, RepositoryError>;
+}
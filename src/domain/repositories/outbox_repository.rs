@@ -0,0 +1,28 @@
+use super::RepositoryError;
+use crate::domain::entities::domain_event::OutboxEvent;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// The relay side of the outbox. Events are written by the repositories of the
+/// writes that emit them
+#[async_trait]
+pub trait OutboxRepository: Send + Sync {
+    /// Takes up to `limit` unpublished events in recording order, hiding them from other
+    /// relays until `lease_until` and counting the attempt
+    async fn claim_pending(
+        &self,
+        limit: i64,
+        lease_until: DateTime<Utc>,
+    ) -> Result<Vec<OutboxEvent>, RepositoryError>;
+    async fn mark_published(&self, id: Uuid) -> Result<(), RepositoryError>;
+    /// Keeps the event for another attempt at `retry_at`
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        error: &str,
+        retry_at: DateTime<Utc>,
+    ) -> Result<(), RepositoryError>;
+    /// Removes events published before `before`, returning how many
+    async fn prune_published(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError>;
+}
@@ -0,0 +1,31 @@
+use super::RepositoryError;
+use crate::domain::entities::{
+    order::OrderStatus,
+    payment::{CreatePaymentDto, Payment, PaymentStatus},
+};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait PaymentRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Payment>, RepositoryError>;
+    async fn find_by_intent_id(&self, intent_id: &str) -> Result<Option<Payment>, RepositoryError>;
+    /// Stores a new payment, moving its order to `order_status` in the same transaction
+    async fn create(
+        &self,
+        payment: CreatePaymentDto,
+        order_status: Option<OrderStatus>,
+    ) -> Result<Payment, RepositoryError>;
+    /// Moves a payment from `from` to `to` and updates its order in one transaction.
+    ///
+    /// When `event_id` is given it is recorded so redeliveries are ignored. Returns `false`
+    /// if the event was already processed or the payment is no longer in status `from`.
+    async fn transition(
+        &self,
+        id: Uuid,
+        from: PaymentStatus,
+        to: PaymentStatus,
+        order_status: Option<OrderStatus>,
+        event_id: Option<&str>,
+    ) -> Result<bool, RepositoryError>;
+}
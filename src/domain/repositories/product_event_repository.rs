@@ -0,0 +1,20 @@
+use super::RepositoryError;
+use crate::domain::entities::product_event::ProductEvent;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait ProductEventRepository: Send + Sync {
+    async fn find_by_id(&self, id: i64) -> Result<Option<ProductEvent>, RepositoryError>;
+    /// Up to `limit` events with an id above `after_id`, oldest first
+    async fn list_after(
+        &self,
+        after_id: i64,
+        limit: i64,
+    ) -> Result<Vec<ProductEvent>, RepositoryError>;
+    /// Id of the oldest retained event
+    async fn oldest_id(&self) -> Result<Option<i64>, RepositoryError>;
+    /// Removes events recorded before `before` except the newest one, returning how many
+    /// were removed
+    async fn prune(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError>;
+}
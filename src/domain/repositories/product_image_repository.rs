@@ -0,0 +1,39 @@
+use super::RepositoryError;
+use crate::domain::entities::{
+    job::JobFactory,
+    product_image::{NewProductImage, ProductImage},
+};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait ProductImageRepository: Send + Sync {
+    /// Images of the product in display order
+    async fn list(&self, product_id: Uuid) -> Result<Vec<ProductImage>, RepositoryError>;
+    /// Images of all given products, in display order per product
+    async fn list_for_products(
+        &self,
+        product_ids: &[Uuid],
+    ) -> Result<Vec<ProductImage>, RepositoryError>;
+    /// Appends the image after the product's existing images
+    async fn create(&self, image: NewProductImage) -> Result<ProductImage, RepositoryError>;
+    async fn set_primary(
+        &self,
+        product_id: Uuid,
+        image_id: Uuid,
+    ) -> Result<ProductImage, RepositoryError>;
+    /// Renumbers the images to follow the order of `image_ids`
+    async fn reorder(
+        &self,
+        product_id: Uuid,
+        image_ids: &[Uuid],
+    ) -> Result<Vec<ProductImage>, RepositoryError>;
+    /// Removes the image, promoting the first remaining image if it was the primary one,
+    /// and enqueues `cleanup` for the removed image
+    async fn delete(
+        &self,
+        product_id: Uuid,
+        image_id: Uuid,
+        cleanup: JobFactory<ProductImage>,
+    ) -> Result<ProductImage, RepositoryError>;
+}
@@ -0,0 +1,37 @@
+use super::RepositoryError;
+use crate::domain::entities::{
+    domain_event::EventFactory,
+    product::Product,
+    product_price::{ProductPrice, SchedulePriceDto},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[async_trait]
+pub trait ProductPriceRepository: Send + Sync {
+    async fn list(&self, product_id: Uuid) -> Result<Vec<ProductPrice>, RepositoryError>;
+    /// Price histories of several products, ordered by product and start
+    async fn list_for_products(
+        &self,
+        product_ids: &[Uuid],
+    ) -> Result<Vec<ProductPrice>, RepositoryError>;
+    async fn price_at(
+        &self,
+        product_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> Result<Option<ProductPrice>, RepositoryError>;
+    /// Records a price for the given period, replacing whatever was in effect during it
+    async fn schedule(
+        &self,
+        product_id: Uuid,
+        price: SchedulePriceDto,
+    ) -> Result<ProductPrice, RepositoryError>;
+    /// Applies prices whose period contains `now` to products, recording `event` for each
+    /// and returning the number changed
+    async fn activate_due(
+        &self,
+        now: DateTime<Utc>,
+        event: EventFactory<Product>,
+    ) -> Result<u64, RepositoryError>;
+}
@@ -1,14 +1,66 @@
 use super::RepositoryError;
-use crate::domain::entities::product::{CreateProductDto, Product, UpdateProductDto};
+use crate::domain::entities::{
+    domain_event::EventFactory,
+    product::{CreateProductDto, Product, UpdateProductDto},
+    product_bulk::{BulkItemResult, BulkUpdateProductDto},
+    product_import::{ImportRowResult, ProductImportRow},
+};
 use async_trait::async_trait;
+use futures::stream::BoxStream;
 use uuid::Uuid;
 
+/// Writes record the events built by their `event` factories in the outbox, in the
+/// same transaction as the change
 #[async_trait]
 pub trait ProductRepository: Send + Sync {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Product>, RepositoryError>;
-    async fn create(&self, product: CreateProductDto) -> Result<Product, RepositoryError>;
-    async fn update(&self, id: Uuid, product: UpdateProductDto)
-        -> Result<Product, RepositoryError>;
-    async fn delete(&self, id: Uuid) -> Result<(), RepositoryError>;
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<Product>, RepositoryError>;
+    async fn create(
+        &self,
+        product: CreateProductDto,
+        event: EventFactory<Product>,
+    ) -> Result<Product, RepositoryError>;
+    async fn update(
+        &self,
+        id: Uuid,
+        product: UpdateProductDto,
+        event: EventFactory<Product>,
+    ) -> Result<Product, RepositoryError>;
+    async fn delete(&self, id: Uuid, event: EventFactory<Uuid>) -> Result<(), RepositoryError>;
     async fn list(&self) -> Result<Vec<Product>, RepositoryError>;
+    /// All products in list order, fetched row by row instead of collected up front
+    fn stream(&self) -> BoxStream<'static, Result<Product, RepositoryError>>;
+    /// Upserts the rows in one transaction, each row in its own savepoint so a bad row
+    /// only fails itself. A dry run rolls the transaction back
+    async fn import_batch(
+        &self,
+        rows: Vec<ProductImportRow>,
+        dry_run: bool,
+        created_event: EventFactory<Product>,
+        updated_event: EventFactory<Product>,
+    ) -> Result<Vec<ImportRowResult>, RepositoryError>;
+    /// Inserts the products with set-based statements. Items whose SKU is taken fail,
+    /// and with `atomic` any failure rolls the whole request back
+    async fn bulk_create(
+        &self,
+        items: Vec<(usize, CreateProductDto)>,
+        atomic: bool,
+        event: EventFactory<Product>,
+    ) -> Result<Vec<BulkItemResult>, RepositoryError>;
+    /// Updates the products with one set-based statement. Unknown products and taken
+    /// SKUs fail, and with `atomic` any failure rolls the whole request back
+    async fn bulk_update(
+        &self,
+        items: Vec<(usize, BulkUpdateProductDto)>,
+        atomic: bool,
+        event: EventFactory<Product>,
+    ) -> Result<Vec<BulkItemResult>, RepositoryError>;
+    /// Deletes the products in one statement. Unknown products fail, and with `atomic`
+    /// any failure rolls the whole request back
+    async fn bulk_delete(
+        &self,
+        items: Vec<(usize, Uuid)>,
+        atomic: bool,
+        event: EventFactory<Uuid>,
+    ) -> Result<Vec<BulkItemResult>, RepositoryError>;
 }
@@ -0,0 +1,31 @@
+use super::RepositoryError;
+use crate::domain::entities::promotion::{CreatePromotionDto, Promotion, UpdatePromotionDto};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait PromotionRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Promotion>, RepositoryError>;
+    async fn list(&self) -> Result<Vec<Promotion>, RepositoryError>;
+    /// Automatic promotions running at `now`, plus the promotion with `code` whatever its state
+    async fn find_applicable(
+        &self,
+        now: DateTime<Utc>,
+        code: Option<&str>,
+    ) -> Result<Vec<Promotion>, RepositoryError>;
+    /// Times `user_id` redeemed each of the given promotions
+    async fn count_redemptions(
+        &self,
+        user_id: Uuid,
+        promotion_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, i64>, RepositoryError>;
+    async fn create(&self, promotion: CreatePromotionDto) -> Result<Promotion, RepositoryError>;
+    async fn update(
+        &self,
+        id: Uuid,
+        promotion: UpdatePromotionDto,
+    ) -> Result<Promotion, RepositoryError>;
+    async fn delete(&self, id: Uuid) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,31 @@
+use super::RepositoryError;
+use crate::domain::entities::scheduled_task::{ScheduledTask, TaskRunStatus};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait ScheduledTaskRepository: Send + Sync {
+    /// Records a task at startup, updating its schedule
+    async fn register(
+        &self,
+        name: &str,
+        schedule: &str,
+        next_run_at: Option<DateTime<Utc>>,
+    ) -> Result<(), RepositoryError>;
+    /// Marks the run for the `scheduled_for` fire time as started, `false` when a
+    /// run for that time or a later one was already started
+    async fn start_run(
+        &self,
+        name: &str,
+        scheduled_for: DateTime<Utc>,
+        next_run_at: Option<DateTime<Utc>>,
+    ) -> Result<bool, RepositoryError>;
+    async fn finish_run(
+        &self,
+        name: &str,
+        status: TaskRunStatus,
+        error: Option<&str>,
+        duration_ms: i64,
+    ) -> Result<(), RepositoryError>;
+    async fn list(&self) -> Result<Vec<ScheduledTask>, RepositoryError>;
+}
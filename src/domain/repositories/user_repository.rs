@@ -1,14 +1,31 @@
 use super::RepositoryError;
-use crate::domain::entities::user::{CreateUserDto, UpdateUserDto, User};
+use crate::domain::entities::{
+    domain_event::EventFactory,
+    user::{CreateUserDto, UpdateUserDto, User},
+};
 use async_trait::async_trait;
+use futures::stream::BoxStream;
 use uuid::Uuid;
 
+/// Writes record the events built by their `event` factories in the outbox, in the
+/// same transaction as the change
 #[async_trait]
 pub trait UserRepository: Send + Sync {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, RepositoryError>;
     async fn find_by_email(&self, email: &str) -> Result<Option<User>, RepositoryError>;
-    async fn create(&self, user: CreateUserDto) -> Result<User, RepositoryError>;
-    async fn update(&self, id: Uuid, user: UpdateUserDto) -> Result<User, RepositoryError>;
-    async fn delete(&self, id: Uuid) -> Result<(), RepositoryError>;
+    async fn create(
+        &self,
+        user: CreateUserDto,
+        event: EventFactory<User>,
+    ) -> Result<User, RepositoryError>;
+    async fn update(
+        &self,
+        id: Uuid,
+        user: UpdateUserDto,
+        event: EventFactory<User>,
+    ) -> Result<User, RepositoryError>;
+    async fn delete(&self, id: Uuid, event: EventFactory<Uuid>) -> Result<(), RepositoryError>;
     async fn list(&self) -> Result<Vec<User>, RepositoryError>;
+    /// All users in list order, fetched row by row instead of collected up front
+    fn stream(&self) -> BoxStream<'static, Result<User, RepositoryError>>;
 }
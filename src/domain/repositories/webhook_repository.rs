@@ -0,0 +1,69 @@
+use super::RepositoryError;
+use crate::domain::entities::{
+    domain_event::OutboxEvent,
+    webhook::{
+        CreateWebhookSubscriptionDto, DueWebhookDelivery, UpdateWebhookSubscriptionDto,
+        WebhookAttemptOutcome, WebhookDelivery, WebhookSubscription,
+    },
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[async_trait]
+pub trait WebhookRepository: Send + Sync {
+    async fn list_subscriptions(&self) -> Result<Vec<WebhookSubscription>, RepositoryError>;
+    async fn find_subscription(
+        &self,
+        id: Uuid,
+    ) -> Result<Option<WebhookSubscription>, RepositoryError>;
+    /// Stores a subscription, `secret` being set by the caller
+    async fn create_subscription(
+        &self,
+        subscription: CreateWebhookSubscriptionDto,
+    ) -> Result<WebhookSubscription, RepositoryError>;
+    /// Applies the changes, reactivating a subscription clears its failures
+    async fn update_subscription(
+        &self,
+        id: Uuid,
+        subscription: UpdateWebhookSubscriptionDto,
+    ) -> Result<WebhookSubscription, RepositoryError>;
+    async fn delete_subscription(&self, id: Uuid) -> Result<(), RepositoryError>;
+    /// The newest deliveries of a subscription
+    async fn list_deliveries(
+        &self,
+        subscription_id: Uuid,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError>;
+    async fn find_delivery(
+        &self,
+        subscription_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<WebhookDelivery>, RepositoryError>;
+    /// Queues a delivery of the event for every active subscription to its type that
+    /// has none yet, returning how many were queued
+    async fn enqueue_event(&self, event: &OutboxEvent) -> Result<u64, RepositoryError>;
+    /// Queues a new delivery of the same event, leaving the original in the log
+    async fn redeliver(&self, id: Uuid) -> Result<WebhookDelivery, RepositoryError>;
+}
+
+/// The queue of pending deliveries the delivery worker works through
+#[async_trait]
+pub trait WebhookDeliveryQueue: Send + Sync {
+    /// Takes up to `limit` due deliveries of active subscriptions and postpones them to
+    /// `lease_until`, so other workers skip them and they are retried if this one dies
+    async fn claim_due(
+        &self,
+        limit: i64,
+        lease_until: DateTime<Utc>,
+    ) -> Result<Vec<DueWebhookDelivery>, RepositoryError>;
+    /// Records an attempt and its effect on the subscription's failure count,
+    /// disabling it once `disable_after` attempts failed in a row. Returns whether
+    /// the subscription was disabled.
+    async fn record_attempt(
+        &self,
+        id: Uuid,
+        outcome: WebhookAttemptOutcome,
+        disable_after: i32,
+    ) -> Result<bool, RepositoryError>;
+}
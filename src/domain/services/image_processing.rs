@@ -0,0 +1,182 @@
+//! Validation and thumbnail generation for uploaded images. CPU bound, so callers
+//! should run [`process`] on a blocking thread.
+
+use std::io::Cursor;
+
+use image::{imageops::FilterType, io::Limits, DynamicImage, ImageError, ImageFormat};
+
+use crate::domain::entities::product_image::{thumbnail_content_type, THUMBNAIL_SIZES};
+
+/// Largest accepted width or height
+const MAX_DIMENSION: u32 = 10_000;
+/// Largest accepted image, a 10 000 pixel square would take 400 MB to decode
+const MAX_MEGAPIXELS: u64 = 40;
+/// Memory the decoder may allocate, guards against decompression bombs whose header
+/// understates what decoding them takes
+const MAX_DECODE_BYTES: u64 = 256 * 1024 * 1024;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ImageProcessingError {
+    #[error("Unsupported image type, expected JPEG, PNG, GIF or WebP")]
+    Unsupported,
+    #[error("Image exceeds {MAX_DIMENSION} pixels per side or {MAX_MEGAPIXELS} megapixels")]
+    TooLarge,
+    #[error("Invalid image: {0}")]
+    Invalid(String),
+}
+
+#[derive(Debug)]
+pub struct Thumbnail {
+    pub variant: &'static str,
+    pub content_type: &'static str,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug)]
+pub struct ProcessedImage {
+    pub content_type: &'static str,
+    pub width: u32,
+    pub height: u32,
+    pub thumbnails: Vec<Thumbnail>,
+}
+
+/// Detects the image type from its leading bytes, ignoring what the client claimed
+pub fn sniff_content_type(data: &[u8]) -> Option<&'static str> {
+    match data {
+        [0xFF, 0xD8, 0xFF, ..] => Some("image/jpeg"),
+        [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, ..] => Some("image/png"),
+        [b'G', b'I', b'F', b'8', b'7' | b'9', b'a', ..] => Some("image/gif"),
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => Some("image/webp"),
+        _ => None,
+    }
+}
+
+/// Decodes the image and renders every thumbnail size
+pub fn process(data: &[u8]) -> Result<ProcessedImage, ImageProcessingError> {
+    let content_type = sniff_content_type(data).ok_or(ImageProcessingError::Unsupported)?;
+    let format = match content_type {
+        "image/png" => ImageFormat::Png,
+        "image/gif" => ImageFormat::Gif,
+        "image/webp" => ImageFormat::WebP,
+        _ => ImageFormat::Jpeg,
+    };
+
+    // Check the header before decoding pixels
+    let reader = image::io::Reader::with_format(Cursor::new(data), format);
+    let (width, height) = reader
+        .into_dimensions()
+        .map_err(|e| ImageProcessingError::Invalid(e.to_string()))?;
+    check_dimensions(width, height)?;
+
+    let mut reader = image::io::Reader::with_format(Cursor::new(data), format);
+    reader.limits(decode_limits());
+    let image = reader.decode().map_err(|e| match e {
+        ImageError::Limits(_) => ImageProcessingError::TooLarge,
+        e => ImageProcessingError::Invalid(e.to_string()),
+    })?;
+
+    let thumbnail_type = thumbnail_content_type(content_type);
+    let thumbnails = THUMBNAIL_SIZES
+        .iter()
+        .map(|&(variant, size)| {
+            Ok(Thumbnail {
+                variant,
+                content_type: thumbnail_type,
+                data: render_thumbnail(&image, size, thumbnail_type)?,
+            })
+        })
+        .collect::<Result<_, ImageProcessingError>>()?;
+
+    Ok(ProcessedImage {
+        content_type,
+        width,
+        height,
+        thumbnails,
+    })
+}
+
+fn check_dimensions(width: u32, height: u32) -> Result<(), ImageProcessingError> {
+    let pixels = u64::from(width) * u64::from(height);
+    if width > MAX_DIMENSION || height > MAX_DIMENSION || pixels > MAX_MEGAPIXELS * 1_000_000 {
+        return Err(ImageProcessingError::TooLarge);
+    }
+    Ok(())
+}
+
+fn decode_limits() -> Limits {
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_DIMENSION);
+    limits.max_image_height = Some(MAX_DIMENSION);
+    limits.max_alloc = Some(MAX_DECODE_BYTES);
+    limits
+}
+
+/// Scales the image to fit a `size` pixel square, never enlarging it
+fn render_thumbnail(
+    image: &DynamicImage,
+    size: u32,
+    content_type: &str,
+) -> Result<Vec<u8>, ImageProcessingError> {
+    let thumbnail = if image.width() <= size && image.height() <= size {
+        image.clone()
+    } else {
+        image.resize(size, size, FilterType::Triangle)
+    };
+
+    let mut data = Cursor::new(Vec::new());
+    let result = if content_type == "image/jpeg" {
+        DynamicImage::ImageRgb8(thumbnail.to_rgb8()).write_to(&mut data, ImageFormat::Jpeg)
+    } else {
+        thumbnail.write_to(&mut data, ImageFormat::Png)
+    };
+    result.map_err(|e| ImageProcessingError::Invalid(e.to_string()))?;
+
+    Ok(data.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgb, RgbImage};
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut data = Cursor::new(Vec::new());
+        RgbImage::from_pixel(width, height, Rgb([200, 40, 40]))
+            .write_to(&mut data, ImageFormat::Png)
+            .unwrap();
+        data.into_inner()
+    }
+
+    #[test]
+    fn processes_images_within_the_limits() {
+        let processed = process(&png(640, 480)).unwrap();
+
+        assert_eq!(processed.content_type, "image/png");
+        assert_eq!((processed.width, processed.height), (640, 480));
+        assert_eq!(processed.thumbnails.len(), THUMBNAIL_SIZES.len());
+    }
+
+    #[test]
+    fn rejects_images_too_large_to_decode() {
+        assert!(check_dimensions(MAX_DIMENSION, 4_000).is_ok());
+        // Within the side limit, but 100 megapixels
+        assert!(matches!(
+            check_dimensions(MAX_DIMENSION, MAX_DIMENSION),
+            Err(ImageProcessingError::TooLarge)
+        ));
+        assert!(matches!(
+            check_dimensions(MAX_DIMENSION + 1, 1),
+            Err(ImageProcessingError::TooLarge)
+        ));
+    }
+
+    #[test]
+    fn stops_decoding_past_the_allocation_limit() {
+        let mut limits = decode_limits();
+        limits.max_alloc = Some(1024);
+        let mut reader = image::io::Reader::with_format(Cursor::new(png(64, 64)), ImageFormat::Png);
+        reader.limits(limits);
+
+        assert!(matches!(reader.decode(), Err(ImageError::Limits(_))));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod image_processing;
+pub mod promotion_engine;
+pub mod webhook_signature;
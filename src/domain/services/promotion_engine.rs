@@ -0,0 +1,468 @@
+//! Pure evaluation of promotions against a priced set of items. Nothing in here
+//! touches storage, so callers load the promotions and usage counts first.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::{
+    cart::PricedCartItem,
+    exchange_rate::minor_units,
+    promotion::{Promotion, PromotionKind},
+};
+
+/// An item being priced
+#[derive(Debug, Clone)]
+pub struct PricingLine {
+    pub product_id: Uuid,
+    pub category: Option<String>,
+    pub quantity: i32,
+    pub unit_price: Decimal,
+}
+
+impl PricingLine {
+    fn total(&self) -> Decimal {
+        self.unit_price * Decimal::from(self.quantity)
+    }
+}
+
+impl From<&PricedCartItem> for PricingLine {
+    fn from(item: &PricedCartItem) -> Self {
+        Self {
+            product_id: item.product_id,
+            category: item.category.clone(),
+            quantity: item.quantity,
+            unit_price: item.unit_price,
+        }
+    }
+}
+
+/// Everything a promotion is evaluated against
+#[derive(Debug)]
+pub struct PricingContext<'a> {
+    pub lines: &'a [PricingLine],
+    /// Currency every line is priced in
+    pub currency: &'a str,
+    /// Customer placing the order, usage limited promotions require one
+    pub user_id: Option<Uuid>,
+    /// Discount code entered by the customer
+    pub code: Option<&'a str>,
+    pub now: DateTime<Utc>,
+    /// Times the customer already redeemed each promotion
+    pub redemptions: &'a HashMap<Uuid, i64>,
+}
+
+/// Why an entered discount code was not applied
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "reason", rename_all = "snake_case")]
+pub enum CodeRejection {
+    Unknown,
+    NotActive,
+    /// The code is limited per user and no user is known
+    RequiresUser,
+    UsageLimitReached,
+    /// The code's amounts are in another currency than the order
+    WrongCurrency {
+        #[schema(example = "EUR")]
+        currency: String,
+    },
+    MinimumNotMet {
+        #[schema(value_type = String, example = "50.00")]
+        minimum: Decimal,
+    },
+    NoEligibleItems,
+}
+
+impl fmt::Display for CodeRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown => write!(f, "unknown discount code"),
+            Self::NotActive => write!(f, "discount code is not active"),
+            Self::RequiresUser => write!(f, "discount code requires a signed in user"),
+            Self::UsageLimitReached => write!(f, "discount code was already used"),
+            Self::WrongCurrency { currency } => {
+                write!(f, "discount code only applies to orders in {currency}")
+            }
+            Self::MinimumNotMet { minimum } => {
+                write!(f, "discount code requires an order of at least {minimum}")
+            }
+            Self::NoEligibleItems => write!(f, "discount code does not apply to these items"),
+        }
+    }
+}
+
+/// A promotion that reduced the price
+#[derive(Debug, Clone)]
+pub struct AppliedPromotion {
+    pub promotion_id: Uuid,
+    pub name: String,
+    pub code: Option<String>,
+    pub discount: Decimal,
+}
+
+/// Result of evaluating promotions
+#[derive(Debug, Clone)]
+pub struct PromotionOutcome {
+    pub discount: Decimal,
+    pub total: Decimal,
+    pub applied: Vec<AppliedPromotion>,
+    pub code_rejection: Option<CodeRejection>,
+}
+
+/// Applies every eligible automatic promotion plus the promotion matching the
+/// entered code. Discounts stack in the given order and never exceed the subtotal.
+pub fn evaluate(promotions: &[Promotion], context: &PricingContext) -> PromotionOutcome {
+    let subtotal: Decimal = context.lines.iter().map(PricingLine::total).sum();
+    let code = context.code.map(|code| code.trim().to_ascii_uppercase());
+
+    let mut applied = Vec::new();
+    let mut remaining = subtotal;
+    let mut code_rejection = code.as_ref().map(|_| CodeRejection::Unknown);
+
+    for promotion in promotions {
+        let entered = match (&promotion.code, &code) {
+            (None, _) => false,
+            (Some(own), Some(code)) if own == code => true,
+            // Code promotions only apply when their code is entered
+            (Some(_), _) => continue,
+        };
+
+        match discount_for(promotion, context, subtotal) {
+            Ok(discount) => {
+                if entered {
+                    code_rejection = None;
+                }
+
+                let discount = discount.min(remaining);
+                if discount.is_zero() {
+                    continue;
+                }
+                remaining -= discount;
+                applied.push(AppliedPromotion {
+                    promotion_id: promotion.id,
+                    name: promotion.name.clone(),
+                    code: promotion.code.clone(),
+                    discount,
+                });
+            }
+            Err(rejection) => {
+                if entered {
+                    code_rejection = Some(rejection);
+                }
+            }
+        }
+    }
+
+    PromotionOutcome {
+        discount: subtotal - remaining,
+        total: remaining,
+        applied,
+        code_rejection,
+    }
+}
+
+/// Discount granted by a single promotion, or why it does not apply
+fn discount_for(
+    promotion: &Promotion,
+    context: &PricingContext,
+    subtotal: Decimal,
+) -> Result<Decimal, CodeRejection> {
+    if !is_active(promotion, context.now) {
+        return Err(CodeRejection::NotActive);
+    }
+
+    if let Some(limit) = promotion.usage_limit_per_user {
+        if context.user_id.is_none() {
+            return Err(CodeRejection::RequiresUser);
+        }
+        let used = context.redemptions.get(&promotion.id).copied().unwrap_or(0);
+        if used >= i64::from(limit) {
+            return Err(CodeRejection::UsageLimitReached);
+        }
+    }
+
+    // Fixed amounts and minimums only make sense in the currency they were set in
+    if let Some(currency) = &promotion.currency {
+        if currency != context.currency {
+            return Err(CodeRejection::WrongCurrency {
+                currency: currency.clone(),
+            });
+        }
+    }
+
+    if let Some(minimum) = promotion.min_order_value {
+        if subtotal < minimum {
+            return Err(CodeRejection::MinimumNotMet { minimum });
+        }
+    }
+
+    let eligible: Vec<&PricingLine> = context
+        .lines
+        .iter()
+        .filter(|line| is_eligible(promotion, line))
+        .collect();
+    if eligible.is_empty() {
+        return Err(CodeRejection::NoEligibleItems);
+    }
+
+    let eligible_total: Decimal = eligible.iter().map(|line| line.total()).sum();
+    let discount = match promotion.kind {
+        PromotionKind::Percentage => eligible_total * promotion.value / Decimal::ONE_HUNDRED,
+        PromotionKind::FixedAmount => promotion.value.min(eligible_total),
+        PromotionKind::BuyXGetY => {
+            let buy = promotion.buy_quantity.unwrap_or(0);
+            let get = promotion.get_quantity.unwrap_or(0);
+            if buy <= 0 || get <= 0 {
+                return Err(CodeRejection::NoEligibleItems);
+            }
+
+            eligible
+                .iter()
+                .map(|line| {
+                    let discounted_units = line.quantity / (buy + get) * get;
+                    line.unit_price * Decimal::from(discounted_units) * promotion.value
+                        / Decimal::ONE_HUNDRED
+                })
+                .sum()
+        }
+    };
+
+    // Round to what the currency can charge, like converted prices
+    Ok(discount.round_dp_with_strategy(
+        minor_units(context.currency),
+        RoundingStrategy::MidpointNearestEven,
+    ))
+}
+
+fn is_active(promotion: &Promotion, now: DateTime<Utc>) -> bool {
+    promotion.active
+        && promotion.starts_at.is_none_or(|starts_at| starts_at <= now)
+        && promotion.ends_at.is_none_or(|ends_at| now < ends_at)
+}
+
+fn is_eligible(promotion: &Promotion, line: &PricingLine) -> bool {
+    if promotion.product_ids.is_empty() && promotion.categories.is_empty() {
+        return true;
+    }
+
+    promotion.product_ids.contains(&line.product_id)
+        || line
+            .category
+            .as_ref()
+            .is_some_and(|category| promotion.categories.contains(category))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use rust_decimal_macros::dec;
+
+    fn promotion(kind: PromotionKind, value: Decimal) -> Promotion {
+        Promotion {
+            id: Uuid::new_v4(),
+            name: "Test".to_string(),
+            code: None,
+            kind,
+            value,
+            buy_quantity: None,
+            get_quantity: None,
+            min_order_value: None,
+            currency: None,
+            product_ids: Vec::new(),
+            categories: Vec::new(),
+            usage_limit_per_user: None,
+            starts_at: None,
+            ends_at: None,
+            active: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn line(category: Option<&str>, quantity: i32, unit_price: Decimal) -> PricingLine {
+        PricingLine {
+            product_id: Uuid::new_v4(),
+            category: category.map(str::to_string),
+            quantity,
+            unit_price,
+        }
+    }
+
+    fn run(
+        promotions: &[Promotion],
+        lines: &[PricingLine],
+        code: Option<&str>,
+    ) -> PromotionOutcome {
+        run_in("USD", promotions, lines, code)
+    }
+
+    fn run_in(
+        currency: &str,
+        promotions: &[Promotion],
+        lines: &[PricingLine],
+        code: Option<&str>,
+    ) -> PromotionOutcome {
+        let redemptions = HashMap::new();
+        evaluate(
+            promotions,
+            &PricingContext {
+                lines,
+                currency,
+                user_id: None,
+                code,
+                now: Utc::now(),
+                redemptions: &redemptions,
+            },
+        )
+    }
+
+    #[test]
+    fn stacks_discounts_in_order() {
+        let lines = [line(None, 1, dec!(100))];
+        let mut fixed = promotion(PromotionKind::FixedAmount, dec!(20));
+        fixed.currency = Some("USD".to_string());
+        let percentage = promotion(PromotionKind::Percentage, dec!(90));
+
+        // 90% of 100, then what is left of the 20 off
+        let outcome = run(&[percentage.clone(), fixed.clone()], &lines, None);
+        assert_eq!(outcome.total, Decimal::ZERO);
+        let discounts: Vec<_> = outcome.applied.iter().map(|a| a.discount).collect();
+        assert_eq!(discounts, [dec!(90), dec!(10)]);
+        assert_eq!(outcome.applied[0].promotion_id, percentage.id);
+
+        // 20 off first, then what is left of the 90%
+        let outcome = run(&[fixed.clone(), percentage], &lines, None);
+        assert_eq!(outcome.total, Decimal::ZERO);
+        let discounts: Vec<_> = outcome.applied.iter().map(|a| a.discount).collect();
+        assert_eq!(discounts, [dec!(20), dec!(80)]);
+        assert_eq!(outcome.applied[0].promotion_id, fixed.id);
+    }
+
+    #[test]
+    fn applies_category_rules_to_matching_lines_only() {
+        let lines = [
+            line(Some("phones"), 1, dec!(200)),
+            line(Some("cases"), 2, dec!(25)),
+        ];
+        let mut phones = promotion(PromotionKind::Percentage, dec!(10));
+        phones.categories = vec!["phones".to_string()];
+
+        let outcome = run(&[phones], &lines, None);
+        assert_eq!(outcome.discount, dec!(20));
+        assert_eq!(outcome.total, dec!(230));
+
+        let mut tablets = promotion(PromotionKind::Percentage, dec!(10));
+        tablets.code = Some("TABLETS".to_string());
+        tablets.categories = vec!["tablets".to_string()];
+
+        let outcome = run(&[tablets], &lines, Some("tablets"));
+        assert!(outcome.applied.is_empty());
+        assert_eq!(outcome.code_rejection, Some(CodeRejection::NoEligibleItems));
+    }
+
+    #[test]
+    fn requires_minimum_subtotal() {
+        let mut promotion = promotion(PromotionKind::Percentage, dec!(10));
+        promotion.code = Some("BIG".to_string());
+        promotion.min_order_value = Some(dec!(50));
+        promotion.currency = Some("USD".to_string());
+
+        let outcome = run(
+            &[promotion.clone()],
+            &[line(None, 1, dec!(49.99))],
+            Some("BIG"),
+        );
+        assert_eq!(outcome.discount, Decimal::ZERO);
+        assert_eq!(
+            outcome.code_rejection,
+            Some(CodeRejection::MinimumNotMet { minimum: dec!(50) })
+        );
+
+        let outcome = run(&[promotion], &[line(None, 2, dec!(25))], Some("BIG"));
+        assert_eq!(outcome.discount, dec!(5));
+        assert_eq!(outcome.code_rejection, None);
+    }
+
+    #[test]
+    fn caps_discount_at_subtotal() {
+        let lines = [line(None, 1, dec!(30))];
+        let mut first = promotion(PromotionKind::FixedAmount, dec!(20));
+        first.currency = Some("USD".to_string());
+        let second = first.clone();
+
+        let outcome = run(&[first, second], &lines, None);
+        assert_eq!(outcome.discount, dec!(30));
+        assert_eq!(outcome.total, Decimal::ZERO);
+        assert_eq!(outcome.applied[1].discount, dec!(10));
+
+        // Nothing is left for a third promotion, so it is not listed
+        let free = promotion(PromotionKind::Percentage, dec!(100));
+        let outcome = run(&[free.clone(), free], &lines, None);
+        assert_eq!(outcome.total, Decimal::ZERO);
+        assert_eq!(outcome.applied.len(), 1);
+    }
+
+    #[test]
+    fn applies_code_promotions_only_with_their_code() {
+        let lines = [line(None, 1, dec!(100))];
+        let mut promotion = promotion(PromotionKind::Percentage, dec!(15));
+        promotion.code = Some("SAVE15".to_string());
+
+        let outcome = run(&[promotion.clone()], &lines, None);
+        assert!(outcome.applied.is_empty());
+        assert_eq!(outcome.code_rejection, None);
+
+        let outcome = run(&[promotion.clone()], &lines, Some("OTHER"));
+        assert!(outcome.applied.is_empty());
+        assert_eq!(outcome.code_rejection, Some(CodeRejection::Unknown));
+
+        // Codes are matched case-insensitively
+        let outcome = run(&[promotion.clone()], &lines, Some(" save15 "));
+        assert_eq!(outcome.discount, dec!(15));
+        assert_eq!(outcome.code_rejection, None);
+
+        promotion.ends_at = Some(Utc::now() - Duration::days(1));
+        let outcome = run(&[promotion], &lines, Some("SAVE15"));
+        assert_eq!(outcome.code_rejection, Some(CodeRejection::NotActive));
+    }
+
+    #[test]
+    fn rounds_discounts_to_the_minor_unit_of_the_currency() {
+        let percentage = [promotion(PromotionKind::Percentage, dec!(12.5))];
+
+        let outcome = run(&percentage, &[line(None, 1, dec!(9.99))], None);
+        assert_eq!(outcome.discount, dec!(1.25));
+
+        // Yen have no minor unit, 12.5% of 999 is 124.875
+        let outcome = run_in("JPY", &percentage, &[line(None, 1, dec!(999))], None);
+        assert_eq!(outcome.discount, dec!(125));
+        assert_eq!(outcome.total, dec!(874));
+
+        // Dinars have three decimals, 12.5% of 9.999 is 1.249875
+        let outcome = run_in("KWD", &percentage, &[line(None, 1, dec!(9.999))], None);
+        assert_eq!(outcome.discount, dec!(1.250));
+        assert_eq!(outcome.total, dec!(8.749));
+    }
+
+    #[test]
+    fn rejects_amounts_in_another_currency() {
+        let mut promotion = promotion(PromotionKind::FixedAmount, dec!(5));
+        promotion.code = Some("FIVE".to_string());
+        promotion.currency = Some("EUR".to_string());
+
+        let outcome = run(&[promotion], &[line(None, 1, dec!(100))], Some("FIVE"));
+        assert!(outcome.applied.is_empty());
+        assert_eq!(
+            outcome.code_rejection,
+            Some(CodeRejection::WrongCurrency {
+                currency: "EUR".to_string()
+            })
+        );
+    }
+}
@@ -18,9 +18,12 @@ CREATE INDEX idx_users_username ON users(username);
 -- Create products table
 CREATE TABLE products (
     id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    sku VARCHAR(64) UNIQUE,
     name VARCHAR(255) NOT NULL,
     description TEXT NOT NULL,
+    category VARCHAR(100),
     price DECIMAL(10,2) NOT NULL CHECK (price >= 0),
+    currency CHAR(3) NOT NULL DEFAULT 'USD' CHECK (currency ~ '^[A-Z]{3}$'),
     stock INTEGER NOT NULL CHECK (stock >= 0),
     created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
@@ -28,6 +31,316 @@ CREATE TABLE products (
 
 -- Create indexes for products
 CREATE INDEX idx_products_name ON products(name);
+CREATE INDEX idx_products_category ON products(category);
+
+-- Create product images table, files live in blob storage
+CREATE TABLE product_images (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    product_id UUID NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL CHECK (position >= 0),
+    is_primary BOOLEAN NOT NULL DEFAULT FALSE,
+    content_type VARCHAR(50) NOT NULL,
+    width INTEGER NOT NULL CHECK (width > 0),
+    height INTEGER NOT NULL CHECK (height > 0),
+    size_bytes BIGINT NOT NULL CHECK (size_bytes > 0),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Create indexes for product images
+CREATE INDEX idx_product_images_product_id ON product_images(product_id, position);
+CREATE UNIQUE INDEX idx_product_images_primary ON product_images(product_id) WHERE is_primary;
+
+-- Create exchange rates table, one unit of base_currency buys `rate` units of quote_currency
+CREATE TABLE exchange_rates (
+    base_currency CHAR(3) NOT NULL CHECK (base_currency ~ '^[A-Z]{3}$'),
+    quote_currency CHAR(3) NOT NULL CHECK (quote_currency ~ '^[A-Z]{3}$'),
+    rate DECIMAL(20,10) NOT NULL CHECK (rate > 0),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (base_currency, quote_currency),
+    CHECK (base_currency <> quote_currency)
+);
+
+-- Create product price history table, periods are [effective_from, effective_to)
+CREATE TABLE product_prices (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    product_id UUID NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+    price DECIMAL(10,2) NOT NULL CHECK (price >= 0),
+    effective_from TIMESTAMP WITH TIME ZONE NOT NULL,
+    effective_to TIMESTAMP WITH TIME ZONE CHECK (effective_to > effective_from),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Create indexes for product prices
+CREATE INDEX idx_product_prices_product_id ON product_prices(product_id, effective_from);
+
+-- Create carts table (user_id is NULL for anonymous carts)
+CREATE TABLE carts (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID UNIQUE REFERENCES users(id) ON DELETE CASCADE,
+    token UUID NOT NULL UNIQUE DEFAULT uuid_generate_v4(),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Create cart items table
+CREATE TABLE cart_items (
+    cart_id UUID NOT NULL REFERENCES carts(id) ON DELETE CASCADE,
+    product_id UUID NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+    quantity INTEGER NOT NULL CHECK (quantity > 0),
+    unit_price DECIMAL(10,2) NOT NULL CHECK (unit_price >= 0),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (cart_id, product_id)
+);
+
+-- Create indexes for cart items
+CREATE INDEX idx_cart_items_product_id ON cart_items(product_id);
+
+-- Create orders table
+CREATE TYPE order_status AS ENUM ('pending_payment', 'paid', 'payment_failed', 'refunded');
+
+CREATE TABLE orders (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    status order_status NOT NULL DEFAULT 'pending_payment',
+    discount DECIMAL(10,2) NOT NULL DEFAULT 0 CHECK (discount >= 0),
+    total DECIMAL(10,2) NOT NULL CHECK (total >= 0),
+    currency CHAR(3) NOT NULL CHECK (currency ~ '^[A-Z]{3}$'),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE order_items (
+    order_id UUID NOT NULL REFERENCES orders(id) ON DELETE CASCADE,
+    product_id UUID NOT NULL REFERENCES products(id),
+    product_name VARCHAR(255) NOT NULL,
+    quantity INTEGER NOT NULL CHECK (quantity > 0),
+    unit_price DECIMAL(10,2) NOT NULL CHECK (unit_price >= 0),
+    PRIMARY KEY (order_id, product_id)
+);
+
+-- Create indexes for orders
+CREATE INDEX idx_orders_user_id ON orders(user_id);
+
+-- Create payments table
+CREATE TYPE payment_status AS ENUM (
+    'requires_action', 'requires_capture', 'succeeded', 'failed', 'refunded'
+);
+
+CREATE TABLE payments (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    order_id UUID NOT NULL REFERENCES orders(id) ON DELETE CASCADE,
+    provider VARCHAR(50) NOT NULL,
+    intent_id VARCHAR(255) NOT NULL UNIQUE,
+    amount DECIMAL(10,2) NOT NULL CHECK (amount >= 0),
+    currency CHAR(3) NOT NULL CHECK (currency ~ '^[A-Z]{3}$'),
+    status payment_status NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Processed provider webhook events, used to ignore redeliveries
+CREATE TABLE payment_events (
+    event_id VARCHAR(255) PRIMARY KEY,
+    payment_id UUID NOT NULL REFERENCES payments(id) ON DELETE CASCADE,
+    status payment_status NOT NULL,
+    received_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Create indexes for payments
+CREATE INDEX idx_payments_order_id ON payments(order_id);
+
+-- Create promotions table (code is NULL for automatic promotions)
+CREATE TYPE promotion_kind AS ENUM ('percentage', 'fixed_amount', 'buy_x_get_y');
+
+CREATE TABLE promotions (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    name VARCHAR(255) NOT NULL,
+    code VARCHAR(50) UNIQUE,
+    kind promotion_kind NOT NULL,
+    value DECIMAL(10,2) NOT NULL CHECK (value > 0),
+    buy_quantity INTEGER CHECK (buy_quantity > 0),
+    get_quantity INTEGER CHECK (get_quantity > 0),
+    min_order_value DECIMAL(10,2) CHECK (min_order_value >= 0),
+    -- Currency of the fixed amount and minimum order value
+    currency CHAR(3) CHECK (currency ~ '^[A-Z]{3}$'),
+    product_ids UUID[] NOT NULL DEFAULT '{}',
+    categories TEXT[] NOT NULL DEFAULT '{}',
+    usage_limit_per_user INTEGER CHECK (usage_limit_per_user > 0),
+    starts_at TIMESTAMP WITH TIME ZONE,
+    ends_at TIMESTAMP WITH TIME ZONE CHECK (ends_at > starts_at),
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CHECK (currency IS NOT NULL OR (kind <> 'fixed_amount' AND min_order_value IS NULL))
+);
+
+-- Promotions redeemed by orders, used to enforce per-user usage limits
+CREATE TABLE promotion_redemptions (
+    promotion_id UUID NOT NULL REFERENCES promotions(id) ON DELETE CASCADE,
+    order_id UUID NOT NULL REFERENCES orders(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    discount DECIMAL(10,2) NOT NULL CHECK (discount >= 0),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (promotion_id, order_id)
+);
+
+-- Create indexes for promotions
+CREATE INDEX idx_promotion_redemptions_user_id ON promotion_redemptions(user_id, promotion_id);
+
+-- Product changes streamed to SSE clients, kept for a short time so clients can resume
+CREATE TYPE product_event_kind AS ENUM ('created', 'updated', 'deleted', 'stock_changed');
+
+CREATE TABLE product_events (
+    id BIGSERIAL PRIMARY KEY,
+    product_id UUID NOT NULL,
+    kind product_event_kind NOT NULL,
+    -- The product after the change, or before it for deletions
+    product JSONB NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_product_events_created_at ON product_events(created_at);
+
+-- Records every product change and announces its event id on the product_events channel
+CREATE OR REPLACE FUNCTION record_product_event()
+RETURNS TRIGGER AS $$
+DECLARE
+    event_kind product_event_kind;
+    event_product JSONB;
+    event_id BIGINT;
+BEGIN
+    IF TG_OP = 'INSERT' THEN
+        event_kind := 'created';
+        event_product := to_jsonb(NEW);
+    ELSIF TG_OP = 'DELETE' THEN
+        event_kind := 'deleted';
+        event_product := to_jsonb(OLD);
+    ELSIF to_jsonb(NEW) - 'stock' - 'updated_at' = to_jsonb(OLD) - 'stock' - 'updated_at' THEN
+        IF NEW.stock = OLD.stock THEN
+            RETURN NULL;
+        END IF;
+        event_kind := 'stock_changed';
+        event_product := to_jsonb(NEW);
+    ELSE
+        event_kind := 'updated';
+        event_product := to_jsonb(NEW);
+    END IF;
+
+    INSERT INTO product_events (product_id, kind, product)
+    VALUES ((event_product->>'id')::uuid, event_kind, event_product)
+    RETURNING id INTO event_id;
+
+    PERFORM pg_notify('product_events', event_id::text);
+    RETURN NULL;
+END;
+$$ language 'plpgsql';
+
+CREATE TRIGGER record_product_events
+    AFTER INSERT OR UPDATE OR DELETE ON products
+    FOR EACH ROW
+    EXECUTE FUNCTION record_product_event();
+
+-- Partner endpoints notified when products or users change
+CREATE TABLE webhook_subscriptions (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    url TEXT NOT NULL,
+    -- product.created, product.updated, product.deleted, user.created, ...
+    event_types TEXT[] NOT NULL CHECK (cardinality(event_types) > 0),
+    secret TEXT NOT NULL,
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    -- Failed attempts since the last success, the subscription is disabled past a limit
+    consecutive_failures INTEGER NOT NULL DEFAULT 0,
+    disabled_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- One row per event and subscription, doubling as retry queue and delivery log
+CREATE TYPE webhook_delivery_status AS ENUM ('pending', 'succeeded', 'failed');
+
+CREATE TABLE webhook_deliveries (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    subscription_id UUID NOT NULL REFERENCES webhook_subscriptions(id) ON DELETE CASCADE,
+    -- Shared by all deliveries of an event, redeliveries included, so receivers can deduplicate
+    event_id UUID NOT NULL,
+    event_type TEXT NOT NULL,
+    payload JSONB NOT NULL,
+    status webhook_delivery_status NOT NULL DEFAULT 'pending',
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    last_attempt_at TIMESTAMP WITH TIME ZONE,
+    response_status INTEGER,
+    last_error TEXT,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_webhook_deliveries_due ON webhook_deliveries(next_attempt_at) WHERE status = 'pending';
+CREATE INDEX idx_webhook_deliveries_subscription ON webhook_deliveries(subscription_id, created_at DESC);
+
+-- Lets the outbox relay skip events it already queued for a subscription
+CREATE INDEX idx_webhook_deliveries_event ON webhook_deliveries(subscription_id, event_id);
+
+-- Domain events, written in the transaction of the change that emitted them and
+-- relayed to the event sinks afterwards
+CREATE TABLE outbox (
+    -- Event id, kept across relay attempts so sinks can deduplicate
+    id UUID PRIMARY KEY,
+    sequence BIGSERIAL NOT NULL,
+    event_type VARCHAR(100) NOT NULL,
+    aggregate_id UUID NOT NULL,
+    payload JSONB NOT NULL,
+    occurred_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    available_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    published_at TIMESTAMP WITH TIME ZONE,
+    last_error TEXT
+);
+
+CREATE INDEX idx_outbox_pending ON outbox(sequence) WHERE published_at IS NULL;
+CREATE INDEX idx_outbox_published_at ON outbox(published_at) WHERE published_at IS NOT NULL;
+
+-- Background jobs, claimed by workers with FOR UPDATE SKIP LOCKED
+CREATE TYPE job_status AS ENUM ('queued', 'running', 'succeeded', 'dead', 'cancelled');
+
+CREATE TABLE jobs (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    queue VARCHAR(100) NOT NULL,
+    kind VARCHAR(100) NOT NULL,
+    payload JSONB NOT NULL,
+    status job_status NOT NULL DEFAULT 'queued',
+    attempts INTEGER NOT NULL DEFAULT 0,
+    max_attempts INTEGER NOT NULL CHECK (max_attempts > 0),
+    run_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    -- Lease of the worker running the job, taken over by another one once it ends
+    locked_until TIMESTAMP WITH TIME ZONE,
+    last_error TEXT,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    finished_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX idx_jobs_queued ON jobs(queue, run_at) WHERE status = 'queued';
+CREATE INDEX idx_jobs_running ON jobs(queue, locked_until) WHERE status = 'running';
+CREATE INDEX idx_jobs_created_at ON jobs(created_at DESC);
+CREATE INDEX idx_jobs_finished_at ON jobs(finished_at) WHERE status IN ('succeeded', 'cancelled');
+
+-- Cron tasks of the in-process scheduler and their last run, shared by all replicas
+CREATE TYPE task_run_status AS ENUM ('running', 'succeeded', 'failed');
+
+CREATE TABLE scheduled_tasks (
+    name VARCHAR(100) PRIMARY KEY,
+    schedule VARCHAR(255) NOT NULL,
+    next_run_at TIMESTAMP WITH TIME ZONE,
+    -- Fire time of the last run, so a run is never repeated by another replica
+    last_scheduled_for TIMESTAMP WITH TIME ZONE,
+    last_started_at TIMESTAMP WITH TIME ZONE,
+    last_finished_at TIMESTAMP WITH TIME ZONE,
+    last_status task_run_status,
+    last_error TEXT,
+    last_duration_ms BIGINT,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
 
 -- Add some sample data for testing
 INSERT INTO users (email, username, password_hash) VALUES
@@ -39,6 +352,13 @@ INSERT INTO products (name, description, price, stock) VALUES
     ('Product 2', 'Description for product 2', 49.99, 50),
     ('Product 3', 'Description for product 3', 19.99, 200);
 
+INSERT INTO product_prices (product_id, price, effective_from)
+SELECT id, price, created_at FROM products;
+
+INSERT INTO exchange_rates (base_currency, quote_currency, rate) VALUES
+    ('USD', 'EUR', 0.92),
+    ('USD', 'GBP', 0.79);
+
 -- Add triggers for updated_at
 CREATE OR REPLACE FUNCTION update_updated_at_column()
 RETURNS TRIGGER AS $$
@@ -56,4 +376,44 @@ CREATE TRIGGER update_users_updated_at
 CREATE TRIGGER update_products_updated_at
     BEFORE UPDATE ON products
     FOR EACH ROW
-    EXECUTE FUNCTION update_updated_at_column();
\ No newline at end of file
+    EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER update_carts_updated_at
+    BEFORE UPDATE ON carts
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER update_cart_items_updated_at
+    BEFORE UPDATE ON cart_items
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER update_orders_updated_at
+    BEFORE UPDATE ON orders
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER update_payments_updated_at
+    BEFORE UPDATE ON payments
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER update_promotions_updated_at
+    BEFORE UPDATE ON promotions
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER update_webhook_subscriptions_updated_at
+    BEFORE UPDATE ON webhook_subscriptions
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER update_jobs_updated_at
+    BEFORE UPDATE ON jobs
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER update_scheduled_tasks_updated_at
+    BEFORE UPDATE ON scheduled_tasks
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
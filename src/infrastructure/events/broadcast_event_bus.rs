@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+use crate::domain::{
+    entities::domain_event::OutboxEvent,
+    gateways::{EventSink, EventSinkError},
+};
+
+/// Events buffered per subscriber before it lags behind
+const CAPACITY: usize = 1024;
+
+/// In-process sink of the outbox relay, for parts of the application reacting to
+/// domain events after they were committed
+#[derive(Clone)]
+pub struct BroadcastEventBus {
+    sender: broadcast::Sender<Arc<OutboxEvent>>,
+}
+
+impl BroadcastEventBus {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CAPACITY);
+        Self { sender }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<OutboxEvent>> {
+        self.sender.subscribe()
+    }
+}
+
+#[async_trait]
+impl EventSink for BroadcastEventBus {
+    fn name(&self) -> &'static str {
+        "bus"
+    }
+
+    async fn publish(&self, event: &OutboxEvent) -> Result<(), EventSinkError> {
+        // Nobody listening is not a failure
+        let _ = self.sender.send(Arc::new(event.clone()));
+        Ok(())
+    }
+}
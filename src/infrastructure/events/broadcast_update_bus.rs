@@ -0,0 +1,30 @@
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+use crate::domain::{entities::live_update::LiveUpdate, gateways::UpdateBus};
+
+/// Updates buffered per subscriber before it lags behind
+const CAPACITY: usize = 1024;
+
+/// In-process update bus on a tokio broadcast channel, cheap to clone into each request
+#[derive(Clone)]
+pub struct BroadcastUpdateBus {
+    sender: broadcast::Sender<Arc<LiveUpdate>>,
+}
+
+impl BroadcastUpdateBus {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CAPACITY);
+        Self { sender }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<LiveUpdate>> {
+        self.sender.subscribe()
+    }
+}
+
+impl UpdateBus for BroadcastUpdateBus {
+    fn publish(&self, update: LiveUpdate) {
+        let _ = self.sender.send(Arc::new(update));
+    }
+}
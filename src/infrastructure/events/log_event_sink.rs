@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use log::info;
+
+use crate::domain::{
+    entities::domain_event::OutboxEvent,
+    gateways::{EventSink, EventSinkError},
+};
+
+/// Writes every relayed event to the application log
+pub struct LogEventSink;
+
+#[async_trait]
+impl EventSink for LogEventSink {
+    fn name(&self) -> &'static str {
+        "log"
+    }
+
+    async fn publish(&self, event: &OutboxEvent) -> Result<(), EventSinkError> {
+        info!(
+            "Domain event {} {} for {}: {}",
+            event.id, event.event_type, event.aggregate_id, event.payload
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+pub mod broadcast_event_bus;
+pub mod broadcast_update_bus;
+pub mod log_event_sink;
+pub mod product_event_feed;
+
+pub use broadcast_event_bus::BroadcastEventBus;
+pub use broadcast_update_bus::BroadcastUpdateBus;
+pub use log_event_sink::LogEventSink;
+pub use product_event_feed::{FeedUpdate, ProductEventFeed};
@@ -0,0 +1,125 @@
+use log::{error, warn};
+use sqlx::{postgres::PgListener, PgPool};
+use std::{sync::Arc, time::Duration};
+use tokio::sync::broadcast;
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    application::{
+        error::ApplicationError,
+        use_cases::{product_event::GetProductEventUseCase, UseCase},
+    },
+    domain::entities::product_event::ProductEvent,
+    infrastructure::{persistence::postgres::PostgresProductEventRepository, shutdown::Shutdown},
+};
+
+/// Channel the `products` trigger notifies with the id of each recorded event
+const CHANNEL: &str = "product_events";
+/// Updates buffered per subscriber before it lags behind
+const CAPACITY: usize = 1024;
+/// Pause before reconnecting the listener after an error
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone)]
+pub enum FeedUpdate {
+    Event(Arc<ProductEvent>),
+    /// The listener lost its connection, notifications may have been missed
+    Gap,
+}
+
+/// Product events as they are committed, fanned out from one Postgres `LISTEN`
+/// connection to every subscriber in the process
+#[derive(Clone)]
+pub struct ProductEventFeed {
+    sender: broadcast::Sender<FeedUpdate>,
+}
+
+impl ProductEventFeed {
+    /// Starts the listener, which disconnects on shutdown
+    pub fn spawn(pool: PgPool, shutdown: &Shutdown) -> Self {
+        let (sender, _) = broadcast::channel(CAPACITY);
+        let feed = Self {
+            sender: sender.clone(),
+        };
+
+        let stopping = shutdown.stopping();
+        shutdown.spawn(async move {
+            let use_case =
+                GetProductEventUseCase::new(PostgresProductEventRepository::new(pool.clone()));
+
+            while !stopping.is_cancelled() {
+                let mut listener = match PgListener::connect_with(&pool).await {
+                    Ok(listener) => listener,
+                    Err(e) => {
+                        error!("Error connecting the product event listener: {:?}", e);
+                        reconnect_delay(&stopping).await;
+                        continue;
+                    }
+                };
+                if let Err(e) = listener.listen(CHANNEL).await {
+                    error!("Error listening for product events: {:?}", e);
+                    reconnect_delay(&stopping).await;
+                    continue;
+                }
+
+                loop {
+                    let received = tokio::select! {
+                        received = listener.try_recv() => received,
+                        _ = stopping.cancelled() => break,
+                    };
+                    let notification = match received {
+                        Ok(Some(notification)) => notification,
+                        // Reconnects on the next call, anything sent meanwhile is lost
+                        Ok(None) => {
+                            let _ = sender.send(FeedUpdate::Gap);
+                            continue;
+                        }
+                        Err(e) => {
+                            error!("Error receiving product events: {:?}", e);
+                            let _ = sender.send(FeedUpdate::Gap);
+                            break;
+                        }
+                    };
+                    if sender.receiver_count() == 0 {
+                        continue;
+                    }
+                    let Ok(id) = notification.payload().parse::<i64>() else {
+                        warn!(
+                            "Ignoring product event notification {:?}",
+                            notification.payload()
+                        );
+                        continue;
+                    };
+
+                    match use_case.execute(id).await {
+                        Ok(event) => {
+                            let _ = sender.send(FeedUpdate::Event(Arc::new(event)));
+                        }
+                        // Already pruned
+                        Err(ApplicationError::NotFound) => {}
+                        Err(e) => {
+                            error!("Error loading product event {}: {:?}", id, e);
+                            let _ = sender.send(FeedUpdate::Gap);
+                        }
+                    }
+                }
+
+                reconnect_delay(&stopping).await;
+            }
+        });
+
+        feed
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<FeedUpdate> {
+        self.sender.subscribe()
+    }
+}
+
+/// Waits before reconnecting, cut short by shutdown
+async fn reconnect_delay(stopping: &CancellationToken) {
+    tokio::select! {
+        _ = actix_web::rt::time::sleep(RECONNECT_DELAY) => {}
+        _ = stopping.cancelled() => {}
+    }
+}
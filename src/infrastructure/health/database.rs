@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::domain::gateways::{HealthCheck, HealthCheckError};
+
+/// Runs a query on a pooled connection and reports pool usage
+pub struct DatabaseHealthCheck {
+    pool: PgPool,
+}
+
+impl DatabaseHealthCheck {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl HealthCheck for DatabaseHealthCheck {
+    fn name(&self) -> &'static str {
+        "database"
+    }
+
+    async fn check(&self) -> Result<Option<serde_json::Value>, HealthCheckError> {
+        sqlx::query("SELECT 1")
+            .execute(&self.pool)
+            .await
+            .map_err(|e| HealthCheckError::Unhealthy(e.to_string()))?;
+
+        Ok(Some(json!({
+            "pool_size": self.pool.size(),
+            "pool_idle": self.pool.num_idle(),
+            "pool_max": self.pool.options().get_max_connections(),
+        })))
+    }
+}
@@ -0,0 +1,9 @@
+pub mod database;
+pub mod registry;
+pub mod schema;
+pub mod storage;
+
+pub use database::DatabaseHealthCheck;
+pub use registry::HealthRegistry;
+pub use schema::SchemaHealthCheck;
+pub use storage::StorageHealthCheck;
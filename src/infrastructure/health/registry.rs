@@ -0,0 +1,58 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tokio_util::sync::CancellationToken;
+
+use crate::domain::gateways::HealthCheck;
+
+/// The dependency checks behind readiness and whether the server is shutting down,
+/// cheap to clone into each request
+#[derive(Clone)]
+pub struct HealthRegistry {
+    checks: Vec<Arc<dyn HealthCheck>>,
+    check_timeout: Duration,
+    started_at: Instant,
+    shutting_down: CancellationToken,
+}
+
+impl HealthRegistry {
+    pub fn new(check_timeout: Duration) -> Self {
+        Self {
+            checks: Vec::new(),
+            check_timeout,
+            started_at: Instant::now(),
+            shutting_down: CancellationToken::new(),
+        }
+    }
+
+    pub fn register<C: HealthCheck + 'static>(mut self, check: C) -> Self {
+        self.checks.push(Arc::new(check));
+        self
+    }
+
+    pub fn checks(&self) -> Vec<Arc<dyn HealthCheck>> {
+        self.checks.clone()
+    }
+
+    pub fn check_timeout(&self) -> Duration {
+        self.check_timeout
+    }
+
+    pub fn uptime_seconds(&self) -> u64 {
+        self.started_at.elapsed().as_secs()
+    }
+
+    /// Fails readiness from now on, so load balancers stop sending traffic
+    pub fn begin_shutdown(&self) {
+        self.shutting_down.cancel();
+    }
+
+    pub fn is_shutting_down(&self) -> bool {
+        self.shutting_down.is_cancelled()
+    }
+
+    /// Resolves once shutdown began
+    pub async fn shutdown_begun(&self) {
+        self.shutting_down.cancelled().await
+    }
+}
@@ -0,0 +1,387 @@
+use async_trait::async_trait;
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::domain::gateways::{HealthCheck, HealthCheckError};
+
+/// Columns of every table this build queries, kept in step with `database/init.sql`
+const REQUIRED_COLUMNS: &[(&str, &[&str])] = &[
+    (
+        "users",
+        &[
+            "id",
+            "email",
+            "username",
+            "password_hash",
+            "created_at",
+            "updated_at",
+        ],
+    ),
+    (
+        "products",
+        &[
+            "id",
+            "sku",
+            "name",
+            "description",
+            "category",
+            "price",
+            "currency",
+            "stock",
+            "created_at",
+            "updated_at",
+        ],
+    ),
+    (
+        "product_images",
+        &[
+            "id",
+            "product_id",
+            "position",
+            "is_primary",
+            "content_type",
+            "width",
+            "height",
+            "size_bytes",
+            "created_at",
+        ],
+    ),
+    (
+        "exchange_rates",
+        &["base_currency", "quote_currency", "rate", "updated_at"],
+    ),
+    (
+        "product_prices",
+        &[
+            "id",
+            "product_id",
+            "price",
+            "effective_from",
+            "effective_to",
+            "created_at",
+        ],
+    ),
+    (
+        "carts",
+        &["id", "user_id", "token", "created_at", "updated_at"],
+    ),
+    (
+        "cart_items",
+        &[
+            "cart_id",
+            "product_id",
+            "quantity",
+            "unit_price",
+            "created_at",
+            "updated_at",
+        ],
+    ),
+    (
+        "orders",
+        &[
+            "id",
+            "user_id",
+            "status",
+            "discount",
+            "total",
+            "currency",
+            "created_at",
+            "updated_at",
+        ],
+    ),
+    (
+        "order_items",
+        &[
+            "order_id",
+            "product_id",
+            "product_name",
+            "quantity",
+            "unit_price",
+        ],
+    ),
+    (
+        "payments",
+        &[
+            "id",
+            "order_id",
+            "provider",
+            "intent_id",
+            "amount",
+            "currency",
+            "status",
+            "created_at",
+            "updated_at",
+        ],
+    ),
+    (
+        "payment_events",
+        &["event_id", "payment_id", "status", "received_at"],
+    ),
+    (
+        "promotions",
+        &[
+            "id",
+            "name",
+            "code",
+            "kind",
+            "value",
+            "buy_quantity",
+            "get_quantity",
+            "min_order_value",
+            "currency",
+            "product_ids",
+            "categories",
+            "usage_limit_per_user",
+            "starts_at",
+            "ends_at",
+            "active",
+            "created_at",
+            "updated_at",
+        ],
+    ),
+    (
+        "promotion_redemptions",
+        &[
+            "promotion_id",
+            "order_id",
+            "user_id",
+            "discount",
+            "created_at",
+        ],
+    ),
+    (
+        "product_events",
+        &["id", "product_id", "kind", "product", "created_at"],
+    ),
+    (
+        "webhook_subscriptions",
+        &[
+            "id",
+            "url",
+            "event_types",
+            "secret",
+            "active",
+            "consecutive_failures",
+            "disabled_at",
+            "created_at",
+            "updated_at",
+        ],
+    ),
+    (
+        "webhook_deliveries",
+        &[
+            "id",
+            "subscription_id",
+            "event_id",
+            "event_type",
+            "payload",
+            "status",
+            "attempts",
+            "next_attempt_at",
+            "last_attempt_at",
+            "response_status",
+            "last_error",
+            "created_at",
+        ],
+    ),
+    (
+        "outbox",
+        &[
+            "id",
+            "sequence",
+            "event_type",
+            "aggregate_id",
+            "payload",
+            "occurred_at",
+            "attempts",
+            "available_at",
+            "published_at",
+            "last_error",
+        ],
+    ),
+    (
+        "jobs",
+        &[
+            "id",
+            "queue",
+            "kind",
+            "payload",
+            "status",
+            "attempts",
+            "max_attempts",
+            "run_at",
+            "locked_until",
+            "last_error",
+            "created_at",
+            "updated_at",
+            "finished_at",
+        ],
+    ),
+    (
+        "scheduled_tasks",
+        &[
+            "name",
+            "schedule",
+            "next_run_at",
+            "last_scheduled_for",
+            "last_started_at",
+            "last_finished_at",
+            "last_status",
+            "last_error",
+            "last_duration_ms",
+            "updated_at",
+        ],
+    ),
+];
+
+/// Verifies the database schema is up to date with the application, down to the
+/// columns, so a database created by an older `init.sql` is reported
+pub struct SchemaHealthCheck {
+    pool: PgPool,
+}
+
+impl SchemaHealthCheck {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl HealthCheck for SchemaHealthCheck {
+    fn name(&self) -> &'static str {
+        "schema"
+    }
+
+    async fn check(&self) -> Result<Option<serde_json::Value>, HealthCheckError> {
+        let (tables, columns): (Vec<&str>, Vec<&str>) = REQUIRED_COLUMNS
+            .iter()
+            .flat_map(|(table, columns)| columns.iter().map(move |column| (*table, *column)))
+            .unzip();
+        let missing = sqlx::query_as::<_, (String, String)>(
+            r#"
+            SELECT required.table_name, required.column_name
+            FROM UNNEST($1::text[], $2::text[]) AS required(table_name, column_name)
+            WHERE NOT EXISTS (
+                SELECT 1 FROM pg_attribute
+                WHERE attrelid = to_regclass(required.table_name)
+                    AND attname = required.column_name
+                    AND NOT attisdropped
+            )
+            "#,
+        )
+        .bind(&tables)
+        .bind(&columns)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| HealthCheckError::Unhealthy(e.to_string()))?;
+
+        if let Some(problems) = describe_missing(&missing) {
+            return Err(HealthCheckError::Unhealthy(problems));
+        }
+
+        Ok(Some(json!({
+            "tables": REQUIRED_COLUMNS.len(),
+            "columns": columns.len(),
+        })))
+    }
+}
+
+/// Lists the missing tables, and the missing columns of the tables that exist
+fn describe_missing(missing: &[(String, String)]) -> Option<String> {
+    let mut tables = Vec::new();
+    let mut columns = Vec::new();
+    for (table, required) in REQUIRED_COLUMNS {
+        let absent: Vec<&str> = missing
+            .iter()
+            .filter(|(missing_table, _)| missing_table == table)
+            .map(|(_, column)| column.as_str())
+            .collect();
+        match absent.len() {
+            0 => {}
+            n if n == required.len() => tables.push(table.to_string()),
+            _ => columns.extend(absent.iter().map(|column| format!("{}.{}", table, column))),
+        }
+    }
+
+    let mut problems = Vec::new();
+    if !tables.is_empty() {
+        problems.push(format!("Missing tables: {}", tables.join(", ")));
+    }
+    if !columns.is_empty() {
+        problems.push(format!("Missing columns: {}", columns.join(", ")));
+    }
+    (!problems.is_empty()).then(|| problems.join("; "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Columns of the `CREATE TABLE` statements of the schema
+    fn schema_columns() -> Vec<(String, Vec<String>)> {
+        let sql = include_str!("../database/init.sql");
+        let mut tables = Vec::new();
+        let mut lines = sql.lines();
+        while let Some(line) = lines.next() {
+            let Some(table) = line
+                .strip_prefix("CREATE TABLE ")
+                .and_then(|rest| rest.strip_suffix(" ("))
+            else {
+                continue;
+            };
+            let columns = lines
+                .by_ref()
+                .take_while(|line| *line != ");")
+                .map(str::trim)
+                .filter(|line| !line.starts_with("--"))
+                .filter_map(|line| line.split_whitespace().next())
+                .filter(|word| !matches!(*word, "PRIMARY" | "CHECK" | "UNIQUE" | "CONSTRAINT"))
+                .map(str::to_string)
+                .collect();
+            tables.push((table.to_string(), columns));
+        }
+        tables
+    }
+
+    #[test]
+    fn requires_the_columns_of_the_schema() {
+        let required: Vec<(String, Vec<String>)> = REQUIRED_COLUMNS
+            .iter()
+            .map(|(table, columns)| {
+                (
+                    table.to_string(),
+                    columns.iter().map(|column| column.to_string()).collect(),
+                )
+            })
+            .collect();
+
+        assert_eq!(required, schema_columns());
+    }
+
+    #[test]
+    fn reports_missing_tables_and_columns() {
+        let missing = |pairs: &[(&str, &str)]| -> Vec<(String, String)> {
+            pairs
+                .iter()
+                .map(|(table, column)| (table.to_string(), column.to_string()))
+                .collect()
+        };
+        let scheduled_tasks: Vec<(&str, &str)> = REQUIRED_COLUMNS
+            .iter()
+            .find(|(table, _)| *table == "scheduled_tasks")
+            .map(|(table, columns)| columns.iter().map(|column| (*table, *column)).collect())
+            .unwrap();
+
+        assert_eq!(describe_missing(&[]), None);
+        assert_eq!(
+            describe_missing(&missing(&[("products", "currency")])).as_deref(),
+            Some("Missing columns: products.currency")
+        );
+        let mut both = missing(&scheduled_tasks);
+        both.extend(missing(&[("orders", "discount")]));
+        assert_eq!(
+            describe_missing(&both).as_deref(),
+            Some("Missing tables: scheduled_tasks; Missing columns: orders.discount")
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+
+use crate::domain::gateways::{BlobStorage, HealthCheck, HealthCheckError};
+
+/// Verifies product image storage is reachable
+pub struct StorageHealthCheck<S: BlobStorage> {
+    storage: S,
+}
+
+impl<S: BlobStorage> StorageHealthCheck<S> {
+    pub fn new(storage: S) -> Self {
+        Self { storage }
+    }
+}
+
+#[async_trait]
+impl<S: BlobStorage> HealthCheck for StorageHealthCheck<S> {
+    fn name(&self) -> &'static str {
+        "storage"
+    }
+
+    async fn check(&self) -> Result<Option<serde_json::Value>, HealthCheckError> {
+        self.storage
+            .check()
+            .await
+            .map_err(|e| HealthCheckError::Unhealthy(e.to_string()))?;
+
+        Ok(None)
+    }
+}
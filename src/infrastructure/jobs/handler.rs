@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::domain::entities::job::JobPayload;
+
+#[derive(thiserror::Error, Debug)]
+pub enum JobError {
+    /// Worth another attempt, until the job runs out of them
+    #[error("{0}")]
+    Retry(String),
+    /// Dead-letters the job right away
+    #[error("{0}")]
+    Permanent(String),
+}
+
+/// Runs the jobs of one payload type. Jobs run at least once, so a handler may see the
+/// same payload again after a lost worker or a timeout
+#[async_trait]
+pub trait JobHandler: Send + Sync + 'static {
+    type Payload: JobPayload;
+
+    async fn handle(&self, payload: Self::Payload) -> Result<(), JobError>;
+}
+
+/// A handler behind the stored JSON payload, so handlers of all kinds share a registry
+#[async_trait]
+pub(super) trait ErasedJobHandler: Send + Sync {
+    async fn run(&self, payload: Value) -> Result<(), JobError>;
+}
+
+pub(super) struct Typed<H>(pub H);
+
+#[async_trait]
+impl<H: JobHandler> ErasedJobHandler for Typed<H> {
+    async fn run(&self, payload: Value) -> Result<(), JobError> {
+        let payload = serde_json::from_value::<H::Payload>(payload)
+            .map_err(|e| JobError::Permanent(format!("Invalid payload: {}", e)))?;
+
+        self.0.handle(payload).await
+    }
+}
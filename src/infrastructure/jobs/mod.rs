@@ -0,0 +1,8 @@
+pub mod handler;
+pub mod registry;
+pub mod remove_image_files;
+pub mod worker;
+
+pub use registry::JobRegistry;
+pub use remove_image_files::RemoveImageFilesHandler;
+pub use worker::spawn_job_workers;
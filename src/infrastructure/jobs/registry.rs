@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::handler::{ErasedJobHandler, JobHandler, Typed};
+use crate::domain::entities::job::JobPayload;
+
+/// Jobs on a queue nobody configured run one at a time
+const DEFAULT_CONCURRENCY: usize = 1;
+
+/// The queues the workers serve and the handler of each job kind, set up at startup
+#[derive(Default)]
+pub struct JobRegistry {
+    queues: HashMap<&'static str, usize>,
+    handlers: HashMap<&'static str, (&'static str, Arc<dyn ErasedJobHandler>)>,
+}
+
+impl JobRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs at most `concurrency` jobs of the queue at once on this replica
+    pub fn queue(mut self, name: &'static str, concurrency: usize) -> Self {
+        self.queues.insert(name, concurrency.max(1));
+        self
+    }
+
+    /// Handles the jobs of the handler's payload kind, on the payload's queue
+    pub fn register<H: JobHandler>(mut self, handler: H) -> Self {
+        let queue = <H::Payload as JobPayload>::QUEUE;
+        self.queues.entry(queue).or_insert(DEFAULT_CONCURRENCY);
+        self.handlers.insert(
+            <H::Payload as JobPayload>::KIND,
+            (queue, Arc::new(Typed(handler))),
+        );
+        self
+    }
+
+    /// Queues with at least one handler, with their concurrency
+    pub(super) fn queues(&self) -> Vec<(&'static str, usize)> {
+        self.queues
+            .iter()
+            .filter(|(name, _)| self.handlers.values().any(|(queue, _)| queue == *name))
+            .map(|(name, concurrency)| (*name, *concurrency))
+            .collect()
+    }
+
+    /// Kinds handled on the queue
+    pub(super) fn kinds(&self, queue: &str) -> Vec<&'static str> {
+        self.handlers
+            .iter()
+            .filter(|(_, (handler_queue, _))| *handler_queue == queue)
+            .map(|(kind, _)| *kind)
+            .collect()
+    }
+
+    pub(super) fn handler(&self, kind: &str) -> Option<Arc<dyn ErasedJobHandler>> {
+        self.handlers.get(kind).map(|(_, handler)| handler.clone())
+    }
+}
@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+
+use super::handler::{JobError, JobHandler};
+use crate::domain::{
+    entities::product_image::RemoveImageFilesJob,
+    gateways::{BlobStorage, BlobStorageError},
+};
+
+/// Removes the files of a deleted product image from storage
+pub struct RemoveImageFilesHandler<S: BlobStorage> {
+    storage: S,
+}
+
+impl<S: BlobStorage> RemoveImageFilesHandler<S> {
+    pub fn new(storage: S) -> Self {
+        Self { storage }
+    }
+}
+
+#[async_trait]
+impl<S: BlobStorage + 'static> JobHandler for RemoveImageFilesHandler<S> {
+    type Payload = RemoveImageFilesJob;
+
+    async fn handle(&self, payload: RemoveImageFilesJob) -> Result<(), JobError> {
+        for key in &payload.keys {
+            match self.storage.delete(key).await {
+                // Removed by an earlier attempt
+                Ok(()) | Err(BlobStorageError::NotFound) => {}
+                Err(e) => {
+                    return Err(JobError::Retry(format!(
+                        "Failed to remove image file {}: {}",
+                        key, e
+                    )))
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use chrono::Utc;
+use log::{error, info, warn};
+use sqlx::PgPool;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio_util::sync::CancellationToken;
+use tracing::instrument;
+
+use super::{handler::JobError, registry::JobRegistry};
+use crate::{
+    domain::{entities::job::Job, repositories::JobRepository},
+    infrastructure::{persistence::postgres::PostgresJobRepository, shutdown::Shutdown},
+};
+
+/// How often an idle queue is checked for due jobs
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// Longest a single attempt may run before it counts as failed
+const JOB_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+/// Slack on top of the timeout before another worker may take a running job over
+const LEASE_GRACE: Duration = Duration::from_secs(60);
+const INITIAL_BACKOFF_SECS: i64 = 10;
+const MAX_BACKOFF_SECS: i64 = 60 * 60;
+
+/// Starts a worker loop per registered queue, each running up to the queue's
+/// concurrency of jobs at once. On shutdown the workers stop claiming jobs and wait
+/// for the running ones
+pub fn spawn_job_workers(pool: PgPool, registry: JobRegistry, shutdown: &Shutdown) {
+    let registry = Arc::new(registry);
+    let repository = Arc::new(PostgresJobRepository::new(pool));
+
+    for (queue, concurrency) in registry.queues() {
+        info!(
+            "Starting job worker for queue {} with concurrency {}",
+            queue, concurrency
+        );
+        shutdown.spawn(run_queue(
+            queue,
+            concurrency,
+            registry.clone(),
+            repository.clone(),
+            shutdown.stopping(),
+        ));
+    }
+}
+
+async fn run_queue(
+    queue: &'static str,
+    concurrency: usize,
+    registry: Arc<JobRegistry>,
+    repository: Arc<PostgresJobRepository>,
+    stopping: CancellationToken,
+) {
+    let kinds = registry.kinds(queue);
+    let semaphore = Arc::new(Semaphore::new(concurrency));
+    let lease = chrono::Duration::from_std(JOB_TIMEOUT + LEASE_GRACE).unwrap_or_default();
+
+    loop {
+        // Wait for a free slot, then claim as many jobs as there are free slots
+        let permit = tokio::select! {
+            permit = semaphore.clone().acquire_owned() => permit,
+            _ = stopping.cancelled() => break,
+        };
+        let Ok(permit) = permit else {
+            break;
+        };
+        let free = semaphore.available_permits() + 1;
+
+        let jobs = match repository
+            .claim(queue, &kinds, free as i64, Utc::now() + lease)
+            .await
+        {
+            Ok(jobs) => jobs,
+            Err(e) => {
+                error!("Error claiming jobs on queue {}: {:?}", queue, e);
+                Vec::new()
+            }
+        };
+
+        if jobs.is_empty() {
+            drop(permit);
+            tokio::select! {
+                _ = actix_web::rt::time::sleep(POLL_INTERVAL) => continue,
+                _ = stopping.cancelled() => break,
+            }
+        }
+
+        let mut permit = Some(permit);
+        for job in jobs {
+            let permit = match permit.take() {
+                Some(permit) => permit,
+                None => match semaphore.clone().acquire_owned().await {
+                    Ok(permit) => permit,
+                    Err(_) => break,
+                },
+            };
+            let registry = registry.clone();
+            let repository = repository.clone();
+
+            actix_web::rt::spawn(async move {
+                run_job(job, &registry, repository.as_ref()).await;
+                drop(permit);
+            });
+        }
+    }
+
+    // Every permit is back once the running jobs finished
+    let _ = semaphore.acquire_many(concurrency as u32).await;
+    info!("Job worker for queue {} stopped", queue);
+}
+
+/// Each run starts its own trace
+#[instrument(
+    name = "job",
+    skip_all,
+    fields(
+        otel.name = %format!("job {}", job.kind),
+        otel.kind = "consumer",
+        job.id = %job.id,
+        job.queue = %job.queue,
+        job.attempt = job.attempts,
+    )
+)]
+async fn run_job(job: Job, registry: &JobRegistry, repository: &impl JobRepository) {
+    let result = match registry.handler(&job.kind) {
+        Some(handler) => {
+            match actix_web::rt::time::timeout(JOB_TIMEOUT, handler.run(job.payload.clone())).await
+            {
+                Ok(result) => result,
+                Err(_) => Err(JobError::Retry(format!(
+                    "Timed out after {}s",
+                    JOB_TIMEOUT.as_secs()
+                ))),
+            }
+        }
+        None => Err(JobError::Permanent(format!(
+            "No handler for job kind {}",
+            job.kind
+        ))),
+    };
+
+    let outcome = match result {
+        Ok(()) => repository.complete(job.id).await,
+        Err(JobError::Retry(message)) if job.attempts < job.max_attempts => {
+            let retry_at = Utc::now() + backoff(job.attempts);
+            warn!(
+                "Job {} ({}) failed attempt {}, retrying at {}: {}",
+                job.id, job.kind, job.attempts, retry_at, message
+            );
+            repository.fail(job.id, &message, Some(retry_at)).await
+        }
+        Err(e) => {
+            error!(
+                "Job {} ({}) dead-lettered after attempt {}: {}",
+                job.id, job.kind, job.attempts, e
+            );
+            repository.fail(job.id, &e.to_string(), None).await
+        }
+    };
+
+    if let Err(e) = outcome {
+        error!("Error recording the outcome of job {}: {:?}", job.id, e);
+    }
+}
+
+/// 10s after the first attempt, doubling up to an hour
+fn backoff(attempts: i32) -> chrono::Duration {
+    let exponent = attempts.clamp(1, 16) as u32 - 1;
+    chrono::Duration::seconds((INITIAL_BACKOFF_SECS << exponent).min(MAX_BACKOFF_SECS))
+}
@@ -0,0 +1,178 @@
+use prometheus::{
+    Encoder, Histogram, HistogramOpts, HistogramTimer, HistogramVec, IntCounter, IntCounterVec,
+    IntGauge, IntGaugeVec, Opts, Registry, TextEncoder,
+};
+use sqlx::PgPool;
+use std::sync::LazyLock;
+use std::time::Duration;
+
+static METRICS: LazyLock<Metrics> = LazyLock::new(Metrics::new);
+
+/// The process-wide metrics, exposed in the Prometheus text format on `/metrics`
+pub fn metrics() -> &'static Metrics {
+    &METRICS
+}
+
+pub struct Metrics {
+    registry: Registry,
+    http_requests: IntCounterVec,
+    http_request_duration: HistogramVec,
+    pool_connections: IntGaugeVec,
+    pool_max_connections: IntGauge,
+    pool_acquire_wait: Histogram,
+    query_duration: HistogramVec,
+    products_created: IntCounter,
+    users_registered: IntCounter,
+    stock_adjustments: IntCounterVec,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        let registry = Registry::new();
+
+        let http_requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "HTTP requests handled"),
+            &["method", "route", "status"],
+        )
+        .unwrap();
+        let http_request_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "Time taken to handle HTTP requests",
+            ),
+            &["method", "route", "status"],
+        )
+        .unwrap();
+        let pool_connections = IntGaugeVec::new(
+            Opts::new("db_pool_connections", "Open database connections by state"),
+            &["state"],
+        )
+        .unwrap();
+        let pool_max_connections = IntGauge::new(
+            "db_pool_max_connections",
+            "Maximum number of database connections",
+        )
+        .unwrap();
+        let pool_acquire_wait = Histogram::with_opts(HistogramOpts::new(
+            "db_pool_acquire_wait_seconds",
+            "Time repositories waited for a database connection",
+        ))
+        .unwrap();
+        let query_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "repository_query_duration_seconds",
+                "Time taken by repository methods",
+            ),
+            &["repository", "method"],
+        )
+        .unwrap();
+        let products_created =
+            IntCounter::new("products_created_total", "Products created").unwrap();
+        let users_registered =
+            IntCounter::new("users_registered_total", "Users registered").unwrap();
+        let stock_adjustments = IntCounterVec::new(
+            Opts::new(
+                "stock_adjustments_total",
+                "Changes to the stock of a product",
+            ),
+            &["source"],
+        )
+        .unwrap();
+
+        registry.register(Box::new(http_requests.clone())).unwrap();
+        registry
+            .register(Box::new(http_request_duration.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(pool_connections.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(pool_max_connections.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(pool_acquire_wait.clone()))
+            .unwrap();
+        registry.register(Box::new(query_duration.clone())).unwrap();
+        registry
+            .register(Box::new(products_created.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(users_registered.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(stock_adjustments.clone()))
+            .unwrap();
+
+        Self {
+            registry,
+            http_requests,
+            http_request_duration,
+            pool_connections,
+            pool_max_connections,
+            pool_acquire_wait,
+            query_duration,
+            products_created,
+            users_registered,
+            stock_adjustments,
+        }
+    }
+
+    /// Records a handled request, `route` is the matched route template so ids don't
+    /// create a series each
+    pub fn observe_request(&self, method: &str, route: &str, status: u16, elapsed: Duration) {
+        let status = status.to_string();
+        let labels = [method, route, status.as_str()];
+        self.http_requests.with_label_values(&labels).inc();
+        self.http_request_duration
+            .with_label_values(&labels)
+            .observe(elapsed.as_secs_f64());
+    }
+
+    /// Samples the pool's connection counts, without taking a connection from it
+    pub fn observe_pool(&self, pool: &PgPool) {
+        let size = i64::from(pool.size());
+        let idle = pool.num_idle() as i64;
+        self.pool_connections.with_label_values(&["idle"]).set(idle);
+        self.pool_connections
+            .with_label_values(&["in_use"])
+            .set(size - idle);
+        self.pool_max_connections
+            .set(i64::from(pool.options().get_max_connections()));
+    }
+
+    /// Times waiting for a database connection until the returned timer is dropped
+    pub fn time_acquire(&self) -> HistogramTimer {
+        self.pool_acquire_wait.start_timer()
+    }
+
+    /// Times a repository method until the returned timer is dropped
+    pub fn time_query(&self, repository: &str, method: &str) -> HistogramTimer {
+        self.query_duration
+            .with_label_values(&[repository, method])
+            .start_timer()
+    }
+
+    pub fn products_created(&self, count: usize) {
+        self.products_created.inc_by(count as u64);
+    }
+
+    pub fn user_registered(&self) {
+        self.users_registered.inc();
+    }
+
+    /// Records stock changes of `count` products, `source` is what changed them
+    pub fn stock_adjusted(&self, source: &str, count: usize) {
+        self.stock_adjustments
+            .with_label_values(&[source])
+            .inc_by(count as u64);
+    }
+
+    /// Renders every metric in the Prometheus text format
+    pub fn encode(&self) -> String {
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .expect("Metrics are valid");
+        String::from_utf8(buffer).expect("Metrics are UTF-8")
+    }
+}
@@ -1,2 +1,13 @@
 pub mod database;
+pub mod events;
+pub mod health;
+pub mod jobs;
+pub mod metrics;
+pub mod payments;
 pub mod persistence;
+pub mod scheduler;
+pub mod shutdown;
+pub mod storage;
+pub mod tasks;
+pub mod telemetry;
+pub mod webhooks;
@@ -0,0 +1,224 @@
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use sha2::Sha256;
+use std::str::FromStr;
+
+use crate::domain::{
+    entities::payment::PaymentStatus,
+    gateways::{
+        payment_gateway::{PaymentIntent, PaymentIntentRequest, PaymentWebhookEvent},
+        PaymentGateway, PaymentGatewayError,
+    },
+};
+
+/// Outcome the fake provider reports for every new payment intent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FakeOutcome {
+    /// Intents are authorized and can be captured
+    Succeed,
+    /// Intents are declined
+    Fail,
+    /// Intents wait for a 3-D Secure challenge, completed via webhook
+    RequiresAction,
+}
+
+impl FromStr for FakeOutcome {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "succeed" => Ok(Self::Succeed),
+            "fail" => Ok(Self::Fail),
+            "requires_action" => Ok(Self::RequiresAction),
+            other => Err(format!("unknown fake payment outcome: {other}")),
+        }
+    }
+}
+
+/// Webhook body sent by the fake provider
+#[derive(Debug, Deserialize)]
+struct FakeWebhookPayload {
+    id: String,
+    #[serde(rename = "type")]
+    event_type: String,
+    intent_id: String,
+}
+
+/// Deterministic in-process payment provider for development and tests
+pub struct FakePaymentGateway {
+    outcome: FakeOutcome,
+    webhook_secret: String,
+}
+
+impl FakePaymentGateway {
+    pub fn new(outcome: FakeOutcome, webhook_secret: impl Into<String>) -> Self {
+        Self {
+            outcome,
+            webhook_secret: webhook_secret.into(),
+        }
+    }
+
+    /// Signs a webhook body the way the provider would
+    #[cfg(test)]
+    pub fn sign(&self, payload: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.webhook_secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(payload);
+        hex::encode(mac.finalize().into_bytes())
+    }
+}
+
+#[async_trait]
+impl PaymentGateway for FakePaymentGateway {
+    fn provider(&self) -> &'static str {
+        "fake"
+    }
+
+    async fn create_intent(
+        &self,
+        request: PaymentIntentRequest,
+    ) -> Result<PaymentIntent, PaymentGatewayError> {
+        // Providers refuse to charge nothing, like for an order fully discounted
+        if request.amount <= Decimal::ZERO {
+            return Err(PaymentGatewayError::Declined(
+                "amount_too_small".to_string(),
+            ));
+        }
+        if request.currency.len() != 3 || !request.currency.bytes().all(|b| b.is_ascii_uppercase())
+        {
+            return Err(PaymentGatewayError::Declined(
+                "invalid_currency".to_string(),
+            ));
+        }
+
+        let status = match self.outcome {
+            FakeOutcome::Succeed => PaymentStatus::RequiresCapture,
+            FakeOutcome::Fail => PaymentStatus::Failed,
+            FakeOutcome::RequiresAction => PaymentStatus::RequiresAction,
+        };
+
+        Ok(PaymentIntent {
+            intent_id: format!("fake_pi_{}", request.payment_id.simple()),
+            status,
+        })
+    }
+
+    async fn capture(&self, intent_id: &str) -> Result<PaymentIntent, PaymentGatewayError> {
+        if self.outcome == FakeOutcome::Fail {
+            return Err(PaymentGatewayError::Declined("card_declined".to_string()));
+        }
+
+        Ok(PaymentIntent {
+            intent_id: intent_id.to_string(),
+            status: PaymentStatus::Succeeded,
+        })
+    }
+
+    async fn refund(
+        &self,
+        intent_id: &str,
+        _amount: Decimal,
+    ) -> Result<PaymentIntent, PaymentGatewayError> {
+        Ok(PaymentIntent {
+            intent_id: intent_id.to_string(),
+            status: PaymentStatus::Refunded,
+        })
+    }
+
+    fn parse_webhook(
+        &self,
+        payload: &[u8],
+        signature: &str,
+    ) -> Result<PaymentWebhookEvent, PaymentGatewayError> {
+        let signature =
+            hex::decode(signature).map_err(|_| PaymentGatewayError::InvalidSignature)?;
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.webhook_secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(payload);
+        mac.verify_slice(&signature)
+            .map_err(|_| PaymentGatewayError::InvalidSignature)?;
+
+        let payload: FakeWebhookPayload = serde_json::from_slice(payload)
+            .map_err(|e| PaymentGatewayError::InvalidPayload(e.to_string()))?;
+
+        let status = match payload.event_type.as_str() {
+            "payment_intent.requires_capture" => PaymentStatus::RequiresCapture,
+            "payment_intent.succeeded" => PaymentStatus::Succeeded,
+            "payment_intent.payment_failed" => PaymentStatus::Failed,
+            "charge.refunded" => PaymentStatus::Refunded,
+            other => {
+                return Err(PaymentGatewayError::InvalidPayload(format!(
+                    "unsupported event type: {other}"
+                )))
+            }
+        };
+
+        Ok(PaymentWebhookEvent {
+            event_id: payload.id,
+            intent_id: payload.intent_id,
+            status,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn gateway() -> FakePaymentGateway {
+        FakePaymentGateway::new(FakeOutcome::Succeed, "whsec_fake")
+    }
+
+    fn webhook(event_type: &str) -> Vec<u8> {
+        json!({ "id": "evt_1", "type": event_type, "intent_id": "fake_pi_1" })
+            .to_string()
+            .into_bytes()
+    }
+
+    #[test]
+    fn parses_signed_webhooks() {
+        let gateway = gateway();
+        let payload = webhook("payment_intent.succeeded");
+
+        let event = gateway
+            .parse_webhook(&payload, &gateway.sign(&payload))
+            .unwrap();
+        assert_eq!(event.event_id, "evt_1");
+        assert_eq!(event.intent_id, "fake_pi_1");
+        assert_eq!(event.status, PaymentStatus::Succeeded);
+    }
+
+    #[test]
+    fn rejects_bad_signatures() {
+        let gateway = gateway();
+        let payload = webhook("payment_intent.succeeded");
+        let other_secret = FakePaymentGateway::new(FakeOutcome::Succeed, "whsec_other");
+        let tampered = webhook("charge.refunded");
+
+        for signature in [
+            other_secret.sign(&payload),
+            gateway.sign(&tampered),
+            "not hex".to_string(),
+            String::new(),
+        ] {
+            assert!(matches!(
+                gateway.parse_webhook(&payload, &signature),
+                Err(PaymentGatewayError::InvalidSignature)
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_event_types() {
+        let gateway = gateway();
+        let payload = webhook("customer.created");
+
+        assert!(matches!(
+            gateway.parse_webhook(&payload, &gateway.sign(&payload)),
+            Err(PaymentGatewayError::InvalidPayload(_))
+        ));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod fake_gateway;
+
+pub use fake_gateway::{FakeOutcome, FakePaymentGateway};
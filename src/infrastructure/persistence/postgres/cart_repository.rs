@@ -0,0 +1,239 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use rust_decimal::Decimal;
+use sqlx::{Connection, PgPool};
+use uuid::Uuid;
+
+use super::database::acquire;
+use crate::domain::{
+    entities::cart::{Cart, CartItem},
+    repositories::{CartRepository, RepositoryError},
+};
+use crate::infrastructure::metrics::metrics;
+use tracing::instrument;
+
+pub struct PostgresCartRepository {
+    pool: PgPool,
+}
+
+impl PostgresCartRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CartRepository for PostgresCartRepository {
+    #[instrument(name = "CartRepository::find_by_token", skip_all)]
+    async fn find_by_token(&self, token: Uuid) -> Result<Option<Cart>, RepositoryError> {
+        let _timer = metrics().time_query("cart", "find_by_token");
+        let cart = sqlx::query_as::<_, Cart>("SELECT * FROM carts WHERE token = $1")
+            .bind(token)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(cart)
+    }
+
+    #[instrument(name = "CartRepository::find_by_user", skip_all)]
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Option<Cart>, RepositoryError> {
+        let _timer = metrics().time_query("cart", "find_by_user");
+        let cart = sqlx::query_as::<_, Cart>("SELECT * FROM carts WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(cart)
+    }
+
+    #[instrument(name = "CartRepository::create", skip_all)]
+    async fn create(&self, user_id: Option<Uuid>) -> Result<Cart, RepositoryError> {
+        let _timer = metrics().time_query("cart", "create");
+        let now = Utc::now();
+
+        let cart = sqlx::query_as::<_, Cart>(
+            r#"
+            INSERT INTO carts (id, user_id, token, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(user_id)
+        .bind(Uuid::new_v4())
+        .bind(now)
+        .bind(now)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(ref e) if e.constraint() == Some("carts_user_id_key") => {
+                RepositoryError::DuplicateEntry
+            }
+            sqlx::Error::Database(ref e) if e.constraint() == Some("carts_user_id_fkey") => {
+                RepositoryError::NotFound
+            }
+            _ => RepositoryError::DatabaseError(e.to_string()),
+        })?;
+
+        Ok(cart)
+    }
+
+    #[instrument(name = "CartRepository::list_items", skip_all)]
+    async fn list_items(&self, cart_id: Uuid) -> Result<Vec<CartItem>, RepositoryError> {
+        let _timer = metrics().time_query("cart", "list_items");
+        let items = sqlx::query_as::<_, CartItem>(
+            "SELECT * FROM cart_items WHERE cart_id = $1 ORDER BY created_at",
+        )
+        .bind(cart_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(items)
+    }
+
+    #[instrument(name = "CartRepository::add_item", skip_all)]
+    async fn add_item(
+        &self,
+        cart_id: Uuid,
+        product_id: Uuid,
+        quantity: i32,
+        unit_price: Decimal,
+    ) -> Result<CartItem, RepositoryError> {
+        let _timer = metrics().time_query("cart", "add_item");
+        let now = Utc::now();
+
+        let item = sqlx::query_as::<_, CartItem>(
+            r#"
+            INSERT INTO cart_items (cart_id, product_id, quantity, unit_price, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (cart_id, product_id) DO UPDATE
+            SET
+                quantity = cart_items.quantity + EXCLUDED.quantity,
+                unit_price = EXCLUDED.unit_price,
+                updated_at = EXCLUDED.updated_at
+            RETURNING *
+            "#,
+        )
+        .bind(cart_id)
+        .bind(product_id)
+        .bind(quantity)
+        .bind(unit_price)
+        .bind(now)
+        .bind(now)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(item)
+    }
+
+    #[instrument(name = "CartRepository::update_item", skip_all)]
+    async fn update_item(
+        &self,
+        cart_id: Uuid,
+        product_id: Uuid,
+        quantity: i32,
+    ) -> Result<CartItem, RepositoryError> {
+        let _timer = metrics().time_query("cart", "update_item");
+        let item = sqlx::query_as::<_, CartItem>(
+            r#"
+            UPDATE cart_items
+            SET quantity = $1, updated_at = $2
+            WHERE cart_id = $3 AND product_id = $4
+            RETURNING *
+            "#,
+        )
+        .bind(quantity)
+        .bind(Utc::now())
+        .bind(cart_id)
+        .bind(product_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(item)
+    }
+
+    #[instrument(name = "CartRepository::update_item_price", skip_all)]
+    async fn update_item_price(
+        &self,
+        cart_id: Uuid,
+        product_id: Uuid,
+        unit_price: Decimal,
+    ) -> Result<(), RepositoryError> {
+        let _timer = metrics().time_query("cart", "update_item_price");
+        sqlx::query("UPDATE cart_items SET unit_price = $1 WHERE cart_id = $2 AND product_id = $3")
+            .bind(unit_price)
+            .bind(cart_id)
+            .bind(product_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "CartRepository::remove_item", skip_all)]
+    async fn remove_item(&self, cart_id: Uuid, product_id: Uuid) -> Result<(), RepositoryError> {
+        let _timer = metrics().time_query("cart", "remove_item");
+        let result = sqlx::query("DELETE FROM cart_items WHERE cart_id = $1 AND product_id = $2")
+            .bind(cart_id)
+            .bind(product_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    #[instrument(name = "CartRepository::merge", skip_all)]
+    async fn merge(&self, source_id: Uuid, target_id: Uuid) -> Result<(), RepositoryError> {
+        let _timer = metrics().time_query("cart", "merge");
+        let mut conn = acquire(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO cart_items (cart_id, product_id, quantity, unit_price, created_at, updated_at)
+            SELECT $1, product_id, quantity, unit_price, created_at, $3
+            FROM cart_items
+            WHERE cart_id = $2
+            ON CONFLICT (cart_id, product_id) DO UPDATE
+            SET
+                quantity = cart_items.quantity + EXCLUDED.quantity,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(target_id)
+        .bind(source_id)
+        .bind(Utc::now())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        sqlx::query("DELETE FROM carts WHERE id = $1")
+            .bind(source_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,306 @@
+use crate::infrastructure::metrics::metrics;
+use sqlx::{pool::PoolConnection, PgConnection, PgPool, Postgres, Transaction};
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, OwnedMappedMutexGuard};
+
+/// How long a call waits for a shared transaction another call is using
+const SHARED_ACQUIRE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Where a repository runs its statements: connections of the pool, or a transaction
+/// shared by several operations, like the writes of an atomic batch.
+///
+/// Repositories acquire one connection per call and hand it to their helpers.
+/// Transactions they open on it become savepoints inside a shared transaction, so a
+/// failed call is rolled back on its own without aborting the others.
+#[derive(Clone)]
+pub struct Database {
+    pool: PgPool,
+    shared: Option<Arc<Mutex<Option<Transaction<'static, Postgres>>>>>,
+}
+
+impl From<PgPool> for Database {
+    fn from(pool: PgPool) -> Self {
+        Self { pool, shared: None }
+    }
+}
+
+impl Database {
+    /// The pool, for streams that read committed rows row by row
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+
+    /// A connection for the statements of one call. Calls on a shared transaction run
+    /// one after another
+    pub async fn acquire(&self) -> Result<DatabaseConnection, sqlx::Error> {
+        let Some(shared) = &self.shared else {
+            return Ok(DatabaseConnection::Pooled(Box::new(
+                acquire(&self.pool).await?,
+            )));
+        };
+
+        let guard = tokio::time::timeout(SHARED_ACQUIRE_TIMEOUT, shared.clone().lock_owned())
+            .await
+            .map_err(|_| sqlx::Error::PoolTimedOut)?;
+        tokio::sync::OwnedMutexGuard::try_map(guard, |transaction| transaction.as_deref_mut())
+            .map(DatabaseConnection::Shared)
+            // The transaction already ended
+            .map_err(|_| sqlx::Error::PoolClosed)
+    }
+}
+
+/// A connection of the pool, recording how long the caller waited for it
+pub async fn acquire(pool: &PgPool) -> Result<PoolConnection<Postgres>, sqlx::Error> {
+    let _timer = metrics().time_acquire();
+    pool.acquire().await
+}
+
+/// A connection of the pool or the connection of a shared transaction
+pub enum DatabaseConnection {
+    Pooled(Box<PoolConnection<Postgres>>),
+    Shared(OwnedMappedMutexGuard<Option<Transaction<'static, Postgres>>, PgConnection>),
+}
+
+impl Deref for DatabaseConnection {
+    type Target = PgConnection;
+
+    fn deref(&self) -> &PgConnection {
+        match self {
+            Self::Pooled(conn) => conn,
+            Self::Shared(conn) => conn,
+        }
+    }
+}
+
+impl DerefMut for DatabaseConnection {
+    fn deref_mut(&mut self) -> &mut PgConnection {
+        match self {
+            Self::Pooled(conn) => conn,
+            Self::Shared(conn) => conn,
+        }
+    }
+}
+
+/// A transaction on a connection of the pool that repositories built from `database`
+/// write into, until it is committed or rolled back
+pub struct SharedTransaction {
+    database: Database,
+}
+
+impl SharedTransaction {
+    pub async fn begin(pool: &PgPool) -> Result<Self, sqlx::Error> {
+        let transaction = {
+            let _timer = metrics().time_acquire();
+            pool.begin().await?
+        };
+
+        Ok(Self {
+            database: Database {
+                pool: pool.clone(),
+                shared: Some(Arc::new(Mutex::new(Some(transaction)))),
+            },
+        })
+    }
+
+    pub fn database(&self) -> Database {
+        self.database.clone()
+    }
+
+    /// Commits the transaction, rolling back instead when a statement failed inside it
+    pub async fn commit(self) -> Result<(), sqlx::Error> {
+        let mut transaction = self.take().await?;
+
+        // Postgres silently turns COMMIT into ROLLBACK for an aborted transaction
+        if let Err(e) = sqlx::query("SELECT 1").execute(&mut *transaction).await {
+            transaction.rollback().await?;
+            return Err(e);
+        }
+        transaction.commit().await
+    }
+
+    pub async fn rollback(self) -> Result<(), sqlx::Error> {
+        self.take().await?.rollback().await
+    }
+
+    /// Ends sharing, repositories still holding the database fail from now on
+    async fn take(self) -> Result<Transaction<'static, Postgres>, sqlx::Error> {
+        let shared = self.database.shared.expect("a shared transaction has one");
+        let transaction = shared.lock().await.take();
+        transaction.ok_or(sqlx::Error::PoolClosed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        entities::{
+            domain_event::DomainEvent,
+            user::{CreateUserDto, UpdateUserDto, User},
+        },
+        repositories::{RepositoryError, UserRepository},
+    };
+    use crate::infrastructure::persistence::postgres::PostgresUserRepository;
+    use sqlx::{postgres::PgPoolOptions, Connection};
+    use uuid::Uuid;
+
+    /// A pool of a single connection, so anything reaching past the shared
+    /// transaction times out
+    async fn single_connection_pool() -> PgPool {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        PgPoolOptions::new()
+            .max_connections(1)
+            .acquire_timeout(Duration::from_millis(500))
+            .connect(&url)
+            .await
+            .expect("Database is not reachable")
+    }
+
+    fn new_user() -> CreateUserDto {
+        let name = Uuid::new_v4().simple().to_string();
+        CreateUserDto {
+            email: format!("{name}@example.com"),
+            username: name,
+            password: "secret".to_string(),
+        }
+    }
+
+    async fn user_exists(pool: &PgPool, id: Uuid) -> bool {
+        sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM users WHERE id = $1)")
+            .bind(id)
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Postgres at DATABASE_URL"]
+    async fn repositories_share_the_transaction_on_its_connection() {
+        let pool = single_connection_pool().await;
+        let transaction = SharedTransaction::begin(&pool).await.unwrap();
+        let users = PostgresUserRepository::new(transaction.database());
+
+        let user = users
+            .create(new_user(), |user: &User| {
+                DomainEvent::UserCreated(user.clone())
+            })
+            .await
+            .unwrap();
+        // Reads see the uncommitted row, without a second connection
+        let renamed = users
+            .update(
+                user.id,
+                UpdateUserDto {
+                    email: None,
+                    username: Some(format!("{}-renamed", user.username)),
+                    password: None,
+                },
+                |user: &User| DomainEvent::UserUpdated(user.clone()),
+            )
+            .await
+            .unwrap();
+        assert!(renamed.username.ends_with("-renamed"));
+        assert!(matches!(
+            Database::from(pool.clone()).acquire().await,
+            Err(sqlx::Error::PoolTimedOut)
+        ));
+
+        transaction.rollback().await.unwrap();
+        assert!(!user_exists(&pool, user.id).await);
+        assert!(matches!(
+            users.find_by_id(user.id).await,
+            Err(RepositoryError::DatabaseError(_))
+        ));
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Postgres at DATABASE_URL"]
+    async fn a_failed_call_is_rolled_back_alone() {
+        let pool = single_connection_pool().await;
+        let transaction = SharedTransaction::begin(&pool).await.unwrap();
+        let users = PostgresUserRepository::new(transaction.database());
+        let user = new_user();
+        let email = user.email.clone();
+
+        let created = users
+            .create(user, |user: &User| DomainEvent::UserCreated(user.clone()))
+            .await
+            .unwrap();
+        let duplicate = CreateUserDto {
+            email,
+            ..new_user()
+        };
+        assert!(matches!(
+            users
+                .create(duplicate, |user: &User| DomainEvent::UserCreated(
+                    user.clone()
+                ))
+                .await,
+            Err(RepositoryError::DuplicateEntry)
+        ));
+
+        transaction.commit().await.unwrap();
+        assert!(user_exists(&pool, created.id).await);
+        sqlx::query("DELETE FROM users WHERE id = $1")
+            .bind(created.id)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Postgres at DATABASE_URL"]
+    async fn does_not_commit_an_aborted_transaction() {
+        let pool = single_connection_pool().await;
+        let transaction = SharedTransaction::begin(&pool).await.unwrap();
+        let id = Uuid::new_v4();
+
+        let mut conn = transaction.database().acquire().await.unwrap();
+        sqlx::query("INSERT INTO users (id, email, username, password_hash, created_at, updated_at) VALUES ($1, $2, 'aborted', 'x', now(), now())")
+            .bind(id)
+            .bind(format!("{}@example.com", id.simple()))
+            .execute(&mut *conn)
+            .await
+            .unwrap();
+        assert!(sqlx::query("SELECT 1 / 0")
+            .execute(&mut *conn)
+            .await
+            .is_err());
+        drop(conn);
+
+        assert!(transaction.commit().await.is_err());
+        assert!(!user_exists(&pool, id).await);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Postgres at DATABASE_URL"]
+    async fn nested_transactions_become_savepoints() {
+        let pool = single_connection_pool().await;
+        let transaction = SharedTransaction::begin(&pool).await.unwrap();
+        let database = transaction.database();
+
+        let mut conn = database.acquire().await.unwrap();
+        let mut nested = conn.begin().await.unwrap();
+        sqlx::query("CREATE TEMPORARY TABLE shared_transaction_test (id INT)")
+            .execute(&mut *nested)
+            .await
+            .unwrap();
+        nested.commit().await.unwrap();
+        let mut nested = conn.begin().await.unwrap();
+        sqlx::query("INSERT INTO shared_transaction_test VALUES (1)")
+            .execute(&mut *nested)
+            .await
+            .unwrap();
+        nested.rollback().await.unwrap();
+
+        let ids: Vec<i32> = sqlx::query_scalar("SELECT id FROM shared_transaction_test")
+            .fetch_all(&mut *conn)
+            .await
+            .unwrap();
+        assert!(ids.is_empty());
+        drop(conn);
+        transaction.rollback().await.unwrap();
+    }
+}
@@ -0,0 +1,99 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+
+use crate::domain::{
+    entities::exchange_rate::ExchangeRate,
+    repositories::{ExchangeRateRepository, RepositoryError},
+};
+use crate::infrastructure::metrics::metrics;
+use tracing::instrument;
+
+pub struct PostgresExchangeRateRepository {
+    pool: PgPool,
+}
+
+impl PostgresExchangeRateRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ExchangeRateRepository for PostgresExchangeRateRepository {
+    #[instrument(name = "ExchangeRateRepository::list", skip_all)]
+    async fn list(&self) -> Result<Vec<ExchangeRate>, RepositoryError> {
+        let _timer = metrics().time_query("exchange_rate", "list");
+        let rates = sqlx::query_as::<_, ExchangeRate>(
+            "SELECT * FROM exchange_rates ORDER BY base_currency, quote_currency",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rates)
+    }
+
+    #[instrument(name = "ExchangeRateRepository::find", skip_all)]
+    async fn find(&self, base: &str, quote: &str) -> Result<Option<ExchangeRate>, RepositoryError> {
+        let _timer = metrics().time_query("exchange_rate", "find");
+        let rate = sqlx::query_as::<_, ExchangeRate>(
+            "SELECT * FROM exchange_rates WHERE base_currency = $1 AND quote_currency = $2",
+        )
+        .bind(base)
+        .bind(quote)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rate)
+    }
+
+    #[instrument(name = "ExchangeRateRepository::set", skip_all)]
+    async fn set(
+        &self,
+        base: &str,
+        quote: &str,
+        rate: Decimal,
+    ) -> Result<ExchangeRate, RepositoryError> {
+        let _timer = metrics().time_query("exchange_rate", "set");
+        let rate = sqlx::query_as::<_, ExchangeRate>(
+            r#"
+            INSERT INTO exchange_rates (base_currency, quote_currency, rate, updated_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (base_currency, quote_currency) DO UPDATE
+            SET rate = EXCLUDED.rate, updated_at = EXCLUDED.updated_at
+            RETURNING *
+            "#,
+        )
+        .bind(base)
+        .bind(quote)
+        .bind(rate)
+        .bind(Utc::now())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rate)
+    }
+
+    #[instrument(name = "ExchangeRateRepository::delete", skip_all)]
+    async fn delete(&self, base: &str, quote: &str) -> Result<(), RepositoryError> {
+        let _timer = metrics().time_query("exchange_rate", "delete");
+        let result = sqlx::query(
+            "DELETE FROM exchange_rates WHERE base_currency = $1 AND quote_currency = $2",
+        )
+        .bind(base)
+        .bind(quote)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
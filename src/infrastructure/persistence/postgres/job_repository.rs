@@ -0,0 +1,262 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::job::{Job, JobFilter, JobStatus, NewJob},
+    repositories::{JobRepository, RepositoryError},
+};
+use crate::infrastructure::metrics::metrics;
+use tracing::instrument;
+
+/// Enqueues the job on the connection, and so in the transaction, of the write it
+/// follows up
+pub async fn insert_job(conn: &mut PgConnection, job: NewJob) -> Result<Job, sqlx::Error> {
+    sqlx::query_as::<_, Job>(
+        r#"
+        INSERT INTO jobs (queue, kind, payload, run_at, max_attempts)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING *
+        "#,
+    )
+    .bind(job.queue)
+    .bind(job.kind)
+    .bind(job.payload)
+    .bind(job.run_at)
+    .bind(job.max_attempts)
+    .fetch_one(conn)
+    .await
+}
+
+pub struct PostgresJobRepository {
+    pool: PgPool,
+}
+
+impl PostgresJobRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// `NotFound` for a missing job, otherwise `Conflict` naming its status
+    async fn refused(&self, id: Uuid, action: &str) -> RepositoryError {
+        match self.find_by_id(id).await {
+            Ok(Some(job)) => RepositoryError::Conflict(format!(
+                "Cannot {} a {} job",
+                action,
+                serde_json::to_value(job.status)
+                    .ok()
+                    .and_then(|status| status.as_str().map(str::to_string))
+                    .unwrap_or_default()
+            )),
+            Ok(None) => RepositoryError::NotFound,
+            Err(e) => e,
+        }
+    }
+}
+
+#[async_trait]
+impl JobRepository for PostgresJobRepository {
+    #[instrument(name = "JobRepository::claim", skip_all)]
+    async fn claim(
+        &self,
+        queue: &str,
+        kinds: &[&'static str],
+        limit: i64,
+        lease_until: DateTime<Utc>,
+    ) -> Result<Vec<Job>, RepositoryError> {
+        let _timer = metrics().time_query("job", "claim");
+        let kinds: Vec<&str> = kinds.to_vec();
+
+        // A worker died while running these, and they have no attempts left
+        sqlx::query(
+            r#"
+            UPDATE jobs
+            SET status = 'dead', locked_until = NULL, finished_at = CURRENT_TIMESTAMP,
+                last_error = 'Worker stopped while running the job'
+            WHERE queue = $1 AND kind = ANY($2)
+                AND status = 'running' AND locked_until < CURRENT_TIMESTAMP
+                AND attempts >= max_attempts
+            "#,
+        )
+        .bind(queue)
+        .bind(&kinds)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let mut jobs = sqlx::query_as::<_, Job>(
+            r#"
+            WITH due AS (
+                SELECT id FROM jobs
+                WHERE queue = $1 AND kind = ANY($2)
+                    AND (
+                        (status = 'queued' AND run_at <= CURRENT_TIMESTAMP)
+                        OR (status = 'running' AND locked_until < CURRENT_TIMESTAMP)
+                    )
+                ORDER BY run_at
+                LIMIT $3
+                FOR UPDATE SKIP LOCKED
+            )
+            UPDATE jobs j
+            SET status = 'running', attempts = j.attempts + 1, locked_until = $4
+            FROM due
+            WHERE j.id = due.id
+            RETURNING j.*
+            "#,
+        )
+        .bind(queue)
+        .bind(&kinds)
+        .bind(limit)
+        .bind(lease_until)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        jobs.sort_by_key(|job| job.run_at);
+        Ok(jobs)
+    }
+
+    #[instrument(name = "JobRepository::complete", skip_all)]
+    async fn complete(&self, id: Uuid) -> Result<(), RepositoryError> {
+        let _timer = metrics().time_query("job", "complete");
+        sqlx::query(
+            r#"
+            UPDATE jobs
+            SET status = 'succeeded', locked_until = NULL, last_error = NULL,
+                finished_at = CURRENT_TIMESTAMP
+            WHERE id = $1 AND status = 'running'
+            "#,
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "JobRepository::fail", skip_all)]
+    async fn fail(
+        &self,
+        id: Uuid,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), RepositoryError> {
+        let _timer = metrics().time_query("job", "fail");
+        sqlx::query(
+            r#"
+            UPDATE jobs
+            SET status = CASE WHEN $3::timestamptz IS NULL THEN 'dead' ELSE 'queued' END::job_status,
+                run_at = COALESCE($3, run_at),
+                locked_until = NULL,
+                last_error = $2,
+                finished_at = CASE WHEN $3::timestamptz IS NULL THEN CURRENT_TIMESTAMP END
+            WHERE id = $1 AND status = 'running'
+            "#,
+        )
+        .bind(id)
+        .bind(error)
+        .bind(retry_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "JobRepository::list", skip_all)]
+    async fn list(&self, filter: JobFilter) -> Result<Vec<Job>, RepositoryError> {
+        let _timer = metrics().time_query("job", "list");
+        let jobs = sqlx::query_as::<_, Job>(
+            r#"
+            SELECT * FROM jobs
+            WHERE ($1::job_status IS NULL OR status = $1)
+                AND ($2::varchar IS NULL OR queue = $2)
+                AND ($3::varchar IS NULL OR kind = $3)
+            ORDER BY created_at DESC
+            LIMIT $4
+            "#,
+        )
+        .bind(filter.status)
+        .bind(filter.queue)
+        .bind(filter.kind)
+        .bind(filter.limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(jobs)
+    }
+
+    #[instrument(name = "JobRepository::find_by_id", skip_all)]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Job>, RepositoryError> {
+        let _timer = metrics().time_query("job", "find_by_id");
+        let job = sqlx::query_as::<_, Job>("SELECT * FROM jobs WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(job)
+    }
+
+    #[instrument(name = "JobRepository::retry", skip_all)]
+    async fn retry(&self, id: Uuid) -> Result<Job, RepositoryError> {
+        let _timer = metrics().time_query("job", "retry");
+        let job = sqlx::query_as::<_, Job>(
+            r#"
+            UPDATE jobs
+            SET status = 'queued', attempts = 0, run_at = CURRENT_TIMESTAMP, finished_at = NULL
+            WHERE id = $1 AND status IN ('dead', 'cancelled')
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        match job {
+            Some(job) => Ok(job),
+            None => Err(self.refused(id, "retry").await),
+        }
+    }
+
+    #[instrument(name = "JobRepository::cancel", skip_all)]
+    async fn cancel(&self, id: Uuid) -> Result<Job, RepositoryError> {
+        let _timer = metrics().time_query("job", "cancel");
+        let job = sqlx::query_as::<_, Job>(
+            r#"
+            UPDATE jobs
+            SET status = $2, finished_at = CURRENT_TIMESTAMP
+            WHERE id = $1 AND status = 'queued'
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(JobStatus::Cancelled)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        match job {
+            Some(job) => Ok(job),
+            None => Err(self.refused(id, "cancel").await),
+        }
+    }
+
+    #[instrument(name = "JobRepository::prune_finished", skip_all)]
+    async fn prune_finished(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError> {
+        let _timer = metrics().time_query("job", "prune_finished");
+        let result = sqlx::query(
+            "DELETE FROM jobs WHERE status IN ('succeeded', 'cancelled') AND finished_at < $1",
+        )
+        .bind(before)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -1,5 +1,31 @@
+pub mod cart_repository;
+pub mod database;
+pub mod exchange_rate_repository;
+pub mod job_repository;
+pub mod order_repository;
+pub mod outbox_repository;
+pub mod payment_repository;
+pub mod product_event_repository;
+pub mod product_image_repository;
+pub mod product_price_repository;
 pub mod product_repository;
+pub mod promotion_repository;
+pub mod scheduled_task_repository;
 pub mod user_repository;
+pub mod webhook_repository;
 
+pub use cart_repository::PostgresCartRepository;
+pub use database::{Database, SharedTransaction};
+pub use exchange_rate_repository::PostgresExchangeRateRepository;
+pub use job_repository::PostgresJobRepository;
+pub use order_repository::PostgresOrderRepository;
+pub use outbox_repository::PostgresOutboxRepository;
+pub use payment_repository::PostgresPaymentRepository;
+pub use product_event_repository::PostgresProductEventRepository;
+pub use product_image_repository::PostgresProductImageRepository;
+pub use product_price_repository::PostgresProductPriceRepository;
 pub use product_repository::PostgresProductRepository;
+pub use promotion_repository::PostgresPromotionRepository;
+pub use scheduled_task_repository::PostgresScheduledTaskRepository;
 pub use user_repository::PostgresUserRepository;
+pub use webhook_repository::PostgresWebhookRepository;
@@ -0,0 +1,197 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::{Connection, PgPool};
+use uuid::Uuid;
+
+use super::{database::acquire, outbox_repository::record_events};
+use crate::domain::{
+    entities::{
+        cart::PricedCartItem,
+        domain_event::EventFactory,
+        order::{Order, OrderItem, OrderStatus},
+        product::Product,
+    },
+    repositories::{OrderRepository, RepositoryError},
+    services::promotion_engine::PromotionOutcome,
+};
+use crate::infrastructure::metrics::metrics;
+use tracing::instrument;
+
+pub struct PostgresOrderRepository {
+    pool: PgPool,
+}
+
+impl PostgresOrderRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl OrderRepository for PostgresOrderRepository {
+    #[instrument(name = "OrderRepository::find_by_id", skip_all)]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Order>, RepositoryError> {
+        let _timer = metrics().time_query("order", "find_by_id");
+        let order = sqlx::query_as::<_, Order>("SELECT * FROM orders WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(order)
+    }
+
+    #[instrument(name = "OrderRepository::list_items", skip_all)]
+    async fn list_items(&self, order_id: Uuid) -> Result<Vec<OrderItem>, RepositoryError> {
+        let _timer = metrics().time_query("order", "list_items");
+        let items = sqlx::query_as::<_, OrderItem>(
+            "SELECT * FROM order_items WHERE order_id = $1 ORDER BY product_name",
+        )
+        .bind(order_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(items)
+    }
+
+    #[instrument(name = "OrderRepository::create_from_cart", skip_all)]
+    async fn create_from_cart(
+        &self,
+        user_id: Uuid,
+        cart_id: Uuid,
+        items: &[PricedCartItem],
+        currency: &str,
+        pricing: &PromotionOutcome,
+        stock_event: EventFactory<Product>,
+    ) -> Result<Order, RepositoryError> {
+        let _timer = metrics().time_query("order", "create_from_cart");
+        let now = Utc::now();
+        let mut conn = acquire(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let order = sqlx::query_as::<_, Order>(
+            r#"
+            INSERT INTO orders (
+                id, user_id, status, discount, total, currency, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(user_id)
+        .bind(OrderStatus::placed(pricing.total))
+        .bind(pricing.discount)
+        .bind(pricing.total)
+        .bind(currency)
+        .bind(now)
+        .bind(now)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let mut events = Vec::with_capacity(items.len());
+        for item in items {
+            // Reserve stock, refusing to oversell
+            let reserved = sqlx::query_as::<_, Product>(
+                "UPDATE products SET stock = stock - $1 WHERE id = $2 AND stock >= $1 RETURNING *",
+            )
+            .bind(item.quantity)
+            .bind(item.product_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+            let Some(product) = reserved else {
+                return Err(RepositoryError::Conflict(format!(
+                    "Insufficient stock for {}",
+                    item.product_name
+                )));
+            };
+            events.push(stock_event(&product));
+
+            sqlx::query(
+                r#"
+                INSERT INTO order_items (order_id, product_id, product_name, quantity, unit_price)
+                VALUES ($1, $2, $3, $4, $5)
+                "#,
+            )
+            .bind(order.id)
+            .bind(item.product_id)
+            .bind(&item.product_name)
+            .bind(item.quantity)
+            .bind(item.unit_price)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        }
+
+        for promotion in &pricing.applied {
+            // Serialize redemptions of the promotion so usage limits hold
+            let limit: Option<i32> = sqlx::query_scalar(
+                "SELECT usage_limit_per_user FROM promotions WHERE id = $1 FOR UPDATE",
+            )
+            .bind(promotion.promotion_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+            .flatten();
+
+            if let Some(limit) = limit {
+                let used: i64 = sqlx::query_scalar(
+                    "SELECT COUNT(*) FROM promotion_redemptions WHERE promotion_id = $1 AND user_id = $2",
+                )
+                .bind(promotion.promotion_id)
+                .bind(user_id)
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+                if used >= i64::from(limit) {
+                    return Err(RepositoryError::Conflict(format!(
+                        "Promotion {} was already used",
+                        promotion.name
+                    )));
+                }
+            }
+
+            sqlx::query(
+                r#"
+                INSERT INTO promotion_redemptions (promotion_id, order_id, user_id, discount, created_at)
+                VALUES ($1, $2, $3, $4, $5)
+                "#,
+            )
+            .bind(promotion.promotion_id)
+            .bind(order.id)
+            .bind(user_id)
+            .bind(promotion.discount)
+            .bind(now)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        }
+
+        sqlx::query("DELETE FROM cart_items WHERE cart_id = $1")
+            .bind(cart_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        record_events(&mut tx, &events)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        metrics().stock_adjusted("checkout", items.len());
+        Ok(order)
+    }
+}
@@ -0,0 +1,143 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::domain_event::{DomainEvent, OutboxEvent},
+    repositories::{OutboxRepository, RepositoryError},
+};
+use crate::infrastructure::metrics::metrics;
+use tracing::instrument;
+
+/// Stores events in the outbox on the connection, and so in the transaction, of the
+/// write that emitted them
+pub async fn record_events(
+    conn: &mut PgConnection,
+    events: &[DomainEvent],
+) -> Result<(), sqlx::Error> {
+    if events.is_empty() {
+        return Ok(());
+    }
+
+    sqlx::query(
+        r#"
+        INSERT INTO outbox (id, event_type, aggregate_id, payload, occurred_at, available_at)
+        SELECT u.id, u.event_type, u.aggregate_id, u.payload, $5, $5
+        FROM UNNEST($1::uuid[], $2::varchar[], $3::uuid[], $4::jsonb[])
+            WITH ORDINALITY AS u(id, event_type, aggregate_id, payload, position)
+        ORDER BY u.position
+        "#,
+    )
+    .bind(events.iter().map(|_| Uuid::new_v4()).collect::<Vec<_>>())
+    .bind(
+        events
+            .iter()
+            .map(|event| event.event_type().to_string())
+            .collect::<Vec<_>>(),
+    )
+    .bind(
+        events
+            .iter()
+            .map(DomainEvent::aggregate_id)
+            .collect::<Vec<_>>(),
+    )
+    .bind(events.iter().map(DomainEvent::payload).collect::<Vec<_>>())
+    .bind(Utc::now())
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+pub struct PostgresOutboxRepository {
+    pool: PgPool,
+}
+
+impl PostgresOutboxRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for PostgresOutboxRepository {
+    #[instrument(name = "OutboxRepository::claim_pending", skip_all)]
+    async fn claim_pending(
+        &self,
+        limit: i64,
+        lease_until: DateTime<Utc>,
+    ) -> Result<Vec<OutboxEvent>, RepositoryError> {
+        let _timer = metrics().time_query("outbox", "claim_pending");
+        let mut events = sqlx::query_as::<_, OutboxEvent>(
+            r#"
+            WITH pending AS (
+                SELECT id FROM outbox
+                WHERE published_at IS NULL AND available_at <= CURRENT_TIMESTAMP
+                ORDER BY sequence
+                LIMIT $1
+                FOR UPDATE SKIP LOCKED
+            )
+            UPDATE outbox o
+            SET available_at = $2, attempts = o.attempts + 1
+            FROM pending
+            WHERE o.id = pending.id
+            RETURNING o.*
+            "#,
+        )
+        .bind(limit)
+        .bind(lease_until)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        // UPDATE ... RETURNING does not keep the order of the CTE
+        events.sort_by_key(|event| event.sequence);
+        Ok(events)
+    }
+
+    #[instrument(name = "OutboxRepository::mark_published", skip_all)]
+    async fn mark_published(&self, id: Uuid) -> Result<(), RepositoryError> {
+        let _timer = metrics().time_query("outbox", "mark_published");
+        sqlx::query(
+            "UPDATE outbox SET published_at = CURRENT_TIMESTAMP, last_error = NULL WHERE id = $1",
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "OutboxRepository::mark_failed", skip_all)]
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        error: &str,
+        retry_at: DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let _timer = metrics().time_query("outbox", "mark_failed");
+        sqlx::query("UPDATE outbox SET available_at = $1, last_error = $2 WHERE id = $3")
+            .bind(retry_at)
+            .bind(error)
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "OutboxRepository::prune_published", skip_all)]
+    async fn prune_published(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError> {
+        let _timer = metrics().time_query("outbox", "prune_published");
+        let result = sqlx::query("DELETE FROM outbox WHERE published_at < $1")
+            .bind(before)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected())
+    }
+}
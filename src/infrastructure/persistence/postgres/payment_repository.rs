@@ -0,0 +1,191 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::{Connection, PgPool};
+use uuid::Uuid;
+
+use super::database::acquire;
+use crate::domain::{
+    entities::{
+        order::OrderStatus,
+        payment::{CreatePaymentDto, Payment, PaymentStatus},
+    },
+    repositories::{PaymentRepository, RepositoryError},
+};
+use crate::infrastructure::metrics::metrics;
+use tracing::instrument;
+
+pub struct PostgresPaymentRepository {
+    pool: PgPool,
+}
+
+impl PostgresPaymentRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PaymentRepository for PostgresPaymentRepository {
+    #[instrument(name = "PaymentRepository::find_by_id", skip_all)]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Payment>, RepositoryError> {
+        let _timer = metrics().time_query("payment", "find_by_id");
+        let payment = sqlx::query_as::<_, Payment>("SELECT * FROM payments WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(payment)
+    }
+
+    #[instrument(name = "PaymentRepository::find_by_intent_id", skip_all)]
+    async fn find_by_intent_id(&self, intent_id: &str) -> Result<Option<Payment>, RepositoryError> {
+        let _timer = metrics().time_query("payment", "find_by_intent_id");
+        let payment = sqlx::query_as::<_, Payment>("SELECT * FROM payments WHERE intent_id = $1")
+            .bind(intent_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(payment)
+    }
+
+    #[instrument(name = "PaymentRepository::create", skip_all)]
+    async fn create(
+        &self,
+        payment: CreatePaymentDto,
+        order_status: Option<OrderStatus>,
+    ) -> Result<Payment, RepositoryError> {
+        let _timer = metrics().time_query("payment", "create");
+        let now = Utc::now();
+        let mut conn = acquire(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let payment = sqlx::query_as::<_, Payment>(
+            r#"
+            INSERT INTO payments (
+                id, order_id, provider, intent_id, amount, currency, status, created_at,
+                updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING *
+            "#,
+        )
+        .bind(payment.id)
+        .bind(payment.order_id)
+        .bind(&payment.provider)
+        .bind(&payment.intent_id)
+        .bind(payment.amount)
+        .bind(&payment.currency)
+        .bind(payment.status)
+        .bind(now)
+        .bind(now)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(ref e) if e.constraint() == Some("payments_intent_id_key") => {
+                RepositoryError::DuplicateEntry
+            }
+            _ => RepositoryError::DatabaseError(e.to_string()),
+        })?;
+
+        if let Some(status) = order_status {
+            sqlx::query("UPDATE orders SET status = $1, updated_at = $2 WHERE id = $3")
+                .bind(status)
+                .bind(now)
+                .bind(payment.order_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(payment)
+    }
+
+    #[instrument(name = "PaymentRepository::transition", skip_all)]
+    async fn transition(
+        &self,
+        id: Uuid,
+        from: PaymentStatus,
+        to: PaymentStatus,
+        order_status: Option<OrderStatus>,
+        event_id: Option<&str>,
+    ) -> Result<bool, RepositoryError> {
+        let _timer = metrics().time_query("payment", "transition");
+        let now = Utc::now();
+        let mut conn = acquire(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        if let Some(event_id) = event_id {
+            let recorded = sqlx::query(
+                r#"
+                INSERT INTO payment_events (event_id, payment_id, status, received_at)
+                VALUES ($1, $2, $3, $4)
+                ON CONFLICT (event_id) DO NOTHING
+                "#,
+            )
+            .bind(event_id)
+            .bind(id)
+            .bind(to)
+            .bind(now)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+            if recorded.rows_affected() == 0 {
+                return Ok(false);
+            }
+        }
+
+        let updated = sqlx::query(
+            "UPDATE payments SET status = $1, updated_at = $2 WHERE id = $3 AND status = $4",
+        )
+        .bind(to)
+        .bind(now)
+        .bind(id)
+        .bind(from)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        if updated.rows_affected() == 0 {
+            // Lost a race with a concurrent transition; leave the event unrecorded
+            return Ok(false);
+        }
+
+        if let Some(status) = order_status {
+            sqlx::query(
+                r#"
+                UPDATE orders SET status = $1, updated_at = $2
+                WHERE id = (SELECT order_id FROM payments WHERE id = $3)
+                "#,
+            )
+            .bind(status)
+            .bind(now)
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(true)
+    }
+}
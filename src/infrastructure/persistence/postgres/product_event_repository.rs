@@ -0,0 +1,78 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use crate::domain::{
+    entities::product_event::ProductEvent,
+    repositories::{ProductEventRepository, RepositoryError},
+};
+use crate::infrastructure::metrics::metrics;
+use tracing::instrument;
+
+pub struct PostgresProductEventRepository {
+    pool: PgPool,
+}
+
+impl PostgresProductEventRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ProductEventRepository for PostgresProductEventRepository {
+    #[instrument(name = "ProductEventRepository::find_by_id", skip_all)]
+    async fn find_by_id(&self, id: i64) -> Result<Option<ProductEvent>, RepositoryError> {
+        let _timer = metrics().time_query("product_event", "find_by_id");
+        let event = sqlx::query_as::<_, ProductEvent>("SELECT * FROM product_events WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(event)
+    }
+
+    #[instrument(name = "ProductEventRepository::list_after", skip_all)]
+    async fn list_after(
+        &self,
+        after_id: i64,
+        limit: i64,
+    ) -> Result<Vec<ProductEvent>, RepositoryError> {
+        let _timer = metrics().time_query("product_event", "list_after");
+        let events = sqlx::query_as::<_, ProductEvent>(
+            "SELECT * FROM product_events WHERE id > $1 ORDER BY id LIMIT $2",
+        )
+        .bind(after_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(events)
+    }
+
+    #[instrument(name = "ProductEventRepository::oldest_id", skip_all)]
+    async fn oldest_id(&self) -> Result<Option<i64>, RepositoryError> {
+        let _timer = metrics().time_query("product_event", "oldest_id");
+        sqlx::query_scalar("SELECT MIN(id) FROM product_events")
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))
+    }
+
+    #[instrument(name = "ProductEventRepository::prune", skip_all)]
+    async fn prune(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError> {
+        let _timer = metrics().time_query("product_event", "prune");
+        // The newest event is kept so resuming clients can tell whether they missed any
+        let result = sqlx::query(
+            "DELETE FROM product_events WHERE created_at < $1 AND id < (SELECT MAX(id) FROM product_events)",
+        )
+            .bind(before)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected())
+    }
+}
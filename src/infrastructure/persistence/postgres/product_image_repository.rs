@@ -0,0 +1,283 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::{Connection, PgConnection, PgPool};
+use uuid::Uuid;
+
+use super::{database::acquire, job_repository::insert_job};
+use crate::domain::{
+    entities::{
+        job::JobFactory,
+        product_image::{NewProductImage, ProductImage},
+    },
+    repositories::{ProductImageRepository, RepositoryError},
+};
+use crate::infrastructure::metrics::metrics;
+use tracing::instrument;
+
+pub struct PostgresProductImageRepository {
+    pool: PgPool,
+}
+
+impl PostgresProductImageRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Serializes image changes of a product, failing when the product does not exist
+async fn lock_product(conn: &mut PgConnection, product_id: Uuid) -> Result<(), RepositoryError> {
+    sqlx::query("SELECT id FROM products WHERE id = $1 FOR UPDATE")
+        .bind(product_id)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+        .ok_or(RepositoryError::NotFound)?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl ProductImageRepository for PostgresProductImageRepository {
+    #[instrument(name = "ProductImageRepository::list", skip_all)]
+    async fn list(&self, product_id: Uuid) -> Result<Vec<ProductImage>, RepositoryError> {
+        let _timer = metrics().time_query("product_image", "list");
+        let images = sqlx::query_as::<_, ProductImage>(
+            "SELECT * FROM product_images WHERE product_id = $1 ORDER BY position",
+        )
+        .bind(product_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(images)
+    }
+
+    #[instrument(name = "ProductImageRepository::list_for_products", skip_all)]
+    async fn list_for_products(
+        &self,
+        product_ids: &[Uuid],
+    ) -> Result<Vec<ProductImage>, RepositoryError> {
+        let _timer = metrics().time_query("product_image", "list_for_products");
+        let images = sqlx::query_as::<_, ProductImage>(
+            "SELECT * FROM product_images WHERE product_id = ANY($1) ORDER BY product_id, position",
+        )
+        .bind(product_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(images)
+    }
+
+    #[instrument(name = "ProductImageRepository::create", skip_all)]
+    async fn create(&self, image: NewProductImage) -> Result<ProductImage, RepositoryError> {
+        let _timer = metrics().time_query("product_image", "create");
+        let mut conn = acquire(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        lock_product(&mut tx, image.product_id).await?;
+
+        let (count,): (i64,) =
+            sqlx::query_as("SELECT COUNT(*) FROM product_images WHERE product_id = $1")
+                .bind(image.product_id)
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let is_primary = image.is_primary || count == 0;
+        if is_primary {
+            sqlx::query("UPDATE product_images SET is_primary = FALSE WHERE product_id = $1")
+                .bind(image.product_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        }
+
+        let created = sqlx::query_as::<_, ProductImage>(
+            r#"
+            INSERT INTO product_images (
+                id, product_id, position, is_primary, content_type, width, height, size_bytes,
+                created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING *
+            "#,
+        )
+        .bind(image.id)
+        .bind(image.product_id)
+        .bind(count as i32)
+        .bind(is_primary)
+        .bind(&image.content_type)
+        .bind(image.width)
+        .bind(image.height)
+        .bind(image.size_bytes)
+        .bind(Utc::now())
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(created)
+    }
+
+    #[instrument(name = "ProductImageRepository::set_primary", skip_all)]
+    async fn set_primary(
+        &self,
+        product_id: Uuid,
+        image_id: Uuid,
+    ) -> Result<ProductImage, RepositoryError> {
+        let _timer = metrics().time_query("product_image", "set_primary");
+        let mut conn = acquire(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        lock_product(&mut tx, product_id).await?;
+
+        // Clear the old primary first, the unique index allows only one per product
+        sqlx::query(
+            "UPDATE product_images SET is_primary = FALSE WHERE product_id = $1 AND id <> $2",
+        )
+        .bind(product_id)
+        .bind(image_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let image = sqlx::query_as::<_, ProductImage>(
+            r#"
+            UPDATE product_images SET is_primary = TRUE
+            WHERE product_id = $1 AND id = $2
+            RETURNING *
+            "#,
+        )
+        .bind(product_id)
+        .bind(image_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+        .ok_or(RepositoryError::NotFound)?;
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(image)
+    }
+
+    #[instrument(name = "ProductImageRepository::reorder", skip_all)]
+    async fn reorder(
+        &self,
+        product_id: Uuid,
+        image_ids: &[Uuid],
+    ) -> Result<Vec<ProductImage>, RepositoryError> {
+        let _timer = metrics().time_query("product_image", "reorder");
+        let mut conn = acquire(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        lock_product(&mut tx, product_id).await?;
+
+        sqlx::query(
+            r#"
+            UPDATE product_images pi
+            SET position = ordered.position - 1
+            FROM UNNEST($2::uuid[]) WITH ORDINALITY AS ordered(id, position)
+            WHERE pi.product_id = $1 AND pi.id = ordered.id
+            "#,
+        )
+        .bind(product_id)
+        .bind(image_ids)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let images = sqlx::query_as::<_, ProductImage>(
+            "SELECT * FROM product_images WHERE product_id = $1 ORDER BY position",
+        )
+        .bind(product_id)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(images)
+    }
+
+    #[instrument(name = "ProductImageRepository::delete", skip_all)]
+    async fn delete(
+        &self,
+        product_id: Uuid,
+        image_id: Uuid,
+        cleanup: JobFactory<ProductImage>,
+    ) -> Result<ProductImage, RepositoryError> {
+        let _timer = metrics().time_query("product_image", "delete");
+        let mut conn = acquire(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        lock_product(&mut tx, product_id).await?;
+
+        let deleted = sqlx::query_as::<_, ProductImage>(
+            "DELETE FROM product_images WHERE product_id = $1 AND id = $2 RETURNING *",
+        )
+        .bind(product_id)
+        .bind(image_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+        .ok_or(RepositoryError::NotFound)?;
+
+        // Close the gap in positions
+        sqlx::query(
+            "UPDATE product_images SET position = position - 1 WHERE product_id = $1 AND position > $2",
+        )
+        .bind(product_id)
+        .bind(deleted.position)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        if deleted.is_primary {
+            sqlx::query(
+                "UPDATE product_images SET is_primary = TRUE WHERE product_id = $1 AND position = 0",
+            )
+            .bind(product_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        }
+
+        insert_job(&mut tx, cleanup(&deleted))
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(deleted)
+    }
+}
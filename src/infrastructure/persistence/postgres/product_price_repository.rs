@@ -0,0 +1,274 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::{Connection, PgConnection, PgPool};
+use uuid::Uuid;
+
+use super::{database::acquire, outbox_repository::record_events};
+use crate::domain::{
+    entities::{
+        domain_event::{DomainEvent, EventFactory},
+        product::Product,
+        product_price::{ProductPrice, SchedulePriceDto},
+    },
+    repositories::{ProductPriceRepository, RepositoryError},
+};
+use crate::infrastructure::metrics::metrics;
+use tracing::instrument;
+
+pub struct PostgresProductPriceRepository {
+    pool: PgPool,
+}
+
+impl PostgresProductPriceRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Writes `price` for `[from, to)` into the price history, trimming or splitting the
+/// entries it overlaps so periods never intersect. Must run inside a transaction.
+pub(super) async fn record_price(
+    conn: &mut PgConnection,
+    product_id: Uuid,
+    price: Decimal,
+    from: DateTime<Utc>,
+    to: Option<DateTime<Utc>>,
+) -> Result<ProductPrice, sqlx::Error> {
+    // Serialize concurrent history changes for the same product
+    sqlx::query("SELECT id FROM products WHERE id = $1 FOR UPDATE")
+        .bind(product_id)
+        .execute(&mut *conn)
+        .await?;
+
+    // The entry in effect when the new period ends resumes afterwards
+    let resumed = match to {
+        Some(to) => {
+            sqlx::query_as::<_, ProductPrice>(
+                r#"
+                SELECT * FROM product_prices
+                WHERE product_id = $1
+                  AND effective_from < $2
+                  AND (effective_to IS NULL OR effective_to > $2)
+                "#,
+            )
+            .bind(product_id)
+            .bind(to)
+            .fetch_optional(&mut *conn)
+            .await?
+        }
+        None => None,
+    };
+
+    // Drop entries starting inside the new period
+    sqlx::query(
+        r#"
+        DELETE FROM product_prices
+        WHERE product_id = $1
+          AND effective_from >= $2
+          AND ($3::timestamptz IS NULL OR effective_from < $3)
+        "#,
+    )
+    .bind(product_id)
+    .bind(from)
+    .bind(to)
+    .execute(&mut *conn)
+    .await?;
+
+    // End the entry that was in effect when the new period starts
+    sqlx::query(
+        r#"
+        UPDATE product_prices SET effective_to = $2
+        WHERE product_id = $1
+          AND effective_from < $2
+          AND (effective_to IS NULL OR effective_to > $2)
+        "#,
+    )
+    .bind(product_id)
+    .bind(from)
+    .execute(&mut *conn)
+    .await?;
+
+    let recorded = sqlx::query_as::<_, ProductPrice>(
+        r#"
+        INSERT INTO product_prices (id, product_id, price, effective_from, effective_to, created_at)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING *
+        "#,
+    )
+    .bind(Uuid::new_v4())
+    .bind(product_id)
+    .bind(price)
+    .bind(from)
+    .bind(to)
+    .bind(Utc::now())
+    .fetch_one(&mut *conn)
+    .await?;
+
+    if let (Some(to), Some(resumed)) = (to, resumed) {
+        sqlx::query(
+            r#"
+            INSERT INTO product_prices (id, product_id, price, effective_from, effective_to, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(product_id)
+        .bind(resumed.price)
+        .bind(to)
+        .bind(resumed.effective_to)
+        .bind(Utc::now())
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(recorded)
+}
+
+/// Start of the next scheduled price change after `at`, if any
+pub(super) async fn next_price_change(
+    conn: &mut PgConnection,
+    product_id: Uuid,
+    at: DateTime<Utc>,
+) -> Result<Option<DateTime<Utc>>, sqlx::Error> {
+    sqlx::query_scalar(
+        "SELECT MIN(effective_from) FROM product_prices WHERE product_id = $1 AND effective_from > $2",
+    )
+    .bind(product_id)
+    .bind(at)
+    .fetch_one(&mut *conn)
+    .await
+}
+
+#[async_trait]
+impl ProductPriceRepository for PostgresProductPriceRepository {
+    #[instrument(name = "ProductPriceRepository::list", skip_all)]
+    async fn list(&self, product_id: Uuid) -> Result<Vec<ProductPrice>, RepositoryError> {
+        let _timer = metrics().time_query("product_price", "list");
+        let prices = sqlx::query_as::<_, ProductPrice>(
+            "SELECT * FROM product_prices WHERE product_id = $1 ORDER BY effective_from",
+        )
+        .bind(product_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(prices)
+    }
+
+    #[instrument(name = "ProductPriceRepository::list_for_products", skip_all)]
+    async fn list_for_products(
+        &self,
+        product_ids: &[Uuid],
+    ) -> Result<Vec<ProductPrice>, RepositoryError> {
+        let _timer = metrics().time_query("product_price", "list_for_products");
+        let prices = sqlx::query_as::<_, ProductPrice>(
+            "SELECT * FROM product_prices WHERE product_id = ANY($1) ORDER BY product_id, effective_from",
+        )
+        .bind(product_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(prices)
+    }
+
+    #[instrument(name = "ProductPriceRepository::price_at", skip_all)]
+    async fn price_at(
+        &self,
+        product_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> Result<Option<ProductPrice>, RepositoryError> {
+        let _timer = metrics().time_query("product_price", "price_at");
+        let price = sqlx::query_as::<_, ProductPrice>(
+            r#"
+            SELECT * FROM product_prices
+            WHERE product_id = $1
+              AND effective_from <= $2
+              AND (effective_to IS NULL OR effective_to > $2)
+            "#,
+        )
+        .bind(product_id)
+        .bind(at)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(price)
+    }
+
+    #[instrument(name = "ProductPriceRepository::schedule", skip_all)]
+    async fn schedule(
+        &self,
+        product_id: Uuid,
+        price: SchedulePriceDto,
+    ) -> Result<ProductPrice, RepositoryError> {
+        let _timer = metrics().time_query("product_price", "schedule");
+        let mut conn = acquire(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let recorded = record_price(
+            &mut tx,
+            product_id,
+            price.price,
+            price.effective_from,
+            price.effective_to,
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(recorded)
+    }
+
+    #[instrument(name = "ProductPriceRepository::activate_due", skip_all)]
+    async fn activate_due(
+        &self,
+        now: DateTime<Utc>,
+        event: EventFactory<Product>,
+    ) -> Result<u64, RepositoryError> {
+        let _timer = metrics().time_query("product_price", "activate_due");
+        let mut conn = acquire(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let activated = sqlx::query_as::<_, Product>(
+            r#"
+            UPDATE products p
+            SET price = pp.price, updated_at = $1
+            FROM product_prices pp
+            WHERE pp.product_id = p.id
+              AND pp.effective_from <= $1
+              AND (pp.effective_to IS NULL OR pp.effective_to > $1)
+              AND p.price <> pp.price
+            RETURNING p.*
+            "#,
+        )
+        .bind(now)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let events: Vec<DomainEvent> = activated.iter().map(event).collect();
+        record_events(&mut tx, &events)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(activated.len() as u64)
+    }
+}
@@ -1,101 +1,438 @@
 use async_trait::async_trait;
 use chrono::Utc;
+use futures::{stream::BoxStream, StreamExt};
 use log::error;
-use sqlx::PgPool;
+use rust_decimal::Decimal;
+use sqlx::{Connection, PgConnection, Postgres, Transaction};
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
+use super::{
+    database::Database,
+    outbox_repository::record_events,
+    product_price_repository::{next_price_change, record_price},
+};
 use crate::domain::{
-    entities::product::{CreateProductDto, Product, UpdateProductDto},
+    entities::{
+        domain_event::{DomainEvent, EventFactory},
+        exchange_rate::DEFAULT_CURRENCY,
+        product::{CreateProductDto, Product, UpdateProductDto},
+        product_bulk::{BulkItemResult, BulkItemStatus, BulkUpdateProductDto},
+        product_import::{ImportRowResult, ImportRowStatus, ProductImportRow},
+    },
     repositories::{ProductRepository, RepositoryError},
 };
+use crate::infrastructure::metrics::metrics;
+use tracing::instrument;
+
+/// Maps a clash on the unique SKU to `DuplicateEntry`
+fn map_sku_conflict(e: sqlx::Error) -> RepositoryError {
+    match e {
+        sqlx::Error::Database(ref e) if e.constraint() == Some("products_sku_key") => {
+            RepositoryError::DuplicateEntry
+        }
+        _ => RepositoryError::DatabaseError(e.to_string()),
+    }
+}
+
+/// Why a single import row could not be saved
+enum RowFailure {
+    Rejected(String),
+    Database(sqlx::Error),
+}
+
+impl From<sqlx::Error> for RowFailure {
+    fn from(e: sqlx::Error) -> Self {
+        match e {
+            sqlx::Error::Database(ref db) if db.constraint() == Some("products_sku_key") => {
+                RowFailure::Rejected("SKU is already used by another product".to_string())
+            }
+            sqlx::Error::Database(ref db) => RowFailure::Rejected(db.message().to_string()),
+            e => RowFailure::Database(e),
+        }
+    }
+}
+
+/// Creates or fully updates the product described by an import row
+async fn import_row(
+    conn: &mut PgConnection,
+    row: &ProductImportRow,
+) -> Result<(ImportRowStatus, Product), RowFailure> {
+    let now = Utc::now();
+    let product = &row.product;
+
+    let existing = match (row.id, &product.sku) {
+        (Some(id), _) => {
+            sqlx::query_as::<_, Product>("SELECT * FROM products WHERE id = $1 FOR UPDATE")
+                .bind(id)
+                .fetch_optional(&mut *conn)
+                .await?
+        }
+        (None, Some(sku)) => {
+            sqlx::query_as::<_, Product>("SELECT * FROM products WHERE sku = $1 FOR UPDATE")
+                .bind(sku)
+                .fetch_optional(&mut *conn)
+                .await?
+        }
+        (None, None) => None,
+    };
+
+    let Some(current) = existing else {
+        let created = sqlx::query_as::<_, Product>(
+            r#"
+            INSERT INTO products (id, sku, name, description, category, price, currency, stock, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING *
+            "#,
+        )
+        .bind(row.id.unwrap_or_else(Uuid::new_v4))
+        .bind(&product.sku)
+        .bind(&product.name)
+        .bind(&product.description)
+        .bind(&product.category)
+        .bind(product.price)
+        .bind(product.currency.as_deref().unwrap_or(DEFAULT_CURRENCY))
+        .bind(product.stock)
+        .bind(now)
+        .bind(now)
+        .fetch_one(&mut *conn)
+        .await?;
+
+        record_price(conn, created.id, created.price, now, None).await?;
+        return Ok((ImportRowStatus::Created, created));
+    };
+
+    if let Some(currency) = &product.currency {
+        if *currency != current.currency {
+            return Err(RowFailure::Rejected(format!(
+                "Currency cannot be changed from {}",
+                current.currency
+            )));
+        }
+    }
+
+    let updated = sqlx::query_as::<_, Product>(
+        r#"
+        UPDATE products
+        SET
+            sku = COALESCE($1, sku),
+            name = $2,
+            description = $3,
+            category = $4,
+            price = $5,
+            stock = $6,
+            updated_at = $7
+        WHERE id = $8
+        RETURNING *
+        "#,
+    )
+    .bind(&product.sku)
+    .bind(&product.name)
+    .bind(&product.description)
+    .bind(&product.category)
+    .bind(product.price)
+    .bind(product.stock)
+    .bind(now)
+    .bind(current.id)
+    .fetch_one(&mut *conn)
+    .await?;
+
+    // Same rule as a manual update, the price lasts until the next scheduled change
+    if updated.price != current.price {
+        let until = next_price_change(conn, current.id, now).await?;
+        record_price(conn, current.id, updated.price, now, until).await?;
+    }
+
+    Ok((ImportRowStatus::Updated, updated))
+}
+
+/// Records `event` for each of the products as the transaction sees them now
+async fn record_product_events(
+    conn: &mut PgConnection,
+    ids: &[Uuid],
+    event: EventFactory<Product>,
+) -> Result<(), sqlx::Error> {
+    let products = sqlx::query_as::<_, Product>("SELECT * FROM products WHERE id = ANY($1)")
+        .bind(ids)
+        .fetch_all(&mut *conn)
+        .await?;
+    let events: Vec<DomainEvent> = products.iter().map(event).collect();
+
+    record_events(conn, &events).await
+}
+
+/// Positions of the bulk update items whose new SKU stays taken: held by a product that
+/// keeps it, or requested by an earlier item. A SKU is free once the item of the product
+/// holding it moves to another one, and since rejecting that item keeps the SKU taken
+/// the check repeats until no more items are rejected
+fn taken_skus(
+    items: &[(usize, BulkUpdateProductDto)],
+    current_skus: &HashMap<Uuid, Option<String>>,
+    sku_owners: &HashMap<String, Uuid>,
+) -> HashSet<usize> {
+    let mut taken = HashSet::new();
+    loop {
+        let releasing: HashSet<Uuid> = items
+            .iter()
+            .enumerate()
+            .filter(|(position, (_, item))| {
+                !taken.contains(position)
+                    && item.changes.sku.is_some()
+                    && current_skus
+                        .get(&item.id)
+                        .is_some_and(|current| *current != item.changes.sku)
+            })
+            .map(|(_, (_, item))| item.id)
+            .collect();
+
+        let mut claimed: HashMap<&str, Uuid> = HashMap::new();
+        let mut rejected = false;
+        for (position, (_, item)) in items.iter().enumerate() {
+            let Some(sku) = item.changes.sku.as_deref() else {
+                continue;
+            };
+            if taken.contains(&position) || !current_skus.contains_key(&item.id) {
+                continue;
+            }
+            let held = sku_owners
+                .get(sku)
+                .is_some_and(|owner| *owner != item.id && !releasing.contains(owner));
+            let claimed_before = claimed.get(sku).is_some_and(|owner| *owner != item.id);
+            if held || claimed_before {
+                taken.insert(position);
+                rejected = true;
+            } else {
+                claimed.insert(sku, item.id);
+            }
+        }
+
+        if !rejected {
+            return taken;
+        }
+    }
+}
+
+/// Commits a bulk request, or rolls it back when it is all-or-nothing and an item failed
+async fn finish_bulk(
+    tx: Transaction<'_, Postgres>,
+    results: Vec<BulkItemResult>,
+    atomic: bool,
+) -> Result<Vec<BulkItemResult>, RepositoryError> {
+    let failed = results
+        .iter()
+        .any(|result| result.status == BulkItemStatus::Failed);
+
+    if atomic && failed {
+        tx.rollback()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        return Ok(results
+            .into_iter()
+            .map(BulkItemResult::rolled_back)
+            .collect());
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+    Ok(results)
+}
 
 pub struct PostgresProductRepository {
-    pool: PgPool,
+    database: Database,
 }
 
 impl PostgresProductRepository {
-    pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+    pub fn new(database: impl Into<Database>) -> Self {
+        Self {
+            database: database.into(),
+        }
     }
 }
 
 #[async_trait]
 impl ProductRepository for PostgresProductRepository {
+    #[instrument(name = "ProductRepository::find_by_id", skip_all)]
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Product>, RepositoryError> {
+        let _timer = metrics().time_query("product", "find_by_id");
+        let mut conn = self
+            .database
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
         let product = sqlx::query_as::<_, Product>("SELECT * FROM products WHERE id = $1")
             .bind(id)
-            .fetch_optional(&self.pool)
+            .fetch_optional(&mut *conn)
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
         Ok(product)
     }
 
-    async fn create(&self, product: CreateProductDto) -> Result<Product, RepositoryError> {
+    #[instrument(name = "ProductRepository::find_by_ids", skip_all)]
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<Product>, RepositoryError> {
+        let _timer = metrics().time_query("product", "find_by_ids");
+        let mut conn = self
+            .database
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let products = sqlx::query_as::<_, Product>("SELECT * FROM products WHERE id = ANY($1)")
+            .bind(ids)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(products)
+    }
+
+    #[instrument(name = "ProductRepository::create", skip_all)]
+    async fn create(
+        &self,
+        product: CreateProductDto,
+        event: EventFactory<Product>,
+    ) -> Result<Product, RepositoryError> {
+        let _timer = metrics().time_query("product", "create");
         let now = Utc::now();
         let id = Uuid::new_v4();
+        let mut conn = self
+            .database
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
         let product = sqlx::query_as::<_, Product>(
             r#"
-            INSERT INTO products (id, name, description, price, stock, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            INSERT INTO products (id, sku, name, description, category, price, currency, stock, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
             RETURNING *
             "#,
         )
         .bind(id)
+        .bind(&product.sku)
         .bind(&product.name)
         .bind(&product.description)
+        .bind(&product.category)
         .bind(product.price)
+        .bind(product.currency.as_deref().unwrap_or(DEFAULT_CURRENCY))
         .bind(product.stock)
         .bind(now)
         .bind(now)
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *tx)
         .await
-        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        .map_err(map_sku_conflict)?;
 
+        // Start the price history
+        record_price(&mut tx, product.id, product.price, now, None)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        record_events(&mut tx, &[event(&product)])
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        metrics().products_created(1);
         Ok(product)
     }
 
+    #[instrument(name = "ProductRepository::update", skip_all)]
     async fn update(
         &self,
         id: Uuid,
         product: UpdateProductDto,
+        event: EventFactory<Product>,
     ) -> Result<Product, RepositoryError> {
-        let _current_product = self
+        let _timer = metrics().time_query("product", "update");
+        let current_product = self
             .find_by_id(id)
             .await?
             .ok_or(RepositoryError::NotFound)?;
 
+        let now = Utc::now();
+        let price = product.price;
+        let mut conn = self
+            .database
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
         let product = sqlx::query_as::<_, Product>(
             r#"
             UPDATE products 
             SET 
                 name = COALESCE($1, name),
                 description = COALESCE($2, description),
-                price = COALESCE($3, price),
-                stock = COALESCE($4, stock),
-                updated_at = $5
-            WHERE id = $6
+                category = COALESCE($3, category),
+                price = COALESCE($4, price),
+                stock = COALESCE($5, stock),
+                sku = COALESCE($6, sku),
+                updated_at = $7
+            WHERE id = $8
             RETURNING *
             "#,
         )
         .bind(product.name)
         .bind(product.description)
+        .bind(product.category)
         .bind(product.price)
         .bind(product.stock)
-        .bind(Utc::now())
+        .bind(product.sku)
+        .bind(now)
         .bind(id)
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *tx)
         .await
-        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        .map_err(map_sku_conflict)?;
 
+        // A manual price change lasts until the next scheduled change
+        if let Some(price) = price {
+            let until = next_price_change(&mut tx, id, now)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+            record_price(&mut tx, id, price, now, until)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        }
+        record_events(&mut tx, &[event(&product)])
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        if product.stock != current_product.stock {
+            metrics().stock_adjusted("update", 1);
+        }
         Ok(product)
     }
 
-    async fn delete(&self, id: Uuid) -> Result<(), RepositoryError> {
+    #[instrument(name = "ProductRepository::delete", skip_all)]
+    async fn delete(&self, id: Uuid, event: EventFactory<Uuid>) -> Result<(), RepositoryError> {
+        let _timer = metrics().time_query("product", "delete");
+        let mut conn = self
+            .database
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
         let result = sqlx::query("DELETE FROM products WHERE id = $1")
             .bind(id)
-            .execute(&self.pool)
+            .execute(&mut *tx)
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
@@ -103,12 +440,26 @@ impl ProductRepository for PostgresProductRepository {
             return Err(RepositoryError::NotFound);
         }
 
+        record_events(&mut tx, &[event(&id)])
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
         Ok(())
     }
 
+    #[instrument(name = "ProductRepository::list", skip_all)]
     async fn list(&self) -> Result<Vec<Product>, RepositoryError> {
+        let _timer = metrics().time_query("product", "list");
+        let mut conn = self
+            .database
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
         match sqlx::query_as::<_, Product>("SELECT * FROM products ORDER BY created_at DESC")
-            .fetch_all(&self.pool)
+            .fetch_all(&mut *conn)
             .await
         {
             Ok(products) => Ok(products),
@@ -118,4 +469,599 @@ impl ProductRepository for PostgresProductRepository {
             }
         }
     }
+
+    fn stream(&self) -> BoxStream<'static, Result<Product, RepositoryError>> {
+        let pool = self.database.pool().clone();
+
+        async_stream::stream! {
+            let mut rows =
+                sqlx::query_as::<_, Product>("SELECT * FROM products ORDER BY created_at DESC")
+                    .fetch(&pool);
+            while let Some(row) = rows.next().await {
+                yield row.map_err(|e| RepositoryError::DatabaseError(e.to_string()));
+            }
+        }
+        .boxed()
+    }
+
+    #[instrument(name = "ProductRepository::import_batch", skip_all)]
+    async fn import_batch(
+        &self,
+        rows: Vec<ProductImportRow>,
+        dry_run: bool,
+        created_event: EventFactory<Product>,
+        updated_event: EventFactory<Product>,
+    ) -> Result<Vec<ImportRowResult>, RepositoryError> {
+        let _timer = metrics().time_query("product", "import_batch");
+        let mut conn = self
+            .database
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let mut results = Vec::with_capacity(rows.len());
+        let mut events = Vec::with_capacity(rows.len());
+        for row in rows {
+            let mut savepoint = tx
+                .begin()
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+            match import_row(&mut savepoint, &row).await {
+                Ok((status, product)) => {
+                    savepoint
+                        .commit()
+                        .await
+                        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+                    events.push(match status {
+                        ImportRowStatus::Created => created_event(&product),
+                        _ => updated_event(&product),
+                    });
+                    results.push(ImportRowResult {
+                        line: row.line,
+                        status,
+                        id: Some(product.id),
+                        sku: product.sku,
+                        error: None,
+                    });
+                }
+                Err(RowFailure::Rejected(reason)) => {
+                    savepoint
+                        .rollback()
+                        .await
+                        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+                    results.push(ImportRowResult::failed(row.line, row.product.sku, reason));
+                }
+                Err(RowFailure::Database(e)) => {
+                    return Err(RepositoryError::DatabaseError(e.to_string()));
+                }
+            }
+        }
+
+        if dry_run {
+            tx.rollback().await
+        } else {
+            record_events(&mut tx, &events)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+            tx.commit().await
+        }
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        if !dry_run {
+            let created = results
+                .iter()
+                .filter(|result| result.status == ImportRowStatus::Created)
+                .count();
+            metrics().products_created(created);
+        }
+        Ok(results)
+    }
+
+    #[instrument(name = "ProductRepository::bulk_create", skip_all)]
+    async fn bulk_create(
+        &self,
+        items: Vec<(usize, CreateProductDto)>,
+        atomic: bool,
+        event: EventFactory<Product>,
+    ) -> Result<Vec<BulkItemResult>, RepositoryError> {
+        let _timer = metrics().time_query("product", "bulk_create");
+        let now = Utc::now();
+        let ids: Vec<Uuid> = items.iter().map(|_| Uuid::new_v4()).collect();
+        let mut conn = self
+            .database
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        // A taken SKU skips its row instead of failing the statement
+        let created: HashSet<Uuid> = sqlx::query_scalar(
+            r#"
+            INSERT INTO products (id, sku, name, description, category, price, currency, stock, created_at, updated_at)
+            SELECT u.id, u.sku, u.name, u.description, u.category, u.price, u.currency, u.stock, $9, $9
+            FROM UNNEST(
+                $1::uuid[], $2::varchar[], $3::varchar[], $4::text[],
+                $5::varchar[], $6::numeric[], $7::char(3)[], $8::int[]
+            ) AS u(id, sku, name, description, category, price, currency, stock)
+            ON CONFLICT (sku) DO NOTHING
+            RETURNING id
+            "#,
+        )
+        .bind(&ids)
+        .bind(items.iter().map(|(_, p)| p.sku.clone()).collect::<Vec<_>>())
+        .bind(items.iter().map(|(_, p)| p.name.clone()).collect::<Vec<_>>())
+        .bind(items.iter().map(|(_, p)| p.description.clone()).collect::<Vec<_>>())
+        .bind(items.iter().map(|(_, p)| p.category.clone()).collect::<Vec<_>>())
+        .bind(items.iter().map(|(_, p)| p.price).collect::<Vec<Decimal>>())
+        .bind(
+            items
+                .iter()
+                .map(|(_, p)| p.currency.as_deref().unwrap_or(DEFAULT_CURRENCY).to_string())
+                .collect::<Vec<_>>(),
+        )
+        .bind(items.iter().map(|(_, p)| p.stock).collect::<Vec<_>>())
+        .bind(now)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+        .into_iter()
+        .collect();
+
+        // Start the price histories
+        let created_ids: Vec<Uuid> = created.iter().copied().collect();
+        sqlx::query(
+            r#"
+            INSERT INTO product_prices (id, product_id, price, effective_from, effective_to, created_at)
+            SELECT uuid_generate_v4(), id, price, created_at, NULL, created_at
+            FROM products
+            WHERE id = ANY($1)
+            "#,
+        )
+        .bind(&created_ids)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        record_product_events(&mut tx, &created_ids, event)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = items
+            .iter()
+            .zip(ids)
+            .map(|((index, _), id)| match created.contains(&id) {
+                true => BulkItemResult::succeeded(*index, BulkItemStatus::Created, id),
+                false => {
+                    BulkItemResult::failed(*index, None, "SKU is already used by another product")
+                }
+            })
+            .collect();
+
+        let results = finish_bulk(tx, results, atomic).await?;
+        let created = results
+            .iter()
+            .filter(|result| result.status == BulkItemStatus::Created)
+            .count();
+        metrics().products_created(created);
+        Ok(results)
+    }
+
+    #[instrument(name = "ProductRepository::bulk_update", skip_all)]
+    async fn bulk_update(
+        &self,
+        items: Vec<(usize, BulkUpdateProductDto)>,
+        atomic: bool,
+        event: EventFactory<Product>,
+    ) -> Result<Vec<BulkItemResult>, RepositoryError> {
+        let _timer = metrics().time_query("product", "bulk_update");
+        let now = Utc::now();
+        let mut conn = self
+            .database
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let ids: Vec<Uuid> = items.iter().map(|(_, item)| item.id).collect();
+        let current_skus: HashMap<Uuid, Option<String>> =
+            sqlx::query_as::<_, (Uuid, Option<String>)>(
+                "SELECT id, sku FROM products WHERE id = ANY($1) FOR UPDATE",
+            )
+            .bind(&ids)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+            .into_iter()
+            .collect();
+
+        // Who holds each requested SKU, so a clash fails its item instead of the statement
+        let skus: Vec<String> = items
+            .iter()
+            .filter_map(|(_, item)| item.changes.sku.clone())
+            .collect();
+        let sku_owners: HashMap<String, Uuid> =
+            sqlx::query_as::<_, (String, Uuid)>("SELECT sku, id FROM products WHERE sku = ANY($1)")
+                .bind(&skus)
+                .fetch_all(&mut *tx)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+                .into_iter()
+                .collect();
+        let taken = taken_skus(&items, &current_skus, &sku_owners);
+
+        let mut results = Vec::with_capacity(items.len());
+        let mut accepted = Vec::with_capacity(items.len());
+        for (position, (index, item)) in items.into_iter().enumerate() {
+            if !current_skus.contains_key(&item.id) {
+                results.push(BulkItemResult::failed(
+                    index,
+                    Some(item.id),
+                    "Product not found",
+                ));
+            } else if taken.contains(&position) {
+                results.push(BulkItemResult::failed(
+                    index,
+                    Some(item.id),
+                    "SKU is already used by another product",
+                ));
+            } else {
+                accepted.push((index, item));
+            }
+        }
+
+        // Release the SKUs being replaced first, the unique constraint is checked row by
+        // row and would otherwise reject swaps depending on the order rows are updated in
+        let renamed: Vec<Uuid> = accepted
+            .iter()
+            .filter(|(_, item)| {
+                item.changes.sku.is_some() && item.changes.sku != current_skus[&item.id]
+            })
+            .map(|(_, item)| item.id)
+            .collect();
+        if !renamed.is_empty() {
+            sqlx::query("UPDATE products SET sku = NULL WHERE id = ANY($1)")
+                .bind(&renamed)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        }
+
+        if !accepted.is_empty() {
+            let changes = accepted.iter().map(|(_, item)| &item.changes);
+            sqlx::query(
+                r#"
+                UPDATE products p
+                SET
+                    name = COALESCE(u.name, p.name),
+                    description = COALESCE(u.description, p.description),
+                    category = COALESCE(u.category, p.category),
+                    price = COALESCE(u.price, p.price),
+                    stock = COALESCE(u.stock, p.stock),
+                    sku = COALESCE(u.sku, p.sku),
+                    updated_at = $8
+                FROM UNNEST(
+                    $1::uuid[], $2::varchar[], $3::text[], $4::varchar[],
+                    $5::numeric[], $6::int[], $7::varchar[]
+                ) AS u(id, name, description, category, price, stock, sku)
+                WHERE p.id = u.id
+                "#,
+            )
+            .bind(accepted.iter().map(|(_, item)| item.id).collect::<Vec<_>>())
+            .bind(changes.clone().map(|c| c.name.clone()).collect::<Vec<_>>())
+            .bind(
+                changes
+                    .clone()
+                    .map(|c| c.description.clone())
+                    .collect::<Vec<_>>(),
+            )
+            .bind(
+                changes
+                    .clone()
+                    .map(|c| c.category.clone())
+                    .collect::<Vec<_>>(),
+            )
+            .bind(changes.clone().map(|c| c.price).collect::<Vec<_>>())
+            .bind(changes.clone().map(|c| c.stock).collect::<Vec<_>>())
+            .bind(changes.map(|c| c.sku.clone()).collect::<Vec<_>>())
+            .bind(now)
+            .execute(&mut *tx)
+            .await
+            .map_err(map_sku_conflict)?;
+        }
+
+        let updated_ids: Vec<Uuid> = accepted.iter().map(|(_, item)| item.id).collect();
+        record_product_events(&mut tx, &updated_ids, event)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        // Price periods depend on each product's schedule, so history is kept per product
+        let restocked = accepted
+            .iter()
+            .filter(|(_, item)| item.changes.stock.is_some())
+            .count();
+        for (index, item) in accepted {
+            if let Some(price) = item.changes.price {
+                let until = next_price_change(&mut tx, item.id, now)
+                    .await
+                    .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+                record_price(&mut tx, item.id, price, now, until)
+                    .await
+                    .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+            }
+            results.push(BulkItemResult::succeeded(
+                index,
+                BulkItemStatus::Updated,
+                item.id,
+            ));
+        }
+
+        let results = finish_bulk(tx, results, atomic).await?;
+        if results
+            .iter()
+            .any(|result| result.status == BulkItemStatus::Updated)
+        {
+            metrics().stock_adjusted("bulk_update", restocked);
+        }
+        Ok(results)
+    }
+
+    #[instrument(name = "ProductRepository::bulk_delete", skip_all)]
+    async fn bulk_delete(
+        &self,
+        items: Vec<(usize, Uuid)>,
+        atomic: bool,
+        event: EventFactory<Uuid>,
+    ) -> Result<Vec<BulkItemResult>, RepositoryError> {
+        let _timer = metrics().time_query("product", "bulk_delete");
+        let mut conn = self
+            .database
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let deleted: HashSet<Uuid> =
+            sqlx::query_scalar("DELETE FROM products WHERE id = ANY($1) RETURNING id")
+                .bind(items.iter().map(|(_, id)| *id).collect::<Vec<_>>())
+                .fetch_all(&mut *tx)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+                .into_iter()
+                .collect();
+        let events: Vec<DomainEvent> = deleted.iter().map(event).collect();
+        record_events(&mut tx, &events)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = items
+            .into_iter()
+            .map(|(index, id)| match deleted.contains(&id) {
+                true => BulkItemResult::succeeded(index, BulkItemStatus::Deleted, id),
+                false => BulkItemResult::failed(index, Some(id), "Product not found"),
+            })
+            .collect();
+
+        finish_bulk(tx, results, atomic).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::PgPool;
+
+    async fn pool() -> PgPool {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        PgPool::connect(&url)
+            .await
+            .expect("Database is not reachable")
+    }
+
+    fn sku() -> String {
+        format!("BULK-{}", Uuid::new_v4().simple())
+    }
+
+    fn new_product(sku: &str) -> CreateProductDto {
+        CreateProductDto {
+            sku: Some(sku.to_string()),
+            name: "Bulk test product".to_string(),
+            description: String::new(),
+            category: None,
+            price: Decimal::new(999, 2),
+            currency: None,
+            stock: 1,
+        }
+    }
+
+    fn sku_change(id: Uuid, sku: &str) -> BulkUpdateProductDto {
+        BulkUpdateProductDto {
+            id,
+            changes: UpdateProductDto {
+                sku: Some(sku.to_string()),
+                name: None,
+                description: None,
+                category: None,
+                price: None,
+                stock: None,
+            },
+        }
+    }
+
+    fn created(product: &Product) -> DomainEvent {
+        DomainEvent::ProductCreated(product.clone())
+    }
+
+    fn updated(product: &Product) -> DomainEvent {
+        DomainEvent::ProductUpdated(product.clone())
+    }
+
+    fn deleted(id: &Uuid) -> DomainEvent {
+        DomainEvent::ProductDeleted(*id)
+    }
+
+    fn statuses(results: &[BulkItemResult]) -> Vec<(usize, BulkItemStatus)> {
+        let mut statuses: Vec<_> = results.iter().map(|r| (r.index, r.status)).collect();
+        statuses.sort_by_key(|(index, _)| *index);
+        statuses
+    }
+
+    async fn create(repository: &PostgresProductRepository, skus: &[&str]) -> Vec<Uuid> {
+        let items = skus
+            .iter()
+            .map(|sku| new_product(sku))
+            .enumerate()
+            .collect();
+        let mut results = repository.bulk_create(items, true, created).await.unwrap();
+        results.sort_by_key(|result| result.index);
+        results
+            .into_iter()
+            .map(|result| result.id.unwrap())
+            .collect()
+    }
+
+    async fn sku_of(pool: &PgPool, id: Uuid) -> Option<String> {
+        sqlx::query_scalar("SELECT sku FROM products WHERE id = $1")
+            .bind(id)
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    async fn cleanup(pool: &PgPool, ids: &[Uuid]) {
+        sqlx::query("DELETE FROM products WHERE id = ANY($1)")
+            .bind(ids)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Postgres at DATABASE_URL"]
+    async fn bulk_create_fails_later_items_repeating_a_sku() {
+        let pool = pool().await;
+        let repository = PostgresProductRepository::new(pool.clone());
+        let sku = sku();
+
+        let results = repository
+            .bulk_create(
+                vec![(0, new_product(&sku)), (1, new_product(&sku))],
+                false,
+                created,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            statuses(&results),
+            [(0, BulkItemStatus::Created), (1, BulkItemStatus::Failed)]
+        );
+        let ids: Vec<Uuid> = results.iter().filter_map(|result| result.id).collect();
+        assert_eq!(ids.len(), 1);
+        assert_eq!(sku_of(&pool, ids[0]).await, Some(sku));
+        cleanup(&pool, &ids).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Postgres at DATABASE_URL"]
+    async fn bulk_update_reuses_skus_released_in_the_same_request() {
+        let pool = pool().await;
+        let repository = PostgresProductRepository::new(pool.clone());
+        let skus: Vec<String> = (0..6).map(|_| sku()).collect();
+        let skus: Vec<&str> = skus.iter().map(String::as_str).collect();
+        let fresh = sku();
+        let ids = create(&repository, &skus).await;
+
+        let results = repository
+            .bulk_update(
+                vec![
+                    // A swap
+                    (0, sku_change(ids[0], skus[1])),
+                    (1, sku_change(ids[1], skus[0])),
+                    // Taking a SKU an item further down gives up
+                    (2, sku_change(ids[2], skus[3])),
+                    (3, sku_change(ids[3], &fresh)),
+                    // A product that keeps its SKU, so the item waiting for it fails too
+                    (4, sku_change(ids[4], skus[0])),
+                    (5, sku_change(ids[5], skus[4])),
+                ],
+                false,
+                updated,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            statuses(&results),
+            [
+                (0, BulkItemStatus::Updated),
+                (1, BulkItemStatus::Updated),
+                (2, BulkItemStatus::Updated),
+                (3, BulkItemStatus::Updated),
+                (4, BulkItemStatus::Failed),
+                (5, BulkItemStatus::Failed),
+            ]
+        );
+        let expected = [skus[1], skus[0], skus[3], &fresh, skus[4], skus[5]];
+        for (id, sku) in ids.iter().zip(expected) {
+            assert_eq!(sku_of(&pool, *id).await.as_deref(), Some(sku));
+        }
+        cleanup(&pool, &ids).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Postgres at DATABASE_URL"]
+    async fn atomic_bulk_requests_report_applied_items_as_rolled_back() {
+        let pool = pool().await;
+        let repository = PostgresProductRepository::new(pool.clone());
+        let (taken, free) = (sku(), sku());
+        let ids = create(&repository, &[&taken]).await;
+
+        let results = repository
+            .bulk_create(
+                vec![(0, new_product(&free)), (1, new_product(&taken))],
+                true,
+                created,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            statuses(&results),
+            [(0, BulkItemStatus::RolledBack), (1, BulkItemStatus::Failed)]
+        );
+        // The product that was never kept has no id to report
+        assert!(results.iter().all(|result| result.id.is_none()));
+        let kept: bool =
+            sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM products WHERE sku = $1)")
+                .bind(&free)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert!(!kept);
+
+        let missing = Uuid::new_v4();
+        let results = repository
+            .bulk_delete(vec![(0, ids[0]), (1, missing)], true, deleted)
+            .await
+            .unwrap();
+        assert_eq!(
+            statuses(&results),
+            [(0, BulkItemStatus::RolledBack), (1, BulkItemStatus::Failed)]
+        );
+        assert_eq!(results[0].id, Some(ids[0]));
+        assert_eq!(sku_of(&pool, ids[0]).await, Some(taken));
+        cleanup(&pool, &ids).await;
+    }
 }
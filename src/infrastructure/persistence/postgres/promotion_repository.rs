@@ -0,0 +1,222 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::promotion::{CreatePromotionDto, Promotion, UpdatePromotionDto},
+    repositories::{PromotionRepository, RepositoryError},
+};
+use crate::infrastructure::metrics::metrics;
+use tracing::instrument;
+
+pub struct PostgresPromotionRepository {
+    pool: PgPool,
+}
+
+impl PostgresPromotionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_write_error(e: sqlx::Error) -> RepositoryError {
+    match e {
+        sqlx::Error::Database(ref db) if db.constraint() == Some("promotions_code_key") => {
+            RepositoryError::DuplicateEntry
+        }
+        _ => RepositoryError::DatabaseError(e.to_string()),
+    }
+}
+
+#[async_trait]
+impl PromotionRepository for PostgresPromotionRepository {
+    #[instrument(name = "PromotionRepository::find_by_id", skip_all)]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Promotion>, RepositoryError> {
+        let _timer = metrics().time_query("promotion", "find_by_id");
+        let promotion = sqlx::query_as::<_, Promotion>("SELECT * FROM promotions WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(promotion)
+    }
+
+    #[instrument(name = "PromotionRepository::list", skip_all)]
+    async fn list(&self) -> Result<Vec<Promotion>, RepositoryError> {
+        let _timer = metrics().time_query("promotion", "list");
+        let promotions =
+            sqlx::query_as::<_, Promotion>("SELECT * FROM promotions ORDER BY created_at DESC")
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(promotions)
+    }
+
+    #[instrument(name = "PromotionRepository::find_applicable", skip_all)]
+    async fn find_applicable(
+        &self,
+        now: DateTime<Utc>,
+        code: Option<&str>,
+    ) -> Result<Vec<Promotion>, RepositoryError> {
+        let _timer = metrics().time_query("promotion", "find_applicable");
+        let promotions = sqlx::query_as::<_, Promotion>(
+            r#"
+            SELECT * FROM promotions
+            WHERE (
+                code IS NULL
+                AND active
+                AND (starts_at IS NULL OR starts_at <= $1)
+                AND (ends_at IS NULL OR ends_at > $1)
+            )
+            OR code = $2
+            ORDER BY created_at
+            "#,
+        )
+        .bind(now)
+        .bind(code)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(promotions)
+    }
+
+    #[instrument(name = "PromotionRepository::count_redemptions", skip_all)]
+    async fn count_redemptions(
+        &self,
+        user_id: Uuid,
+        promotion_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, i64>, RepositoryError> {
+        let _timer = metrics().time_query("promotion", "count_redemptions");
+        let counts = sqlx::query_as::<_, (Uuid, i64)>(
+            r#"
+            SELECT promotion_id, COUNT(*) FROM promotion_redemptions
+            WHERE user_id = $1 AND promotion_id = ANY($2)
+            GROUP BY promotion_id
+            "#,
+        )
+        .bind(user_id)
+        .bind(promotion_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(counts.into_iter().collect())
+    }
+
+    #[instrument(name = "PromotionRepository::create", skip_all)]
+    async fn create(&self, promotion: CreatePromotionDto) -> Result<Promotion, RepositoryError> {
+        let _timer = metrics().time_query("promotion", "create");
+        let now = Utc::now();
+
+        let promotion = sqlx::query_as::<_, Promotion>(
+            r#"
+            INSERT INTO promotions (
+                id, name, code, kind, value, buy_quantity, get_quantity, min_order_value,
+                currency, product_ids, categories, usage_limit_per_user, starts_at, ends_at,
+                active, created_at, updated_at
+            )
+            VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17
+            )
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(&promotion.name)
+        .bind(&promotion.code)
+        .bind(promotion.kind)
+        .bind(promotion.value)
+        .bind(promotion.buy_quantity)
+        .bind(promotion.get_quantity)
+        .bind(promotion.min_order_value)
+        .bind(&promotion.currency)
+        .bind(&promotion.product_ids)
+        .bind(&promotion.categories)
+        .bind(promotion.usage_limit_per_user)
+        .bind(promotion.starts_at)
+        .bind(promotion.ends_at)
+        .bind(promotion.active.unwrap_or(true))
+        .bind(now)
+        .bind(now)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_write_error)?;
+
+        Ok(promotion)
+    }
+
+    #[instrument(name = "PromotionRepository::update", skip_all)]
+    async fn update(
+        &self,
+        id: Uuid,
+        promotion: UpdatePromotionDto,
+    ) -> Result<Promotion, RepositoryError> {
+        let _timer = metrics().time_query("promotion", "update");
+        let promotion = sqlx::query_as::<_, Promotion>(
+            r#"
+            UPDATE promotions
+            SET
+                name = COALESCE($1, name),
+                code = COALESCE($2, code),
+                kind = COALESCE($3, kind),
+                value = COALESCE($4, value),
+                buy_quantity = COALESCE($5, buy_quantity),
+                get_quantity = COALESCE($6, get_quantity),
+                min_order_value = COALESCE($7, min_order_value),
+                currency = COALESCE($8, currency),
+                product_ids = COALESCE($9, product_ids),
+                categories = COALESCE($10, categories),
+                usage_limit_per_user = COALESCE($11, usage_limit_per_user),
+                starts_at = COALESCE($12, starts_at),
+                ends_at = COALESCE($13, ends_at),
+                active = COALESCE($14, active),
+                updated_at = $15
+            WHERE id = $16
+            RETURNING *
+            "#,
+        )
+        .bind(promotion.name)
+        .bind(promotion.code)
+        .bind(promotion.kind)
+        .bind(promotion.value)
+        .bind(promotion.buy_quantity)
+        .bind(promotion.get_quantity)
+        .bind(promotion.min_order_value)
+        .bind(promotion.currency)
+        .bind(promotion.product_ids)
+        .bind(promotion.categories)
+        .bind(promotion.usage_limit_per_user)
+        .bind(promotion.starts_at)
+        .bind(promotion.ends_at)
+        .bind(promotion.active)
+        .bind(Utc::now())
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_write_error)?
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(promotion)
+    }
+
+    #[instrument(name = "PromotionRepository::delete", skip_all)]
+    async fn delete(&self, id: Uuid) -> Result<(), RepositoryError> {
+        let _timer = metrics().time_query("promotion", "delete");
+        let result = sqlx::query("DELETE FROM promotions WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
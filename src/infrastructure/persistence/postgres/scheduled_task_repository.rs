@@ -0,0 +1,117 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use crate::domain::{
+    entities::scheduled_task::{ScheduledTask, TaskRunStatus},
+    repositories::{RepositoryError, ScheduledTaskRepository},
+};
+use crate::infrastructure::metrics::metrics;
+use tracing::instrument;
+
+pub struct PostgresScheduledTaskRepository {
+    pool: PgPool,
+}
+
+impl PostgresScheduledTaskRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ScheduledTaskRepository for PostgresScheduledTaskRepository {
+    #[instrument(name = "ScheduledTaskRepository::register", skip_all)]
+    async fn register(
+        &self,
+        name: &str,
+        schedule: &str,
+        next_run_at: Option<DateTime<Utc>>,
+    ) -> Result<(), RepositoryError> {
+        let _timer = metrics().time_query("scheduled_task", "register");
+        sqlx::query(
+            r#"
+            INSERT INTO scheduled_tasks (name, schedule, next_run_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (name) DO UPDATE
+            SET schedule = EXCLUDED.schedule, next_run_at = EXCLUDED.next_run_at
+            "#,
+        )
+        .bind(name)
+        .bind(schedule)
+        .bind(next_run_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "ScheduledTaskRepository::start_run", skip_all)]
+    async fn start_run(
+        &self,
+        name: &str,
+        scheduled_for: DateTime<Utc>,
+        next_run_at: Option<DateTime<Utc>>,
+    ) -> Result<bool, RepositoryError> {
+        let _timer = metrics().time_query("scheduled_task", "start_run");
+        let result = sqlx::query(
+            r#"
+            UPDATE scheduled_tasks
+            SET last_scheduled_for = $2, next_run_at = $3, last_status = $4,
+                last_started_at = CURRENT_TIMESTAMP, last_finished_at = NULL
+            WHERE name = $1
+                AND (last_scheduled_for IS NULL OR last_scheduled_for < $2)
+            "#,
+        )
+        .bind(name)
+        .bind(scheduled_for)
+        .bind(next_run_at)
+        .bind(TaskRunStatus::Running)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(name = "ScheduledTaskRepository::finish_run", skip_all)]
+    async fn finish_run(
+        &self,
+        name: &str,
+        status: TaskRunStatus,
+        error: Option<&str>,
+        duration_ms: i64,
+    ) -> Result<(), RepositoryError> {
+        let _timer = metrics().time_query("scheduled_task", "finish_run");
+        sqlx::query(
+            r#"
+            UPDATE scheduled_tasks
+            SET last_status = $2, last_error = $3, last_duration_ms = $4,
+                last_finished_at = CURRENT_TIMESTAMP
+            WHERE name = $1
+            "#,
+        )
+        .bind(name)
+        .bind(status)
+        .bind(error)
+        .bind(duration_ms)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "ScheduledTaskRepository::list", skip_all)]
+    async fn list(&self) -> Result<Vec<ScheduledTask>, RepositoryError> {
+        let _timer = metrics().time_query("scheduled_task", "list");
+        let tasks =
+            sqlx::query_as::<_, ScheduledTask>("SELECT * FROM scheduled_tasks ORDER BY name")
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(tasks)
+    }
+}
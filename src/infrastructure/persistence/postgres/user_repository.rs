@@ -1,48 +1,86 @@
 use async_trait::async_trait;
 use chrono::Utc;
-use sqlx::PgPool;
+use futures::{stream::BoxStream, StreamExt};
+use sqlx::Connection;
 use uuid::Uuid;
 
+use super::{database::Database, outbox_repository::record_events};
 use crate::domain::{
-    entities::user::{CreateUserDto, UpdateUserDto, User},
+    entities::{
+        domain_event::EventFactory,
+        user::{CreateUserDto, UpdateUserDto, User},
+    },
     repositories::{RepositoryError, UserRepository},
 };
+use crate::infrastructure::metrics::metrics;
+use tracing::instrument;
 
 pub struct PostgresUserRepository {
-    pool: PgPool,
+    database: Database,
 }
 
 impl PostgresUserRepository {
-    pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+    pub fn new(database: impl Into<Database>) -> Self {
+        Self {
+            database: database.into(),
+        }
     }
 }
 
 #[async_trait]
 impl UserRepository for PostgresUserRepository {
+    #[instrument(name = "UserRepository::find_by_id", skip_all)]
     async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, RepositoryError> {
+        let _timer = metrics().time_query("user", "find_by_id");
+        let mut conn = self
+            .database
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
         let user = sqlx::query_as::<_, User>("SELECT * FROM users WHERE id = $1")
             .bind(id)
-            .fetch_optional(&self.pool)
+            .fetch_optional(&mut *conn)
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
         Ok(user)
     }
 
+    #[instrument(name = "UserRepository::find_by_email", skip_all)]
     async fn find_by_email(&self, email: &str) -> Result<Option<User>, RepositoryError> {
+        let _timer = metrics().time_query("user", "find_by_email");
+        let mut conn = self
+            .database
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
         let user = sqlx::query_as::<_, User>("SELECT * FROM users WHERE email = $1")
             .bind(email)
-            .fetch_optional(&self.pool)
+            .fetch_optional(&mut *conn)
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
         Ok(user)
     }
 
-    async fn create(&self, user: CreateUserDto) -> Result<User, RepositoryError> {
+    #[instrument(name = "UserRepository::create", skip_all)]
+    async fn create(
+        &self,
+        user: CreateUserDto,
+        event: EventFactory<User>,
+    ) -> Result<User, RepositoryError> {
+        let _timer = metrics().time_query("user", "create");
         let now = Utc::now();
         let id = Uuid::new_v4();
+        let mut conn = self
+            .database
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
         let user = sqlx::query_as::<_, User>(
             r#"
@@ -57,7 +95,7 @@ impl UserRepository for PostgresUserRepository {
         .bind(&user.password) // Note: In real app, hash password before storing
         .bind(now)
         .bind(now)
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *tx)
         .await
         .map_err(|e| match e {
             sqlx::Error::Database(ref e) if e.constraint() == Some("users_email_key") => {
@@ -66,14 +104,38 @@ impl UserRepository for PostgresUserRepository {
             _ => RepositoryError::DatabaseError(e.to_string()),
         })?;
 
+        record_events(&mut tx, &[event(&user)])
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        metrics().user_registered();
         Ok(user)
     }
 
-    async fn update(&self, id: Uuid, user: UpdateUserDto) -> Result<User, RepositoryError> {
+    #[instrument(name = "UserRepository::update", skip_all)]
+    async fn update(
+        &self,
+        id: Uuid,
+        user: UpdateUserDto,
+        event: EventFactory<User>,
+    ) -> Result<User, RepositoryError> {
+        let _timer = metrics().time_query("user", "update");
         let _current_user = self
             .find_by_id(id)
             .await?
             .ok_or(RepositoryError::NotFound)?;
+        let mut conn = self
+            .database
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
         let user = sqlx::query_as::<_, User>(
             r#"
@@ -92,17 +154,36 @@ impl UserRepository for PostgresUserRepository {
         .bind(user.password) // Note: In real app, hash password before storing
         .bind(Utc::now())
         .bind(id)
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *tx)
         .await
         .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
+        record_events(&mut tx, &[event(&user)])
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
         Ok(user)
     }
 
-    async fn delete(&self, id: Uuid) -> Result<(), RepositoryError> {
+    #[instrument(name = "UserRepository::delete", skip_all)]
+    async fn delete(&self, id: Uuid, event: EventFactory<Uuid>) -> Result<(), RepositoryError> {
+        let _timer = metrics().time_query("user", "delete");
+        let mut conn = self
+            .database
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
         let result = sqlx::query("DELETE FROM users WHERE id = $1")
             .bind(id)
-            .execute(&self.pool)
+            .execute(&mut *tx)
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
@@ -110,15 +191,43 @@ impl UserRepository for PostgresUserRepository {
             return Err(RepositoryError::NotFound);
         }
 
+        record_events(&mut tx, &[event(&id)])
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
         Ok(())
     }
 
+    #[instrument(name = "UserRepository::list", skip_all)]
     async fn list(&self) -> Result<Vec<User>, RepositoryError> {
+        let _timer = metrics().time_query("user", "list");
+        let mut conn = self
+            .database
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
         let users = sqlx::query_as::<_, User>("SELECT * FROM users ORDER BY created_at DESC")
-            .fetch_all(&self.pool)
+            .fetch_all(&mut *conn)
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
         Ok(users)
     }
+
+    fn stream(&self) -> BoxStream<'static, Result<User, RepositoryError>> {
+        let pool = self.database.pool().clone();
+
+        async_stream::stream! {
+            let mut rows =
+                sqlx::query_as::<_, User>("SELECT * FROM users ORDER BY created_at DESC")
+                    .fetch(&pool);
+            while let Some(row) = rows.next().await {
+                yield row.map_err(|e| RepositoryError::DatabaseError(e.to_string()));
+            }
+        }
+        .boxed()
+    }
 }
@@ -0,0 +1,535 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::json;
+use sqlx::{Connection, PgPool};
+use uuid::Uuid;
+
+use super::database::acquire;
+use crate::domain::{
+    entities::{
+        domain_event::OutboxEvent,
+        webhook::{
+            CreateWebhookSubscriptionDto, DueWebhookDelivery, UpdateWebhookSubscriptionDto,
+            WebhookAttemptOutcome, WebhookDelivery, WebhookDeliveryStatus, WebhookSubscription,
+        },
+    },
+    repositories::{RepositoryError, WebhookDeliveryQueue, WebhookRepository},
+};
+use crate::infrastructure::metrics::metrics;
+use tracing::instrument;
+
+pub struct PostgresWebhookRepository {
+    pool: PgPool,
+}
+
+impl PostgresWebhookRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for PostgresWebhookRepository {
+    #[instrument(name = "WebhookRepository::list_subscriptions", skip_all)]
+    async fn list_subscriptions(&self) -> Result<Vec<WebhookSubscription>, RepositoryError> {
+        let _timer = metrics().time_query("webhook", "list_subscriptions");
+        let subscriptions = sqlx::query_as::<_, WebhookSubscription>(
+            "SELECT * FROM webhook_subscriptions ORDER BY created_at DESC",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(subscriptions)
+    }
+
+    #[instrument(name = "WebhookRepository::find_subscription", skip_all)]
+    async fn find_subscription(
+        &self,
+        id: Uuid,
+    ) -> Result<Option<WebhookSubscription>, RepositoryError> {
+        let _timer = metrics().time_query("webhook", "find_subscription");
+        let subscription = sqlx::query_as::<_, WebhookSubscription>(
+            "SELECT * FROM webhook_subscriptions WHERE id = $1",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(subscription)
+    }
+
+    #[instrument(name = "WebhookRepository::create_subscription", skip_all)]
+    async fn create_subscription(
+        &self,
+        subscription: CreateWebhookSubscriptionDto,
+    ) -> Result<WebhookSubscription, RepositoryError> {
+        let _timer = metrics().time_query("webhook", "create_subscription");
+        let subscription = sqlx::query_as::<_, WebhookSubscription>(
+            r#"
+            INSERT INTO webhook_subscriptions (id, url, event_types, secret)
+            VALUES ($1, $2, $3, $4)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(&subscription.url)
+        .bind(&subscription.event_types)
+        .bind(subscription.secret.unwrap_or_default())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(subscription)
+    }
+
+    #[instrument(name = "WebhookRepository::update_subscription", skip_all)]
+    async fn update_subscription(
+        &self,
+        id: Uuid,
+        subscription: UpdateWebhookSubscriptionDto,
+    ) -> Result<WebhookSubscription, RepositoryError> {
+        let _timer = metrics().time_query("webhook", "update_subscription");
+        let subscription = sqlx::query_as::<_, WebhookSubscription>(
+            r#"
+            UPDATE webhook_subscriptions
+            SET
+                url = COALESCE($1, url),
+                event_types = COALESCE($2, event_types),
+                secret = COALESCE($3, secret),
+                active = COALESCE($4, active),
+                consecutive_failures = CASE WHEN $4 THEN 0 ELSE consecutive_failures END,
+                disabled_at = CASE WHEN $4 THEN NULL ELSE disabled_at END
+            WHERE id = $5
+            RETURNING *
+            "#,
+        )
+        .bind(subscription.url)
+        .bind(subscription.event_types)
+        .bind(subscription.secret)
+        .bind(subscription.active)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(subscription)
+    }
+
+    #[instrument(name = "WebhookRepository::delete_subscription", skip_all)]
+    async fn delete_subscription(&self, id: Uuid) -> Result<(), RepositoryError> {
+        let _timer = metrics().time_query("webhook", "delete_subscription");
+        let result = sqlx::query("DELETE FROM webhook_subscriptions WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    #[instrument(name = "WebhookRepository::list_deliveries", skip_all)]
+    async fn list_deliveries(
+        &self,
+        subscription_id: Uuid,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        let _timer = metrics().time_query("webhook", "list_deliveries");
+        let deliveries = sqlx::query_as::<_, WebhookDelivery>(
+            r#"
+            SELECT * FROM webhook_deliveries
+            WHERE subscription_id = $1
+            ORDER BY created_at DESC, id
+            LIMIT $2
+            "#,
+        )
+        .bind(subscription_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(deliveries)
+    }
+
+    #[instrument(name = "WebhookRepository::find_delivery", skip_all)]
+    async fn find_delivery(
+        &self,
+        subscription_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<WebhookDelivery>, RepositoryError> {
+        let _timer = metrics().time_query("webhook", "find_delivery");
+        let delivery = sqlx::query_as::<_, WebhookDelivery>(
+            "SELECT * FROM webhook_deliveries WHERE id = $1 AND subscription_id = $2",
+        )
+        .bind(id)
+        .bind(subscription_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(delivery)
+    }
+
+    #[instrument(name = "WebhookRepository::enqueue_event", skip_all)]
+    async fn enqueue_event(&self, event: &OutboxEvent) -> Result<u64, RepositoryError> {
+        let _timer = metrics().time_query("webhook", "enqueue_event");
+        let payload = json!({
+            "id": event.id,
+            "type": event.event_type,
+            "occurred_at": event.occurred_at,
+            "data": event.payload,
+        });
+
+        // A relay retrying the event must not queue it twice
+        let result = sqlx::query(
+            r#"
+            INSERT INTO webhook_deliveries (subscription_id, event_id, event_type, payload)
+            SELECT s.id, $1, $2, $3
+            FROM webhook_subscriptions s
+            WHERE s.active AND $2 = ANY(s.event_types)
+                AND NOT EXISTS (
+                    SELECT 1 FROM webhook_deliveries d
+                    WHERE d.subscription_id = s.id AND d.event_id = $1
+                )
+            "#,
+        )
+        .bind(event.id)
+        .bind(&event.event_type)
+        .bind(payload)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(name = "WebhookRepository::redeliver", skip_all)]
+    async fn redeliver(&self, id: Uuid) -> Result<WebhookDelivery, RepositoryError> {
+        let _timer = metrics().time_query("webhook", "redeliver");
+        let delivery = sqlx::query_as::<_, WebhookDelivery>(
+            r#"
+            INSERT INTO webhook_deliveries (subscription_id, event_id, event_type, payload)
+            SELECT subscription_id, event_id, event_type, payload
+            FROM webhook_deliveries
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(delivery)
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryQueue for PostgresWebhookRepository {
+    #[instrument(name = "WebhookDeliveryQueue::claim_due", skip_all)]
+    async fn claim_due(
+        &self,
+        limit: i64,
+        lease_until: DateTime<Utc>,
+    ) -> Result<Vec<DueWebhookDelivery>, RepositoryError> {
+        let _timer = metrics().time_query("webhook", "claim_due");
+        let deliveries = sqlx::query_as::<_, DueWebhookDelivery>(
+            r#"
+            WITH due AS (
+                SELECT d.id FROM webhook_deliveries d
+                JOIN webhook_subscriptions s ON s.id = d.subscription_id
+                WHERE d.status = 'pending'
+                    AND d.next_attempt_at <= CURRENT_TIMESTAMP
+                    AND s.active
+                ORDER BY d.next_attempt_at
+                LIMIT $1
+                FOR UPDATE OF d SKIP LOCKED
+            )
+            UPDATE webhook_deliveries d
+            SET next_attempt_at = $2
+            FROM due, webhook_subscriptions s
+            WHERE d.id = due.id AND s.id = d.subscription_id
+            RETURNING d.*, s.url, s.secret
+            "#,
+        )
+        .bind(limit)
+        .bind(lease_until)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(deliveries)
+    }
+
+    #[instrument(name = "WebhookDeliveryQueue::record_attempt", skip_all)]
+    async fn record_attempt(
+        &self,
+        id: Uuid,
+        outcome: WebhookAttemptOutcome,
+        disable_after: i32,
+    ) -> Result<bool, RepositoryError> {
+        let _timer = metrics().time_query("webhook", "record_attempt");
+        let (status, response_status, error, retry_at) = match outcome {
+            WebhookAttemptOutcome::Succeeded { response_status } => (
+                WebhookDeliveryStatus::Succeeded,
+                Some(response_status),
+                None,
+                None,
+            ),
+            WebhookAttemptOutcome::Failed {
+                response_status,
+                error,
+                retry_at: Some(retry_at),
+            } => (
+                WebhookDeliveryStatus::Pending,
+                response_status,
+                Some(error),
+                Some(retry_at),
+            ),
+            WebhookAttemptOutcome::Failed {
+                response_status,
+                error,
+                retry_at: None,
+            } => (
+                WebhookDeliveryStatus::Failed,
+                response_status,
+                Some(error),
+                None,
+            ),
+        };
+
+        let mut conn = acquire(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let subscription_id = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            UPDATE webhook_deliveries
+            SET
+                status = $1,
+                attempts = attempts + 1,
+                last_attempt_at = CURRENT_TIMESTAMP,
+                next_attempt_at = COALESCE($2, next_attempt_at),
+                response_status = $3,
+                last_error = $4
+            WHERE id = $5
+            RETURNING subscription_id
+            "#,
+        )
+        .bind(status)
+        .bind(retry_at)
+        .bind(response_status)
+        .bind(error)
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+        .ok_or(RepositoryError::NotFound)?;
+
+        let disabled = if status == WebhookDeliveryStatus::Succeeded {
+            sqlx::query(
+                r#"
+                UPDATE webhook_subscriptions
+                SET consecutive_failures = 0
+                WHERE id = $1 AND consecutive_failures > 0
+                "#,
+            )
+            .bind(subscription_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+            false
+        } else {
+            sqlx::query_scalar::<_, bool>(
+                r#"
+                UPDATE webhook_subscriptions
+                SET
+                    consecutive_failures = consecutive_failures + 1,
+                    active = active AND consecutive_failures + 1 < $1,
+                    disabled_at = CASE
+                        WHEN active AND consecutive_failures + 1 >= $1 THEN CURRENT_TIMESTAMP
+                        ELSE disabled_at
+                    END
+                WHERE id = $2
+                -- Whether this attempt disabled it
+                RETURNING COALESCE(disabled_at = CURRENT_TIMESTAMP, FALSE)
+                "#,
+            )
+            .bind(disable_after)
+            .bind(subscription_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+            .unwrap_or(false)
+        };
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(disabled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn repository() -> PostgresWebhookRepository {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        let pool = PgPool::connect(&url)
+            .await
+            .expect("Database is not reachable");
+        PostgresWebhookRepository::new(pool)
+    }
+
+    /// A subscription with one queued delivery, to an event type no one else uses
+    async fn queued(repository: &PostgresWebhookRepository) -> (Uuid, Uuid) {
+        let event_type = format!("test.{}", Uuid::new_v4().simple());
+        let subscription = repository
+            .create_subscription(CreateWebhookSubscriptionDto {
+                url: "http://127.0.0.1:9/hooks".to_string(),
+                event_types: vec![event_type.clone()],
+                secret: Some("whsec_test".to_string()),
+            })
+            .await
+            .unwrap();
+        let event = OutboxEvent {
+            id: Uuid::new_v4(),
+            sequence: 0,
+            event_type,
+            aggregate_id: Uuid::new_v4(),
+            payload: json!({}),
+            occurred_at: Utc::now(),
+            attempts: 0,
+            available_at: Utc::now(),
+            published_at: None,
+            last_error: None,
+        };
+        assert_eq!(repository.enqueue_event(&event).await.unwrap(), 1);
+        let delivery = repository
+            .list_deliveries(subscription.id, 1)
+            .await
+            .unwrap();
+
+        (subscription.id, delivery[0].id)
+    }
+
+    fn failed(retry_at: Option<DateTime<Utc>>) -> WebhookAttemptOutcome {
+        WebhookAttemptOutcome::Failed {
+            response_status: Some(503),
+            error: "Endpoint answered 503".to_string(),
+            retry_at,
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Postgres at DATABASE_URL"]
+    async fn reschedules_failures_and_dead_letters_the_last_one() {
+        let repository = repository().await;
+        let (subscription_id, delivery_id) = queued(&repository).await;
+
+        let retry_at = Utc::now() + chrono::Duration::seconds(30);
+        repository
+            .record_attempt(delivery_id, failed(Some(retry_at)), 20)
+            .await
+            .unwrap();
+        let delivery = repository
+            .find_delivery(subscription_id, delivery_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Pending);
+        assert_eq!(delivery.attempts, 1);
+        assert_eq!(delivery.response_status, Some(503));
+        assert!(
+            (delivery.next_attempt_at - retry_at)
+                .num_milliseconds()
+                .abs()
+                < 1
+        );
+
+        repository
+            .record_attempt(delivery_id, failed(None), 20)
+            .await
+            .unwrap();
+        let delivery = repository
+            .find_delivery(subscription_id, delivery_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Failed);
+        assert_eq!(delivery.attempts, 2);
+
+        repository
+            .delete_subscription(subscription_id)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Postgres at DATABASE_URL"]
+    async fn disables_subscriptions_after_failures_in_a_row() {
+        let repository = repository().await;
+        let (subscription_id, delivery_id) = queued(&repository).await;
+        let retry_at = Some(Utc::now());
+
+        // A success in between starts the count over
+        assert!(!repository
+            .record_attempt(delivery_id, failed(retry_at), 3)
+            .await
+            .unwrap());
+        repository
+            .record_attempt(
+                delivery_id,
+                WebhookAttemptOutcome::Succeeded {
+                    response_status: 200,
+                },
+                3,
+            )
+            .await
+            .unwrap();
+        for _ in 0..2 {
+            assert!(!repository
+                .record_attempt(delivery_id, failed(retry_at), 3)
+                .await
+                .unwrap());
+        }
+        assert!(repository
+            .record_attempt(delivery_id, failed(retry_at), 3)
+            .await
+            .unwrap());
+
+        let subscription = repository
+            .find_subscription(subscription_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!subscription.active);
+        assert!(subscription.disabled_at.is_some());
+        assert_eq!(subscription.consecutive_failures, 3);
+
+        // Only the attempt crossing the limit reports disabling it
+        assert!(!repository
+            .record_attempt(delivery_id, failed(retry_at), 3)
+            .await
+            .unwrap());
+
+        repository
+            .delete_subscription(subscription_id)
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,5 @@
+pub mod runtime;
+pub mod task;
+
+pub use runtime::Scheduler;
+pub use task::{CronTask, TaskError};
@@ -0,0 +1,245 @@
+use chrono::{DateTime, Utc};
+use cron::Schedule;
+use log::{debug, error, info, warn};
+use sqlx::PgPool;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio_util::sync::CancellationToken;
+use tracing::{info_span, Instrument};
+
+use super::task::CronTask;
+use crate::{
+    domain::{entities::scheduled_task::TaskRunStatus, repositories::ScheduledTaskRepository},
+    infrastructure::{persistence::postgres::PostgresScheduledTaskRepository, shutdown::Shutdown},
+};
+
+struct Entry {
+    expression: String,
+    schedule: Schedule,
+    task: Arc<dyn CronTask>,
+}
+
+/// Runs registered tasks on cron expressions (with seconds, in UTC). Every replica
+/// runs the scheduler, a Postgres advisory lock lets only one of them run a task
+pub struct Scheduler {
+    pool: PgPool,
+    entries: Vec<Entry>,
+}
+
+impl Scheduler {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Runs the task on the schedule, e.g. `0 30 3 * * *` for 03:30 every day
+    pub fn task<T: CronTask>(mut self, expression: &str, task: T) -> Self {
+        let schedule = Schedule::from_str(expression).unwrap_or_else(|e| {
+            panic!(
+                "Invalid schedule '{}' for task {}: {}",
+                expression,
+                task.name(),
+                e
+            )
+        });
+
+        self.entries.push(Entry {
+            expression: expression.to_string(),
+            schedule,
+            task: Arc::new(task),
+        });
+        self
+    }
+
+    /// Starts a timer per task. On shutdown no new runs start, running ones finish
+    pub fn spawn(self, shutdown: &Shutdown) {
+        for entry in self.entries {
+            info!(
+                "Scheduling task {} on '{}'",
+                entry.task.name(),
+                entry.expression
+            );
+            shutdown.spawn(run_entry(self.pool.clone(), entry, shutdown.stopping()));
+        }
+    }
+}
+
+async fn run_entry(pool: PgPool, entry: Entry, stopping: CancellationToken) {
+    let repository = PostgresScheduledTaskRepository::new(pool.clone());
+    let name = entry.task.name();
+
+    if let Err(e) = repository
+        .register(name, &entry.expression, entry.schedule.upcoming(Utc).next())
+        .await
+    {
+        error!("Error registering scheduled task {}: {:?}", name, e);
+    }
+
+    // A run that overlaps the next fire time skips it
+    while let Some(fire_at) = entry.schedule.upcoming(Utc).next() {
+        let wait = (fire_at - Utc::now()).to_std().unwrap_or_default();
+        tokio::select! {
+            _ = actix_web::rt::time::sleep(wait) => {}
+            _ = stopping.cancelled() => break,
+        }
+
+        run_once(&pool, &repository, &entry, fire_at).await;
+    }
+}
+
+async fn run_once(
+    pool: &PgPool,
+    repository: &PostgresScheduledTaskRepository,
+    entry: &Entry,
+    fire_at: DateTime<Utc>,
+) {
+    let name = entry.task.name();
+    let lock_key = format!("scheduled_task:{}", name);
+
+    // Transaction level lock, it ends with the transaction even when the task panics
+    // and the connection goes back to the pool
+    let mut lock = match pool.begin().await {
+        Ok(lock) => lock,
+        Err(e) => {
+            error!(
+                "Error starting the lock transaction of task {}: {:?}",
+                name, e
+            );
+            return;
+        }
+    };
+    let locked =
+        sqlx::query_scalar::<_, bool>("SELECT pg_try_advisory_xact_lock(hashtextextended($1, 0))")
+            .bind(&lock_key)
+            .fetch_one(&mut *lock)
+            .await;
+
+    match locked {
+        Ok(true) => {}
+        Ok(false) => {
+            debug!("Task {} is running on another replica, skipping", name);
+            return;
+        }
+        Err(e) => {
+            error!("Error locking task {}: {:?}", name, e);
+            return;
+        }
+    }
+
+    // Another replica may already have run this fire time and released the lock
+    match repository
+        .start_run(name, fire_at, entry.schedule.after(&fire_at).next())
+        .await
+    {
+        Ok(true) => {
+            let started = Instant::now();
+            let result = entry
+                .task
+                .run()
+                .instrument(info_span!(
+                    "scheduled task",
+                    otel.name = %format!("task {}", name),
+                    otel.kind = "consumer",
+                    task.name = name,
+                ))
+                .await;
+            let duration_ms = started.elapsed().as_millis() as i64;
+
+            let (status, error) = match &result {
+                Ok(()) => (TaskRunStatus::Succeeded, None),
+                Err(e) => {
+                    warn!("Scheduled task {} failed: {}", name, e);
+                    (TaskRunStatus::Failed, Some(e.to_string()))
+                }
+            };
+            if let Err(e) = repository
+                .finish_run(name, status, error.as_deref(), duration_ms)
+                .await
+            {
+                error!("Error recording the run of task {}: {:?}", name, e);
+            }
+        }
+        Ok(false) => debug!("Task {} already ran for {}", name, fire_at),
+        Err(e) => error!("Error starting task {}: {:?}", name, e),
+    }
+
+    // Nothing was written in the transaction, ending it releases the lock
+    if let Err(e) = lock.rollback().await {
+        error!("Error unlocking task {}: {:?}", name, e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::scheduler::task::TaskError;
+    use async_trait::async_trait;
+    use sqlx::Connection;
+
+    struct PanickingTask;
+
+    #[async_trait]
+    impl CronTask for PanickingTask {
+        fn name(&self) -> &'static str {
+            "scheduler_test_panicking_task"
+        }
+
+        async fn run(&self) -> Result<(), TaskError> {
+            panic!("the task failed badly");
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Postgres at DATABASE_URL"]
+    async fn a_panicking_task_releases_its_lock() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        let pool = PgPool::connect(&url)
+            .await
+            .expect("Database is not reachable");
+        let repository = PostgresScheduledTaskRepository::new(pool.clone());
+        let entry = Entry {
+            expression: "0 0 * * * *".to_string(),
+            schedule: Schedule::from_str("0 0 * * * *").unwrap(),
+            task: Arc::new(PanickingTask),
+        };
+        let name = entry.task.name();
+        repository
+            .register(name, &entry.expression, None)
+            .await
+            .unwrap();
+
+        let run = tokio::spawn({
+            let pool = pool.clone();
+            async move { run_once(&pool, &repository, &entry, Utc::now()).await }
+        });
+        assert!(run.await.unwrap_err().is_panic());
+
+        // Another session can take the lock once the connection is back in the pool,
+        // which happens in the background. Sessions may take a lock they hold again, so
+        // the check needs its own
+        let mut other = sqlx::PgConnection::connect(&url).await.unwrap();
+        let mut locked = false;
+        for _ in 0..50 {
+            locked = sqlx::query_scalar("SELECT pg_try_advisory_lock(hashtextextended($1, 0))")
+                .bind(format!("scheduled_task:{}", name))
+                .fetch_one(&mut other)
+                .await
+                .unwrap();
+            if locked {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        assert!(locked);
+        other.close().await.unwrap();
+
+        sqlx::query("DELETE FROM scheduled_tasks WHERE name = $1")
+            .bind(name)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+
+use crate::application::error::ApplicationError;
+
+#[derive(thiserror::Error, Debug)]
+#[error("{0}")]
+pub struct TaskError(pub String);
+
+impl From<ApplicationError> for TaskError {
+    fn from(e: ApplicationError) -> Self {
+        Self(e.to_string())
+    }
+}
+
+/// Periodic work run by the scheduler on one replica at a time
+#[async_trait]
+pub trait CronTask: Send + Sync + 'static {
+    /// Unique name, keying the task's lock and status
+    fn name(&self) -> &'static str;
+    async fn run(&self) -> Result<(), TaskError>;
+}
@@ -0,0 +1,56 @@
+use std::future::Future;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tokio_util::task::TaskTracker;
+
+/// Coordinates shutdown of long-lived work, cheap to clone into each task and request.
+/// Streams end once the server starts draining connections, background tasks are
+/// asked to stop after in-flight requests drained
+#[derive(Clone, Default)]
+pub struct Shutdown {
+    draining: CancellationToken,
+    stopping: CancellationToken,
+    tasks: TaskTracker,
+}
+
+impl Shutdown {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs a background task that is waited for on shutdown. It should return soon
+    /// after `stopping` is cancelled, finishing the work at hand
+    pub fn spawn<F>(&self, task: F)
+    where
+        F: Future<Output = ()> + 'static,
+    {
+        actix_web::rt::spawn(self.tasks.track_future(task));
+    }
+
+    /// Cancelled when the server stops accepting connections
+    pub fn draining(&self) -> CancellationToken {
+        self.draining.clone()
+    }
+
+    /// Cancelled when background tasks should stop
+    pub fn stopping(&self) -> CancellationToken {
+        self.stopping.clone()
+    }
+
+    /// Ends open streams such as WebSockets so in-flight requests can drain
+    pub fn begin_draining(&self) {
+        self.draining.cancel();
+    }
+
+    /// Asks background tasks to stop and waits for them, `false` if some were still
+    /// running after `timeout`
+    pub async fn stop(&self, timeout: Duration) -> bool {
+        self.draining.cancel();
+        self.stopping.cancel();
+        self.tasks.close();
+
+        actix_web::rt::time::timeout(timeout, self.tasks.wait())
+            .await
+            .is_ok()
+    }
+}
@@ -0,0 +1,85 @@
+use async_trait::async_trait;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use crate::domain::gateways::{BlobStorage, BlobStorageError};
+
+/// Stores blobs as files below a root directory
+pub struct LocalBlobStorage {
+    root: PathBuf,
+    base_url: String,
+}
+
+impl LocalBlobStorage {
+    /// Creates the root directory if needed so it can be served right away
+    pub fn new(root: impl Into<PathBuf>, base_url: &str) -> Self {
+        let root = root.into();
+        if let Err(e) = std::fs::create_dir_all(&root) {
+            panic!("Cannot create media directory {}: {}", root.display(), e);
+        }
+
+        Self {
+            root,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    fn path(&self, key: &str) -> Result<PathBuf, BlobStorageError> {
+        // Keys are generated by the application, but never let one escape the root
+        if key
+            .split('/')
+            .any(|segment| segment.is_empty() || segment == "." || segment == "..")
+        {
+            return Err(BlobStorageError::Backend(format!(
+                "Invalid blob key: {key}"
+            )));
+        }
+        Ok(self.root.join(key))
+    }
+}
+
+#[async_trait]
+impl BlobStorage for LocalBlobStorage {
+    async fn put(
+        &self,
+        key: &str,
+        _content_type: &str,
+        data: Vec<u8>,
+    ) -> Result<(), BlobStorageError> {
+        let path = self.path(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| BlobStorageError::Backend(e.to_string()))?;
+        }
+
+        tokio::fs::write(&path, data)
+            .await
+            .map_err(|e| BlobStorageError::Backend(e.to_string()))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), BlobStorageError> {
+        match tokio::fs::remove_file(self.path(key)?).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Err(BlobStorageError::NotFound),
+            Err(e) => Err(BlobStorageError::Backend(e.to_string())),
+        }
+    }
+
+    async fn check(&self) -> Result<(), BlobStorageError> {
+        let metadata = tokio::fs::metadata(&self.root)
+            .await
+            .map_err(|e| BlobStorageError::Backend(e.to_string()))?;
+        if !metadata.is_dir() || metadata.permissions().readonly() {
+            return Err(BlobStorageError::Backend(format!(
+                "Media directory {} is not a writable directory",
+                self.root.display()
+            )));
+        }
+        Ok(())
+    }
+
+    fn url(&self, key: &str) -> String {
+        format!("{}/{}", self.base_url, key)
+    }
+}
@@ -0,0 +1,65 @@
+pub mod local;
+#[cfg(feature = "s3")]
+pub mod s3;
+
+pub use local::LocalBlobStorage;
+#[cfg(feature = "s3")]
+pub use s3::S3BlobStorage;
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::config::media::{MediaBackend, MediaConfig};
+use crate::domain::gateways::{BlobStorage, BlobStorageError};
+
+/// The blob storage selected by `MEDIA_STORAGE`, cheap to clone into each request
+#[derive(Clone)]
+pub enum ConfiguredStorage {
+    Local(Arc<LocalBlobStorage>),
+    #[cfg(feature = "s3")]
+    S3(Arc<S3BlobStorage>),
+}
+
+impl ConfiguredStorage {
+    pub fn new(config: &MediaConfig) -> Self {
+        match &config.backend {
+            MediaBackend::Local { dir } => {
+                Self::Local(Arc::new(LocalBlobStorage::new(dir, &config.base_url)))
+            }
+            #[cfg(feature = "s3")]
+            MediaBackend::S3(s3) => Self::S3(Arc::new(S3BlobStorage::new(s3, &config.base_url))),
+        }
+    }
+
+    fn backend(&self) -> &dyn BlobStorage {
+        match self {
+            Self::Local(storage) => storage.as_ref(),
+            #[cfg(feature = "s3")]
+            Self::S3(storage) => storage.as_ref(),
+        }
+    }
+}
+
+#[async_trait]
+impl BlobStorage for ConfiguredStorage {
+    async fn put(
+        &self,
+        key: &str,
+        content_type: &str,
+        data: Vec<u8>,
+    ) -> Result<(), BlobStorageError> {
+        self.backend().put(key, content_type, data).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), BlobStorageError> {
+        self.backend().delete(key).await
+    }
+
+    async fn check(&self) -> Result<(), BlobStorageError> {
+        self.backend().check().await
+    }
+
+    fn url(&self, key: &str) -> String {
+        self.backend().url(key)
+    }
+}
@@ -0,0 +1,167 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use reqwest::{Client, Method, StatusCode};
+use sha2::{Digest, Sha256};
+
+use crate::config::media::S3Config;
+use crate::domain::gateways::{BlobStorage, BlobStorageError};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Stores blobs in an S3-compatible bucket using path-style requests signed with SigV4
+pub struct S3BlobStorage {
+    client: Client,
+    config: S3Config,
+    base_url: String,
+}
+
+impl S3BlobStorage {
+    pub fn new(config: &S3Config, base_url: &str) -> Self {
+        Self {
+            client: Client::new(),
+            config: S3Config {
+                endpoint: config.endpoint.trim_end_matches('/').to_string(),
+                ..config.clone()
+            },
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        key: &str,
+        content_type: Option<&str>,
+        body: Vec<u8>,
+    ) -> Result<StatusCode, BlobStorageError> {
+        let path = format!("/{}/{}", self.config.bucket, key);
+        let url = format!("{}{}", self.config.endpoint, path);
+        let host = url
+            .split("://")
+            .nth(1)
+            .and_then(|rest| rest.split('/').next())
+            .unwrap_or_default()
+            .to_string();
+
+        let now = Utc::now();
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let date = now.format("%Y%m%d").to_string();
+        let payload_hash = hex::encode(Sha256::digest(&body));
+
+        // Canonical request with the headers listed alphabetically
+        let mut headers = vec![
+            ("host", host),
+            ("x-amz-content-sha256", payload_hash.clone()),
+            ("x-amz-date", amz_date.clone()),
+        ];
+        if let Some(content_type) = content_type {
+            headers.insert(0, ("content-type", content_type.to_string()));
+        }
+        let canonical_headers: String = headers
+            .iter()
+            .map(|(name, value)| format!("{name}:{value}\n"))
+            .collect();
+        let signed_headers = headers
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(";");
+        let canonical_request = format!(
+            "{}\n{}\n\n{}\n{}\n{}",
+            method, path, canonical_headers, signed_headers, payload_hash
+        );
+
+        let scope = format!("{}/{}/s3/aws4_request", date, self.config.region);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date,
+            scope,
+            hex::encode(Sha256::digest(canonical_request.as_bytes()))
+        );
+
+        let mut key_material = sign(
+            format!("AWS4{}", self.config.secret_access_key).as_bytes(),
+            &date,
+        );
+        for part in [self.config.region.as_str(), "s3", "aws4_request"] {
+            key_material = sign(&key_material, part);
+        }
+        let signature = hex::encode(sign(&key_material, &string_to_sign));
+
+        let authorization = format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            self.config.access_key_id, scope, signed_headers, signature
+        );
+
+        let mut request = self
+            .client
+            .request(method, &url)
+            .header("x-amz-content-sha256", payload_hash)
+            .header("x-amz-date", amz_date)
+            .header("authorization", authorization)
+            .body(body);
+        if let Some(content_type) = content_type {
+            request = request.header("content-type", content_type);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| BlobStorageError::Backend(e.to_string()))?;
+
+        Ok(response.status())
+    }
+}
+
+fn sign(key: &[u8], message: &str) -> Vec<u8> {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(message.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+#[async_trait]
+impl BlobStorage for S3BlobStorage {
+    async fn put(
+        &self,
+        key: &str,
+        content_type: &str,
+        data: Vec<u8>,
+    ) -> Result<(), BlobStorageError> {
+        let status = self
+            .send(Method::PUT, key, Some(content_type), data)
+            .await?;
+        if !status.is_success() {
+            return Err(BlobStorageError::Backend(format!(
+                "S3 upload of {key} failed with status {status}"
+            )));
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), BlobStorageError> {
+        match self.send(Method::DELETE, key, None, Vec::new()).await? {
+            StatusCode::NOT_FOUND => Err(BlobStorageError::NotFound),
+            status if status.is_success() => Ok(()),
+            status => Err(BlobStorageError::Backend(format!(
+                "S3 delete of {key} failed with status {status}"
+            ))),
+        }
+    }
+
+    async fn check(&self) -> Result<(), BlobStorageError> {
+        // An empty key addresses the bucket itself
+        let status = self.send(Method::HEAD, "", None, Vec::new()).await?;
+        if !status.is_success() {
+            return Err(BlobStorageError::Backend(format!(
+                "S3 bucket {} answered with status {status}",
+                self.config.bucket
+            )));
+        }
+        Ok(())
+    }
+
+    fn url(&self, key: &str) -> String {
+        format!("{}/{}", self.base_url, key)
+    }
+}
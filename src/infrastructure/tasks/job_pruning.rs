@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use log::info;
+use sqlx::PgPool;
+use std::time::Duration;
+
+use crate::{
+    application::use_cases::{job::PruneJobsUseCase, UseCase},
+    infrastructure::{
+        persistence::postgres::PostgresJobRepository,
+        scheduler::{CronTask, TaskError},
+    },
+};
+
+/// How long succeeded and cancelled jobs stay listed
+const JOB_RETENTION: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Removes finished jobs past their retention period
+pub struct PruneJobsTask {
+    pool: PgPool,
+}
+
+impl PruneJobsTask {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CronTask for PruneJobsTask {
+    fn name(&self) -> &'static str {
+        "jobs.prune"
+    }
+
+    async fn run(&self) -> Result<(), TaskError> {
+        let use_case = PruneJobsUseCase::new(PostgresJobRepository::new(self.pool.clone()));
+        let retention = chrono::Duration::from_std(JOB_RETENTION)
+            .expect("retention must fit a chrono Duration");
+
+        match use_case.execute(retention).await? {
+            0 => {}
+            count => info!("Pruned {} finished jobs", count),
+        }
+        Ok(())
+    }
+}
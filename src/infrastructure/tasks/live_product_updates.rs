@@ -0,0 +1,157 @@
+use log::warn;
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::{
+    domain::{entities::live_update::LiveUpdate, gateways::UpdateBus},
+    infrastructure::{
+        events::{BroadcastEventBus, BroadcastUpdateBus},
+        shutdown::Shutdown,
+    },
+};
+
+/// Publishes the product events the outbox relay hands to the bus as live updates, so
+/// subscribers see every committed product change whichever write path made it
+pub fn spawn_live_product_updates(
+    events: &BroadcastEventBus,
+    updates: BroadcastUpdateBus,
+    shutdown: &Shutdown,
+) {
+    let mut received = events.subscribe();
+    let stopping = shutdown.stopping();
+    shutdown.spawn(async move {
+        loop {
+            let event = tokio::select! {
+                event = received.recv() => event,
+                _ = stopping.cancelled() => break,
+            };
+            match event {
+                Ok(event) => {
+                    if let Some(update) = LiveUpdate::from_event(&event) {
+                        updates.publish(update);
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!(
+                        "Live product updates fell behind, {} events skipped",
+                        skipped
+                    )
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        application::use_cases::{outbox::RelayOutboxUseCase, UseCase},
+        domain::entities::{domain_event::DomainEvent, product::Product},
+        infrastructure::persistence::postgres::{
+            outbox_repository::record_events, PostgresOutboxRepository,
+        },
+    };
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+    use sqlx::PgPool;
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    fn product() -> Product {
+        Product {
+            id: Uuid::new_v4(),
+            sku: None,
+            name: "Bus".to_string(),
+            description: "Relayed".to_string(),
+            category: None,
+            price: dec!(9.99),
+            currency: "USD".to_string(),
+            stock: 3,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[actix_web::test]
+    #[ignore = "needs Postgres at DATABASE_URL"]
+    async fn committed_product_events_reach_live_subscribers() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        let pool = PgPool::connect(&url)
+            .await
+            .expect("Database is not reachable");
+        let events = BroadcastEventBus::new();
+        let updates = BroadcastUpdateBus::new();
+        let mut received = updates.subscribe();
+        let shutdown = Shutdown::new();
+        spawn_live_product_updates(&events, updates, &shutdown);
+
+        let committed = product();
+        let mut tx = pool.begin().await.unwrap();
+        record_events(&mut tx, &[DomainEvent::ProductUpdated(committed.clone())])
+            .await
+            .unwrap();
+        tx.commit().await.unwrap();
+        let rolled_back = Uuid::new_v4();
+        let mut tx = pool.begin().await.unwrap();
+        record_events(&mut tx, &[DomainEvent::ProductDeleted(rolled_back)])
+            .await
+            .unwrap();
+        tx.rollback().await.unwrap();
+
+        let relay = RelayOutboxUseCase::new(
+            PostgresOutboxRepository::new(pool),
+            vec![Box::new(events.clone())],
+        );
+        while relay.execute(()).await.unwrap() > 0 {}
+
+        let mut seen = Vec::new();
+        while let Ok(Ok(update)) =
+            tokio::time::timeout(Duration::from_millis(200), received.recv()).await
+        {
+            match update.as_ref() {
+                LiveUpdate::ProductUpdated { product } if product.id == committed.id => {
+                    assert_eq!(product.stock, committed.stock);
+                    seen.push(product.id);
+                }
+                LiveUpdate::ProductDeleted { product_id } => {
+                    assert_ne!(*product_id, rolled_back, "a rolled back event was relayed")
+                }
+                _ => {}
+            }
+        }
+        assert_eq!(seen, vec![committed.id]);
+        assert!(shutdown.stop(Duration::from_secs(1)).await);
+    }
+
+    #[test]
+    fn only_product_events_become_live_updates() {
+        let product = product();
+        let event =
+            |domain_event: DomainEvent| crate::domain::entities::domain_event::OutboxEvent {
+                id: Uuid::new_v4(),
+                sequence: 1,
+                event_type: domain_event.event_type().to_string(),
+                aggregate_id: domain_event.aggregate_id(),
+                payload: domain_event.payload(),
+                occurred_at: Utc::now(),
+                attempts: 0,
+                available_at: Utc::now(),
+                published_at: None,
+                last_error: None,
+            };
+
+        match LiveUpdate::from_event(&event(DomainEvent::ProductCreated(product.clone()))) {
+            Some(LiveUpdate::ProductCreated { product: created }) => {
+                assert_eq!(created.id, product.id);
+                assert_eq!(created.price, product.price);
+            }
+            other => panic!("expected product_created, got {other:?}"),
+        }
+        assert!(matches!(
+            LiveUpdate::from_event(&event(DomainEvent::ProductDeleted(product.id))),
+            Some(LiveUpdate::ProductDeleted { product_id }) if product_id == product.id
+        ));
+        assert!(LiveUpdate::from_event(&event(DomainEvent::UserDeleted(product.id))).is_none());
+    }
+}
@@ -0,0 +1,15 @@
+pub mod job_pruning;
+pub mod live_product_updates;
+pub mod outbox_pruning;
+pub mod outbox_relay;
+pub mod price_activation;
+pub mod product_event_pruning;
+pub mod webhook_delivery;
+
+pub use job_pruning::PruneJobsTask;
+pub use live_product_updates::spawn_live_product_updates;
+pub use outbox_pruning::PruneOutboxTask;
+pub use outbox_relay::spawn_outbox_relay;
+pub use price_activation::ActivatePricesTask;
+pub use product_event_pruning::PruneProductEventsTask;
+pub use webhook_delivery::spawn_webhook_delivery;
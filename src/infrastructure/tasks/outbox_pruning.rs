@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use log::info;
+use sqlx::PgPool;
+use std::time::Duration;
+
+use crate::{
+    application::use_cases::{outbox::PruneOutboxUseCase, UseCase},
+    infrastructure::{
+        persistence::postgres::PostgresOutboxRepository,
+        scheduler::{CronTask, TaskError},
+    },
+};
+
+/// How long published events stay in the outbox for inspection
+const EVENT_RETENTION: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Removes published outbox events past their retention period
+pub struct PruneOutboxTask {
+    pool: PgPool,
+}
+
+impl PruneOutboxTask {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CronTask for PruneOutboxTask {
+    fn name(&self) -> &'static str {
+        "outbox.prune"
+    }
+
+    async fn run(&self) -> Result<(), TaskError> {
+        let use_case = PruneOutboxUseCase::new(PostgresOutboxRepository::new(self.pool.clone()));
+        let retention = chrono::Duration::from_std(EVENT_RETENTION)
+            .expect("retention must fit a chrono Duration");
+
+        match use_case.execute(retention).await? {
+            0 => {}
+            count => info!("Pruned {} published outbox events", count),
+        }
+        Ok(())
+    }
+}
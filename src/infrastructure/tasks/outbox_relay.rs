@@ -0,0 +1,63 @@
+use log::error;
+use sqlx::PgPool;
+use std::time::Duration;
+
+use crate::{
+    application::use_cases::{outbox::RelayOutboxUseCase, UseCase},
+    config::{outbox::OutboxSink, OutboxConfig},
+    domain::gateways::EventSink,
+    infrastructure::{
+        events::{BroadcastEventBus, LogEventSink},
+        persistence::postgres::{PostgresOutboxRepository, PostgresWebhookRepository},
+        shutdown::Shutdown,
+        webhooks::WebhookEventSink,
+    },
+};
+
+/// How often the outbox is checked for pending events
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Relays outbox events to the configured sinks, draining the outbox while events are pending
+pub fn spawn_outbox_relay(
+    pool: PgPool,
+    config: &OutboxConfig,
+    bus: BroadcastEventBus,
+    shutdown: &Shutdown,
+) {
+    let sinks: Vec<Box<dyn EventSink>> = config
+        .sinks
+        .iter()
+        .map(|sink| -> Box<dyn EventSink> {
+            match sink {
+                OutboxSink::Bus => Box::new(bus.clone()),
+                OutboxSink::Webhooks => Box::new(WebhookEventSink::new(
+                    PostgresWebhookRepository::new(pool.clone()),
+                )),
+                OutboxSink::Log => Box::new(LogEventSink),
+            }
+        })
+        .collect();
+
+    let stopping = shutdown.stopping();
+    shutdown.spawn(async move {
+        let use_case = RelayOutboxUseCase::new(PostgresOutboxRepository::new(pool), sinks);
+        let mut interval = actix_web::rt::time::interval(POLL_INTERVAL);
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {}
+                _ = stopping.cancelled() => break,
+            }
+            while !stopping.is_cancelled() {
+                match use_case.execute(()).await {
+                    Ok(0) => break,
+                    Ok(_) => {}
+                    Err(e) => {
+                        error!("Error relaying outbox events: {:?}", e);
+                        break;
+                    }
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+use log::info;
+use sqlx::PgPool;
+
+use crate::{
+    application::use_cases::{product::ActivateScheduledPricesUseCase, UseCase},
+    infrastructure::{
+        persistence::postgres::PostgresProductPriceRepository,
+        scheduler::{CronTask, TaskError},
+    },
+};
+
+/// Applies scheduled price changes that became due to their products
+pub struct ActivatePricesTask {
+    pool: PgPool,
+}
+
+impl ActivatePricesTask {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CronTask for ActivatePricesTask {
+    fn name(&self) -> &'static str {
+        "product_prices.activate"
+    }
+
+    async fn run(&self) -> Result<(), TaskError> {
+        let use_case = ActivateScheduledPricesUseCase::new(PostgresProductPriceRepository::new(
+            self.pool.clone(),
+        ));
+
+        match use_case.execute(()).await? {
+            0 => {}
+            count => info!("Activated scheduled prices for {} products", count),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+use log::info;
+use sqlx::PgPool;
+use std::time::Duration;
+
+use crate::{
+    application::use_cases::{product_event::PruneProductEventsUseCase, UseCase},
+    infrastructure::{
+        persistence::postgres::PostgresProductEventRepository,
+        scheduler::{CronTask, TaskError},
+    },
+};
+
+/// How long product events stay available for resuming SSE clients
+const EVENT_RETENTION: Duration = Duration::from_secs(60 * 60);
+
+/// Removes product events past their retention period
+pub struct PruneProductEventsTask {
+    pool: PgPool,
+}
+
+impl PruneProductEventsTask {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CronTask for PruneProductEventsTask {
+    fn name(&self) -> &'static str {
+        "product_events.prune"
+    }
+
+    async fn run(&self) -> Result<(), TaskError> {
+        let use_case =
+            PruneProductEventsUseCase::new(PostgresProductEventRepository::new(self.pool.clone()));
+        let retention = chrono::Duration::from_std(EVENT_RETENTION)
+            .expect("retention must fit a chrono Duration");
+
+        match use_case.execute(retention).await? {
+            0 => {}
+            count => info!("Pruned {} product events", count),
+        }
+        Ok(())
+    }
+}
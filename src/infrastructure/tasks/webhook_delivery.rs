@@ -0,0 +1,43 @@
+use log::error;
+use sqlx::PgPool;
+use std::time::Duration;
+
+use crate::{
+    application::use_cases::{webhook::DeliverWebhooksUseCase, UseCase},
+    infrastructure::{
+        persistence::postgres::PostgresWebhookRepository, shutdown::Shutdown,
+        webhooks::HttpWebhookSender,
+    },
+};
+
+/// How often the queue is checked for due deliveries
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Works through the webhook delivery queue, draining it while deliveries are due
+pub fn spawn_webhook_delivery(pool: PgPool, shutdown: &Shutdown) {
+    let stopping = shutdown.stopping();
+    shutdown.spawn(async move {
+        let use_case = DeliverWebhooksUseCase::new(
+            PostgresWebhookRepository::new(pool),
+            HttpWebhookSender::new(),
+        );
+        let mut interval = actix_web::rt::time::interval(POLL_INTERVAL);
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {}
+                _ = stopping.cancelled() => break,
+            }
+            while !stopping.is_cancelled() {
+                match use_case.execute(()).await {
+                    Ok(0) => break,
+                    Ok(_) => {}
+                    Err(e) => {
+                        error!("Error delivering webhooks: {:?}", e);
+                        break;
+                    }
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,179 @@
+use chrono::{SecondsFormat, Utc};
+use opentelemetry::trace::{TraceContextExt, TraceId};
+use serde_json::{Map, Value};
+use std::fmt;
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id},
+    Event, Subscriber,
+};
+use tracing_log::NormalizeEvent;
+use tracing_opentelemetry::OtelData;
+use tracing_subscriber::{
+    fmt::{format::Writer, FmtContext, FormatEvent, FormatFields},
+    layer::Context,
+    registry::{LookupSpan, SpanRef},
+    Layer,
+};
+
+use super::redact::{is_sensitive, redact_text, REDACTED};
+
+/// Writes each event as one JSON object with its level, target, message and fields,
+/// the `request_id` of the request it happened in and the trace it belongs to
+pub struct JsonFormat;
+
+impl<S, N> FormatEvent<S, N> for JsonFormat
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        // Records forwarded from the `log` crate carry their real target in fields
+        let normalized = event.normalized_metadata();
+        let metadata = normalized.as_ref().unwrap_or_else(|| event.metadata());
+
+        let mut fields = JsonFields::default();
+        event.record(&mut fields);
+
+        let mut line = Map::new();
+        line.insert(
+            "timestamp".into(),
+            Utc::now()
+                .to_rfc3339_opts(SecondsFormat::Millis, true)
+                .into(),
+        );
+        line.insert("level".into(), metadata.level().as_str().into());
+        line.insert("target".into(), metadata.target().into());
+        line.insert("message".into(), fields.message.unwrap_or_default().into());
+        if !fields.values.is_empty() {
+            line.insert("fields".into(), fields.values.into());
+        }
+
+        if let Some(scope) = ctx.event_scope() {
+            let spans: Vec<_> = scope.collect();
+            if let Some(span) = spans.first() {
+                line.insert("span".into(), span.name().into());
+            }
+            if let Some(RequestIdField(id)) = spans
+                .iter()
+                .find_map(|span| span.extensions().get::<RequestIdField>().cloned())
+            {
+                line.insert("request_id".into(), id.into());
+            }
+            if let Some(trace_id) = spans.first().and_then(trace_id) {
+                line.insert("trace_id".into(), trace_id.to_string().into());
+            }
+        }
+
+        writeln!(writer, "{}", Value::Object(line))
+    }
+}
+
+/// The trace a span belongs to, once trace export is enabled
+fn trace_id<S>(span: &SpanRef<'_, S>) -> Option<TraceId>
+where
+    S: for<'a> LookupSpan<'a>,
+{
+    let extensions = span.extensions();
+    let data = extensions.get::<OtelData>()?;
+    // A parent set after the span was created, like a remote one, replaces its own id
+    if data.parent_cx.has_active_span() {
+        Some(data.parent_cx.span().span_context().trace_id())
+    } else {
+        data.builder.trace_id
+    }
+}
+
+/// Keeps the `request_id` field of a span where [`JsonFormat`] finds it for every
+/// event inside that span
+pub struct RequestIdLayer;
+
+#[derive(Clone)]
+struct RequestIdField(String);
+
+impl<S> Layer<S> for RequestIdLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut visitor = RequestIdVisitor(None);
+        attrs.record(&mut visitor);
+
+        if let (Some(request_id), Some(span)) = (visitor.0, ctx.span(id)) {
+            span.extensions_mut().insert(RequestIdField(request_id));
+        }
+    }
+}
+
+struct RequestIdVisitor(Option<String>);
+
+impl Visit for RequestIdVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "request_id" {
+            self.0 = Some(value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "request_id" {
+            self.0 = Some(format!("{:?}", value));
+        }
+    }
+}
+
+/// Event fields as JSON values, credentials masked
+#[derive(Default)]
+struct JsonFields {
+    message: Option<String>,
+    values: Map<String, Value>,
+}
+
+impl JsonFields {
+    fn insert(&mut self, field: &Field, value: Value) {
+        let name = field.name();
+        if name.starts_with("log.") {
+            return;
+        }
+        let value = if is_sensitive(name) {
+            REDACTED.into()
+        } else {
+            value
+        };
+        self.values.insert(name.to_string(), value);
+    }
+}
+
+impl Visit for JsonFields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = Some(redact_text(value).into_owned());
+        } else {
+            self.insert(field, redact_text(value).into_owned().into());
+        }
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.insert(field, value.into());
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.insert(field, value.into());
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.insert(field, value.into());
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.insert(field, value.into());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.record_str(field, &format!("{:?}", value));
+    }
+}
@@ -0,0 +1,11 @@
+pub mod json_format;
+pub mod redact;
+pub mod sql_spans;
+pub mod stdout_exporter;
+pub mod tracer;
+
+pub use json_format::{JsonFormat, RequestIdLayer};
+pub use redact::RedactedFields;
+pub use sql_spans::SqlStatementLayer;
+pub use stdout_exporter::StdoutSpanExporter;
+pub use tracer::Telemetry;
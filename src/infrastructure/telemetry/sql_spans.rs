@@ -0,0 +1,101 @@
+use opentelemetry::{
+    trace::{Span as _, SpanKind, Tracer as _},
+    KeyValue,
+};
+use opentelemetry_sdk::trace::SdkTracer;
+use std::time::{Duration, SystemTime};
+use tracing::{
+    field::{Field, Visit},
+    Event, Subscriber,
+};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+/// Turns the event SQLx logs after each statement into a client span, a child of
+/// the repository call that ran it, carrying `db.statement`
+pub struct SqlStatementLayer {
+    tracer: SdkTracer,
+}
+
+impl SqlStatementLayer {
+    /// SQLx logs statements under this target at `DEBUG`
+    pub const TARGET: &'static str = "sqlx::query";
+
+    pub fn new(tracer: SdkTracer) -> Self {
+        Self { tracer }
+    }
+}
+
+impl<S> Layer<S> for SqlStatementLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        if event.metadata().target() != Self::TARGET {
+            return;
+        }
+
+        let mut statement = StatementFields::default();
+        event.record(&mut statement);
+
+        // The full statement is only logged, pretty-printed, when the summary cut it short
+        let sql = match statement.sql.trim() {
+            "" => statement.summary.trim_end_matches(" …").to_string(),
+            sql => sql.split_whitespace().collect::<Vec<_>>().join(" "),
+        };
+        let operation = sql.split_whitespace().next().unwrap_or("query");
+
+        let end = SystemTime::now();
+        let start = end - Duration::from_secs_f64(statement.elapsed_secs);
+        let parent = tracing::Span::current().context();
+        let mut span = self
+            .tracer
+            .span_builder(format!("postgres {}", operation.to_uppercase()))
+            .with_kind(SpanKind::Client)
+            .with_start_time(start)
+            .with_attributes([
+                KeyValue::new("db.system", "postgresql"),
+                KeyValue::new("db.operation", operation.to_uppercase()),
+                KeyValue::new("db.statement", sql.clone()),
+                KeyValue::new("db.rows_affected", statement.rows_affected as i64),
+                KeyValue::new("db.rows_returned", statement.rows_returned as i64),
+            ])
+            .start_with_context(&self.tracer, &parent);
+        span.end_with_timestamp(end);
+    }
+}
+
+#[derive(Default)]
+struct StatementFields {
+    summary: String,
+    sql: String,
+    elapsed_secs: f64,
+    rows_affected: u64,
+    rows_returned: u64,
+}
+
+impl Visit for StatementFields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "summary" => self.summary = value.to_string(),
+            "db.statement" => self.sql = value.to_string(),
+            _ => {}
+        }
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        if field.name() == "elapsed_secs" {
+            self.elapsed_secs = value;
+        }
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        match field.name() {
+            "rows_affected" => self.rows_affected = value,
+            "rows_returned" => self.rows_returned = value,
+            _ => {}
+        }
+    }
+
+    fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+}
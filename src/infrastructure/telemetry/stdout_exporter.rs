@@ -0,0 +1,51 @@
+use chrono::{DateTime, Utc};
+use futures::future::{self, BoxFuture};
+use opentelemetry::trace::Status;
+use opentelemetry_sdk::{
+    error::OTelSdkResult,
+    trace::{SpanData, SpanExporter},
+};
+use serde_json::{json, Map, Value};
+use std::io::Write;
+
+/// Writes each finished span as a JSON line on stdout
+#[derive(Debug, Default)]
+pub struct StdoutSpanExporter;
+
+impl SpanExporter for StdoutSpanExporter {
+    fn export(&mut self, batch: Vec<SpanData>) -> BoxFuture<'static, OTelSdkResult> {
+        let mut stdout = std::io::stdout().lock();
+        for span in batch {
+            let _ = writeln!(stdout, "{}", span_json(&span));
+        }
+        Box::pin(future::ready(Ok(())))
+    }
+}
+
+fn span_json(span: &SpanData) -> Value {
+    let attributes: Map<String, Value> = span
+        .attributes
+        .iter()
+        .map(|kv| (kv.key.to_string(), Value::String(kv.value.to_string())))
+        .collect();
+    let duration = span
+        .end_time
+        .duration_since(span.start_time)
+        .unwrap_or_default();
+
+    json!({
+        "trace_id": span.span_context.trace_id().to_string(),
+        "span_id": span.span_context.span_id().to_string(),
+        "parent_span_id": span.parent_span_id.to_string(),
+        "name": span.name,
+        "kind": format!("{:?}", span.span_kind),
+        "start_time": DateTime::<Utc>::from(span.start_time),
+        "duration_ms": duration.as_secs_f64() * 1000.0,
+        "status": match &span.status {
+            Status::Unset => "unset".into(),
+            Status::Ok => "ok".into(),
+            Status::Error { description } => format!("error: {description}"),
+        },
+        "attributes": attributes,
+    })
+}
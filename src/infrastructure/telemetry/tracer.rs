@@ -0,0 +1,146 @@
+use log::{error, info};
+use opentelemetry::{
+    global,
+    trace::{
+        Link, SamplingDecision, SamplingResult, SpanKind, TraceContextExt, TraceId, TraceState,
+        TracerProvider as _,
+    },
+    Context, KeyValue,
+};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::{
+    propagation::TraceContextPropagator,
+    trace::{Sampler, SdkTracerProvider, ShouldSample},
+    Resource,
+};
+use std::io::IsTerminal;
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::{
+    filter::{EnvFilter, Targets},
+    fmt,
+    layer::SubscriberExt,
+    util::SubscriberInitExt,
+    Layer,
+};
+
+use super::{JsonFormat, RedactedFields, RequestIdLayer, SqlStatementLayer, StdoutSpanExporter};
+use crate::config::logger::{LogFormat, LoggerConfig};
+use crate::config::telemetry::{TelemetryConfig, TraceExporter};
+
+/// The installed log and trace pipeline, traces are flushed on shutdown
+pub struct Telemetry {
+    provider: Option<SdkTracerProvider>,
+}
+
+impl Telemetry {
+    /// Writes logs, including those of the `log` crate, in the configured format,
+    /// sends `tracing` spans to the configured exporter and continues traces from
+    /// incoming W3C `traceparent` headers. Must run inside the Tokio runtime.
+    pub fn init(logger: &LoggerConfig, config: &TelemetryConfig) -> Self {
+        let provider = SdkTracerProvider::builder()
+            .with_sampler(EntryPointSampler(Sampler::ParentBased(Box::new(
+                Sampler::TraceIdRatioBased(config.sample_ratio),
+            ))))
+            .with_resource(
+                Resource::builder()
+                    .with_service_name(config.service_name.clone())
+                    .build(),
+            );
+        let provider = match &config.exporter {
+            TraceExporter::None => None,
+            TraceExporter::Otlp { endpoint } => {
+                let exporter = opentelemetry_otlp::SpanExporter::builder()
+                    .with_tonic()
+                    .with_endpoint(endpoint)
+                    .build()
+                    .expect("Failed to build the OTLP span exporter");
+                Some(provider.with_batch_exporter(exporter).build())
+            }
+            TraceExporter::Stdout => Some(provider.with_batch_exporter(StdoutSpanExporter).build()),
+        };
+
+        let filter = EnvFilter::try_new(&logger.filter).expect("RUST_LOG must be a valid filter");
+        let logs = match logger.format {
+            LogFormat::Json => fmt::layer()
+                .event_format(JsonFormat)
+                .with_filter(filter)
+                .boxed(),
+            LogFormat::Pretty => fmt::layer()
+                .fmt_fields(RedactedFields)
+                .with_ansi(std::io::stdout().is_terminal())
+                .with_filter(filter)
+                .boxed(),
+        };
+
+        let tracer = provider
+            .as_ref()
+            .map(|provider| provider.tracer(env!("CARGO_PKG_NAME")));
+        tracing_subscriber::registry()
+            .with(RequestIdLayer)
+            .with(logs)
+            .with(tracer.clone().map(|tracer| {
+                tracing_opentelemetry::layer()
+                    .with_tracer(tracer)
+                    .with_filter(Targets::new().with_default(LevelFilter::INFO))
+            }))
+            .with(tracer.map(|tracer| {
+                SqlStatementLayer::new(tracer).with_filter(
+                    Targets::new().with_target(SqlStatementLayer::TARGET, LevelFilter::DEBUG),
+                )
+            }))
+            .init();
+        global::set_text_map_propagator(TraceContextPropagator::new());
+
+        match &config.exporter {
+            TraceExporter::None => {}
+            TraceExporter::Otlp { endpoint } => {
+                info!("Exporting traces over OTLP to {}", endpoint)
+            }
+            TraceExporter::Stdout => info!("Exporting traces to stdout"),
+        }
+
+        Self { provider }
+    }
+
+    /// Exports the spans still buffered
+    pub async fn shutdown(self) {
+        let Some(provider) = self.provider else {
+            return;
+        };
+
+        // Blocks until the exporter is done, which may need this runtime for its I/O
+        let result = tokio::task::spawn_blocking(move || provider.shutdown()).await;
+        if let Ok(Err(e)) = result {
+            error!("Failed to flush traces: {}", e);
+        }
+    }
+}
+
+/// Only starts traces at requests and job runs, so background polling finding
+/// nothing to do doesn't produce a trace every second
+#[derive(Debug, Clone)]
+struct EntryPointSampler(Sampler);
+
+impl ShouldSample for EntryPointSampler {
+    fn should_sample(
+        &self,
+        parent_context: Option<&Context>,
+        trace_id: TraceId,
+        name: &str,
+        span_kind: &SpanKind,
+        attributes: &[KeyValue],
+        links: &[Link],
+    ) -> SamplingResult {
+        let is_root = parent_context.is_none_or(|cx| !cx.has_active_span());
+        if is_root && matches!(span_kind, SpanKind::Internal | SpanKind::Client) {
+            return SamplingResult {
+                decision: SamplingDecision::Drop,
+                attributes: Vec::new(),
+                trace_state: TraceState::default(),
+            };
+        }
+
+        self.0
+            .should_sample(parent_context, trace_id, name, span_kind, attributes, links)
+    }
+}
@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::domain_event::OutboxEvent,
+    gateways::{EventSink, EventSinkError},
+    repositories::WebhookRepository,
+};
+
+/// Queues webhook deliveries of relayed events for the subscriptions to their type
+pub struct WebhookEventSink<R: WebhookRepository> {
+    repository: R,
+}
+
+impl<R: WebhookRepository> WebhookEventSink<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: WebhookRepository> EventSink for WebhookEventSink<R> {
+    fn name(&self) -> &'static str {
+        "webhooks"
+    }
+
+    async fn publish(&self, event: &OutboxEvent) -> Result<(), EventSinkError> {
+        self.repository
+            .enqueue_event(event)
+            .await
+            .map(|_| ())
+            .map_err(|e| EventSinkError::Unavailable(e.to_string()))
+    }
+}
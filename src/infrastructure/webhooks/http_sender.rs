@@ -0,0 +1,47 @@
+use async_trait::async_trait;
+use reqwest::{redirect::Policy, Client};
+use std::time::Duration;
+
+use crate::domain::gateways::{webhook_sender::WebhookRequest, WebhookSender, WebhookSenderError};
+
+/// How long an endpoint gets to answer
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Posts webhooks over HTTP, without following redirects
+#[derive(Clone)]
+pub struct HttpWebhookSender {
+    client: Client,
+}
+
+impl HttpWebhookSender {
+    pub fn new() -> Self {
+        Self {
+            client: Client::builder()
+                .timeout(TIMEOUT)
+                .redirect(Policy::none())
+                .user_agent(concat!(env!("CARGO_PKG_NAME"), "-webhooks"))
+                .build()
+                .expect("webhook HTTP client settings must be valid"),
+        }
+    }
+}
+
+#[async_trait]
+impl WebhookSender for HttpWebhookSender {
+    async fn send(&self, request: WebhookRequest<'_>) -> Result<u16, WebhookSenderError> {
+        let mut builder = self.client.post(request.url).body(request.body);
+        for (name, value) in request.headers {
+            builder = builder.header(name, value);
+        }
+
+        let response = builder.send().await.map_err(|e| {
+            if e.is_timeout() {
+                WebhookSenderError::Timeout
+            } else {
+                WebhookSenderError::Unreachable(e.to_string())
+            }
+        })?;
+
+        Ok(response.status().as_u16())
+    }
+}
@@ -0,0 +1,5 @@
+pub mod event_sink;
+pub mod http_sender;
+
+pub use event_sink::WebhookEventSink;
+pub use http_sender::HttpWebhookSender;
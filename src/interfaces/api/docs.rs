@@ -1,13 +1,56 @@
 use crate::{
     domain::entities::{
+        cart::{AddCartItemDto, Cart, CartItem, MergeCartDto, StockWarning, UpdateCartItemDto},
+        exchange_rate::{ExchangeRate, SetExchangeRateDto},
+        health::{HealthCheckResult, HealthReport, HealthStatus},
+        job::{Job, JobStatus},
+        order::{CheckoutDto, Order, OrderItem, OrderStatus},
+        payment::{Payment, PaymentStatus},
         product::{CreateProductDto, Product, UpdateProductDto},
+        product_bulk::{BulkItemResult, BulkItemStatus, BulkReport, BulkUpdateProductDto},
+        product_event::{ProductEvent, ProductEventKind},
+        product_image::{ProductImage, ReorderProductImagesDto},
+        product_import::{ImportInterruption, ImportReport, ImportRowResult, ImportRowStatus},
+        product_price::{ProductPrice, SchedulePriceDto},
+        promotion::{CreatePromotionDto, Promotion, PromotionKind, UpdatePromotionDto},
+        scheduled_task::{ScheduledTask, TaskRunStatus},
         user::{CreateUserDto, UpdateUserDto, User},
+        webhook::{
+            CreateWebhookSubscriptionDto, UpdateWebhookSubscriptionDto, WebhookDelivery,
+            WebhookDeliveryStatus, WebhookSubscription,
+        },
     },
+    domain::services::promotion_engine::CodeRejection,
     interfaces::http::{
-        requests::user_requests::{CreateUserRequest, UpdateUserRequest},
+        requests::{
+            batch_requests::{BatchOperation, BatchRequest},
+            product_requests::{
+                BulkCreateProductsRequest, BulkDeleteProductsRequest, BulkUpdateProductsRequest,
+                UploadImageForm,
+            },
+            user_requests::{CreateUserRequest, UpdateUserRequest},
+        },
         responses::{
-            product_responses::{ProductResponse, ProductsListResponse},
+            batch_responses::{BatchOperationResult, BatchResponse},
+            cart_responses::{
+                AppliedPromotionResponse, CartItemResponse, CartQuoteResponse, CartResponse,
+            },
+            exchange_rate_responses::{ExchangeRateResponse, ExchangeRatesListResponse},
+            health_responses::LivenessResponse,
+            job_responses::JobsListResponse,
+            order_responses::{OrderItemResponse, OrderResponse},
+            payment_responses::PaymentResponse,
+            product_responses::{
+                PriceHistoryResponse, ProductImageResponse, ProductImagesListResponse,
+                ProductPriceResponse, ProductResponse, ProductsListResponse,
+            },
+            promotion_responses::{PromotionResponse, PromotionsListResponse},
+            scheduler_responses::ScheduledTasksListResponse,
             user_responses::{UserResponse, UsersListResponse},
+            webhook_responses::{
+                WebhookDeliveriesListResponse, WebhookSubscriptionResponse,
+                WebhookSubscriptionsListResponse,
+            },
         },
     },
 };
@@ -22,27 +65,137 @@ use utoipa::OpenApi;
         crate::interfaces::http::controllers::product_controller::get_product_doc,
         crate::interfaces::http::controllers::product_controller::update_product_doc,
         crate::interfaces::http::controllers::product_controller::delete_product_doc,
+        crate::interfaces::http::controllers::product_controller::get_price_history_doc,
+        crate::interfaces::http::controllers::product_controller::schedule_price_doc,
+        crate::interfaces::http::controllers::product_controller::import_products_doc,
+        crate::interfaces::http::controllers::product_controller::export_products_doc,
+        crate::interfaces::http::controllers::product_controller::bulk_create_products_doc,
+        crate::interfaces::http::controllers::product_controller::bulk_update_products_doc,
+        crate::interfaces::http::controllers::product_controller::bulk_delete_products_doc,
+        crate::interfaces::http::controllers::product_event_controller::stream_events_doc,
+        crate::interfaces::http::controllers::product_image_controller::upload_image_doc,
+        crate::interfaces::http::controllers::product_image_controller::list_images_doc,
+        crate::interfaces::http::controllers::product_image_controller::reorder_images_doc,
+        crate::interfaces::http::controllers::product_image_controller::set_primary_image_doc,
+        crate::interfaces::http::controllers::product_image_controller::delete_image_doc,
         // User endpoints
         crate::interfaces::http::controllers::user_controller::list_users_doc,
         crate::interfaces::http::controllers::user_controller::create_user_doc,
         crate::interfaces::http::controllers::user_controller::get_user_doc,
         crate::interfaces::http::controllers::user_controller::update_user_doc,
         crate::interfaces::http::controllers::user_controller::delete_user_doc,
+        crate::interfaces::http::controllers::user_controller::export_users_doc,
+        // Cart endpoints
+        crate::interfaces::http::controllers::cart_controller::create_cart_doc,
+        crate::interfaces::http::controllers::cart_controller::get_anonymous_cart_doc,
+        crate::interfaces::http::controllers::cart_controller::add_anonymous_cart_item_doc,
+        crate::interfaces::http::controllers::cart_controller::update_anonymous_cart_item_doc,
+        crate::interfaces::http::controllers::cart_controller::remove_anonymous_cart_item_doc,
+        crate::interfaces::http::controllers::cart_controller::get_user_cart_doc,
+        crate::interfaces::http::controllers::cart_controller::add_user_cart_item_doc,
+        crate::interfaces::http::controllers::cart_controller::update_user_cart_item_doc,
+        crate::interfaces::http::controllers::cart_controller::remove_user_cart_item_doc,
+        crate::interfaces::http::controllers::cart_controller::merge_cart_doc,
+        crate::interfaces::http::controllers::cart_controller::quote_anonymous_cart_doc,
+        crate::interfaces::http::controllers::cart_controller::quote_user_cart_doc,
+        // Order endpoints
+        crate::interfaces::http::controllers::order_controller::checkout_doc,
+        crate::interfaces::http::controllers::order_controller::get_order_doc,
+        // Payment endpoints
+        crate::interfaces::http::controllers::payment_controller::create_payment_doc,
+        crate::interfaces::http::controllers::payment_controller::capture_payment_doc,
+        crate::interfaces::http::controllers::payment_controller::refund_payment_doc,
+        crate::interfaces::http::controllers::payment_controller::payment_webhook_doc,
+        // Promotion endpoints
+        crate::interfaces::http::controllers::promotion_controller::list_promotions_doc,
+        crate::interfaces::http::controllers::promotion_controller::create_promotion_doc,
+        crate::interfaces::http::controllers::promotion_controller::get_promotion_doc,
+        crate::interfaces::http::controllers::promotion_controller::update_promotion_doc,
+        crate::interfaces::http::controllers::promotion_controller::delete_promotion_doc,
+        // Exchange rate endpoints
+        crate::interfaces::http::controllers::exchange_rate_controller::list_exchange_rates_doc,
+        crate::interfaces::http::controllers::exchange_rate_controller::set_exchange_rate_doc,
+        crate::interfaces::http::controllers::exchange_rate_controller::delete_exchange_rate_doc,
+        // Webhook endpoints
+        crate::interfaces::http::controllers::webhook_controller::list_subscriptions_doc,
+        crate::interfaces::http::controllers::webhook_controller::create_subscription_doc,
+        crate::interfaces::http::controllers::webhook_controller::get_subscription_doc,
+        crate::interfaces::http::controllers::webhook_controller::update_subscription_doc,
+        crate::interfaces::http::controllers::webhook_controller::delete_subscription_doc,
+        crate::interfaces::http::controllers::webhook_controller::list_deliveries_doc,
+        crate::interfaces::http::controllers::webhook_controller::redeliver_doc,
+        // Job endpoints
+        crate::interfaces::http::controllers::job_controller::list_jobs_doc,
+        crate::interfaces::http::controllers::job_controller::get_job_doc,
+        crate::interfaces::http::controllers::job_controller::retry_job_doc,
+        crate::interfaces::http::controllers::job_controller::cancel_job_doc,
+        // Scheduler endpoints
+        crate::interfaces::http::controllers::scheduler_controller::list_tasks_doc,
+        // Health endpoints
+        crate::interfaces::http::controllers::health_controller::live_doc,
+        crate::interfaces::http::controllers::health_controller::ready_doc,
+        // Metrics endpoints
+        crate::interfaces::http::controllers::metrics_controller::metrics_doc,
+        // Batch endpoints
+        crate::interfaces::http::controllers::batch_controller::execute_batch_doc,
     ),
     components(
         schemas(
             // Product schemas
             Product, CreateProductDto, UpdateProductDto,
             ProductResponse, ProductsListResponse,
+            ProductPrice, SchedulePriceDto, ProductPriceResponse, PriceHistoryResponse,
+            ProductImage, ReorderProductImagesDto, UploadImageForm,
+            ImportReport, ImportInterruption, ImportRowResult, ImportRowStatus,
+            BulkCreateProductsRequest, BulkUpdateProductsRequest, BulkDeleteProductsRequest,
+            BulkUpdateProductDto, BulkReport, BulkItemResult, BulkItemStatus,
+            ProductEvent, ProductEventKind,
+            ProductImageResponse, ProductImagesListResponse,
             // User schemas
             User, CreateUserDto, UpdateUserDto,
             CreateUserRequest, UpdateUserRequest,
-            UserResponse, UsersListResponse
+            UserResponse, UsersListResponse,
+            // Cart schemas
+            Cart, CartItem, AddCartItemDto, UpdateCartItemDto, MergeCartDto, StockWarning,
+            CartResponse, CartItemResponse, CartQuoteResponse, AppliedPromotionResponse,
+            // Order schemas
+            Order, OrderItem, OrderStatus, CheckoutDto, OrderResponse, OrderItemResponse,
+            // Payment schemas
+            Payment, PaymentStatus, PaymentResponse,
+            // Promotion schemas
+            Promotion, PromotionKind, CreatePromotionDto, UpdatePromotionDto, CodeRejection,
+            PromotionResponse, PromotionsListResponse,
+            // Exchange rate schemas
+            ExchangeRate, SetExchangeRateDto, ExchangeRateResponse, ExchangeRatesListResponse,
+            // Webhook schemas
+            WebhookSubscription, CreateWebhookSubscriptionDto, UpdateWebhookSubscriptionDto,
+            WebhookDelivery, WebhookDeliveryStatus,
+            WebhookSubscriptionResponse, WebhookSubscriptionsListResponse,
+            WebhookDeliveriesListResponse,
+            // Job schemas
+            Job, JobStatus, JobsListResponse,
+            // Scheduler schemas
+            ScheduledTask, TaskRunStatus, ScheduledTasksListResponse,
+            // Health schemas
+            HealthReport, HealthCheckResult, HealthStatus, LivenessResponse,
+            // Batch schemas
+            BatchRequest, BatchOperation, BatchResponse, BatchOperationResult
         )
     ),
     tags(
         (name = "products", description = "Product management endpoints"),
-        (name = "users", description = "User management endpoints")
+        (name = "users", description = "User management endpoints"),
+        (name = "carts", description = "Shopping cart endpoints"),
+        (name = "orders", description = "Order checkout endpoints"),
+        (name = "payments", description = "Payment processing endpoints"),
+        (name = "promotions", description = "Promotion and discount code management endpoints"),
+        (name = "exchange-rates", description = "Currency exchange rate management endpoints"),
+        (name = "webhooks", description = "Outgoing webhook subscription and delivery endpoints"),
+        (name = "jobs", description = "Background job queue administration endpoints"),
+        (name = "scheduler", description = "Scheduled task status endpoints"),
+        (name = "health", description = "Liveness and readiness probes"),
+        (name = "metrics", description = "Prometheus metrics"),
+        (name = "batch", description = "Several API operations in one request")
     ),
     info(
         title = "Rust Clean Architecture API",
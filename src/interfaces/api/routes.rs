@@ -1,8 +1,25 @@
 use crate::interfaces::api::docs::ApiDoc;
 use crate::interfaces::http::controllers::{
-    product_controller::ProductController, user_controller::UserController,
+    batch_controller::{BatchController, InternalApi},
+    cart_controller::CartController,
+    exchange_rate_controller::ExchangeRateController,
+    graphql_controller::GraphQLController,
+    health_controller::HealthController,
+    job_controller::JobController,
+    metrics_controller::MetricsController,
+    order_controller::OrderController,
+    payment_controller::PaymentController,
+    product_controller::ProductController,
+    product_event_controller::ProductEventController,
+    product_image_controller::ProductImageController,
+    promotion_controller::PromotionController,
+    scheduler_controller::SchedulerController,
+    user_controller::UserController,
+    webhook_controller::WebhookController,
+    websocket_controller::WebSocketController,
 };
-use actix_web::web;
+use crate::interfaces::http::negotiation::{content_negotiation, json_error_handler};
+use actix_web::{middleware::from_fn, web};
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
@@ -10,24 +27,229 @@ pub fn configure_routes(cfg: &mut web::ServiceConfig) {
     cfg
         // Swagger UI
         .service(SwaggerUi::new("/docs/{_:.*}").url("/api-docs/openapi.json", ApiDoc::openapi()))
-        // API routes
+        // Health probes
         .service(
-            web::scope("/api/v1")
-                .service(
-                    web::scope("/users")
-                        .route("", web::get().to(UserController::list_users))
-                        .route("", web::post().to(UserController::create_user))
-                        .route("/{id}", web::get().to(UserController::get_user))
-                        .route("/{id}", web::put().to(UserController::update_user))
-                        .route("/{id}", web::delete().to(UserController::delete_user)),
-                )
-                .service(
-                    web::scope("/products")
-                        .route("", web::get().to(ProductController::list_products))
-                        .route("", web::post().to(ProductController::create_product))
-                        .route("/{id}", web::get().to(ProductController::get_product))
-                        .route("/{id}", web::put().to(ProductController::update_product))
-                        .route("/{id}", web::delete().to(ProductController::delete_product)),
-                ),
-        );
+            web::scope("/health")
+                .route("/live", web::get().to(HealthController::live))
+                .route("/ready", web::get().to(HealthController::ready)),
+        )
+        // Prometheus scrape endpoint
+        .route("/metrics", web::get().to(MetricsController::metrics))
+        // Live updates
+        .route("/ws", web::get().to(WebSocketController::connect))
+        // API routes
+        .configure(configure_api);
+}
+
+/// GraphQL endpoint, with the GraphiQL IDE on `GET /graphql` when `graphiql` is set
+pub fn configure_graphql(cfg: &mut web::ServiceConfig, graphiql: bool) {
+    cfg.route("/graphql", web::post().to(GraphQLController::execute));
+    if graphiql {
+        cfg.route("/graphql", web::get().to(GraphQLController::graphiql));
+    }
+}
+
+/// The `/api/v1` endpoints, also served internally to batch operations
+pub fn configure_api(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/v1")
+            .wrap(from_fn(content_negotiation))
+            .app_data(web::JsonConfig::default().error_handler(json_error_handler))
+            .app_data(InternalApi::default())
+            .route("/batch", web::post().to(BatchController::execute_batch))
+            .service(
+                web::scope("/users")
+                    .route("", web::get().to(UserController::list_users))
+                    .route("", web::post().to(UserController::create_user))
+                    .route("/export", web::get().to(UserController::export_users))
+                    .route("/{id}", web::get().to(UserController::get_user))
+                    .route("/{id}", web::put().to(UserController::update_user))
+                    .route("/{id}", web::delete().to(UserController::delete_user))
+                    .route("/{id}/cart", web::get().to(CartController::get_user_cart))
+                    .route(
+                        "/{id}/cart/items",
+                        web::post().to(CartController::add_user_cart_item),
+                    )
+                    .route(
+                        "/{id}/cart/items/{product_id}",
+                        web::put().to(CartController::update_user_cart_item),
+                    )
+                    .route(
+                        "/{id}/cart/items/{product_id}",
+                        web::delete().to(CartController::remove_user_cart_item),
+                    )
+                    .route(
+                        "/{id}/cart/quote",
+                        web::get().to(CartController::quote_user_cart),
+                    )
+                    .route(
+                        "/{id}/cart/merge",
+                        web::post().to(CartController::merge_cart),
+                    )
+                    .route("/{id}/orders", web::post().to(OrderController::checkout)),
+            )
+            .service(
+                web::scope("/products")
+                    .route("", web::get().to(ProductController::list_products))
+                    .route("", web::post().to(ProductController::create_product))
+                    .route(
+                        "/import",
+                        web::post().to(ProductController::import_products),
+                    )
+                    .route("/export", web::get().to(ProductController::export_products))
+                    .route(
+                        "/events",
+                        web::get().to(ProductEventController::stream_events),
+                    )
+                    .route(
+                        "/bulk",
+                        web::post().to(ProductController::bulk_create_products),
+                    )
+                    .route(
+                        "/bulk",
+                        web::patch().to(ProductController::bulk_update_products),
+                    )
+                    .route(
+                        "/bulk",
+                        web::delete().to(ProductController::bulk_delete_products),
+                    )
+                    .route("/{id}", web::get().to(ProductController::get_product))
+                    .route("/{id}", web::put().to(ProductController::update_product))
+                    .route("/{id}", web::delete().to(ProductController::delete_product))
+                    .route(
+                        "/{id}/price-history",
+                        web::get().to(ProductController::get_price_history),
+                    )
+                    .route(
+                        "/{id}/prices",
+                        web::post().to(ProductController::schedule_price),
+                    )
+                    .route(
+                        "/{id}/images",
+                        web::get().to(ProductImageController::list_images),
+                    )
+                    .route(
+                        "/{id}/images",
+                        web::post().to(ProductImageController::upload_image),
+                    )
+                    .route(
+                        "/{id}/images/order",
+                        web::put().to(ProductImageController::reorder_images),
+                    )
+                    .route(
+                        "/{id}/images/{image_id}/primary",
+                        web::put().to(ProductImageController::set_primary_image),
+                    )
+                    .route(
+                        "/{id}/images/{image_id}",
+                        web::delete().to(ProductImageController::delete_image),
+                    ),
+            )
+            .service(
+                web::scope("/carts")
+                    .route("", web::post().to(CartController::create_cart))
+                    .route(
+                        "/{token}",
+                        web::get().to(CartController::get_anonymous_cart),
+                    )
+                    .route(
+                        "/{token}/quote",
+                        web::get().to(CartController::quote_anonymous_cart),
+                    )
+                    .route(
+                        "/{token}/items",
+                        web::post().to(CartController::add_anonymous_cart_item),
+                    )
+                    .route(
+                        "/{token}/items/{product_id}",
+                        web::put().to(CartController::update_anonymous_cart_item),
+                    )
+                    .route(
+                        "/{token}/items/{product_id}",
+                        web::delete().to(CartController::remove_anonymous_cart_item),
+                    ),
+            )
+            .service(
+                web::scope("/orders")
+                    .route("/{id}", web::get().to(OrderController::get_order))
+                    .route(
+                        "/{id}/payments",
+                        web::post().to(PaymentController::create_payment),
+                    ),
+            )
+            .service(
+                web::scope("/exchange-rates")
+                    .route(
+                        "",
+                        web::get().to(ExchangeRateController::list_exchange_rates),
+                    )
+                    .route(
+                        "/{base}/{quote}",
+                        web::put().to(ExchangeRateController::set_exchange_rate),
+                    )
+                    .route(
+                        "/{base}/{quote}",
+                        web::delete().to(ExchangeRateController::delete_exchange_rate),
+                    ),
+            )
+            .service(
+                web::scope("/promotions")
+                    .route("", web::get().to(PromotionController::list_promotions))
+                    .route("", web::post().to(PromotionController::create_promotion))
+                    .route("/{id}", web::get().to(PromotionController::get_promotion))
+                    .route(
+                        "/{id}",
+                        web::put().to(PromotionController::update_promotion),
+                    )
+                    .route(
+                        "/{id}",
+                        web::delete().to(PromotionController::delete_promotion),
+                    ),
+            )
+            .service(
+                web::scope("/webhooks")
+                    .route("", web::get().to(WebhookController::list_subscriptions))
+                    .route("", web::post().to(WebhookController::create_subscription))
+                    .route("/{id}", web::get().to(WebhookController::get_subscription))
+                    .route(
+                        "/{id}",
+                        web::put().to(WebhookController::update_subscription),
+                    )
+                    .route(
+                        "/{id}",
+                        web::delete().to(WebhookController::delete_subscription),
+                    )
+                    .route(
+                        "/{id}/deliveries",
+                        web::get().to(WebhookController::list_deliveries),
+                    )
+                    .route(
+                        "/{id}/deliveries/{delivery_id}/redeliver",
+                        web::post().to(WebhookController::redeliver),
+                    ),
+            )
+            .service(
+                web::scope("/jobs")
+                    .route("", web::get().to(JobController::list_jobs))
+                    .route("/{id}", web::get().to(JobController::get_job))
+                    .route("/{id}/retry", web::post().to(JobController::retry_job))
+                    .route("/{id}/cancel", web::post().to(JobController::cancel_job)),
+            )
+            .service(
+                web::scope("/scheduler")
+                    .route("/tasks", web::get().to(SchedulerController::list_tasks)),
+            )
+            .service(
+                web::scope("/payments")
+                    .route("/webhook", web::post().to(PaymentController::webhook))
+                    .route(
+                        "/{id}/capture",
+                        web::post().to(PaymentController::capture_payment),
+                    )
+                    .route(
+                        "/{id}/refund",
+                        web::post().to(PaymentController::refund_payment),
+                    ),
+            ),
+    );
 }
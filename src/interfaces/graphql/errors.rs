@@ -0,0 +1,24 @@
+use crate::application::error::ApplicationError;
+use async_graphql::{Error, ErrorExtensions};
+use log::error;
+
+/// Maps a use case error onto a GraphQL error with a machine readable `code`
+pub fn graphql_error(e: &ApplicationError) -> Error {
+    match e {
+        ApplicationError::Validation(msg) => Error::new(msg).extend_with(|_, ext| {
+            ext.set("code", "BAD_USER_INPUT");
+        }),
+        ApplicationError::NotFound => Error::new("Not found").extend_with(|_, ext| {
+            ext.set("code", "NOT_FOUND");
+        }),
+        ApplicationError::Unauthorized => Error::new("Forbidden").extend_with(|_, ext| {
+            ext.set("code", "FORBIDDEN");
+        }),
+        e => {
+            error!("GraphQL resolver failed: {:?}", e);
+            Error::new("Internal server error").extend_with(|_, ext| {
+                ext.set("code", "INTERNAL_SERVER_ERROR");
+            })
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use crate::interfaces::middleware::auth::Principal;
+use async_graphql::{Context, Error, ErrorExtensions, Guard, Result};
+use uuid::Uuid;
+
+fn unauthenticated() -> Error {
+    Error::new("Authentication required").extend_with(|_, ext| {
+        ext.set("code", "UNAUTHENTICATED");
+    })
+}
+
+fn forbidden() -> Error {
+    Error::new("Forbidden").extend_with(|_, ext| {
+        ext.set("code", "FORBIDDEN");
+    })
+}
+
+/// Only administrators
+pub struct AdminGuard;
+
+impl Guard for AdminGuard {
+    async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+        match ctx.data_opt::<Principal>() {
+            Some(principal) if principal.is_admin => Ok(()),
+            Some(_) => Err(forbidden()),
+            None => Err(unauthenticated()),
+        }
+    }
+}
+
+/// The user itself or an administrator
+pub struct UserAccessGuard(pub Uuid);
+
+impl Guard for UserAccessGuard {
+    async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+        match ctx.data_opt::<Principal>() {
+            Some(principal) if principal.can_access_user(self.0) => Ok(()),
+            Some(_) => Err(forbidden()),
+            None => Err(unauthenticated()),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{
+    application::{
+        error::ApplicationError,
+        use_cases::{
+            product::ListPriceHistoriesUseCase, product_image::ListImagesForProductsUseCase,
+            UseCase,
+        },
+    },
+    domain::entities::{product_image::ProductImage, product_price::ProductPrice},
+    infrastructure::persistence::postgres::{
+        PostgresProductImageRepository, PostgresProductPriceRepository,
+    },
+};
+use async_graphql::dataloader::Loader;
+use sqlx::PgPool;
+use std::{collections::HashMap, sync::Arc};
+use uuid::Uuid;
+
+/// Batches the image lookups of all products in a response into one query
+pub struct ProductImagesLoader {
+    pool: PgPool,
+}
+
+impl ProductImagesLoader {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl Loader<Uuid> for ProductImagesLoader {
+    type Value = Vec<ProductImage>;
+    type Error = Arc<ApplicationError>;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        let use_case = ListImagesForProductsUseCase::new(PostgresProductImageRepository::new(
+            self.pool.clone(),
+        ));
+        use_case.execute(keys.to_vec()).await.map_err(Arc::new)
+    }
+}
+
+/// Batches the price history lookups of all products in a response into one query
+pub struct PriceHistoryLoader {
+    pool: PgPool,
+}
+
+impl PriceHistoryLoader {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl Loader<Uuid> for PriceHistoryLoader {
+    type Value = Vec<ProductPrice>;
+    type Error = Arc<ApplicationError>;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        let use_case =
+            ListPriceHistoriesUseCase::new(PostgresProductPriceRepository::new(self.pool.clone()));
+        use_case.execute(keys.to_vec()).await.map_err(Arc::new)
+    }
+}
@@ -0,0 +1,7 @@
+pub mod errors;
+pub mod guards;
+pub mod loaders;
+pub mod schema;
+pub mod types;
+
+pub use schema::{build_schema, AppSchema};
@@ -0,0 +1,274 @@
+use crate::{
+    application::error::ApplicationError,
+    application::use_cases::{
+        product::{
+            CreateProductUseCase, DeleteProductUseCase, GetProductUseCase, ListProductsUseCase,
+            UpdateProductUseCase,
+        },
+        user::{
+            CreateUserUseCase, DeleteUserUseCase, GetUserUseCase, ListUsersUseCase,
+            UpdateUserUseCase,
+        },
+        UseCase,
+    },
+    infrastructure::{
+        persistence::postgres::{PostgresProductRepository, PostgresUserRepository},
+        storage::ConfiguredStorage,
+    },
+    interfaces::{
+        graphql::{
+            errors::graphql_error,
+            guards::{AdminGuard, UserAccessGuard},
+            types::{
+                CreateProductInput, CreateUserInput, ProductNode, TotalCount, UpdateProductInput,
+                UpdateUserInput, UserNode,
+            },
+        },
+        middleware::auth::Principal,
+    },
+};
+use async_graphql::{
+    connection::{query, Connection, Edge},
+    Context, EmptySubscription, Object, OutputType, Result, Schema,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+pub type AppSchema = Schema<QueryRoot, MutationRoot, EmptySubscription>;
+
+/// Page size when neither `first` nor `last` is given
+const DEFAULT_PAGE_SIZE: usize = 20;
+/// Largest page a client may request
+const MAX_PAGE_SIZE: usize = 100;
+
+pub fn build_schema(pool: PgPool, storage: ConfiguredStorage) -> AppSchema {
+    Schema::build(QueryRoot, MutationRoot, EmptySubscription)
+        .data(pool)
+        .data(storage)
+        .limit_depth(10)
+        .finish()
+}
+
+type Page<T> = Connection<usize, T, TotalCount>;
+
+/// Slices `items` into a Relay connection whose cursors are list offsets
+async fn paginate<T: OutputType>(
+    items: Vec<T>,
+    after: Option<String>,
+    before: Option<String>,
+    first: Option<i32>,
+    last: Option<i32>,
+) -> Result<Page<T>> {
+    query(
+        after,
+        before,
+        first,
+        last,
+        |after: Option<usize>, before: Option<usize>, first, last| async move {
+            let total = items.len();
+            let mut start = after.map(|after| after + 1).unwrap_or(0).min(total);
+            let mut end = before.unwrap_or(total).clamp(start, total);
+
+            if let Some(first) = first {
+                end = end.min(start + first.min(MAX_PAGE_SIZE));
+            }
+            if let Some(last) = last {
+                start = start.max(end.saturating_sub(last.min(MAX_PAGE_SIZE)));
+            }
+            if first.is_none() && last.is_none() {
+                end = end.min(start + DEFAULT_PAGE_SIZE);
+            }
+
+            let mut connection = Connection::with_additional_fields(
+                start > 0,
+                end < total,
+                TotalCount { total_count: total },
+            );
+            connection.edges.extend(
+                items
+                    .into_iter()
+                    .enumerate()
+                    .skip(start)
+                    .take(end - start)
+                    .map(|(cursor, node)| Edge::new(cursor, node)),
+            );
+
+            Ok::<_, async_graphql::Error>(connection)
+        },
+    )
+    .await
+}
+
+fn not_found_as_none<T>(result: Result<T, ApplicationError>) -> Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(ApplicationError::NotFound) => Ok(None),
+        Err(e) => Err(graphql_error(&e)),
+    }
+}
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// Products, newest first
+    async fn products(
+        &self,
+        ctx: &Context<'_>,
+        after: Option<String>,
+        before: Option<String>,
+        first: Option<i32>,
+        last: Option<i32>,
+    ) -> Result<Page<ProductNode>> {
+        let pool = ctx.data_unchecked::<PgPool>();
+        let use_case = ListProductsUseCase::new(PostgresProductRepository::new(pool.clone()));
+        let products = use_case.execute(()).await.map_err(|e| graphql_error(&e))?;
+
+        paginate(
+            products.into_iter().map(ProductNode).collect(),
+            after,
+            before,
+            first,
+            last,
+        )
+        .await
+    }
+
+    async fn product(&self, ctx: &Context<'_>, id: Uuid) -> Result<Option<ProductNode>> {
+        let pool = ctx.data_unchecked::<PgPool>();
+        let use_case = GetProductUseCase::new(PostgresProductRepository::new(pool.clone()));
+
+        Ok(not_found_as_none(use_case.execute(id).await)?.map(ProductNode))
+    }
+
+    /// Users, newest first. Administrators only
+    #[graphql(guard = "AdminGuard")]
+    async fn users(
+        &self,
+        ctx: &Context<'_>,
+        after: Option<String>,
+        before: Option<String>,
+        first: Option<i32>,
+        last: Option<i32>,
+    ) -> Result<Page<UserNode>> {
+        let pool = ctx.data_unchecked::<PgPool>();
+        let use_case = ListUsersUseCase::new(PostgresUserRepository::new(pool.clone()));
+        let users = use_case.execute(()).await.map_err(|e| graphql_error(&e))?;
+
+        paginate(
+            users.into_iter().map(UserNode::from).collect(),
+            after,
+            before,
+            first,
+            last,
+        )
+        .await
+    }
+
+    /// A user, visible to the user itself and administrators
+    #[graphql(guard = "UserAccessGuard(id)")]
+    async fn user(&self, ctx: &Context<'_>, id: Uuid) -> Result<Option<UserNode>> {
+        let pool = ctx.data_unchecked::<PgPool>();
+        let use_case = GetUserUseCase::new(PostgresUserRepository::new(pool.clone()));
+
+        Ok(not_found_as_none(use_case.execute(id).await)?.map(UserNode::from))
+    }
+
+    /// The authenticated user, null for anonymous requests
+    async fn me(&self, ctx: &Context<'_>) -> Result<Option<UserNode>> {
+        let Some(principal) = ctx.data_opt::<Principal>() else {
+            return Ok(None);
+        };
+        let pool = ctx.data_unchecked::<PgPool>();
+        let use_case = GetUserUseCase::new(PostgresUserRepository::new(pool.clone()));
+
+        Ok(not_found_as_none(use_case.execute(principal.user_id).await)?.map(UserNode::from))
+    }
+}
+
+pub struct MutationRoot;
+
+#[Object]
+impl MutationRoot {
+    #[graphql(guard = "AdminGuard")]
+    async fn create_product(
+        &self,
+        ctx: &Context<'_>,
+        input: CreateProductInput,
+    ) -> Result<ProductNode> {
+        let pool = ctx.data_unchecked::<PgPool>();
+        let use_case = CreateProductUseCase::new(PostgresProductRepository::new(pool.clone()));
+
+        match use_case.execute(input.into()).await {
+            Ok(product) => Ok(ProductNode(product)),
+            Err(e) => Err(graphql_error(&e)),
+        }
+    }
+
+    #[graphql(guard = "AdminGuard")]
+    async fn update_product(
+        &self,
+        ctx: &Context<'_>,
+        id: Uuid,
+        input: UpdateProductInput,
+    ) -> Result<ProductNode> {
+        let pool = ctx.data_unchecked::<PgPool>();
+        let use_case = UpdateProductUseCase::new(PostgresProductRepository::new(pool.clone()));
+
+        match use_case.execute((id, input.into())).await {
+            Ok(product) => Ok(ProductNode(product)),
+            Err(e) => Err(graphql_error(&e)),
+        }
+    }
+
+    /// Deletes a product, returns its id
+    #[graphql(guard = "AdminGuard")]
+    async fn delete_product(&self, ctx: &Context<'_>, id: Uuid) -> Result<Uuid> {
+        let pool = ctx.data_unchecked::<PgPool>();
+        let use_case = DeleteProductUseCase::new(PostgresProductRepository::new(pool.clone()));
+
+        match use_case.execute(id).await {
+            Ok(()) => Ok(id),
+            Err(e) => Err(graphql_error(&e)),
+        }
+    }
+
+    /// Registers a user, open to everyone
+    async fn create_user(&self, ctx: &Context<'_>, input: CreateUserInput) -> Result<UserNode> {
+        let pool = ctx.data_unchecked::<PgPool>();
+        let use_case = CreateUserUseCase::new(PostgresUserRepository::new(pool.clone()));
+
+        match use_case.execute(input.into()).await {
+            Ok(user) => Ok(UserNode::from(user)),
+            Err(e) => Err(graphql_error(&e)),
+        }
+    }
+
+    #[graphql(guard = "UserAccessGuard(id)")]
+    async fn update_user(
+        &self,
+        ctx: &Context<'_>,
+        id: Uuid,
+        input: UpdateUserInput,
+    ) -> Result<UserNode> {
+        let pool = ctx.data_unchecked::<PgPool>();
+        let use_case = UpdateUserUseCase::new(PostgresUserRepository::new(pool.clone()));
+
+        match use_case.execute((id, input.into())).await {
+            Ok(user) => Ok(UserNode::from(user)),
+            Err(e) => Err(graphql_error(&e)),
+        }
+    }
+
+    /// Deletes a user, returns its id
+    #[graphql(guard = "UserAccessGuard(id)")]
+    async fn delete_user(&self, ctx: &Context<'_>, id: Uuid) -> Result<Uuid> {
+        let pool = ctx.data_unchecked::<PgPool>();
+        let use_case = DeleteUserUseCase::new(PostgresUserRepository::new(pool.clone()));
+
+        match use_case.execute(id).await {
+            Ok(()) => Ok(id),
+            Err(e) => Err(graphql_error(&e)),
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use crate::{
+    domain::{
+        entities::{
+            product::{CreateProductDto, Product, UpdateProductDto},
+            product_image::{ProductImage, THUMBNAIL_SIZES},
+            product_price::ProductPrice,
+            user::{CreateUserDto, UpdateUserDto, User},
+        },
+        gateways::BlobStorage,
+    },
+    infrastructure::storage::ConfiguredStorage,
+    interfaces::graphql::{
+        errors::graphql_error,
+        guards::UserAccessGuard,
+        loaders::{PriceHistoryLoader, ProductImagesLoader},
+    },
+};
+use async_graphql::{
+    dataloader::DataLoader, ComplexObject, Context, InputObject, Object, Result, SimpleObject,
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+/// Total size of a paginated list, next to its edges
+#[derive(SimpleObject)]
+pub struct TotalCount {
+    pub total_count: usize,
+}
+
+pub struct ProductNode(pub Product);
+
+#[Object(name = "Product")]
+impl ProductNode {
+    async fn id(&self) -> Uuid {
+        self.0.id
+    }
+
+    async fn sku(&self) -> Option<&str> {
+        self.0.sku.as_deref()
+    }
+
+    async fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    async fn description(&self) -> &str {
+        &self.0.description
+    }
+
+    async fn category(&self) -> Option<&str> {
+        self.0.category.as_deref()
+    }
+
+    async fn price(&self) -> Decimal {
+        self.0.price
+    }
+
+    /// ISO 4217 currency of the price
+    async fn currency(&self) -> &str {
+        &self.0.currency
+    }
+
+    async fn stock(&self) -> i32 {
+        self.0.stock
+    }
+
+    async fn created_at(&self) -> DateTime<Utc> {
+        self.0.created_at
+    }
+
+    async fn updated_at(&self) -> DateTime<Utc> {
+        self.0.updated_at
+    }
+
+    /// Images in display order
+    async fn images(&self, ctx: &Context<'_>) -> Result<Vec<ProductImageNode>> {
+        let images = ctx
+            .data_unchecked::<DataLoader<ProductImagesLoader>>()
+            .load_one(self.0.id)
+            .await
+            .map_err(|e| graphql_error(&e))?
+            .unwrap_or_default();
+        let storage = ctx.data_unchecked::<ConfiguredStorage>();
+
+        Ok(images
+            .into_iter()
+            .map(|image| ProductImageNode::new(image, storage))
+            .collect())
+    }
+
+    /// Past, current and scheduled prices in chronological order
+    async fn price_history(&self, ctx: &Context<'_>) -> Result<Vec<ProductPriceNode>> {
+        let prices = ctx
+            .data_unchecked::<DataLoader<PriceHistoryLoader>>()
+            .load_one(self.0.id)
+            .await
+            .map_err(|e| graphql_error(&e))?
+            .unwrap_or_default();
+
+        Ok(prices.into_iter().map(ProductPriceNode::from).collect())
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "ProductImage")]
+pub struct ProductImageNode {
+    pub id: Uuid,
+    /// Display order, starting at 0
+    pub position: i32,
+    pub is_primary: bool,
+    pub content_type: String,
+    pub width: i32,
+    pub height: i32,
+    /// URL of the original
+    pub url: String,
+    pub thumbnails: Vec<ThumbnailNode>,
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "Thumbnail")]
+pub struct ThumbnailNode {
+    /// small, medium or large
+    pub size: String,
+    pub url: String,
+}
+
+impl ProductImageNode {
+    fn new(image: ProductImage, storage: &dyn BlobStorage) -> Self {
+        Self {
+            url: storage.url(&image.storage_key(None)),
+            thumbnails: THUMBNAIL_SIZES
+                .iter()
+                .map(|(name, _)| ThumbnailNode {
+                    size: name.to_string(),
+                    url: storage.url(&image.storage_key(Some(name))),
+                })
+                .collect(),
+            id: image.id,
+            position: image.position,
+            is_primary: image.is_primary,
+            content_type: image.content_type,
+            width: image.width,
+            height: image.height,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "ProductPrice")]
+pub struct ProductPriceNode {
+    pub price: Decimal,
+    /// Start of the period (inclusive)
+    pub effective_from: DateTime<Utc>,
+    /// End of the period (exclusive), null while open-ended
+    pub effective_to: Option<DateTime<Utc>>,
+}
+
+impl From<ProductPrice> for ProductPriceNode {
+    fn from(price: ProductPrice) -> Self {
+        Self {
+            price: price.price,
+            effective_from: price.effective_from,
+            effective_to: price.effective_to,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "User", complex)]
+pub struct UserNode {
+    pub id: Uuid,
+    pub username: String,
+    #[graphql(skip)]
+    pub email: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[ComplexObject]
+impl UserNode {
+    /// Only visible to the user itself and administrators
+    #[graphql(guard = "UserAccessGuard(self.id)")]
+    async fn email(&self) -> Option<&str> {
+        Some(&self.email)
+    }
+}
+
+impl From<User> for UserNode {
+    fn from(user: User) -> Self {
+        Self {
+            id: user.id,
+            username: user.username,
+            email: user.email,
+            created_at: user.created_at,
+            updated_at: user.updated_at,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct CreateProductInput {
+    pub sku: Option<String>,
+    pub name: String,
+    pub description: String,
+    pub category: Option<String>,
+    pub price: Decimal,
+    /// ISO 4217 currency, USD when absent
+    pub currency: Option<String>,
+    pub stock: i32,
+}
+
+impl From<CreateProductInput> for CreateProductDto {
+    fn from(input: CreateProductInput) -> Self {
+        Self {
+            sku: input.sku,
+            name: input.name,
+            description: input.description,
+            category: input.category,
+            price: input.price,
+            currency: input.currency,
+            stock: input.stock,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct UpdateProductInput {
+    pub sku: Option<String>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub category: Option<String>,
+    pub price: Option<Decimal>,
+    pub stock: Option<i32>,
+}
+
+impl From<UpdateProductInput> for UpdateProductDto {
+    fn from(input: UpdateProductInput) -> Self {
+        Self {
+            sku: input.sku,
+            name: input.name,
+            description: input.description,
+            category: input.category,
+            price: input.price,
+            stock: input.stock,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct CreateUserInput {
+    pub email: String,
+    pub username: String,
+    pub password: String,
+}
+
+impl From<CreateUserInput> for CreateUserDto {
+    fn from(input: CreateUserInput) -> Self {
+        Self {
+            email: input.email,
+            username: input.username,
+            password: input.password,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct UpdateUserInput {
+    pub email: Option<String>,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+impl From<UpdateUserInput> for UpdateUserDto {
+    fn from(input: UpdateUserInput) -> Self {
+        Self {
+            email: input.email,
+            username: input.username,
+            password: input.password,
+        }
+    }
+}
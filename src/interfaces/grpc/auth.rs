@@ -0,0 +1,146 @@
+use crate::interfaces::middleware::auth::{Principal, TokenValidator};
+use tonic::{service::Interceptor, Request, Status};
+use uuid::Uuid;
+
+/// Resolves the caller of a call from its `authorization` metadata. Calls without a
+/// token stay anonymous and the methods decide what they allow, an invalid token is
+/// rejected with UNAUTHENTICATED
+#[derive(Clone)]
+pub struct AuthInterceptor {
+    validator: TokenValidator,
+}
+
+impl AuthInterceptor {
+    pub fn new(validator: TokenValidator) -> Self {
+        Self { validator }
+    }
+}
+
+impl Interceptor for AuthInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let Some(value) = request.metadata().get("authorization") else {
+            return Ok(request);
+        };
+        let principal = value
+            .to_str()
+            .ok()
+            .and_then(TokenValidator::bearer)
+            .and_then(|token| self.validator.validate(token))
+            .ok_or_else(|| Status::unauthenticated("Invalid or expired token"))?;
+
+        request.extensions_mut().insert(principal);
+        Ok(request)
+    }
+}
+
+/// Only administrators, like the GraphQL `AdminGuard`
+pub fn require_admin<T>(request: &Request<T>) -> Result<Principal, Status> {
+    match request.extensions().get::<Principal>() {
+        Some(principal) if principal.is_admin => Ok(*principal),
+        Some(_) => Err(Status::permission_denied("Forbidden")),
+        None => Err(Status::unauthenticated("Authentication required")),
+    }
+}
+
+/// The user itself or an administrator, like the GraphQL `UserAccessGuard`
+pub fn require_user_access<T>(request: &Request<T>, user_id: Uuid) -> Result<Principal, Status> {
+    match request.extensions().get::<Principal>() {
+        Some(principal) if principal.can_access_user(user_id) => Ok(*principal),
+        Some(_) => Err(Status::permission_denied("Forbidden")),
+        None => Err(Status::unauthenticated("Authentication required")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AuthConfig;
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use serde_json::json;
+    use tonic::Code;
+
+    const SECRET: &str = "grpc-test-secret";
+
+    fn interceptor() -> AuthInterceptor {
+        AuthInterceptor::new(TokenValidator::new(&AuthConfig {
+            jwt_secret: Some(SECRET.to_string()),
+        }))
+    }
+
+    fn token(user_id: Uuid, role: Option<&str>) -> String {
+        let exp = chrono::Utc::now().timestamp() + 60;
+        encode(
+            &Header::default(),
+            &json!({ "sub": user_id, "role": role, "exp": exp }),
+            &EncodingKey::from_secret(SECRET.as_bytes()),
+        )
+        .unwrap()
+    }
+
+    fn call(authorization: Option<String>) -> Result<Request<()>, Status> {
+        let mut request = Request::new(());
+        if let Some(value) = authorization {
+            request
+                .metadata_mut()
+                .insert("authorization", value.parse().unwrap());
+        }
+        interceptor().call(request)
+    }
+
+    #[test]
+    fn anonymous_calls_pass_without_a_principal() {
+        let request = call(None).unwrap();
+
+        assert_eq!(
+            require_admin(&request).unwrap_err().code(),
+            Code::Unauthenticated
+        );
+        assert_eq!(
+            require_user_access(&request, Uuid::new_v4())
+                .unwrap_err()
+                .code(),
+            Code::Unauthenticated
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_tokens() {
+        for value in ["Bearer not-a-token", "Basic dXNlcjpwYXNz", "Bearer "] {
+            let status = call(Some(value.to_string())).unwrap_err();
+            assert_eq!(status.code(), Code::Unauthenticated, "{value}");
+        }
+    }
+
+    #[test]
+    fn users_may_only_access_themselves() {
+        let user_id = Uuid::new_v4();
+        let request = call(Some(format!("Bearer {}", token(user_id, None)))).unwrap();
+
+        assert_eq!(
+            require_user_access(&request, user_id).unwrap().user_id,
+            user_id
+        );
+        assert_eq!(
+            require_user_access(&request, Uuid::new_v4())
+                .unwrap_err()
+                .code(),
+            Code::PermissionDenied
+        );
+        assert_eq!(
+            require_admin(&request).unwrap_err().code(),
+            Code::PermissionDenied
+        );
+    }
+
+    #[test]
+    fn administrators_may_access_everyone() {
+        let request = call(Some(format!(
+            "Bearer {}",
+            token(Uuid::new_v4(), Some("admin"))
+        )))
+        .unwrap();
+
+        assert!(require_admin(&request).unwrap().is_admin);
+        assert!(require_user_access(&request, Uuid::new_v4()).is_ok());
+    }
+}
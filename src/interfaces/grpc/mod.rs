@@ -0,0 +1,223 @@
+// tonic::Status is large, but it is what every handler returns anyway
+#![allow(clippy::result_large_err)]
+
+pub mod auth;
+pub mod product_service;
+pub mod status;
+pub mod user_service;
+
+pub use auth::AuthInterceptor;
+pub use product_service::GrpcProductService;
+pub use user_service::GrpcUserService;
+
+use crate::{infrastructure::health::HealthRegistry, interfaces::middleware::auth::TokenValidator};
+use opentelemetry::global;
+use opentelemetry_http::HeaderExtractor;
+use prost_types::Timestamp;
+use sqlx::PgPool;
+use std::{future::Future, net::SocketAddr};
+use tonic::{codegen::http, server::NamedService, transport::Server, Status};
+use tonic_health::ServingStatus;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use uuid::Uuid;
+
+/// Code generated from `proto/`
+#[allow(clippy::all)]
+pub mod proto {
+    pub mod product {
+        tonic::include_proto!("product.v1");
+    }
+
+    pub mod user {
+        tonic::include_proto!("user.v1");
+    }
+
+    /// Descriptors of all services, served through reflection
+    pub const FILE_DESCRIPTOR_SET: &[u8] = tonic::include_file_descriptor_set!("descriptor");
+}
+
+use proto::{
+    product::product_service_server::ProductServiceServer,
+    user::user_service_server::UserServiceServer,
+};
+
+/// Page size when the client asks for none
+const DEFAULT_PAGE_SIZE: usize = 20;
+/// Largest page a client may request
+const MAX_PAGE_SIZE: usize = 100;
+
+/// Serves the gRPC services with reflection and the health checking protocol until
+/// `shutdown` completes. The services report NOT_SERVING as soon as `health` begins
+/// shutting down, like HTTP readiness. Callers authenticate with a bearer token in
+/// their `authorization` metadata, checked as for HTTP and GraphQL
+pub async fn serve(
+    addr: SocketAddr,
+    pool: PgPool,
+    validator: TokenValidator,
+    health: HealthRegistry,
+    shutdown: impl Future<Output = ()>,
+) -> Result<(), tonic::transport::Error> {
+    let (mut health_reporter, health_service) = tonic_health::server::health_reporter();
+    health_reporter
+        .set_serving::<ProductServiceServer<GrpcProductService>>()
+        .await;
+    health_reporter
+        .set_serving::<UserServiceServer<GrpcUserService>>()
+        .await;
+    let mut reporter = health_reporter.clone();
+    let not_serving = tokio::spawn(async move {
+        health.shutdown_begun().await;
+        for service in [
+            "",
+            ProductServiceServer::<GrpcProductService>::NAME,
+            UserServiceServer::<GrpcUserService>::NAME,
+        ] {
+            reporter
+                .set_service_status(service, ServingStatus::NotServing)
+                .await;
+        }
+    });
+
+    let reflection_service = tonic_reflection::server::Builder::configure()
+        .register_encoded_file_descriptor_set(proto::FILE_DESCRIPTOR_SET)
+        .register_encoded_file_descriptor_set(tonic_health::pb::FILE_DESCRIPTOR_SET)
+        .build_v1()
+        .expect("gRPC file descriptors must be valid");
+
+    let served = Server::builder()
+        .trace_fn(request_span)
+        .add_service(health_service)
+        .add_service(reflection_service)
+        .add_service(ProductServiceServer::with_interceptor(
+            GrpcProductService::new(pool.clone()),
+            AuthInterceptor::new(validator.clone()),
+        ))
+        .add_service(UserServiceServer::with_interceptor(
+            GrpcUserService::new(pool),
+            AuthInterceptor::new(validator),
+        ))
+        .serve_with_shutdown(addr, shutdown)
+        .await;
+
+    not_serving.abort();
+    served
+}
+
+/// A server span per call, continuing the caller's trace from its `traceparent`
+fn request_span(request: &http::Request<()>) -> tracing::Span {
+    let method = request.uri().path().trim_start_matches('/');
+    let span = tracing::info_span!(
+        "gRPC request",
+        otel.name = %method,
+        otel.kind = "server",
+        rpc.system = "grpc",
+    );
+    span.set_parent(global::get_text_map_propagator(|propagator| {
+        propagator.extract(&HeaderExtractor(request.headers()))
+    }));
+    span
+}
+
+fn parse_id(id: &str) -> Result<Uuid, Status> {
+    Uuid::parse_str(id).map_err(|_| Status::invalid_argument("Invalid id"))
+}
+
+fn timestamp(at: chrono::DateTime<chrono::Utc>) -> Option<Timestamp> {
+    Some(Timestamp {
+        seconds: at.timestamp(),
+        nanos: at.timestamp_subsec_nanos() as i32,
+    })
+}
+
+/// Slices `items` by an offset page token, returning the page and the next token
+fn paginate<T>(
+    items: Vec<T>,
+    page_size: i32,
+    page_token: &str,
+) -> Result<(Vec<T>, String), Status> {
+    let offset = match page_token {
+        "" => 0,
+        token => token
+            .parse::<usize>()
+            .map_err(|_| Status::invalid_argument("Invalid page_token"))?,
+    };
+    let page_size = match page_size {
+        size if size < 0 => return Err(Status::invalid_argument("page_size cannot be negative")),
+        0 => DEFAULT_PAGE_SIZE,
+        size => (size as usize).min(MAX_PAGE_SIZE),
+    };
+
+    let total = items.len();
+    let end = offset.saturating_add(page_size).min(total);
+    let next_page_token = if end < total {
+        end.to_string()
+    } else {
+        String::new()
+    };
+
+    Ok((
+        items.into_iter().skip(offset).take(page_size).collect(),
+        next_page_token,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AuthConfig;
+    use std::time::Duration;
+    use tonic_health::pb::{
+        health_check_response::ServingStatus as Status, health_client::HealthClient,
+        HealthCheckRequest,
+    };
+
+    async fn status(client: &mut HealthClient<tonic::transport::Channel>, service: &str) -> Status {
+        client
+            .check(HealthCheckRequest {
+                service: service.to_string(),
+            })
+            .await
+            .expect("the health service answers")
+            .into_inner()
+            .status()
+    }
+
+    #[tokio::test]
+    async fn services_stop_serving_when_shutdown_begins() {
+        let addr = std::net::TcpListener::bind("127.0.0.1:0")
+            .and_then(|listener| listener.local_addr())
+            .unwrap();
+        let pool = PgPool::connect_lazy("postgres://localhost/unused").unwrap();
+        let health = HealthRegistry::new(Duration::from_secs(1));
+        let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+        let validator = TokenValidator::new(&AuthConfig { jwt_secret: None });
+        let server = tokio::spawn(serve(addr, pool, validator, health.clone(), async {
+            let _ = stopped.await;
+        }));
+
+        let endpoint = tonic::transport::Endpoint::from_shared(format!("http://{addr}")).unwrap();
+        let mut client = loop {
+            match endpoint.connect().await {
+                Ok(channel) => break HealthClient::new(channel),
+                Err(_) => tokio::time::sleep(Duration::from_millis(20)).await,
+            }
+        };
+        let services = [
+            "",
+            ProductServiceServer::<GrpcProductService>::NAME,
+            UserServiceServer::<GrpcUserService>::NAME,
+        ];
+        for service in services {
+            assert_eq!(status(&mut client, service).await, Status::Serving);
+        }
+
+        health.begin_shutdown();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        for service in services {
+            assert_eq!(status(&mut client, service).await, Status::NotServing);
+        }
+
+        stop.send(()).unwrap();
+        server.await.unwrap().unwrap();
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{
+    application::use_cases::{
+        product::{
+            CreateProductUseCase, DeleteProductUseCase, GetProductUseCase, ListProductsUseCase,
+            UpdateProductUseCase,
+        },
+        UseCase,
+    },
+    domain::entities::product::{CreateProductDto, Product, UpdateProductDto},
+    infrastructure::persistence::postgres::PostgresProductRepository,
+    interfaces::grpc::{
+        auth::require_admin,
+        paginate, parse_id,
+        proto::product::{
+            product_service_server::ProductService, CreateProductRequest, DeleteProductRequest,
+            DeleteProductResponse, GetProductRequest, ListProductsRequest, ListProductsResponse,
+            Product as ProductMessage, UpdateProductRequest,
+        },
+        timestamp,
+    },
+};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use std::str::FromStr;
+use tonic::{Request, Response, Status};
+
+pub struct GrpcProductService {
+    pool: PgPool,
+}
+
+impl GrpcProductService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn repository(&self) -> PostgresProductRepository {
+        PostgresProductRepository::new(self.pool.clone())
+    }
+}
+
+fn parse_price(price: &str) -> Result<Decimal, Status> {
+    Decimal::from_str(price).map_err(|_| Status::invalid_argument("Invalid price"))
+}
+
+impl From<Product> for ProductMessage {
+    fn from(product: Product) -> Self {
+        Self {
+            id: product.id.to_string(),
+            sku: product.sku,
+            name: product.name,
+            description: product.description,
+            category: product.category,
+            price: product.price.to_string(),
+            currency: product.currency,
+            stock: product.stock,
+            created_at: timestamp(product.created_at),
+            updated_at: timestamp(product.updated_at),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl ProductService for GrpcProductService {
+    async fn get_product(
+        &self,
+        request: Request<GetProductRequest>,
+    ) -> Result<Response<ProductMessage>, Status> {
+        let id = parse_id(&request.get_ref().id)?;
+        let use_case = GetProductUseCase::new(self.repository());
+
+        let product = use_case.execute(id).await?;
+        Ok(Response::new(product.into()))
+    }
+
+    async fn list_products(
+        &self,
+        request: Request<ListProductsRequest>,
+    ) -> Result<Response<ListProductsResponse>, Status> {
+        let request = request.into_inner();
+        let use_case = ListProductsUseCase::new(self.repository());
+
+        let products = use_case.execute(()).await?;
+        let total = products.len() as i64;
+        let (page, next_page_token) = paginate(products, request.page_size, &request.page_token)?;
+
+        Ok(Response::new(ListProductsResponse {
+            products: page.into_iter().map(ProductMessage::from).collect(),
+            next_page_token,
+            total,
+        }))
+    }
+
+    async fn create_product(
+        &self,
+        request: Request<CreateProductRequest>,
+    ) -> Result<Response<ProductMessage>, Status> {
+        require_admin(&request)?;
+        let request = request.into_inner();
+        let dto = CreateProductDto {
+            sku: request.sku,
+            name: request.name,
+            description: request.description,
+            category: request.category,
+            price: parse_price(&request.price)?,
+            currency: request.currency,
+            stock: request.stock,
+        };
+        let use_case = CreateProductUseCase::new(self.repository());
+
+        let product = use_case.execute(dto).await?;
+        Ok(Response::new(product.into()))
+    }
+
+    async fn update_product(
+        &self,
+        request: Request<UpdateProductRequest>,
+    ) -> Result<Response<ProductMessage>, Status> {
+        require_admin(&request)?;
+        let request = request.into_inner();
+        let id = parse_id(&request.id)?;
+        let dto = UpdateProductDto {
+            sku: request.sku,
+            name: request.name,
+            description: request.description,
+            category: request.category,
+            price: request.price.as_deref().map(parse_price).transpose()?,
+            stock: request.stock,
+        };
+        let use_case = UpdateProductUseCase::new(self.repository());
+
+        let product = use_case.execute((id, dto)).await?;
+        Ok(Response::new(product.into()))
+    }
+
+    async fn delete_product(
+        &self,
+        request: Request<DeleteProductRequest>,
+    ) -> Result<Response<DeleteProductResponse>, Status> {
+        require_admin(&request)?;
+        let id = parse_id(&request.get_ref().id)?;
+        let use_case = DeleteProductUseCase::new(self.repository());
+
+        use_case.execute(id).await?;
+        Ok(Response::new(DeleteProductResponse {}))
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{application::error::ApplicationError, domain::repositories::RepositoryError};
+use log::error;
+use tonic::Status;
+
+impl From<ApplicationError> for Status {
+    fn from(e: ApplicationError) -> Self {
+        match e {
+            ApplicationError::Validation(msg) => Status::invalid_argument(msg),
+            ApplicationError::NotFound
+            | ApplicationError::Repository(RepositoryError::NotFound) => {
+                Status::not_found("Not found")
+            }
+            ApplicationError::Repository(RepositoryError::DuplicateEntry) => {
+                Status::already_exists("Already exists")
+            }
+            ApplicationError::Repository(RepositoryError::Conflict(msg)) => Status::aborted(msg),
+            ApplicationError::Unauthorized => Status::permission_denied("Unauthorized"),
+            e => {
+                error!("gRPC call failed: {:?}", e);
+                Status::internal("Internal server error")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tonic::Code;
+
+    fn status(e: ApplicationError) -> (Code, String) {
+        let status = Status::from(e);
+        (status.code(), status.message().to_string())
+    }
+
+    #[test]
+    fn maps_application_errors_to_status_codes() {
+        let cases = [
+            (
+                ApplicationError::Validation("Name is required".into()),
+                Code::InvalidArgument,
+                "Name is required",
+            ),
+            (ApplicationError::NotFound, Code::NotFound, "Not found"),
+            (
+                ApplicationError::Repository(RepositoryError::NotFound),
+                Code::NotFound,
+                "Not found",
+            ),
+            (
+                ApplicationError::Repository(RepositoryError::DuplicateEntry),
+                Code::AlreadyExists,
+                "Already exists",
+            ),
+            (
+                ApplicationError::Repository(RepositoryError::Conflict("Stock changed".into())),
+                Code::Aborted,
+                "Stock changed",
+            ),
+            (
+                ApplicationError::Unauthorized,
+                Code::PermissionDenied,
+                "Unauthorized",
+            ),
+        ];
+
+        for (error, code, message) in cases {
+            assert_eq!(status(error), (code, message.to_string()));
+        }
+    }
+
+    #[test]
+    fn hides_internal_failures() {
+        for error in [
+            ApplicationError::Repository(RepositoryError::DatabaseError(
+                "connection refused".into(),
+            )),
+            ApplicationError::Internal("template missing".into()),
+        ] {
+            assert_eq!(
+                status(error),
+                (Code::Internal, "Internal server error".to_string())
+            );
+        }
+    }
+}
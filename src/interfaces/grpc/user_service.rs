@@ -0,0 +1,130 @@
+use crate::{
+    application::use_cases::{
+        user::{
+            CreateUserUseCase, DeleteUserUseCase, GetUserUseCase, ListUsersUseCase,
+            UpdateUserUseCase,
+        },
+        UseCase,
+    },
+    domain::entities::user::{CreateUserDto, UpdateUserDto, User},
+    infrastructure::persistence::postgres::PostgresUserRepository,
+    interfaces::grpc::{
+        auth::{require_admin, require_user_access},
+        paginate, parse_id,
+        proto::user::{
+            user_service_server::UserService, CreateUserRequest, DeleteUserRequest,
+            DeleteUserResponse, GetUserRequest, ListUsersRequest, ListUsersResponse,
+            UpdateUserRequest, User as UserMessage,
+        },
+        timestamp,
+    },
+};
+use sqlx::PgPool;
+use tonic::{Request, Response, Status};
+
+pub struct GrpcUserService {
+    pool: PgPool,
+}
+
+impl GrpcUserService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn repository(&self) -> PostgresUserRepository {
+        PostgresUserRepository::new(self.pool.clone())
+    }
+}
+
+impl From<User> for UserMessage {
+    fn from(user: User) -> Self {
+        Self {
+            id: user.id.to_string(),
+            email: user.email,
+            username: user.username,
+            created_at: timestamp(user.created_at),
+            updated_at: timestamp(user.updated_at),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl UserService for GrpcUserService {
+    async fn get_user(
+        &self,
+        request: Request<GetUserRequest>,
+    ) -> Result<Response<UserMessage>, Status> {
+        let id = parse_id(&request.get_ref().id)?;
+        require_user_access(&request, id)?;
+        let use_case = GetUserUseCase::new(self.repository());
+
+        let user = use_case.execute(id).await?;
+        Ok(Response::new(user.into()))
+    }
+
+    async fn list_users(
+        &self,
+        request: Request<ListUsersRequest>,
+    ) -> Result<Response<ListUsersResponse>, Status> {
+        require_admin(&request)?;
+        let request = request.into_inner();
+        let use_case = ListUsersUseCase::new(self.repository());
+
+        let users = use_case.execute(()).await?;
+        let total = users.len() as i64;
+        let (page, next_page_token) = paginate(users, request.page_size, &request.page_token)?;
+
+        Ok(Response::new(ListUsersResponse {
+            users: page.into_iter().map(UserMessage::from).collect(),
+            next_page_token,
+            total,
+        }))
+    }
+
+    /// Open to everyone, like signing up through GraphQL
+    async fn create_user(
+        &self,
+        request: Request<CreateUserRequest>,
+    ) -> Result<Response<UserMessage>, Status> {
+        let request = request.into_inner();
+        let dto = CreateUserDto {
+            email: request.email,
+            username: request.username,
+            password: request.password,
+        };
+        let use_case = CreateUserUseCase::new(self.repository());
+
+        let user = use_case.execute(dto).await?;
+        Ok(Response::new(user.into()))
+    }
+
+    async fn update_user(
+        &self,
+        request: Request<UpdateUserRequest>,
+    ) -> Result<Response<UserMessage>, Status> {
+        let id = parse_id(&request.get_ref().id)?;
+        require_user_access(&request, id)?;
+        let request = request.into_inner();
+        let dto = UpdateUserDto {
+            email: request.email,
+            username: request.username,
+            password: request.password,
+        };
+        let use_case = UpdateUserUseCase::new(self.repository());
+
+        let user = use_case.execute((id, dto)).await?;
+        Ok(Response::new(user.into()))
+    }
+
+    async fn delete_user(
+        &self,
+        request: Request<DeleteUserRequest>,
+    ) -> Result<Response<DeleteUserResponse>, Status> {
+        let id = parse_id(&request.get_ref().id)?;
+        require_user_access(&request, id)?;
+        let use_case = DeleteUserUseCase::new(self.repository());
+
+        use_case.execute(id).await?;
+        Ok(Response::new(DeleteUserResponse {}))
+    }
+}
@@ -0,0 +1,360 @@
+use actix_http::Request;
+use actix_service::{boxed::BoxService, IntoServiceFactory, ServiceExt};
+use actix_web::{
+    body::{self, MessageBody},
+    dev::{AppConfig, Payload, Service, ServiceFactory, ServiceResponse},
+    error::PayloadError,
+    http::{
+        header::{self, HeaderName, HeaderValue},
+        Method, StatusCode,
+    },
+    web::{self, Bytes},
+    App, HttpMessage, HttpRequest, HttpResponse, Responder,
+};
+use futures::Stream;
+use log::error;
+use serde_json::{json, Value};
+use std::pin::Pin;
+use std::rc::Rc;
+use tokio::sync::OnceCell;
+use uuid::Uuid;
+
+use crate::{
+    config::{MediaConfig, PaymentConfig},
+    infrastructure::{
+        events::BroadcastUpdateBus, persistence::postgres::SharedTransaction,
+        storage::ConfiguredStorage,
+    },
+    interfaces::{
+        api::routes::configure_api,
+        http::{
+            requests::batch_requests::{BatchOperation, BatchRequest},
+            responses::batch_responses::{BatchOperationResult, BatchResponse},
+        },
+        middleware::auth::Principal,
+    },
+};
+
+pub struct BatchController;
+
+type ApiService = BoxService<Request, ServiceResponse, actix_web::Error>;
+
+/// The `/api/v1` endpoints as a service batch operations run through, built on a
+/// worker's first batch and reused by the ones after it
+#[derive(Default)]
+pub struct InternalApi {
+    service: OnceCell<Rc<ApiService>>,
+}
+
+impl InternalApi {
+    async fn service(&self, req: &HttpRequest) -> Result<Rc<ApiService>, ()> {
+        self.service
+            .get_or_try_init(|| async {
+                // The same endpoints and shared state as the server
+                let app = App::new().configure(|cfg| {
+                    share::<sqlx::PgPool>(cfg, req);
+                    share::<PaymentConfig>(cfg, req);
+                    share::<MediaConfig>(cfg, req);
+                    share::<ConfiguredStorage>(cfg, req);
+                    share::<BroadcastUpdateBus>(cfg, req);
+                    configure_api(cfg);
+                });
+                let service = app.into_factory().new_service(AppConfig::default()).await?;
+                Ok(Rc::new(actix_service::boxed::service(
+                    service.map(ServiceResponse::map_into_boxed_body),
+                )))
+            })
+            .await
+            .cloned()
+    }
+}
+
+/// Hands the server's `web::Data<T>` to the internal service
+fn share<T: 'static>(cfg: &mut web::ServiceConfig, req: &HttpRequest) {
+    if let Some(data) = req.app_data::<web::Data<T>>() {
+        cfg.app_data(data.clone());
+    }
+}
+
+/// Most operations a single batch may carry
+const MAX_BATCH_OPERATIONS: usize = 100;
+
+const BATCH_PATH: &str = "/api/v1/batch";
+
+/// Endpoints streaming until the client disconnects, a batch waiting on them would never finish
+const STREAMING_PATHS: [&str; 1] = ["/api/v1/products/events"];
+
+/// Checks an operation before anything runs, returning why it is unusable
+fn validate_operation(operation: &BatchOperation, atomic: bool) -> Result<Method, String> {
+    let method = Method::from_bytes(operation.method.to_ascii_uppercase().as_bytes())
+        .ok()
+        .filter(|method| {
+            [
+                Method::GET,
+                Method::POST,
+                Method::PUT,
+                Method::PATCH,
+                Method::DELETE,
+            ]
+            .contains(method)
+        })
+        .ok_or_else(|| format!("Unsupported method '{}'", operation.method))?;
+
+    let path = operation.path.split('?').next().unwrap_or_default();
+    if !path.starts_with("/api/v1/") {
+        return Err(format!(
+            "Path '{}' is not an /api/v1 endpoint",
+            operation.path
+        ));
+    }
+    if path.trim_end_matches('/') == BATCH_PATH {
+        return Err("Batches cannot be nested".to_string());
+    }
+    if STREAMING_PATHS.contains(&path.trim_end_matches('/')) {
+        return Err(format!(
+            "Path '{}' streams events and cannot be batched",
+            operation.path
+        ));
+    }
+    if atomic && !joins_transaction(&method, path) {
+        return Err(format!(
+            "{} '{}' cannot be part of an atomic batch, only product and user writes can",
+            method, operation.path
+        ));
+    }
+    if operation.path.parse::<actix_web::http::Uri>().is_err() {
+        return Err(format!("Invalid path '{}'", operation.path));
+    }
+
+    for (name, value) in &operation.headers {
+        if HeaderName::try_from(name.as_str()).is_err() || HeaderValue::from_str(value).is_err() {
+            return Err(format!("Invalid header '{}'", name));
+        }
+    }
+
+    Ok(method)
+}
+
+/// Whether an operation only writes through the product and user repositories, which
+/// run on the batch's transaction
+fn joins_transaction(method: &Method, path: &str) -> bool {
+    let segments: Vec<&str> = path
+        .trim_start_matches("/api/v1/")
+        .trim_end_matches('/')
+        .split('/')
+        .collect();
+    let is_id = |segment: &str| Uuid::parse_str(segment).is_ok();
+
+    match segments.as_slice() {
+        ["products" | "users"] => method == Method::POST,
+        ["products", "bulk"] => [Method::POST, Method::PATCH, Method::DELETE].contains(method),
+        ["products" | "users", id] if is_id(id) => [Method::PUT, Method::DELETE].contains(method),
+        _ => false,
+    }
+}
+
+/// Turns an operation into a request for the internal service
+fn build_request(operation: &BatchOperation, method: Method) -> Request {
+    let body = operation
+        .body
+        .as_ref()
+        .map(|body| Bytes::from(body.to_string()));
+
+    let mut request = match &body {
+        Some(body) => {
+            let body = body.clone();
+            let stream: Pin<Box<dyn Stream<Item = Result<Bytes, PayloadError>>>> =
+                Box::pin(futures::stream::once(async move { Ok(body) }));
+            Request::with_payload(Payload::from(stream))
+        }
+        None => Request::new(),
+    };
+
+    let head = request.head_mut();
+    head.method = method;
+    // Validated beforehand
+    head.uri = operation.path.parse().unwrap_or_default();
+    head.headers
+        .insert(header::ACCEPT, HeaderValue::from_static("application/json"));
+    if let Some(body) = &body {
+        head.headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+        head.headers
+            .insert(header::CONTENT_LENGTH, HeaderValue::from(body.len()));
+    }
+    for (name, value) in &operation.headers {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::try_from(name.as_str()),
+            HeaderValue::from_str(value),
+        ) {
+            head.headers.insert(name, value);
+        }
+    }
+
+    request
+}
+
+/// Runs one operation through the internal service and captures its answer
+async fn dispatch<S, B>(service: &S, request: Request) -> (u16, Value)
+where
+    S: Service<Request, Response = ServiceResponse<B>, Error = actix_web::Error>,
+    B: MessageBody + 'static,
+{
+    let response = match service.call(request).await {
+        Ok(response) => response.into_parts().1.map_into_boxed_body(),
+        Err(e) => e.error_response(),
+    };
+
+    let status = response.status().as_u16();
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"));
+
+    let body = match body::to_bytes(response.into_body()).await {
+        Ok(data) if data.is_empty() => Value::Null,
+        Ok(data) if is_json => serde_json::from_slice(&data)
+            .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&data).into_owned())),
+        Ok(data) => Value::String(String::from_utf8_lossy(&data).into_owned()),
+        Err(_) => json!({ "error": "Unreadable response body" }),
+    };
+
+    (status, body)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/batch",
+    tag = "batch",
+    request_body = BatchRequest,
+    responses(
+        (status = 200, description = "Operations executed, with one result each", body = BatchResponse),
+        (status = 400, description = "Empty or oversized batch, or an unusable operation", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn execute_batch_doc() {}
+
+impl BatchController {
+    /// Execute several API operations in one request
+    pub async fn execute_batch(
+        req: HttpRequest,
+        pool: web::Data<sqlx::PgPool>,
+        batch: web::Json<BatchRequest>,
+    ) -> impl Responder {
+        let BatchRequest { atomic, operations } = batch.into_inner();
+
+        // Validate input
+        if operations.is_empty() {
+            return HttpResponse::BadRequest().json(json!({
+                "error": "A batch needs at least one operation"
+            }));
+        }
+        if operations.len() > MAX_BATCH_OPERATIONS {
+            return HttpResponse::BadRequest().json(json!({
+                "error": format!("A batch holds at most {} operations", MAX_BATCH_OPERATIONS)
+            }));
+        }
+        let mut methods = Vec::with_capacity(operations.len());
+        for (index, operation) in operations.iter().enumerate() {
+            match validate_operation(operation, atomic) {
+                Ok(method) => methods.push(method),
+                Err(msg) => {
+                    return HttpResponse::BadRequest().json(json!({
+                        "error": format!("Operation {}: {}", index, msg)
+                    }))
+                }
+            }
+        }
+
+        let transaction = if atomic {
+            match SharedTransaction::begin(pool.get_ref()).await {
+                Ok(transaction) => Some(transaction),
+                Err(e) => {
+                    error!("Error starting batch transaction: {:?}", e);
+                    return HttpResponse::InternalServerError().json(json!({
+                        "error": "Internal server error"
+                    }));
+                }
+            }
+        } else {
+            None
+        };
+        let database = transaction.as_ref().map(SharedTransaction::database);
+        // Operations run as the caller of the batch
+        let principal = req.extensions().get::<Principal>().copied();
+
+        let api = req.app_data::<InternalApi>();
+        let service = match api.map(|api| api.service(&req)) {
+            Some(service) => service.await,
+            None => Err(()),
+        };
+        let service = match service {
+            Ok(service) => service,
+            Err(_) => {
+                error!("Error building the batch service");
+                if let Some(transaction) = transaction {
+                    let _ = transaction.rollback().await;
+                }
+                return HttpResponse::InternalServerError().json(json!({
+                    "error": "Internal server error"
+                }));
+            }
+        };
+
+        let mut results = Vec::with_capacity(operations.len());
+        let mut failed = false;
+        for (index, (operation, method)) in operations.iter().zip(methods).enumerate() {
+            // An atomic batch stops at the first failure, the rest would be rolled back anyway
+            if atomic && failed {
+                results.push(BatchOperationResult {
+                    index,
+                    status: StatusCode::FAILED_DEPENDENCY.as_u16(),
+                    body: json!({ "error": "Not executed, an earlier operation failed" }),
+                });
+                continue;
+            }
+
+            let request = build_request(operation, method);
+            if let Some(principal) = principal {
+                request.extensions_mut().insert(principal);
+            }
+            // Product and user writes pick the transaction up from the request
+            if let Some(database) = &database {
+                request.extensions_mut().insert(database.clone());
+            }
+            let (status, body) = dispatch(service.as_ref(), request).await;
+            failed |= status >= 400;
+            results.push(BatchOperationResult {
+                index,
+                status,
+                body,
+            });
+        }
+
+        let committed = match transaction {
+            Some(transaction) if failed => {
+                if let Err(e) = transaction.rollback().await {
+                    error!("Error rolling back batch: {:?}", e);
+                }
+                false
+            }
+            Some(transaction) => match transaction.commit().await {
+                Ok(()) => true,
+                Err(e) => {
+                    error!("Error committing batch: {:?}", e);
+                    false
+                }
+            },
+            None => true,
+        };
+        HttpResponse::Ok().json(BatchResponse {
+            atomic,
+            committed,
+            results,
+        })
+    }
+}
@@ -0,0 +1,474 @@
+use actix_web::{web, HttpResponse, Responder};
+use log::error;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        error::ApplicationError,
+        use_cases::{
+            cart::{
+                AddCartItemUseCase, CreateCartUseCase, GetCartUseCase, MergeCartUseCase,
+                QuoteCartUseCase, RemoveCartItemUseCase, UpdateCartItemUseCase,
+            },
+            UseCase,
+        },
+    },
+    domain::entities::cart::{
+        AddCartItemDto, CartOwner, MergeCartDto, PricedCart, UpdateCartItemDto,
+    },
+    infrastructure::persistence::postgres::{
+        PostgresCartRepository, PostgresProductRepository, PostgresPromotionRepository,
+    },
+    interfaces::http::{
+        requests::cart_requests::QuoteCartQuery,
+        responses::cart_responses::{CartQuoteResponse, CartResponse},
+    },
+};
+
+pub struct CartController;
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/carts",
+    tag = "carts",
+    responses(
+        (status = 201, description = "Anonymous cart created successfully", body = CartResponse),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn create_cart_doc() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/carts/{token}",
+    tag = "carts",
+    params(
+        ("token" = Uuid, Path, description = "Anonymous cart token")
+    ),
+    responses(
+        (status = 200, description = "Cart found and re-priced", body = CartResponse),
+        (status = 404, description = "Cart not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn get_anonymous_cart_doc() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/carts/{token}/items",
+    tag = "carts",
+    params(
+        ("token" = Uuid, Path, description = "Anonymous cart token")
+    ),
+    request_body = AddCartItemDto,
+    responses(
+        (status = 200, description = "Item added to cart", body = CartResponse),
+        (status = 400, description = "Invalid input", body = String),
+        (status = 404, description = "Cart not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn add_anonymous_cart_item_doc() {}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/carts/{token}/items/{product_id}",
+    tag = "carts",
+    params(
+        ("token" = Uuid, Path, description = "Anonymous cart token"),
+        ("product_id" = Uuid, Path, description = "Product ID")
+    ),
+    request_body = UpdateCartItemDto,
+    responses(
+        (status = 200, description = "Cart item updated", body = CartResponse),
+        (status = 400, description = "Invalid input", body = String),
+        (status = 404, description = "Cart or item not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn update_anonymous_cart_item_doc() {}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/carts/{token}/items/{product_id}",
+    tag = "carts",
+    params(
+        ("token" = Uuid, Path, description = "Anonymous cart token"),
+        ("product_id" = Uuid, Path, description = "Product ID")
+    ),
+    responses(
+        (status = 200, description = "Cart item removed", body = CartResponse),
+        (status = 404, description = "Cart or item not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn remove_anonymous_cart_item_doc() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/{id}/cart",
+    tag = "carts",
+    params(
+        ("id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "Cart found and re-priced", body = CartResponse),
+        (status = 404, description = "User not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn get_user_cart_doc() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/users/{id}/cart/items",
+    tag = "carts",
+    params(
+        ("id" = Uuid, Path, description = "User ID")
+    ),
+    request_body = AddCartItemDto,
+    responses(
+        (status = 200, description = "Item added to cart", body = CartResponse),
+        (status = 400, description = "Invalid input", body = String),
+        (status = 404, description = "User not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn add_user_cart_item_doc() {}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/users/{id}/cart/items/{product_id}",
+    tag = "carts",
+    params(
+        ("id" = Uuid, Path, description = "User ID"),
+        ("product_id" = Uuid, Path, description = "Product ID")
+    ),
+    request_body = UpdateCartItemDto,
+    responses(
+        (status = 200, description = "Cart item updated", body = CartResponse),
+        (status = 400, description = "Invalid input", body = String),
+        (status = 404, description = "User or item not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn update_user_cart_item_doc() {}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/users/{id}/cart/items/{product_id}",
+    tag = "carts",
+    params(
+        ("id" = Uuid, Path, description = "User ID"),
+        ("product_id" = Uuid, Path, description = "Product ID")
+    ),
+    responses(
+        (status = 200, description = "Cart item removed", body = CartResponse),
+        (status = 404, description = "User or item not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn remove_user_cart_item_doc() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/users/{id}/cart/merge",
+    tag = "carts",
+    params(
+        ("id" = Uuid, Path, description = "User ID")
+    ),
+    request_body = MergeCartDto,
+    responses(
+        (status = 200, description = "Anonymous cart merged into user cart", body = CartResponse),
+        (status = 400, description = "Cart cannot be merged", body = String),
+        (status = 404, description = "User or anonymous cart not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn merge_cart_doc() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/carts/{token}/quote",
+    tag = "carts",
+    params(
+        ("token" = Uuid, Path, description = "Anonymous cart token"),
+        QuoteCartQuery
+    ),
+    responses(
+        (status = 200, description = "Cart priced with promotions applied", body = CartQuoteResponse),
+        (status = 404, description = "Cart not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn quote_anonymous_cart_doc() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/{id}/cart/quote",
+    tag = "carts",
+    params(
+        ("id" = Uuid, Path, description = "User ID"),
+        QuoteCartQuery
+    ),
+    responses(
+        (status = 200, description = "Cart priced with promotions applied", body = CartQuoteResponse),
+        (status = 404, description = "User not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn quote_user_cart_doc() {}
+
+impl CartController {
+    /// Create a new anonymous cart
+    pub async fn create_cart(pool: web::Data<sqlx::PgPool>) -> impl Responder {
+        let use_case = CreateCartUseCase::new(PostgresCartRepository::new(pool.get_ref().clone()));
+
+        match use_case.execute(()).await {
+            Ok(cart) => HttpResponse::Created().json(CartResponse::from(cart)),
+            Err(e) => {
+                error!("Error creating cart: {:?}", e);
+                HttpResponse::InternalServerError().json(json!({
+                    "error": "Internal server error"
+                }))
+            }
+        }
+    }
+
+    /// Get an anonymous cart by token
+    pub async fn get_anonymous_cart(
+        pool: web::Data<sqlx::PgPool>,
+        token: web::Path<Uuid>,
+    ) -> impl Responder {
+        Self::get_cart(pool, CartOwner::Anonymous(token.into_inner())).await
+    }
+
+    /// Add an item to an anonymous cart
+    pub async fn add_anonymous_cart_item(
+        pool: web::Data<sqlx::PgPool>,
+        token: web::Path<Uuid>,
+        item_data: web::Json<AddCartItemDto>,
+    ) -> impl Responder {
+        Self::add_item(
+            pool,
+            CartOwner::Anonymous(token.into_inner()),
+            item_data.into_inner(),
+        )
+        .await
+    }
+
+    /// Update the quantity of an item in an anonymous cart
+    pub async fn update_anonymous_cart_item(
+        pool: web::Data<sqlx::PgPool>,
+        path: web::Path<(Uuid, Uuid)>,
+        item_data: web::Json<UpdateCartItemDto>,
+    ) -> impl Responder {
+        let (token, product_id) = path.into_inner();
+        Self::update_item(
+            pool,
+            CartOwner::Anonymous(token),
+            product_id,
+            item_data.into_inner(),
+        )
+        .await
+    }
+
+    /// Remove an item from an anonymous cart
+    pub async fn remove_anonymous_cart_item(
+        pool: web::Data<sqlx::PgPool>,
+        path: web::Path<(Uuid, Uuid)>,
+    ) -> impl Responder {
+        let (token, product_id) = path.into_inner();
+        Self::remove_item(pool, CartOwner::Anonymous(token), product_id).await
+    }
+
+    /// Get a user's cart, creating it on first access
+    pub async fn get_user_cart(
+        pool: web::Data<sqlx::PgPool>,
+        user_id: web::Path<Uuid>,
+    ) -> impl Responder {
+        Self::get_cart(pool, CartOwner::User(user_id.into_inner())).await
+    }
+
+    /// Add an item to a user's cart
+    pub async fn add_user_cart_item(
+        pool: web::Data<sqlx::PgPool>,
+        user_id: web::Path<Uuid>,
+        item_data: web::Json<AddCartItemDto>,
+    ) -> impl Responder {
+        Self::add_item(
+            pool,
+            CartOwner::User(user_id.into_inner()),
+            item_data.into_inner(),
+        )
+        .await
+    }
+
+    /// Update the quantity of an item in a user's cart
+    pub async fn update_user_cart_item(
+        pool: web::Data<sqlx::PgPool>,
+        path: web::Path<(Uuid, Uuid)>,
+        item_data: web::Json<UpdateCartItemDto>,
+    ) -> impl Responder {
+        let (user_id, product_id) = path.into_inner();
+        Self::update_item(
+            pool,
+            CartOwner::User(user_id),
+            product_id,
+            item_data.into_inner(),
+        )
+        .await
+    }
+
+    /// Remove an item from a user's cart
+    pub async fn remove_user_cart_item(
+        pool: web::Data<sqlx::PgPool>,
+        path: web::Path<(Uuid, Uuid)>,
+    ) -> impl Responder {
+        let (user_id, product_id) = path.into_inner();
+        Self::remove_item(pool, CartOwner::User(user_id), product_id).await
+    }
+
+    /// Merge an anonymous cart into a user's cart
+    pub async fn merge_cart(
+        pool: web::Data<sqlx::PgPool>,
+        user_id: web::Path<Uuid>,
+        merge_data: web::Json<MergeCartDto>,
+    ) -> impl Responder {
+        let use_case = MergeCartUseCase::new(
+            PostgresCartRepository::new(pool.get_ref().clone()),
+            PostgresProductRepository::new(pool.get_ref().clone()),
+        );
+
+        let result = use_case
+            .execute((user_id.into_inner(), merge_data.into_inner()))
+            .await;
+        Self::cart_response(result, "Cart not found")
+    }
+
+    /// Price an anonymous cart with promotions and an optional discount code
+    pub async fn quote_anonymous_cart(
+        pool: web::Data<sqlx::PgPool>,
+        token: web::Path<Uuid>,
+        query: web::Query<QuoteCartQuery>,
+    ) -> impl Responder {
+        Self::quote_cart(
+            pool,
+            CartOwner::Anonymous(token.into_inner()),
+            query.into_inner().code,
+        )
+        .await
+    }
+
+    /// Price a user's cart with promotions and an optional discount code
+    pub async fn quote_user_cart(
+        pool: web::Data<sqlx::PgPool>,
+        user_id: web::Path<Uuid>,
+        query: web::Query<QuoteCartQuery>,
+    ) -> impl Responder {
+        Self::quote_cart(
+            pool,
+            CartOwner::User(user_id.into_inner()),
+            query.into_inner().code,
+        )
+        .await
+    }
+
+    async fn get_cart(pool: web::Data<sqlx::PgPool>, owner: CartOwner) -> HttpResponse {
+        let use_case = GetCartUseCase::new(
+            PostgresCartRepository::new(pool.get_ref().clone()),
+            PostgresProductRepository::new(pool.get_ref().clone()),
+        );
+
+        Self::cart_response(use_case.execute(owner).await, "Cart not found")
+    }
+
+    async fn add_item(
+        pool: web::Data<sqlx::PgPool>,
+        owner: CartOwner,
+        item: AddCartItemDto,
+    ) -> HttpResponse {
+        let use_case = AddCartItemUseCase::new(
+            PostgresCartRepository::new(pool.get_ref().clone()),
+            PostgresProductRepository::new(pool.get_ref().clone()),
+        );
+
+        Self::cart_response(use_case.execute((owner, item)).await, "Cart not found")
+    }
+
+    async fn update_item(
+        pool: web::Data<sqlx::PgPool>,
+        owner: CartOwner,
+        product_id: Uuid,
+        item: UpdateCartItemDto,
+    ) -> HttpResponse {
+        let use_case = UpdateCartItemUseCase::new(
+            PostgresCartRepository::new(pool.get_ref().clone()),
+            PostgresProductRepository::new(pool.get_ref().clone()),
+        );
+
+        let result = use_case.execute((owner, product_id, item)).await;
+        Self::cart_response(result, "Cart item not found")
+    }
+
+    async fn remove_item(
+        pool: web::Data<sqlx::PgPool>,
+        owner: CartOwner,
+        product_id: Uuid,
+    ) -> HttpResponse {
+        let use_case = RemoveCartItemUseCase::new(
+            PostgresCartRepository::new(pool.get_ref().clone()),
+            PostgresProductRepository::new(pool.get_ref().clone()),
+        );
+
+        let result = use_case.execute((owner, product_id)).await;
+        Self::cart_response(result, "Cart item not found")
+    }
+
+    async fn quote_cart(
+        pool: web::Data<sqlx::PgPool>,
+        owner: CartOwner,
+        code: Option<String>,
+    ) -> HttpResponse {
+        let use_case = QuoteCartUseCase::new(
+            PostgresCartRepository::new(pool.get_ref().clone()),
+            PostgresProductRepository::new(pool.get_ref().clone()),
+            PostgresPromotionRepository::new(pool.get_ref().clone()),
+        );
+
+        match use_case.execute((owner, code)).await {
+            Ok(quote) => HttpResponse::Ok().json(CartQuoteResponse::from(quote)),
+            Err(ApplicationError::NotFound) => {
+                HttpResponse::NotFound().json(json!({ "error": "Cart not found" }))
+            }
+            Err(e) => {
+                error!("Error quoting cart: {:?}", e);
+                HttpResponse::InternalServerError().json(json!({
+                    "error": "Internal server error"
+                }))
+            }
+        }
+    }
+
+    fn cart_response(
+        result: Result<PricedCart, ApplicationError>,
+        not_found_message: &str,
+    ) -> HttpResponse {
+        match result {
+            Ok(cart) => HttpResponse::Ok().json(CartResponse::from(cart)),
+            Err(ApplicationError::NotFound) => {
+                HttpResponse::NotFound().json(json!({ "error": not_found_message }))
+            }
+            Err(ApplicationError::Validation(msg)) => {
+                HttpResponse::BadRequest().json(json!({ "error": msg }))
+            }
+            Err(e) => {
+                error!("Error handling cart request: {:?}", e);
+                HttpResponse::InternalServerError().json(json!({
+                    "error": "Internal server error"
+                }))
+            }
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use actix_web::{web, HttpResponse, Responder};
+use log::error;
+use serde_json::json;
+
+use crate::{
+    application::{
+        error::ApplicationError,
+        use_cases::{
+            exchange_rate::{
+                DeleteExchangeRateUseCase, ListExchangeRatesUseCase, SetExchangeRateUseCase,
+            },
+            UseCase,
+        },
+    },
+    domain::entities::exchange_rate::SetExchangeRateDto,
+    infrastructure::persistence::postgres::PostgresExchangeRateRepository,
+    interfaces::http::responses::exchange_rate_responses::{
+        ExchangeRateResponse, ExchangeRatesListResponse,
+    },
+};
+
+pub struct ExchangeRateController;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/exchange-rates",
+    tag = "exchange-rates",
+    responses(
+        (status = 200, description = "List all exchange rates", body = ExchangeRatesListResponse),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn list_exchange_rates_doc() {}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/exchange-rates/{base}/{quote}",
+    tag = "exchange-rates",
+    params(
+        ("base" = String, Path, description = "ISO 4217 currency converted from"),
+        ("quote" = String, Path, description = "ISO 4217 currency converted to")
+    ),
+    request_body = SetExchangeRateDto,
+    responses(
+        (status = 200, description = "Exchange rate set", body = ExchangeRateResponse),
+        (status = 400, description = "Invalid input", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn set_exchange_rate_doc() {}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/exchange-rates/{base}/{quote}",
+    tag = "exchange-rates",
+    params(
+        ("base" = String, Path, description = "ISO 4217 currency converted from"),
+        ("quote" = String, Path, description = "ISO 4217 currency converted to")
+    ),
+    responses(
+        (status = 204, description = "Exchange rate deleted"),
+        (status = 400, description = "Invalid input", body = String),
+        (status = 404, description = "Exchange rate not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn delete_exchange_rate_doc() {}
+
+impl ExchangeRateController {
+    /// List all exchange rates
+    pub async fn list_exchange_rates(pool: web::Data<sqlx::PgPool>) -> impl Responder {
+        let use_case = ListExchangeRatesUseCase::new(PostgresExchangeRateRepository::new(
+            pool.get_ref().clone(),
+        ));
+
+        match use_case.execute(()).await {
+            Ok(rates) => HttpResponse::Ok().json(ExchangeRatesListResponse::from(rates)),
+            Err(e) => {
+                error!("Error listing exchange rates: {:?}", e);
+                HttpResponse::InternalServerError().json(json!({
+                    "error": "Internal server error"
+                }))
+            }
+        }
+    }
+
+    /// Create or replace the exchange rate for a currency pair
+    pub async fn set_exchange_rate(
+        pool: web::Data<sqlx::PgPool>,
+        path: web::Path<(String, String)>,
+        rate_data: web::Json<SetExchangeRateDto>,
+    ) -> impl Responder {
+        let use_case = SetExchangeRateUseCase::new(PostgresExchangeRateRepository::new(
+            pool.get_ref().clone(),
+        ));
+        let (base, quote) = path.into_inner();
+
+        match use_case
+            .execute((
+                base.to_ascii_uppercase(),
+                quote.to_ascii_uppercase(),
+                rate_data.into_inner(),
+            ))
+            .await
+        {
+            Ok(rate) => HttpResponse::Ok().json(ExchangeRateResponse::from(rate)),
+            Err(ApplicationError::Validation(msg)) => {
+                HttpResponse::BadRequest().json(json!({ "error": msg }))
+            }
+            Err(e) => {
+                error!("Error setting exchange rate: {:?}", e);
+                HttpResponse::InternalServerError().json(json!({
+                    "error": "Internal server error"
+                }))
+            }
+        }
+    }
+
+    /// Delete the exchange rate for a currency pair
+    pub async fn delete_exchange_rate(
+        pool: web::Data<sqlx::PgPool>,
+        path: web::Path<(String, String)>,
+    ) -> impl Responder {
+        let use_case = DeleteExchangeRateUseCase::new(PostgresExchangeRateRepository::new(
+            pool.get_ref().clone(),
+        ));
+        let (base, quote) = path.into_inner();
+
+        match use_case
+            .execute((base.to_ascii_uppercase(), quote.to_ascii_uppercase()))
+            .await
+        {
+            Ok(_) => HttpResponse::NoContent().finish(),
+            Err(ApplicationError::NotFound) => HttpResponse::NotFound().json(json!({
+                "error": "Exchange rate not found"
+            })),
+            Err(ApplicationError::Validation(msg)) => {
+                HttpResponse::BadRequest().json(json!({ "error": msg }))
+            }
+            Err(_) => HttpResponse::InternalServerError().json(json!({
+                "error": "Internal server error"
+            })),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::interfaces::{
+    graphql::{
+        loaders::{PriceHistoryLoader, ProductImagesLoader},
+        AppSchema,
+    },
+    middleware::auth::Principal,
+};
+use actix_web::{http::header, web, HttpMessage, HttpRequest, HttpResponse};
+use async_graphql::{dataloader::DataLoader, http::GraphiQLSource};
+use async_graphql_actix_web::{GraphQLRequest, GraphQLResponse};
+use sqlx::PgPool;
+
+pub struct GraphQLController;
+
+impl GraphQLController {
+    /// Executes a query or mutation as the authenticated principal, with fresh
+    /// DataLoaders so batching and caching stay within the request
+    pub async fn execute(
+        schema: web::Data<AppSchema>,
+        pool: web::Data<PgPool>,
+        req: HttpRequest,
+        request: GraphQLRequest,
+    ) -> GraphQLResponse {
+        let mut request = request
+            .into_inner()
+            .data(DataLoader::new(
+                ProductImagesLoader::new(pool.get_ref().clone()),
+                actix_web::rt::spawn,
+            ))
+            .data(DataLoader::new(
+                PriceHistoryLoader::new(pool.get_ref().clone()),
+                actix_web::rt::spawn,
+            ));
+
+        if let Some(principal) = req.extensions().get::<Principal>().copied() {
+            request = request.data(principal);
+        }
+
+        schema.execute(request).await.into()
+    }
+
+    /// GraphiQL IDE, only served outside production
+    pub async fn graphiql() -> HttpResponse {
+        HttpResponse::Ok()
+            .insert_header((header::CONTENT_TYPE, "text/html; charset=utf-8"))
+            .body(GraphiQLSource::build().endpoint("/graphql").finish())
+    }
+}
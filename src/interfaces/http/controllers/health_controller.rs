@@ -0,0 +1,73 @@
+use actix_web::{web, HttpResponse, Responder};
+use log::{error, warn};
+use serde_json::json;
+
+use crate::{
+    application::use_cases::{health::CheckReadinessUseCase, UseCase},
+    domain::entities::health::HealthStatus,
+    infrastructure::health::HealthRegistry,
+    interfaces::http::responses::health_responses::LivenessResponse,
+};
+
+pub struct HealthController;
+
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    tag = "health",
+    responses(
+        (status = 200, description = "The process is running, dependencies are not checked", body = LivenessResponse)
+    )
+)]
+async fn live_doc() {}
+
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "health",
+    responses(
+        (status = 200, description = "Every dependency is up, the server accepts traffic", body = HealthReport),
+        (status = 503, description = "A dependency is down or the server is shutting down", body = HealthReport)
+    )
+)]
+async fn ready_doc() {}
+
+impl HealthController {
+    /// Liveness probe
+    pub async fn live(health: web::Data<HealthRegistry>) -> impl Responder {
+        HttpResponse::Ok().json(LivenessResponse {
+            status: HealthStatus::Up,
+            shutting_down: health.is_shutting_down(),
+            uptime_seconds: health.uptime_seconds(),
+        })
+    }
+
+    /// Readiness probe with a report of every dependency check
+    pub async fn ready(health: web::Data<HealthRegistry>) -> impl Responder {
+        let use_case = CheckReadinessUseCase::new(health.checks(), health.check_timeout());
+
+        match use_case.execute(health.is_shutting_down()).await {
+            Ok(report) if report.status == HealthStatus::Up => HttpResponse::Ok().json(report),
+            Ok(report) => {
+                for check in report
+                    .checks
+                    .iter()
+                    .filter(|c| c.status == HealthStatus::Down)
+                {
+                    warn!(
+                        "Readiness check {} failed: {}",
+                        check.name,
+                        check.error.as_deref().unwrap_or_default()
+                    );
+                }
+                HttpResponse::ServiceUnavailable().json(report)
+            }
+            Err(e) => {
+                error!("Error checking readiness: {:?}", e);
+                HttpResponse::InternalServerError().json(json!({
+                    "error": "Internal server error"
+                }))
+            }
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use actix_web::{web, HttpResponse, Responder};
+use log::error;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        error::ApplicationError,
+        use_cases::{
+            job::{CancelJobUseCase, GetJobUseCase, ListJobsUseCase, RetryJobUseCase},
+            UseCase,
+        },
+    },
+    domain::{entities::job::JobFilter, repositories::RepositoryError},
+    infrastructure::persistence::postgres::PostgresJobRepository,
+    interfaces::{
+        http::{requests::job_requests::JobsQuery, responses::job_responses::JobsListResponse},
+        middleware::auth::AdminPrincipal,
+    },
+};
+
+pub struct JobController;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/jobs",
+    tag = "jobs",
+    params(JobsQuery),
+    responses(
+        (status = 200, description = "Background jobs, newest first", body = JobsListResponse),
+        (status = 400, description = "Invalid filter or limit", body = String),
+        (status = 401, description = "Authentication required", body = String),
+        (status = 403, description = "Administrator access required", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn list_jobs_doc() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/jobs/{id}",
+    tag = "jobs",
+    params(
+        ("id" = Uuid, Path, description = "Job ID")
+    ),
+    responses(
+        (status = 200, description = "Job found", body = Job),
+        (status = 401, description = "Authentication required", body = String),
+        (status = 403, description = "Administrator access required", body = String),
+        (status = 404, description = "Job not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn get_job_doc() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/jobs/{id}/retry",
+    tag = "jobs",
+    params(
+        ("id" = Uuid, Path, description = "Job ID")
+    ),
+    responses(
+        (status = 202, description = "The job was queued again with fresh attempts", body = Job),
+        (status = 401, description = "Authentication required", body = String),
+        (status = 403, description = "Administrator access required", body = String),
+        (status = 404, description = "Job not found", body = String),
+        (status = 409, description = "Only dead or cancelled jobs can be retried", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn retry_job_doc() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/jobs/{id}/cancel",
+    tag = "jobs",
+    params(
+        ("id" = Uuid, Path, description = "Job ID")
+    ),
+    responses(
+        (status = 200, description = "Job cancelled", body = Job),
+        (status = 401, description = "Authentication required", body = String),
+        (status = 403, description = "Administrator access required", body = String),
+        (status = 404, description = "Job not found", body = String),
+        (status = 409, description = "Only queued jobs can be cancelled", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn cancel_job_doc() {}
+
+impl JobController {
+    /// List background jobs
+    pub async fn list_jobs(
+        _: AdminPrincipal,
+        pool: web::Data<sqlx::PgPool>,
+        query: web::Query<JobsQuery>,
+    ) -> impl Responder {
+        let use_case = ListJobsUseCase::new(PostgresJobRepository::new(pool.get_ref().clone()));
+        let query = query.into_inner();
+        let filter = JobFilter {
+            status: query.status,
+            queue: query.queue,
+            kind: query.kind,
+            limit: query.limit.unwrap_or_default(),
+        };
+
+        match use_case.execute(filter).await {
+            Ok(jobs) => HttpResponse::Ok().json(JobsListResponse::from(jobs)),
+            Err(e) => Self::error_response(e, "Error listing jobs"),
+        }
+    }
+
+    /// Get a background job by ID
+    pub async fn get_job(
+        _: AdminPrincipal,
+        pool: web::Data<sqlx::PgPool>,
+        job_id: web::Path<Uuid>,
+    ) -> impl Responder {
+        let use_case = GetJobUseCase::new(PostgresJobRepository::new(pool.get_ref().clone()));
+
+        match use_case.execute(job_id.into_inner()).await {
+            Ok(job) => HttpResponse::Ok().json(job),
+            Err(e) => Self::error_response(e, "Error getting job"),
+        }
+    }
+
+    /// Queue a dead or cancelled job again
+    pub async fn retry_job(
+        _: AdminPrincipal,
+        pool: web::Data<sqlx::PgPool>,
+        job_id: web::Path<Uuid>,
+    ) -> impl Responder {
+        let use_case = RetryJobUseCase::new(PostgresJobRepository::new(pool.get_ref().clone()));
+
+        match use_case.execute(job_id.into_inner()).await {
+            Ok(job) => HttpResponse::Accepted().json(job),
+            Err(e) => Self::error_response(e, "Error retrying job"),
+        }
+    }
+
+    /// Cancel a queued job
+    pub async fn cancel_job(
+        _: AdminPrincipal,
+        pool: web::Data<sqlx::PgPool>,
+        job_id: web::Path<Uuid>,
+    ) -> impl Responder {
+        let use_case = CancelJobUseCase::new(PostgresJobRepository::new(pool.get_ref().clone()));
+
+        match use_case.execute(job_id.into_inner()).await {
+            Ok(job) => HttpResponse::Ok().json(job),
+            Err(e) => Self::error_response(e, "Error cancelling job"),
+        }
+    }
+
+    fn error_response(e: ApplicationError, context: &str) -> HttpResponse {
+        match e {
+            ApplicationError::NotFound => {
+                HttpResponse::NotFound().json(json!({ "error": "Job not found" }))
+            }
+            ApplicationError::Validation(msg) => {
+                HttpResponse::BadRequest().json(json!({ "error": msg }))
+            }
+            ApplicationError::Repository(RepositoryError::Conflict(msg)) => {
+                HttpResponse::Conflict().json(json!({ "error": msg }))
+            }
+            e => {
+                error!("{}: {:?}", context, e);
+                HttpResponse::InternalServerError().json(json!({
+                    "error": "Internal server error"
+                }))
+            }
+        }
+    }
+}
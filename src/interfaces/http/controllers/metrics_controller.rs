@@ -0,0 +1,27 @@
+use actix_web::{web, HttpResponse, Responder};
+use prometheus::TEXT_FORMAT;
+
+use crate::infrastructure::metrics::metrics;
+
+pub struct MetricsController;
+
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "metrics",
+    responses(
+        (status = 200, description = "HTTP, database pool, repository and business metrics in the Prometheus text format", content_type = "text/plain", body = String)
+    )
+)]
+async fn metrics_doc() {}
+
+impl MetricsController {
+    /// Prometheus scrape endpoint, the pool is sampled on each scrape
+    pub async fn metrics(pool: web::Data<sqlx::PgPool>) -> impl Responder {
+        metrics().observe_pool(&pool);
+
+        HttpResponse::Ok()
+            .content_type(TEXT_FORMAT)
+            .body(metrics().encode())
+    }
+}
@@ -1,5 +1,35 @@
+pub mod batch_controller;
+pub mod cart_controller;
+pub mod exchange_rate_controller;
+pub mod graphql_controller;
+pub mod health_controller;
+pub mod job_controller;
+pub mod metrics_controller;
+pub mod order_controller;
+pub mod payment_controller;
 pub mod product_controller;
+pub mod product_event_controller;
+pub mod product_image_controller;
+pub mod promotion_controller;
+pub mod scheduler_controller;
 pub mod user_controller;
+pub mod webhook_controller;
+pub mod websocket_controller;
 
+pub use batch_controller::BatchController;
+pub use cart_controller::CartController;
+pub use exchange_rate_controller::ExchangeRateController;
+pub use graphql_controller::GraphQLController;
+pub use health_controller::HealthController;
+pub use job_controller::JobController;
+pub use metrics_controller::MetricsController;
+pub use order_controller::OrderController;
+pub use payment_controller::PaymentController;
 pub use product_controller::ProductController;
+pub use product_event_controller::ProductEventController;
+pub use product_image_controller::ProductImageController;
+pub use promotion_controller::PromotionController;
+pub use scheduler_controller::SchedulerController;
 pub use user_controller::UserController;
+pub use webhook_controller::WebhookController;
+pub use websocket_controller::WebSocketController;
@@ -0,0 +1,112 @@
+use actix_web::{web, HttpResponse, Responder};
+use log::error;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        error::ApplicationError,
+        use_cases::{
+            order::{CheckoutCartUseCase, GetOrderUseCase},
+            UseCase,
+        },
+    },
+    domain::entities::order::CheckoutDto,
+    infrastructure::{
+        events::BroadcastUpdateBus,
+        persistence::postgres::{
+            PostgresCartRepository, PostgresOrderRepository, PostgresProductRepository,
+            PostgresPromotionRepository,
+        },
+    },
+    interfaces::http::responses::order_responses::OrderResponse,
+};
+
+pub struct OrderController;
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/users/{id}/orders",
+    tag = "orders",
+    params(
+        ("id" = Uuid, Path, description = "User ID")
+    ),
+    request_body(content = Option<CheckoutDto>, description = "Optional discount code"),
+    responses(
+        (status = 201, description = "Order placed from the user's cart", body = OrderResponse),
+        (status = 400, description = "Cart is empty, stock is insufficient or the discount code was rejected", body = String),
+        (status = 404, description = "User not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn checkout_doc() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/orders/{id}",
+    tag = "orders",
+    params(
+        ("id" = Uuid, Path, description = "Order ID")
+    ),
+    responses(
+        (status = 200, description = "Order found", body = OrderResponse),
+        (status = 404, description = "Order not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn get_order_doc() {}
+
+impl OrderController {
+    /// Place an order from the user's cart
+    pub async fn checkout(
+        pool: web::Data<sqlx::PgPool>,
+        updates: web::Data<BroadcastUpdateBus>,
+        user_id: web::Path<Uuid>,
+        checkout_data: Option<web::Json<CheckoutDto>>,
+    ) -> impl Responder {
+        let use_case = CheckoutCartUseCase::new(
+            PostgresCartRepository::new(pool.get_ref().clone()),
+            PostgresProductRepository::new(pool.get_ref().clone()),
+            PostgresOrderRepository::new(pool.get_ref().clone()),
+            PostgresPromotionRepository::new(pool.get_ref().clone()),
+            updates.get_ref().clone(),
+        );
+
+        let checkout = checkout_data
+            .map(|data| data.into_inner())
+            .unwrap_or_default();
+        match use_case.execute((user_id.into_inner(), checkout)).await {
+            Ok(order) => HttpResponse::Created().json(OrderResponse::from(order)),
+            Err(ApplicationError::NotFound) => {
+                HttpResponse::NotFound().json(json!({ "error": "User not found" }))
+            }
+            Err(ApplicationError::Validation(msg)) => {
+                HttpResponse::BadRequest().json(json!({ "error": msg }))
+            }
+            Err(e) => {
+                error!("Error placing order: {:?}", e);
+                HttpResponse::InternalServerError().json(json!({
+                    "error": "Internal server error"
+                }))
+            }
+        }
+    }
+
+    /// Get an order by ID
+    pub async fn get_order(
+        pool: web::Data<sqlx::PgPool>,
+        order_id: web::Path<Uuid>,
+    ) -> impl Responder {
+        let use_case = GetOrderUseCase::new(PostgresOrderRepository::new(pool.get_ref().clone()));
+
+        match use_case.execute(order_id.into_inner()).await {
+            Ok(order) => HttpResponse::Ok().json(OrderResponse::from(order)),
+            Err(ApplicationError::NotFound) => HttpResponse::NotFound().json(json!({
+                "error": "Order not found"
+            })),
+            Err(_) => HttpResponse::InternalServerError().json(json!({
+                "error": "Internal server error"
+            })),
+        }
+    }
+}
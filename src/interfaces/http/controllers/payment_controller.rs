@@ -0,0 +1,220 @@
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use log::error;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        error::ApplicationError,
+        use_cases::{
+            payment::{
+                CapturePaymentUseCase, CreatePaymentUseCase, HandlePaymentWebhookUseCase,
+                PaymentWebhookInput, RefundPaymentUseCase,
+            },
+            UseCase,
+        },
+    },
+    config::PaymentConfig,
+    domain::{entities::payment::Payment, gateways::PaymentGatewayError},
+    infrastructure::{
+        events::BroadcastUpdateBus,
+        payments::FakePaymentGateway,
+        persistence::postgres::{PostgresOrderRepository, PostgresPaymentRepository},
+    },
+    interfaces::http::responses::payment_responses::PaymentResponse,
+};
+
+/// Header carrying the hex-encoded HMAC-SHA256 of the webhook body
+pub const SIGNATURE_HEADER: &str = "X-Payment-Signature";
+
+pub struct PaymentController;
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/orders/{id}/payments",
+    tag = "payments",
+    params(
+        ("id" = Uuid, Path, description = "Order ID")
+    ),
+    responses(
+        (status = 201, description = "Payment intent created", body = PaymentResponse),
+        (status = 400, description = "Order is not awaiting payment", body = String),
+        (status = 404, description = "Order not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn create_payment_doc() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/payments/{id}/capture",
+    tag = "payments",
+    params(
+        ("id" = Uuid, Path, description = "Payment ID")
+    ),
+    responses(
+        (status = 200, description = "Payment captured", body = PaymentResponse),
+        (status = 400, description = "Payment is not awaiting capture", body = String),
+        (status = 402, description = "Payment declined by the provider", body = String),
+        (status = 404, description = "Payment not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn capture_payment_doc() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/payments/{id}/refund",
+    tag = "payments",
+    params(
+        ("id" = Uuid, Path, description = "Payment ID")
+    ),
+    responses(
+        (status = 200, description = "Payment refunded", body = PaymentResponse),
+        (status = 400, description = "Payment cannot be refunded", body = String),
+        (status = 404, description = "Payment not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn refund_payment_doc() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/payments/webhook",
+    tag = "payments",
+    params(
+        ("X-Payment-Signature" = String, Header, description = "Hex-encoded HMAC-SHA256 of the request body")
+    ),
+    responses(
+        (status = 200, description = "Event accepted"),
+        (status = 400, description = "Malformed event", body = String),
+        (status = 401, description = "Invalid signature", body = String),
+        (status = 404, description = "Unknown payment", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn payment_webhook_doc() {}
+
+impl PaymentController {
+    /// Start a payment for an order
+    pub async fn create_payment(
+        pool: web::Data<sqlx::PgPool>,
+        config: web::Data<PaymentConfig>,
+        updates: web::Data<BroadcastUpdateBus>,
+        order_id: web::Path<Uuid>,
+    ) -> impl Responder {
+        let use_case = CreatePaymentUseCase::new(
+            PostgresOrderRepository::new(pool.get_ref().clone()),
+            PostgresPaymentRepository::new(pool.get_ref().clone()),
+            Self::gateway(&config),
+            updates.get_ref().clone(),
+        );
+
+        match use_case.execute(order_id.into_inner()).await {
+            Ok(payment) => HttpResponse::Created().json(PaymentResponse::from(payment)),
+            Err(e) => Self::error_response(e, "Order not found"),
+        }
+    }
+
+    /// Capture an authorized payment
+    pub async fn capture_payment(
+        pool: web::Data<sqlx::PgPool>,
+        config: web::Data<PaymentConfig>,
+        updates: web::Data<BroadcastUpdateBus>,
+        payment_id: web::Path<Uuid>,
+    ) -> impl Responder {
+        let use_case = CapturePaymentUseCase::new(
+            PostgresPaymentRepository::new(pool.get_ref().clone()),
+            Self::gateway(&config),
+            updates.get_ref().clone(),
+        );
+
+        Self::payment_response(use_case.execute(payment_id.into_inner()).await)
+    }
+
+    /// Refund a succeeded payment
+    pub async fn refund_payment(
+        pool: web::Data<sqlx::PgPool>,
+        config: web::Data<PaymentConfig>,
+        updates: web::Data<BroadcastUpdateBus>,
+        payment_id: web::Path<Uuid>,
+    ) -> impl Responder {
+        let use_case = RefundPaymentUseCase::new(
+            PostgresPaymentRepository::new(pool.get_ref().clone()),
+            Self::gateway(&config),
+            updates.get_ref().clone(),
+        );
+
+        Self::payment_response(use_case.execute(payment_id.into_inner()).await)
+    }
+
+    /// Receive a payment provider callback
+    pub async fn webhook(
+        pool: web::Data<sqlx::PgPool>,
+        config: web::Data<PaymentConfig>,
+        updates: web::Data<BroadcastUpdateBus>,
+        req: HttpRequest,
+        body: web::Bytes,
+    ) -> impl Responder {
+        let signature = req
+            .headers()
+            .get(SIGNATURE_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+
+        let use_case = HandlePaymentWebhookUseCase::new(
+            PostgresPaymentRepository::new(pool.get_ref().clone()),
+            Self::gateway(&config),
+            updates.get_ref().clone(),
+        );
+
+        let input = PaymentWebhookInput {
+            payload: body.to_vec(),
+            signature,
+        };
+
+        match use_case.execute(input).await {
+            Ok(()) => HttpResponse::Ok().json(json!({ "received": true })),
+            Err(e) => Self::error_response(e, "Payment not found"),
+        }
+    }
+
+    fn gateway(config: &PaymentConfig) -> FakePaymentGateway {
+        FakePaymentGateway::new(config.fake_outcome, config.webhook_secret.clone())
+    }
+
+    fn payment_response(result: Result<Payment, ApplicationError>) -> HttpResponse {
+        match result {
+            Ok(payment) => HttpResponse::Ok().json(PaymentResponse::from(payment)),
+            Err(e) => Self::error_response(e, "Payment not found"),
+        }
+    }
+
+    fn error_response(error: ApplicationError, not_found_message: &str) -> HttpResponse {
+        match error {
+            ApplicationError::NotFound => {
+                HttpResponse::NotFound().json(json!({ "error": not_found_message }))
+            }
+            ApplicationError::Validation(msg) => {
+                HttpResponse::BadRequest().json(json!({ "error": msg }))
+            }
+            ApplicationError::Payment(PaymentGatewayError::Declined(reason)) => {
+                HttpResponse::PaymentRequired()
+                    .json(json!({ "error": "Payment declined", "details": reason }))
+            }
+            ApplicationError::Payment(PaymentGatewayError::InvalidSignature) => {
+                HttpResponse::Unauthorized().json(json!({ "error": "Invalid signature" }))
+            }
+            ApplicationError::Payment(PaymentGatewayError::InvalidPayload(msg)) => {
+                HttpResponse::BadRequest().json(json!({ "error": msg }))
+            }
+            e => {
+                error!("Error handling payment request: {:?}", e);
+                HttpResponse::InternalServerError().json(json!({
+                    "error": "Internal server error"
+                }))
+            }
+        }
+    }
+}
@@ -1,32 +1,184 @@
-use actix_web::{web, HttpResponse, Responder};
+use actix_web::{http::header, web, HttpRequest, HttpResponse, Responder};
+use futures::{
+    channel::mpsc,
+    stream::{self, BoxStream},
+    SinkExt, StreamExt,
+};
 use log::{error, info};
 use serde_json::json;
+use tokio_util::io::StreamReader;
 use uuid::Uuid;
 
 use crate::{
     application::{
         error::ApplicationError,
         use_cases::{
+            exchange_rate::ConvertPricesUseCase,
             product::{
-                CreateProductUseCase, DeleteProductUseCase, GetProductUseCase, ListProductsUseCase,
-                UpdateProductUseCase,
+                BulkCreateProductsUseCase, BulkDeleteProductsUseCase, BulkUpdateProductsUseCase,
+                CreateProductUseCase, DeleteProductUseCase, ExportProductsUseCase,
+                GetPriceHistoryUseCase, GetProductAtUseCase, GetProductUseCase,
+                ImportProductsInput, ImportProductsUseCase, ListPriceHistoriesUseCase,
+                ListProductsUseCase, SchedulePriceUseCase, UpdateProductUseCase,
             },
+            product_image::ListImagesForProductsUseCase,
             UseCase,
         },
     },
-    domain::entities::product::{CreateProductDto, UpdateProductDto},
-    infrastructure::persistence::postgres::PostgresProductRepository,
-    interfaces::http::responses::product_responses::{ProductResponse, ProductsListResponse},
+    domain::entities::{
+        product::{CreateProductDto, Product, UpdateProductDto},
+        product_bulk::BulkReport,
+        product_price::SchedulePriceDto,
+    },
+    infrastructure::{
+        persistence::postgres::{
+            Database, PostgresExchangeRateRepository, PostgresProductImageRepository,
+            PostgresProductPriceRepository, PostgresProductRepository,
+        },
+        storage::ConfiguredStorage,
+    },
+    interfaces::http::{
+        export::{export_response, ExportFormat, ExportFormatError},
+        projection::{Projection, PRODUCT_PROJECTION},
+        requests::product_requests::{
+            BulkCreateProductsRequest, BulkDeleteProductsRequest, BulkUpdateProductsRequest,
+            ExportProductsQuery, GetProductQuery, ImportProductsQuery, ListProductsQuery,
+        },
+        responses::product_responses::{
+            PriceHistoryResponse, ProductExportRow, ProductPriceResponse, ProductResponse,
+        },
+    },
 };
 
 pub struct ProductController;
 
+/// Request header naming the currency prices should be shown in
+const ACCEPT_CURRENCY: &str = "Accept-Currency";
+
+/// The currency requested by the client, `?currency=` taking precedence over the header
+fn requested_currency(req: &HttpRequest, param: Option<&str>) -> Option<String> {
+    let header = req
+        .headers()
+        .get(ACCEPT_CURRENCY)
+        .and_then(|value| value.to_str().ok());
+
+    param
+        .or(header)
+        .map(|currency| currency.trim().to_ascii_uppercase())
+        .filter(|currency| !currency.is_empty())
+}
+
+/// Converts prices when a currency was requested, leaving them untouched otherwise
+async fn convert_prices(
+    pool: &sqlx::PgPool,
+    products: Vec<Product>,
+    currency: Option<String>,
+) -> Result<Vec<Product>, ApplicationError> {
+    match currency {
+        Some(currency) => {
+            ConvertPricesUseCase::new(PostgresExchangeRateRepository::new(pool.clone()))
+                .execute((products, currency))
+                .await
+        }
+        None => Ok(products),
+    }
+}
+
+/// Rows converted per exchange rate lookup during an export
+const EXPORT_CONVERSION_CHUNK: usize = 256;
+
+/// Converts streamed prices chunk by chunk when a currency was requested
+fn convert_price_stream(
+    pool: sqlx::PgPool,
+    products: BoxStream<'static, Result<Product, ApplicationError>>,
+    currency: Option<String>,
+) -> BoxStream<'static, Result<Product, ApplicationError>> {
+    let Some(currency) = currency else {
+        return products;
+    };
+
+    products
+        .ready_chunks(EXPORT_CONVERSION_CHUNK)
+        .then(move |chunk| {
+            let pool = pool.clone();
+            let currency = currency.clone();
+            async move {
+                let products = chunk.into_iter().collect::<Result<Vec<_>, _>>()?;
+                convert_prices(&pool, products, Some(currency)).await
+            }
+        })
+        .flat_map(|converted| {
+            stream::iter(match converted {
+                Ok(products) => products.into_iter().map(Ok).collect::<Vec<_>>(),
+                Err(e) => vec![Err(e)],
+            })
+        })
+        .boxed()
+}
+
+/// Builds product responses with their images embedded
+async fn with_images(
+    pool: &sqlx::PgPool,
+    storage: &ConfiguredStorage,
+    products: Vec<Product>,
+) -> Result<Vec<ProductResponse>, ApplicationError> {
+    let mut images =
+        ListImagesForProductsUseCase::new(PostgresProductImageRepository::new(pool.clone()))
+            .execute(products.iter().map(|product| product.id).collect())
+            .await?;
+
+    Ok(products
+        .into_iter()
+        .map(|product| {
+            let product_images = images.remove(&product.id).unwrap_or_default();
+            ProductResponse::with_images(product, product_images, storage)
+        })
+        .collect())
+}
+
+/// Builds product responses with only the data the projection needs loaded
+async fn project_products(
+    pool: &sqlx::PgPool,
+    storage: &ConfiguredStorage,
+    products: Vec<Product>,
+    projection: &Projection,
+) -> Result<Vec<ProductResponse>, ApplicationError> {
+    let ids: Vec<Uuid> = products.iter().map(|product| product.id).collect();
+    let mut responses = if projection.wants("images") {
+        with_images(pool, storage, products).await?
+    } else {
+        products.into_iter().map(ProductResponse::from).collect()
+    };
+
+    if projection.includes("price_history") {
+        let mut histories =
+            ListPriceHistoriesUseCase::new(PostgresProductPriceRepository::new(pool.clone()))
+                .execute(ids)
+                .await?;
+        for response in &mut responses {
+            let prices = histories.remove(&response.id).unwrap_or_default();
+            response.price_history =
+                Some(prices.into_iter().map(ProductPriceResponse::from).collect());
+        }
+    }
+
+    Ok(responses)
+}
+
+/// Content types accepted by the product import
+const CSV_CONTENT_TYPES: [&str; 3] = ["text/csv", "application/csv", "text/plain"];
+
 #[utoipa::path(
     get,
     path = "/api/v1/products",
     tag = "products",
+    params(
+        ListProductsQuery,
+        ("Accept-Currency" = Option<String>, Header, description = "Convert prices into this ISO 4217 currency")
+    ),
     responses(
         (status = 200, description = "List all products successfully", body = ProductsListResponse),
+        (status = 400, description = "Unsupported currency, missing exchange rate, or unknown field or include", body = String),
         (status = 500, description = "Internal server error", body = String)
     )
 )]
@@ -50,10 +202,13 @@ async fn create_product_doc() {}
     path = "/api/v1/products/{id}",
     tag = "products",
     params(
-        ("id" = Uuid, Path, description = "Product ID")
+        ("id" = Uuid, Path, description = "Product ID"),
+        GetProductQuery,
+        ("Accept-Currency" = Option<String>, Header, description = "Convert the price into this ISO 4217 currency")
     ),
     responses(
         (status = 200, description = "Product found", body = ProductResponse),
+        (status = 400, description = "No price was in effect at the requested moment, the currency cannot be converted to, or unknown field or include", body = String),
         (status = 404, description = "Product not found", body = String),
         (status = 500, description = "Internal server error", body = String)
     )
@@ -92,17 +247,156 @@ async fn update_product_doc() {}
 )]
 async fn delete_product_doc() {}
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/products/{id}/price-history",
+    tag = "products",
+    params(
+        ("id" = Uuid, Path, description = "Product ID")
+    ),
+    responses(
+        (status = 200, description = "Price history of the product", body = PriceHistoryResponse),
+        (status = 404, description = "Product not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn get_price_history_doc() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/products/{id}/prices",
+    tag = "products",
+    params(
+        ("id" = Uuid, Path, description = "Product ID")
+    ),
+    request_body = SchedulePriceDto,
+    responses(
+        (status = 201, description = "Price change scheduled", body = ProductPriceResponse),
+        (status = 400, description = "Invalid input", body = String),
+        (status = 404, description = "Product not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn schedule_price_doc() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/products/import",
+    tag = "products",
+    params(ImportProductsQuery),
+    request_body(
+        content = String,
+        content_type = "text/csv",
+        description = "Header row followed by one product per row. Columns: id, sku, name, description, category, price, currency, stock; name, price and stock are required. Rows update the product with the given id, else the one with the given SKU, else create a product",
+        example = "sku,name,description,category,price,currency,stock\nTSHIRT-RED-M,Red T-shirt,Cotton t-shirt,apparel,19.99,USD,120"
+    ),
+    responses(
+        (status = 200, description = "Import finished, see the report for failed rows", body = ImportReport),
+        (status = 400, description = "Unreadable file or missing required columns", body = String),
+        (status = 415, description = "Body is not CSV", body = String),
+        (status = 500, description = "The import stopped early, the report covers the rows before the line it stopped at", body = ImportReport)
+    )
+)]
+async fn import_products_doc() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/products/bulk",
+    tag = "products",
+    request_body = BulkCreateProductsRequest,
+    responses(
+        (status = 200, description = "Request processed, see the report for failed items", body = BulkReport),
+        (status = 400, description = "No items or too many items", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn bulk_create_products_doc() {}
+
+#[utoipa::path(
+    patch,
+    path = "/api/v1/products/bulk",
+    tag = "products",
+    request_body = BulkUpdateProductsRequest,
+    responses(
+        (status = 200, description = "Request processed, see the report for failed items", body = BulkReport),
+        (status = 400, description = "No items, too many items, or a SKU taken concurrently", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn bulk_update_products_doc() {}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/products/bulk",
+    tag = "products",
+    request_body = BulkDeleteProductsRequest,
+    responses(
+        (status = 200, description = "Request processed, see the report for failed items", body = BulkReport),
+        (status = 400, description = "No items or too many items", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn bulk_delete_products_doc() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/products/export",
+    tag = "products",
+    params(
+        ExportProductsQuery,
+        ("Accept" = Option<String>, Header, description = "text/csv, application/x-ndjson or application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"),
+        ("Accept-Currency" = Option<String>, Header, description = "Convert prices into this ISO 4217 currency")
+    ),
+    responses(
+        (status = 200, description = "All products, streamed as CSV, NDJSON or XLSX. A product without an exchange rate into the requested currency aborts the download", content_type = "text/csv"),
+        (status = 400, description = "Unknown format or invalid currency", body = String),
+        (status = 406, description = "None of the accepted media types can be produced", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn export_products_doc() {}
+
 impl ProductController {
     /// List all products
-    pub async fn list_products(pool: web::Data<sqlx::PgPool>) -> impl Responder {
+    pub async fn list_products(
+        req: HttpRequest,
+        pool: web::Data<sqlx::PgPool>,
+        storage: web::Data<ConfiguredStorage>,
+        query: web::Query<ListProductsQuery>,
+    ) -> impl Responder {
+        let projection = match Projection::parse(
+            &PRODUCT_PROJECTION,
+            query.fields.as_deref(),
+            query.include.as_deref(),
+        ) {
+            Ok(projection) => projection,
+            Err(msg) => return HttpResponse::BadRequest().json(json!({ "error": msg })),
+        };
         let repository = PostgresProductRepository::new(pool.get_ref().clone());
         let use_case = ListProductsUseCase::new(repository);
+        let currency = requested_currency(&req, query.currency.as_deref())
+            .filter(|_| projection.wants("price") || projection.wants("currency"));
 
-        match use_case.execute(()).await {
+        let result = match use_case.execute(()).await {
+            Ok(products) => convert_prices(pool.get_ref(), products, currency).await,
+            Err(e) => Err(e),
+        };
+        let result = match result {
+            Ok(products) => {
+                project_products(pool.get_ref(), storage.get_ref(), products, &projection).await
+            }
+            Err(e) => Err(e),
+        };
+
+        match result {
             Ok(products) => {
                 info!("Successfully retrieved {} products", products.len());
-                let response = ProductsListResponse::from(products);
-                HttpResponse::Ok().json(response)
+                HttpResponse::Ok()
+                    .insert_header((header::VARY, ACCEPT_CURRENCY))
+                    .json(projection.apply_list("products", &products))
+            }
+            Err(ApplicationError::Validation(msg)) => {
+                HttpResponse::BadRequest().json(json!({ "error": msg }))
             }
             Err(e) => {
                 error!("Error listing products: {:?}", e);
@@ -113,12 +407,68 @@ impl ProductController {
         }
     }
 
+    /// Download all products as a file, streamed straight from the database
+    pub async fn export_products(
+        req: HttpRequest,
+        pool: web::Data<sqlx::PgPool>,
+        query: web::Query<ExportProductsQuery>,
+    ) -> impl Responder {
+        let accept = req
+            .headers()
+            .get(header::ACCEPT)
+            .and_then(|value| value.to_str().ok());
+        let format = match ExportFormat::negotiate(query.format.as_deref(), accept) {
+            Ok(format) => format,
+            Err(ExportFormatError::Unknown(format)) => {
+                return HttpResponse::BadRequest().json(json!({
+                    "error": format!("Unknown export format '{}', expected csv, ndjson or xlsx", format)
+                }))
+            }
+            Err(ExportFormatError::NotAcceptable) => {
+                return HttpResponse::NotAcceptable().json(json!({
+                    "error": "Exports are available as text/csv, application/x-ndjson or xlsx"
+                }))
+            }
+        };
+
+        // Reject a malformed currency before the response starts
+        let currency = requested_currency(&req, query.currency.as_deref());
+        if let Err(ApplicationError::Validation(msg)) =
+            convert_prices(pool.get_ref(), Vec::new(), currency.clone()).await
+        {
+            return HttpResponse::BadRequest().json(json!({ "error": msg }));
+        }
+
+        let use_case =
+            ExportProductsUseCase::new(PostgresProductRepository::new(pool.get_ref().clone()));
+        let products = match use_case.execute(()).await {
+            Ok(products) => convert_price_stream(pool.get_ref().clone(), products, currency),
+            Err(e) => {
+                error!("Error exporting products: {:?}", e);
+                return HttpResponse::InternalServerError().json(json!({
+                    "error": "Internal server error"
+                }));
+            }
+        };
+
+        let mut response = export_response(
+            format,
+            "products",
+            products.map(|product| product.map(ProductExportRow::from)),
+        );
+        response.headers_mut().insert(
+            header::VARY,
+            header::HeaderValue::from_static("Accept, Accept-Currency"),
+        );
+        response
+    }
+
     /// Create a new product
     pub async fn create_product(
-        pool: web::Data<sqlx::PgPool>,
+        database: Database,
         product_data: web::Json<CreateProductDto>,
     ) -> impl Responder {
-        let repository = PostgresProductRepository::new(pool.get_ref().clone());
+        let repository = PostgresProductRepository::new(database);
         let use_case = CreateProductUseCase::new(repository);
 
         match use_case.execute(product_data.into_inner()).await {
@@ -132,16 +482,87 @@ impl ProductController {
         }
     }
 
-    /// Get a product by ID
+    /// Get a product by ID, optionally with the price valid at a given moment
     pub async fn get_product(
+        req: HttpRequest,
         pool: web::Data<sqlx::PgPool>,
+        storage: web::Data<ConfiguredStorage>,
         product_id: web::Path<Uuid>,
+        query: web::Query<GetProductQuery>,
     ) -> impl Responder {
+        let projection = match Projection::parse(
+            &PRODUCT_PROJECTION,
+            query.fields.as_deref(),
+            query.include.as_deref(),
+        ) {
+            Ok(projection) => projection,
+            Err(msg) => return HttpResponse::BadRequest().json(json!({ "error": msg })),
+        };
         let repository = PostgresProductRepository::new(pool.get_ref().clone());
-        let use_case = GetProductUseCase::new(repository);
+        let currency = requested_currency(&req, query.currency.as_deref())
+            .filter(|_| projection.wants("price") || projection.wants("currency"));
 
-        match use_case.execute(product_id.into_inner()).await {
-            Ok(product) => HttpResponse::Ok().json(ProductResponse::from(product)),
+        let result = match query.at {
+            Some(at) => {
+                let prices = PostgresProductPriceRepository::new(pool.get_ref().clone());
+                GetProductAtUseCase::new(repository, prices)
+                    .execute((product_id.into_inner(), at))
+                    .await
+            }
+            None => {
+                GetProductUseCase::new(repository)
+                    .execute(product_id.into_inner())
+                    .await
+            }
+        };
+
+        let result = match result {
+            Ok(product) => convert_prices(pool.get_ref(), vec![product], currency)
+                .await
+                .map(|mut products| products.remove(0)),
+            Err(e) => Err(e),
+        };
+        let result = match result {
+            Ok(product) => project_products(
+                pool.get_ref(),
+                storage.get_ref(),
+                vec![product],
+                &projection,
+            )
+            .await
+            .map(|mut products| products.remove(0)),
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(product) => HttpResponse::Ok()
+                .insert_header((header::VARY, ACCEPT_CURRENCY))
+                .json(projection.apply(&product)),
+            Err(ApplicationError::NotFound) => HttpResponse::NotFound().json(json!({
+                "error": "Product not found"
+            })),
+            Err(ApplicationError::Validation(msg)) => {
+                HttpResponse::BadRequest().json(json!({ "error": msg }))
+            }
+            Err(_) => HttpResponse::InternalServerError().json(json!({
+                "error": "Internal server error"
+            })),
+        }
+    }
+
+    /// Get the price history of a product
+    pub async fn get_price_history(
+        pool: web::Data<sqlx::PgPool>,
+        product_id: web::Path<Uuid>,
+    ) -> impl Responder {
+        let use_case = GetPriceHistoryUseCase::new(
+            PostgresProductRepository::new(pool.get_ref().clone()),
+            PostgresProductPriceRepository::new(pool.get_ref().clone()),
+        );
+
+        let product_id = product_id.into_inner();
+        match use_case.execute(product_id).await {
+            Ok(prices) => HttpResponse::Ok().json(PriceHistoryResponse::new(product_id, prices)),
             Err(ApplicationError::NotFound) => HttpResponse::NotFound().json(json!({
                 "error": "Product not found"
             })),
@@ -151,20 +572,172 @@ impl ProductController {
         }
     }
 
+    /// Schedule a future price change
+    pub async fn schedule_price(
+        pool: web::Data<sqlx::PgPool>,
+        product_id: web::Path<Uuid>,
+        price_data: web::Json<SchedulePriceDto>,
+    ) -> impl Responder {
+        let use_case = SchedulePriceUseCase::new(
+            PostgresProductRepository::new(pool.get_ref().clone()),
+            PostgresProductPriceRepository::new(pool.get_ref().clone()),
+        );
+
+        match use_case
+            .execute((product_id.into_inner(), price_data.into_inner()))
+            .await
+        {
+            Ok(price) => HttpResponse::Created().json(ProductPriceResponse::from(price)),
+            Err(ApplicationError::NotFound) => {
+                HttpResponse::NotFound().json(json!({ "error": "Product not found" }))
+            }
+            Err(ApplicationError::Validation(msg)) => {
+                HttpResponse::BadRequest().json(json!({ "error": msg }))
+            }
+            Err(_) => HttpResponse::InternalServerError().json(json!({
+                "error": "Internal server error"
+            })),
+        }
+    }
+
+    /// Create or update products from a CSV upload
+    pub async fn import_products(
+        req: HttpRequest,
+        pool: web::Data<sqlx::PgPool>,
+        query: web::Query<ImportProductsQuery>,
+        mut payload: web::Payload,
+    ) -> impl Responder {
+        let content_type = req
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(';').next())
+            .map(|value| value.trim().to_ascii_lowercase());
+        if content_type.is_some_and(|value| !CSV_CONTENT_TYPES.contains(&value.as_str())) {
+            return HttpResponse::UnsupportedMediaType().json(json!({
+                "error": "Expected a text/csv body"
+            }));
+        }
+
+        // The body is not Send, so it is forwarded through a bounded channel to the parser
+        let (mut sender, receiver) = mpsc::channel::<std::io::Result<web::Bytes>>(16);
+        let forward = async move {
+            while let Some(chunk) = payload.next().await {
+                let chunk = chunk.map_err(|e| std::io::Error::other(e.to_string()));
+                let failed = chunk.is_err();
+                if sender.send(chunk).await.is_err() || failed {
+                    break;
+                }
+            }
+        };
+        let use_case =
+            ImportProductsUseCase::new(PostgresProductRepository::new(pool.get_ref().clone()));
+        let import = use_case.execute(ImportProductsInput {
+            csv: StreamReader::new(receiver),
+            dry_run: query.dry_run.unwrap_or(false),
+        });
+        let ((), result) = futures::join!(forward, import);
+
+        match result {
+            Ok(report) if report.interrupted.is_some() => {
+                HttpResponse::InternalServerError().json(report)
+            }
+            Ok(report) => {
+                info!(
+                    "Product import finished: {} created, {} updated, {} failed (dry run: {})",
+                    report.created, report.updated, report.failed, report.dry_run
+                );
+                HttpResponse::Ok().json(report)
+            }
+            Err(ApplicationError::Validation(msg)) => {
+                HttpResponse::BadRequest().json(json!({ "error": msg }))
+            }
+            Err(e) => {
+                error!("Error importing products: {:?}", e);
+                HttpResponse::InternalServerError().json(json!({
+                    "error": "Internal server error"
+                }))
+            }
+        }
+    }
+
+    /// Create many products with set-based statements
+    pub async fn bulk_create_products(
+        database: Database,
+        request: web::Json<BulkCreateProductsRequest>,
+    ) -> impl Responder {
+        let BulkCreateProductsRequest { atomic, items } = request.into_inner();
+        let use_case = BulkCreateProductsUseCase::new(PostgresProductRepository::new(database));
+
+        Self::bulk_response(use_case.execute((items, atomic)).await)
+    }
+
+    /// Update many products with set-based statements
+    pub async fn bulk_update_products(
+        database: Database,
+        request: web::Json<BulkUpdateProductsRequest>,
+    ) -> impl Responder {
+        let BulkUpdateProductsRequest { atomic, items } = request.into_inner();
+        let use_case = BulkUpdateProductsUseCase::new(PostgresProductRepository::new(database));
+
+        Self::bulk_response(use_case.execute((items, atomic)).await)
+    }
+
+    /// Delete many products in one statement
+    pub async fn bulk_delete_products(
+        database: Database,
+        request: web::Json<BulkDeleteProductsRequest>,
+    ) -> impl Responder {
+        let BulkDeleteProductsRequest { atomic, ids } = request.into_inner();
+        let use_case = BulkDeleteProductsUseCase::new(PostgresProductRepository::new(database));
+
+        Self::bulk_response(use_case.execute((ids, atomic)).await)
+    }
+
+    fn bulk_response(result: Result<BulkReport, ApplicationError>) -> HttpResponse {
+        match result {
+            Ok(report) => {
+                info!(
+                    "Bulk product request: {} succeeded, {} failed",
+                    report.succeeded, report.failed
+                );
+                HttpResponse::Ok().json(report)
+            }
+            Err(ApplicationError::Validation(msg)) => {
+                HttpResponse::BadRequest().json(json!({ "error": msg }))
+            }
+            Err(e) => {
+                error!("Error in bulk product request: {:?}", e);
+                HttpResponse::InternalServerError().json(json!({
+                    "error": "Internal server error"
+                }))
+            }
+        }
+    }
+
     /// Update a product
     pub async fn update_product(
+        database: Database,
         pool: web::Data<sqlx::PgPool>,
+        storage: web::Data<ConfiguredStorage>,
         product_id: web::Path<Uuid>,
         product_data: web::Json<UpdateProductDto>,
     ) -> impl Responder {
-        let repository = PostgresProductRepository::new(pool.get_ref().clone());
+        let repository = PostgresProductRepository::new(database);
         let use_case = UpdateProductUseCase::new(repository);
 
-        match use_case
+        let result = match use_case
             .execute((product_id.into_inner(), product_data.into_inner()))
             .await
         {
-            Ok(product) => HttpResponse::Ok().json(ProductResponse::from(product)),
+            Ok(product) => with_images(pool.get_ref(), storage.get_ref(), vec![product])
+                .await
+                .map(|mut products| products.remove(0)),
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(product) => HttpResponse::Ok().json(product),
             Err(ApplicationError::NotFound) => {
                 HttpResponse::NotFound().json(json!({ "error": "Product not found" }))
             }
@@ -178,11 +751,8 @@ impl ProductController {
     }
 
     /// Delete a product
-    pub async fn delete_product(
-        pool: web::Data<sqlx::PgPool>,
-        product_id: web::Path<Uuid>,
-    ) -> impl Responder {
-        let repository = PostgresProductRepository::new(pool.get_ref().clone());
+    pub async fn delete_product(database: Database, product_id: web::Path<Uuid>) -> impl Responder {
+        let repository = PostgresProductRepository::new(database);
         let use_case = DeleteProductUseCase::new(repository);
 
         match use_case.execute(product_id.into_inner()).await {
@@ -0,0 +1,201 @@
+use actix_web::{http::header, web, web::Bytes, HttpRequest, HttpResponse, Responder};
+use log::error;
+use serde_json::json;
+use sqlx::PgPool;
+use std::{collections::BTreeSet, convert::Infallible, time::Duration};
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::{
+    application::use_cases::{product_event::ReplayProductEventsUseCase, UseCase},
+    domain::entities::product_event::ProductEvent,
+    infrastructure::{
+        events::{FeedUpdate, ProductEventFeed},
+        persistence::postgres::PostgresProductEventRepository,
+        shutdown::Shutdown,
+    },
+    interfaces::http::requests::product_requests::ProductEventsQuery,
+};
+
+pub struct ProductEventController;
+
+/// Events read from the retention table per query when catching up
+const REPLAY_BATCH_SIZE: i64 = 500;
+/// Ids below the cursor read again when catching up. Event ids are taken before their
+/// transaction commits, so a lower id can become visible after a higher one was sent
+const LATE_COMMIT_WINDOW: i64 = 100;
+/// Comment lines keep idle connections open through proxies
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+/// Reconnection delay suggested to clients, in milliseconds
+const RETRY_MILLIS: u64 = 3000;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/products/events",
+    tag = "products",
+    params(
+        ProductEventsQuery,
+        ("Last-Event-ID" = Option<i64>, Header, description = "Resume after this event")
+    ),
+    responses(
+        (status = 200, description = "Server-sent events named created, updated, deleted or stock_changed, each carrying a ProductEvent; a reset event means events were missed and the client should reload", content_type = "text/event-stream", body = ProductEvent),
+        (status = 400, description = "Invalid event id", body = String)
+    )
+)]
+async fn stream_events_doc() {}
+
+/// An SSE frame for one event
+fn event_frame(event: &ProductEvent) -> Bytes {
+    let kind = serde_json::to_value(event.kind).unwrap_or_default();
+    let data = serde_json::to_string(event).unwrap_or_default();
+    Bytes::from(format!(
+        "id: {}\nevent: {}\ndata: {}\n\n",
+        event.id,
+        kind.as_str().unwrap_or_default(),
+        data
+    ))
+}
+
+/// Drops sent ids too far below the cursor to be read again
+fn forget_old(sent: &mut BTreeSet<i64>, cursor: i64) {
+    *sent = sent.split_off(&cursor.saturating_sub(LATE_COMMIT_WINDOW));
+}
+
+/// Tells the client it missed events and has to reload the products
+fn reset_frame() -> Bytes {
+    Bytes::from(format!(
+        "event: reset\ndata: {}\n\n",
+        json!({"reason": "Events were missed, reload the products"})
+    ))
+}
+
+/// Frames for every retained event after `after_id` and the late commits just below
+/// it that are not in `sent`, with the new cursor, or a reset when some were pruned
+async fn replay(
+    pool: &PgPool,
+    after_id: i64,
+    sent: &mut BTreeSet<i64>,
+) -> (Vec<Bytes>, Option<i64>) {
+    let use_case =
+        ReplayProductEventsUseCase::new(PostgresProductEventRepository::new(pool.clone()));
+    let mut frames = Vec::new();
+    let mut cursor = after_id;
+    let mut last_id = after_id;
+    let mut lookback = LATE_COMMIT_WINDOW;
+
+    loop {
+        match use_case
+            .execute((last_id, lookback, REPLAY_BATCH_SIZE))
+            .await
+        {
+            Ok(Some(events)) => {
+                for event in &events {
+                    if sent.insert(event.id) {
+                        frames.push(event_frame(event));
+                    }
+                    cursor = cursor.max(event.id);
+                }
+                match events.last() {
+                    Some(last) if events.len() as i64 == REPLAY_BATCH_SIZE => last_id = last.id,
+                    _ => {
+                        forget_old(sent, cursor);
+                        return (frames, Some(cursor));
+                    }
+                }
+                lookback = 0;
+            }
+            Ok(None) => return (vec![reset_frame()], None),
+            Err(e) => {
+                error!("Error replaying product events: {:?}", e);
+                frames.push(reset_frame());
+                return (frames, None);
+            }
+        }
+    }
+}
+
+impl ProductEventController {
+    /// Streams product changes as server-sent events, resuming after `Last-Event-ID`
+    pub async fn stream_events(
+        pool: web::Data<PgPool>,
+        feed: web::Data<ProductEventFeed>,
+        shutdown: web::Data<Shutdown>,
+        query: web::Query<ProductEventsQuery>,
+        req: HttpRequest,
+    ) -> impl Responder {
+        let header_id = req
+            .headers()
+            .get("Last-Event-ID")
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.trim().parse::<i64>());
+        let last_event_id = match (header_id, query.last_event_id) {
+            (Some(Ok(id)), _) | (None, Some(id)) if id >= 0 => Some(id),
+            (None, None) => None,
+            _ => {
+                return HttpResponse::BadRequest().json(json!({
+                    "error": "Last-Event-ID must be a non-negative event id"
+                }))
+            }
+        };
+
+        // Subscribe before replaying so nothing committed in between is lost
+        let mut updates = feed.subscribe();
+        let pool = pool.get_ref().clone();
+        let draining = shutdown.draining();
+
+        let stream = async_stream::stream! {
+            yield Ok::<_, Infallible>(Bytes::from(format!("retry: {}\n\n", RETRY_MILLIS)));
+
+            // Highest event id sent, `None` until the client has a position to resume from
+            let mut cursor = last_event_id;
+            // Recent ids sent on this connection, as events arrive both live and replayed
+            let mut sent = BTreeSet::new();
+            if let Some(after_id) = cursor {
+                let (frames, last_id) = replay(&pool, after_id, &mut sent).await;
+                for frame in frames {
+                    yield Ok(frame);
+                }
+                cursor = last_id;
+            }
+
+            let mut keep_alive = actix_web::rt::time::interval(KEEP_ALIVE_INTERVAL);
+            keep_alive.tick().await;
+
+            loop {
+                tokio::select! {
+                    update = updates.recv() => match update {
+                        Ok(FeedUpdate::Event(event)) => {
+                            if !sent.insert(event.id) {
+                                continue;
+                            }
+                            let latest = cursor.map_or(event.id, |cursor| cursor.max(event.id));
+                            forget_old(&mut sent, latest);
+                            cursor = Some(latest);
+                            yield Ok(event_frame(&event));
+                        }
+                        // Catch up from the table, or start over without a position
+                        Ok(FeedUpdate::Gap) | Err(RecvError::Lagged(_)) => {
+                            let (frames, last_id) = match cursor {
+                                Some(after_id) => replay(&pool, after_id, &mut sent).await,
+                                None => (vec![reset_frame()], None),
+                            };
+                            for frame in frames {
+                                yield Ok(frame);
+                            }
+                            cursor = last_id;
+                        }
+                        Err(RecvError::Closed) => break,
+                    },
+                    _ = keep_alive.tick() => yield Ok(Bytes::from_static(b": keep-alive\n\n")),
+                    // Clients reconnect to another replica and resume from their last event
+                    _ = draining.cancelled() => break,
+                }
+            }
+        };
+
+        HttpResponse::Ok()
+            .insert_header((header::CONTENT_TYPE, "text/event-stream"))
+            .insert_header((header::CACHE_CONTROL, "no-cache"))
+            .insert_header(("X-Accel-Buffering", "no"))
+            .streaming(stream)
+    }
+}
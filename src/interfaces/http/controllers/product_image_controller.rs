@@ -0,0 +1,306 @@
+use actix_multipart::Multipart;
+use actix_web::{web, HttpResponse, Responder};
+use futures::{StreamExt, TryStreamExt};
+use log::error;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        error::ApplicationError,
+        use_cases::{
+            product_image::{
+                DeleteProductImageUseCase, ListProductImagesUseCase, ReorderProductImagesUseCase,
+                SetPrimaryProductImageUseCase, UploadProductImageInput, UploadProductImageUseCase,
+            },
+            UseCase,
+        },
+    },
+    config::MediaConfig,
+    domain::entities::product_image::ReorderProductImagesDto,
+    infrastructure::{
+        persistence::postgres::{PostgresProductImageRepository, PostgresProductRepository},
+        storage::ConfiguredStorage,
+    },
+    interfaces::http::{
+        requests::product_requests::UploadImageQuery,
+        responses::product_responses::{ProductImageResponse, ProductImagesListResponse},
+    },
+};
+
+pub struct ProductImageController;
+
+/// Multipart field carrying the image
+const FILE_FIELD: &str = "file";
+
+enum UploadError {
+    MissingFile,
+    TooLarge(usize),
+    Malformed(String),
+}
+
+/// Reads the `file` field, aborting as soon as it grows past `limit` bytes
+async fn read_file_field(mut payload: Multipart, limit: usize) -> Result<Vec<u8>, UploadError> {
+    while let Some(mut field) = payload
+        .try_next()
+        .await
+        .map_err(|e| UploadError::Malformed(e.to_string()))?
+    {
+        if field.name() != FILE_FIELD {
+            continue;
+        }
+
+        let mut data = Vec::new();
+        while let Some(chunk) = field.next().await {
+            let chunk = chunk.map_err(|e| UploadError::Malformed(e.to_string()))?;
+            if data.len() + chunk.len() > limit {
+                return Err(UploadError::TooLarge(limit));
+            }
+            data.extend_from_slice(&chunk);
+        }
+        return Ok(data);
+    }
+
+    Err(UploadError::MissingFile)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/products/{id}/images",
+    tag = "products",
+    params(
+        ("id" = Uuid, Path, description = "Product ID"),
+        UploadImageQuery
+    ),
+    request_body(content = UploadImageForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "Image uploaded and thumbnails generated", body = ProductImageResponse),
+        (status = 400, description = "Missing file, unsupported type or undecodable image", body = String),
+        (status = 404, description = "Product not found", body = String),
+        (status = 413, description = "Image exceeds the size limit", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn upload_image_doc() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/products/{id}/images",
+    tag = "products",
+    params(
+        ("id" = Uuid, Path, description = "Product ID")
+    ),
+    responses(
+        (status = 200, description = "Images of the product in display order", body = ProductImagesListResponse),
+        (status = 404, description = "Product not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn list_images_doc() {}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/products/{id}/images/order",
+    tag = "products",
+    params(
+        ("id" = Uuid, Path, description = "Product ID")
+    ),
+    request_body = ReorderProductImagesDto,
+    responses(
+        (status = 200, description = "Images reordered", body = ProductImagesListResponse),
+        (status = 400, description = "The order does not list every image exactly once", body = String),
+        (status = 404, description = "Product not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn reorder_images_doc() {}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/products/{id}/images/{image_id}/primary",
+    tag = "products",
+    params(
+        ("id" = Uuid, Path, description = "Product ID"),
+        ("image_id" = Uuid, Path, description = "Image ID")
+    ),
+    responses(
+        (status = 200, description = "Primary image changed", body = ProductImageResponse),
+        (status = 404, description = "Image not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn set_primary_image_doc() {}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/products/{id}/images/{image_id}",
+    tag = "products",
+    params(
+        ("id" = Uuid, Path, description = "Product ID"),
+        ("image_id" = Uuid, Path, description = "Image ID")
+    ),
+    responses(
+        (status = 204, description = "Image and its thumbnails deleted"),
+        (status = 404, description = "Image not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn delete_image_doc() {}
+
+impl ProductImageController {
+    /// Upload an image for a product
+    pub async fn upload_image(
+        pool: web::Data<sqlx::PgPool>,
+        storage: web::Data<ConfiguredStorage>,
+        media: web::Data<MediaConfig>,
+        product_id: web::Path<Uuid>,
+        query: web::Query<UploadImageQuery>,
+        payload: Multipart,
+    ) -> impl Responder {
+        let data = match read_file_field(payload, media.max_image_bytes).await {
+            Ok(data) => data,
+            Err(UploadError::MissingFile) => {
+                return HttpResponse::BadRequest().json(json!({
+                    "error": format!("Multipart field '{FILE_FIELD}' is required")
+                }))
+            }
+            Err(UploadError::TooLarge(limit)) => {
+                return HttpResponse::PayloadTooLarge().json(json!({
+                    "error": format!("Image exceeds the limit of {limit} bytes")
+                }))
+            }
+            Err(UploadError::Malformed(msg)) => {
+                return HttpResponse::BadRequest().json(json!({ "error": msg }))
+            }
+        };
+
+        let use_case = UploadProductImageUseCase::new(
+            PostgresProductRepository::new(pool.get_ref().clone()),
+            PostgresProductImageRepository::new(pool.get_ref().clone()),
+            storage.get_ref().clone(),
+        );
+
+        match use_case
+            .execute(UploadProductImageInput {
+                product_id: product_id.into_inner(),
+                data,
+                is_primary: query.primary.unwrap_or(false),
+            })
+            .await
+        {
+            Ok(image) => {
+                HttpResponse::Created().json(ProductImageResponse::new(image, storage.get_ref()))
+            }
+            Err(ApplicationError::NotFound) => {
+                HttpResponse::NotFound().json(json!({ "error": "Product not found" }))
+            }
+            Err(ApplicationError::Validation(msg)) => {
+                HttpResponse::BadRequest().json(json!({ "error": msg }))
+            }
+            Err(e) => {
+                error!("Error uploading product image: {:?}", e);
+                HttpResponse::InternalServerError().json(json!({
+                    "error": "Internal server error"
+                }))
+            }
+        }
+    }
+
+    /// List the images of a product
+    pub async fn list_images(
+        pool: web::Data<sqlx::PgPool>,
+        storage: web::Data<ConfiguredStorage>,
+        product_id: web::Path<Uuid>,
+    ) -> impl Responder {
+        let use_case = ListProductImagesUseCase::new(
+            PostgresProductRepository::new(pool.get_ref().clone()),
+            PostgresProductImageRepository::new(pool.get_ref().clone()),
+        );
+
+        match use_case.execute(product_id.into_inner()).await {
+            Ok(images) => {
+                HttpResponse::Ok().json(ProductImagesListResponse::new(images, storage.get_ref()))
+            }
+            Err(ApplicationError::NotFound) => {
+                HttpResponse::NotFound().json(json!({ "error": "Product not found" }))
+            }
+            Err(_) => HttpResponse::InternalServerError().json(json!({
+                "error": "Internal server error"
+            })),
+        }
+    }
+
+    /// Change the display order of a product's images
+    pub async fn reorder_images(
+        pool: web::Data<sqlx::PgPool>,
+        storage: web::Data<ConfiguredStorage>,
+        product_id: web::Path<Uuid>,
+        order: web::Json<ReorderProductImagesDto>,
+    ) -> impl Responder {
+        let use_case = ReorderProductImagesUseCase::new(
+            PostgresProductRepository::new(pool.get_ref().clone()),
+            PostgresProductImageRepository::new(pool.get_ref().clone()),
+        );
+
+        match use_case
+            .execute((product_id.into_inner(), order.into_inner()))
+            .await
+        {
+            Ok(images) => {
+                HttpResponse::Ok().json(ProductImagesListResponse::new(images, storage.get_ref()))
+            }
+            Err(ApplicationError::NotFound) => {
+                HttpResponse::NotFound().json(json!({ "error": "Product not found" }))
+            }
+            Err(ApplicationError::Validation(msg)) => {
+                HttpResponse::BadRequest().json(json!({ "error": msg }))
+            }
+            Err(_) => HttpResponse::InternalServerError().json(json!({
+                "error": "Internal server error"
+            })),
+        }
+    }
+
+    /// Make an image the product's primary image
+    pub async fn set_primary_image(
+        pool: web::Data<sqlx::PgPool>,
+        storage: web::Data<ConfiguredStorage>,
+        path: web::Path<(Uuid, Uuid)>,
+    ) -> impl Responder {
+        let use_case = SetPrimaryProductImageUseCase::new(PostgresProductImageRepository::new(
+            pool.get_ref().clone(),
+        ));
+
+        match use_case.execute(path.into_inner()).await {
+            Ok(image) => {
+                HttpResponse::Ok().json(ProductImageResponse::new(image, storage.get_ref()))
+            }
+            Err(ApplicationError::NotFound) => {
+                HttpResponse::NotFound().json(json!({ "error": "Image not found" }))
+            }
+            Err(_) => HttpResponse::InternalServerError().json(json!({
+                "error": "Internal server error"
+            })),
+        }
+    }
+
+    /// Delete an image and its thumbnails
+    pub async fn delete_image(
+        pool: web::Data<sqlx::PgPool>,
+        path: web::Path<(Uuid, Uuid)>,
+    ) -> impl Responder {
+        let use_case = DeleteProductImageUseCase::new(PostgresProductImageRepository::new(
+            pool.get_ref().clone(),
+        ));
+
+        match use_case.execute(path.into_inner()).await {
+            Ok(_) => HttpResponse::NoContent().finish(),
+            Err(ApplicationError::NotFound) => {
+                HttpResponse::NotFound().json(json!({ "error": "Image not found" }))
+            }
+            Err(_) => HttpResponse::InternalServerError().json(json!({
+                "error": "Internal server error"
+            })),
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use actix_web::{web, HttpResponse, Responder};
+use log::error;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        error::ApplicationError,
+        use_cases::{
+            promotion::{
+                CreatePromotionUseCase, DeletePromotionUseCase, GetPromotionUseCase,
+                ListPromotionsUseCase, UpdatePromotionUseCase,
+            },
+            UseCase,
+        },
+    },
+    domain::entities::promotion::{CreatePromotionDto, UpdatePromotionDto},
+    infrastructure::persistence::postgres::PostgresPromotionRepository,
+    interfaces::http::responses::promotion_responses::{PromotionResponse, PromotionsListResponse},
+};
+
+pub struct PromotionController;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/promotions",
+    tag = "promotions",
+    responses(
+        (status = 200, description = "List all promotions", body = PromotionsListResponse),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn list_promotions_doc() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/promotions",
+    tag = "promotions",
+    request_body = CreatePromotionDto,
+    responses(
+        (status = 201, description = "Promotion created successfully", body = PromotionResponse),
+        (status = 400, description = "Invalid input", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn create_promotion_doc() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/promotions/{id}",
+    tag = "promotions",
+    params(
+        ("id" = Uuid, Path, description = "Promotion ID")
+    ),
+    responses(
+        (status = 200, description = "Promotion found", body = PromotionResponse),
+        (status = 404, description = "Promotion not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn get_promotion_doc() {}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/promotions/{id}",
+    tag = "promotions",
+    params(
+        ("id" = Uuid, Path, description = "Promotion ID")
+    ),
+    request_body = UpdatePromotionDto,
+    responses(
+        (status = 200, description = "Promotion updated successfully", body = PromotionResponse),
+        (status = 400, description = "Invalid input", body = String),
+        (status = 404, description = "Promotion not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn update_promotion_doc() {}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/promotions/{id}",
+    tag = "promotions",
+    params(
+        ("id" = Uuid, Path, description = "Promotion ID")
+    ),
+    responses(
+        (status = 204, description = "Promotion deleted successfully"),
+        (status = 404, description = "Promotion not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn delete_promotion_doc() {}
+
+impl PromotionController {
+    /// List all promotions
+    pub async fn list_promotions(pool: web::Data<sqlx::PgPool>) -> impl Responder {
+        let use_case =
+            ListPromotionsUseCase::new(PostgresPromotionRepository::new(pool.get_ref().clone()));
+
+        match use_case.execute(()).await {
+            Ok(promotions) => HttpResponse::Ok().json(PromotionsListResponse::from(promotions)),
+            Err(e) => {
+                error!("Error listing promotions: {:?}", e);
+                HttpResponse::InternalServerError().json(json!({
+                    "error": "Internal server error"
+                }))
+            }
+        }
+    }
+
+    /// Create a new promotion
+    pub async fn create_promotion(
+        pool: web::Data<sqlx::PgPool>,
+        promotion_data: web::Json<CreatePromotionDto>,
+    ) -> impl Responder {
+        let use_case =
+            CreatePromotionUseCase::new(PostgresPromotionRepository::new(pool.get_ref().clone()));
+
+        match use_case.execute(promotion_data.into_inner()).await {
+            Ok(promotion) => HttpResponse::Created().json(PromotionResponse::from(promotion)),
+            Err(ApplicationError::Validation(msg)) => {
+                HttpResponse::BadRequest().json(json!({ "error": msg }))
+            }
+            Err(e) => {
+                error!("Error creating promotion: {:?}", e);
+                HttpResponse::InternalServerError().json(json!({
+                    "error": "Internal server error"
+                }))
+            }
+        }
+    }
+
+    /// Get a promotion by ID
+    pub async fn get_promotion(
+        pool: web::Data<sqlx::PgPool>,
+        promotion_id: web::Path<Uuid>,
+    ) -> impl Responder {
+        let use_case =
+            GetPromotionUseCase::new(PostgresPromotionRepository::new(pool.get_ref().clone()));
+
+        match use_case.execute(promotion_id.into_inner()).await {
+            Ok(promotion) => HttpResponse::Ok().json(PromotionResponse::from(promotion)),
+            Err(ApplicationError::NotFound) => HttpResponse::NotFound().json(json!({
+                "error": "Promotion not found"
+            })),
+            Err(_) => HttpResponse::InternalServerError().json(json!({
+                "error": "Internal server error"
+            })),
+        }
+    }
+
+    /// Update a promotion
+    pub async fn update_promotion(
+        pool: web::Data<sqlx::PgPool>,
+        promotion_id: web::Path<Uuid>,
+        promotion_data: web::Json<UpdatePromotionDto>,
+    ) -> impl Responder {
+        let use_case =
+            UpdatePromotionUseCase::new(PostgresPromotionRepository::new(pool.get_ref().clone()));
+
+        match use_case
+            .execute((promotion_id.into_inner(), promotion_data.into_inner()))
+            .await
+        {
+            Ok(promotion) => HttpResponse::Ok().json(PromotionResponse::from(promotion)),
+            Err(ApplicationError::NotFound) => HttpResponse::NotFound().json(json!({
+                "error": "Promotion not found"
+            })),
+            Err(ApplicationError::Validation(msg)) => {
+                HttpResponse::BadRequest().json(json!({ "error": msg }))
+            }
+            Err(e) => {
+                error!("Error updating promotion: {:?}", e);
+                HttpResponse::InternalServerError().json(json!({
+                    "error": "Internal server error"
+                }))
+            }
+        }
+    }
+
+    /// Delete a promotion
+    pub async fn delete_promotion(
+        pool: web::Data<sqlx::PgPool>,
+        promotion_id: web::Path<Uuid>,
+    ) -> impl Responder {
+        let use_case =
+            DeletePromotionUseCase::new(PostgresPromotionRepository::new(pool.get_ref().clone()));
+
+        match use_case.execute(promotion_id.into_inner()).await {
+            Ok(_) => HttpResponse::NoContent().finish(),
+            Err(ApplicationError::NotFound) => HttpResponse::NotFound().json(json!({
+                "error": "Promotion not found"
+            })),
+            Err(_) => HttpResponse::InternalServerError().json(json!({
+                "error": "Internal server error"
+            })),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use actix_web::{web, HttpResponse, Responder};
+use log::error;
+use serde_json::json;
+
+use crate::{
+    application::use_cases::{scheduler::ListScheduledTasksUseCase, UseCase},
+    infrastructure::persistence::postgres::PostgresScheduledTaskRepository,
+    interfaces::{
+        http::responses::scheduler_responses::ScheduledTasksListResponse,
+        middleware::auth::AdminPrincipal,
+    },
+};
+
+pub struct SchedulerController;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/scheduler/tasks",
+    tag = "scheduler",
+    responses(
+        (status = 200, description = "Scheduled tasks with their next and last run", body = ScheduledTasksListResponse),
+        (status = 401, description = "Authentication required", body = String),
+        (status = 403, description = "Administrator access required", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn list_tasks_doc() {}
+
+impl SchedulerController {
+    /// List scheduled tasks and their last run
+    pub async fn list_tasks(_: AdminPrincipal, pool: web::Data<sqlx::PgPool>) -> impl Responder {
+        let use_case = ListScheduledTasksUseCase::new(PostgresScheduledTaskRepository::new(
+            pool.get_ref().clone(),
+        ));
+
+        match use_case.execute(()).await {
+            Ok(tasks) => HttpResponse::Ok().json(ScheduledTasksListResponse::from(tasks)),
+            Err(e) => {
+                error!("Error listing scheduled tasks: {:?}", e);
+                HttpResponse::InternalServerError().json(json!({
+                    "error": "Internal server error"
+                }))
+            }
+        }
+    }
+}
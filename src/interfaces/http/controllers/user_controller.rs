@@ -3,31 +3,56 @@ use crate::{
         error::ApplicationError,
         use_cases::{
             user::{
-                CreateUserUseCase, DeleteUserUseCase, GetUserUseCase, ListUsersUseCase,
-                UpdateUserUseCase,
+                CreateUserUseCase, DeleteUserUseCase, ExportUsersUseCase, GetUserUseCase,
+                ListUsersUseCase, UpdateUserUseCase,
             },
             UseCase,
         },
     },
     domain::entities::user::{CreateUserDto, UpdateUserDto},
-    infrastructure::persistence::postgres::PostgresUserRepository,
+    infrastructure::persistence::postgres::{Database, PostgresUserRepository},
     interfaces::http::{
-        requests::user_requests::{CreateUserRequest, UpdateUserRequest},
+        export::{export_response, ExportFormat, ExportFormatError},
+        projection::{Projection, USER_PROJECTION},
+        requests::user_requests::{
+            CreateUserRequest, ExportUsersQuery, UpdateUserRequest, UserFieldsQuery,
+        },
         responses::user_responses::{UserResponse, UsersListResponse},
     },
 };
-use actix_web::{web, HttpResponse, Responder};
+use actix_web::{http::header, web, HttpRequest, HttpResponse, Responder};
+use futures::StreamExt;
+use log::error;
 use serde_json::json;
 use uuid::Uuid;
 
 pub struct UserController;
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/export",
+    tag = "users",
+    params(
+        ExportUsersQuery,
+        ("Accept" = Option<String>, Header, description = "text/csv, application/x-ndjson or application/vnd.openxmlformats-officedocument.spreadsheetml.sheet")
+    ),
+    responses(
+        (status = 200, description = "All users, streamed as CSV, NDJSON or XLSX", content_type = "text/csv"),
+        (status = 400, description = "Unknown format", body = String),
+        (status = 406, description = "None of the accepted media types can be produced", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn export_users_doc() {}
+
 #[utoipa::path(
     get,
     path = "/api/v1/users",
     tag = "users",
+    params(UserFieldsQuery),
     responses(
         (status = 200, description = "List all users successfully", body = UsersListResponse),
+        (status = 400, description = "Unknown field", body = String),
         (status = 500, description = "Internal server error", body = String)
     )
 )]
@@ -51,10 +76,12 @@ async fn create_user_doc() {}
     path = "/api/v1/users/{id}",
     tag = "users",
     params(
-        ("id" = Uuid, Path, description = "User ID")
+        ("id" = Uuid, Path, description = "User ID"),
+        UserFieldsQuery
     ),
     responses(
         (status = 200, description = "User found", body = UserResponse),
+        (status = 400, description = "Unknown field", body = String),
         (status = 404, description = "User not found", body = String),
         (status = 500, description = "Internal server error", body = String)
     )
@@ -96,10 +123,10 @@ async fn delete_user_doc() {}
 impl UserController {
     /// Create a new user
     pub async fn create_user(
-        pool: web::Data<sqlx::PgPool>,
+        database: Database,
         user_data: web::Json<CreateUserRequest>,
     ) -> impl Responder {
-        let repository = PostgresUserRepository::new(pool.get_ref().clone());
+        let repository = PostgresUserRepository::new(database);
         let use_case = CreateUserUseCase::new(repository);
 
         let create_dto = CreateUserDto {
@@ -121,11 +148,11 @@ impl UserController {
 
     /// Update an existing user
     pub async fn update_user(
-        pool: web::Data<sqlx::PgPool>,
+        database: Database,
         user_id: web::Path<Uuid>,
         user_data: web::Json<UpdateUserRequest>,
     ) -> impl Responder {
-        let repository = PostgresUserRepository::new(pool.get_ref().clone());
+        let repository = PostgresUserRepository::new(database);
         let use_case = UpdateUserUseCase::new(repository);
 
         let update_dto = UpdateUserDto {
@@ -149,11 +176,8 @@ impl UserController {
     }
 
     /// Delete a user
-    pub async fn delete_user(
-        pool: web::Data<sqlx::PgPool>,
-        user_id: web::Path<Uuid>,
-    ) -> impl Responder {
-        let repository = PostgresUserRepository::new(pool.get_ref().clone());
+    pub async fn delete_user(database: Database, user_id: web::Path<Uuid>) -> impl Responder {
+        let repository = PostgresUserRepository::new(database);
         let use_case = DeleteUserUseCase::new(repository);
 
         match use_case.execute(user_id.into_inner()).await {
@@ -171,12 +195,17 @@ impl UserController {
     pub async fn get_user(
         pool: web::Data<sqlx::PgPool>,
         user_id: web::Path<Uuid>,
+        query: web::Query<UserFieldsQuery>,
     ) -> impl Responder {
+        let projection = match Projection::parse(&USER_PROJECTION, query.fields.as_deref(), None) {
+            Ok(projection) => projection,
+            Err(msg) => return HttpResponse::BadRequest().json(json!({ "error": msg })),
+        };
         let repository = PostgresUserRepository::new(pool.get_ref().clone());
         let use_case = GetUserUseCase::new(repository);
 
         match use_case.execute(user_id.into_inner()).await {
-            Ok(user) => HttpResponse::Ok().json(UserResponse::from(user)),
+            Ok(user) => HttpResponse::Ok().json(projection.apply(&UserResponse::from(user))),
             Err(ApplicationError::NotFound) => HttpResponse::NotFound().json(json!({
                 "error": "User not found"
             })),
@@ -187,18 +216,65 @@ impl UserController {
     }
 
     /// List all users
-    pub async fn list_users(pool: web::Data<sqlx::PgPool>) -> impl Responder {
+    pub async fn list_users(
+        pool: web::Data<sqlx::PgPool>,
+        query: web::Query<UserFieldsQuery>,
+    ) -> impl Responder {
+        let projection = match Projection::parse(&USER_PROJECTION, query.fields.as_deref(), None) {
+            Ok(projection) => projection,
+            Err(msg) => return HttpResponse::BadRequest().json(json!({ "error": msg })),
+        };
         let repository = PostgresUserRepository::new(pool.get_ref().clone());
         let use_case = ListUsersUseCase::new(repository);
 
         match use_case.execute(()).await {
             Ok(users) => {
                 let response = UsersListResponse::from(users);
-                HttpResponse::Ok().json(response)
+                HttpResponse::Ok().json(projection.apply_list("users", &response.users))
             }
             Err(_) => HttpResponse::InternalServerError().json(json!({
                 "error": "Internal server error"
             })),
         }
     }
+
+    /// Download all users as a file, streamed straight from the database
+    pub async fn export_users(
+        req: HttpRequest,
+        pool: web::Data<sqlx::PgPool>,
+        query: web::Query<ExportUsersQuery>,
+    ) -> impl Responder {
+        let accept = req
+            .headers()
+            .get(header::ACCEPT)
+            .and_then(|value| value.to_str().ok());
+        let format = match ExportFormat::negotiate(query.format.as_deref(), accept) {
+            Ok(format) => format,
+            Err(ExportFormatError::Unknown(format)) => {
+                return HttpResponse::BadRequest().json(json!({
+                    "error": format!("Unknown export format '{}', expected csv, ndjson or xlsx", format)
+                }))
+            }
+            Err(ExportFormatError::NotAcceptable) => {
+                return HttpResponse::NotAcceptable().json(json!({
+                    "error": "Exports are available as text/csv, application/x-ndjson or xlsx"
+                }))
+            }
+        };
+
+        let use_case = ExportUsersUseCase::new(PostgresUserRepository::new(pool.get_ref().clone()));
+        match use_case.execute(()).await {
+            Ok(users) => export_response(
+                format,
+                "users",
+                users.map(|user| user.map(UserResponse::from)),
+            ),
+            Err(e) => {
+                error!("Error exporting users: {:?}", e);
+                HttpResponse::InternalServerError().json(json!({
+                    "error": "Internal server error"
+                }))
+            }
+        }
+    }
 }
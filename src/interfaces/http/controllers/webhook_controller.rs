@@ -0,0 +1,297 @@
+use actix_web::{web, HttpResponse, Responder};
+use log::error;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        error::ApplicationError,
+        use_cases::{
+            webhook::{
+                CreateWebhookSubscriptionUseCase, DeleteWebhookSubscriptionUseCase,
+                GetWebhookSubscriptionUseCase, ListWebhookDeliveriesUseCase,
+                ListWebhookSubscriptionsUseCase, RedeliverWebhookUseCase,
+                UpdateWebhookSubscriptionUseCase,
+            },
+            UseCase,
+        },
+    },
+    domain::entities::webhook::{CreateWebhookSubscriptionDto, UpdateWebhookSubscriptionDto},
+    infrastructure::persistence::postgres::PostgresWebhookRepository,
+    interfaces::{
+        http::{
+            requests::webhook_requests::WebhookDeliveriesQuery,
+            responses::webhook_responses::{
+                WebhookDeliveriesListResponse, WebhookSubscriptionResponse,
+                WebhookSubscriptionsListResponse,
+            },
+        },
+        middleware::auth::AdminPrincipal,
+    },
+};
+
+pub struct WebhookController;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/webhooks",
+    tag = "webhooks",
+    responses(
+        (status = 200, description = "List all webhook subscriptions", body = WebhookSubscriptionsListResponse),
+        (status = 401, description = "Authentication required", body = String),
+        (status = 403, description = "Administrator access required", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn list_subscriptions_doc() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/webhooks",
+    tag = "webhooks",
+    request_body = CreateWebhookSubscriptionDto,
+    responses(
+        (status = 201, description = "Subscription created, the response is the only one showing its secret", body = WebhookSubscriptionResponse),
+        (status = 400, description = "Invalid input", body = String),
+        (status = 401, description = "Authentication required", body = String),
+        (status = 403, description = "Administrator access required", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn create_subscription_doc() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/webhooks/{id}",
+    tag = "webhooks",
+    params(
+        ("id" = Uuid, Path, description = "Subscription ID")
+    ),
+    responses(
+        (status = 200, description = "Subscription found", body = WebhookSubscriptionResponse),
+        (status = 401, description = "Authentication required", body = String),
+        (status = 403, description = "Administrator access required", body = String),
+        (status = 404, description = "Subscription not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn get_subscription_doc() {}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/webhooks/{id}",
+    tag = "webhooks",
+    params(
+        ("id" = Uuid, Path, description = "Subscription ID")
+    ),
+    request_body = UpdateWebhookSubscriptionDto,
+    responses(
+        (status = 200, description = "Subscription updated successfully", body = WebhookSubscriptionResponse),
+        (status = 400, description = "Invalid input", body = String),
+        (status = 401, description = "Authentication required", body = String),
+        (status = 403, description = "Administrator access required", body = String),
+        (status = 404, description = "Subscription not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn update_subscription_doc() {}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/webhooks/{id}",
+    tag = "webhooks",
+    params(
+        ("id" = Uuid, Path, description = "Subscription ID")
+    ),
+    responses(
+        (status = 204, description = "Subscription and its deliveries deleted"),
+        (status = 401, description = "Authentication required", body = String),
+        (status = 403, description = "Administrator access required", body = String),
+        (status = 404, description = "Subscription not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn delete_subscription_doc() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/webhooks/{id}/deliveries",
+    tag = "webhooks",
+    params(
+        ("id" = Uuid, Path, description = "Subscription ID"),
+        WebhookDeliveriesQuery
+    ),
+    responses(
+        (status = 200, description = "Delivery log of the subscription, newest first", body = WebhookDeliveriesListResponse),
+        (status = 400, description = "Invalid limit", body = String),
+        (status = 401, description = "Authentication required", body = String),
+        (status = 403, description = "Administrator access required", body = String),
+        (status = 404, description = "Subscription not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn list_deliveries_doc() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/webhooks/{id}/deliveries/{delivery_id}/redeliver",
+    tag = "webhooks",
+    params(
+        ("id" = Uuid, Path, description = "Subscription ID"),
+        ("delivery_id" = Uuid, Path, description = "Delivery to send again")
+    ),
+    responses(
+        (status = 202, description = "A new delivery of the same event was queued", body = WebhookDelivery),
+        (status = 400, description = "Subscription is disabled", body = String),
+        (status = 401, description = "Authentication required", body = String),
+        (status = 403, description = "Administrator access required", body = String),
+        (status = 404, description = "Subscription or delivery not found", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    )
+)]
+async fn redeliver_doc() {}
+
+impl WebhookController {
+    /// List all webhook subscriptions
+    pub async fn list_subscriptions(
+        _: AdminPrincipal,
+        pool: web::Data<sqlx::PgPool>,
+    ) -> impl Responder {
+        let use_case = ListWebhookSubscriptionsUseCase::new(PostgresWebhookRepository::new(
+            pool.get_ref().clone(),
+        ));
+
+        match use_case.execute(()).await {
+            Ok(subscriptions) => {
+                HttpResponse::Ok().json(WebhookSubscriptionsListResponse::from(subscriptions))
+            }
+            Err(e) => Self::error_response(e, "Error listing webhook subscriptions"),
+        }
+    }
+
+    /// Subscribe an endpoint to events
+    pub async fn create_subscription(
+        _: AdminPrincipal,
+        pool: web::Data<sqlx::PgPool>,
+        subscription_data: web::Json<CreateWebhookSubscriptionDto>,
+    ) -> impl Responder {
+        let use_case = CreateWebhookSubscriptionUseCase::new(PostgresWebhookRepository::new(
+            pool.get_ref().clone(),
+        ));
+
+        match use_case.execute(subscription_data.into_inner()).await {
+            Ok(subscription) => {
+                HttpResponse::Created().json(WebhookSubscriptionResponse::with_secret(subscription))
+            }
+            Err(e) => Self::error_response(e, "Error creating webhook subscription"),
+        }
+    }
+
+    /// Get a webhook subscription by ID
+    pub async fn get_subscription(
+        _: AdminPrincipal,
+        pool: web::Data<sqlx::PgPool>,
+        subscription_id: web::Path<Uuid>,
+    ) -> impl Responder {
+        let use_case = GetWebhookSubscriptionUseCase::new(PostgresWebhookRepository::new(
+            pool.get_ref().clone(),
+        ));
+
+        match use_case.execute(subscription_id.into_inner()).await {
+            Ok(subscription) => {
+                HttpResponse::Ok().json(WebhookSubscriptionResponse::from(subscription))
+            }
+            Err(e) => Self::error_response(e, "Error getting webhook subscription"),
+        }
+    }
+
+    /// Update a webhook subscription
+    pub async fn update_subscription(
+        _: AdminPrincipal,
+        pool: web::Data<sqlx::PgPool>,
+        subscription_id: web::Path<Uuid>,
+        subscription_data: web::Json<UpdateWebhookSubscriptionDto>,
+    ) -> impl Responder {
+        let use_case = UpdateWebhookSubscriptionUseCase::new(PostgresWebhookRepository::new(
+            pool.get_ref().clone(),
+        ));
+
+        match use_case
+            .execute((subscription_id.into_inner(), subscription_data.into_inner()))
+            .await
+        {
+            Ok(subscription) => {
+                HttpResponse::Ok().json(WebhookSubscriptionResponse::from(subscription))
+            }
+            Err(e) => Self::error_response(e, "Error updating webhook subscription"),
+        }
+    }
+
+    /// Delete a webhook subscription
+    pub async fn delete_subscription(
+        _: AdminPrincipal,
+        pool: web::Data<sqlx::PgPool>,
+        subscription_id: web::Path<Uuid>,
+    ) -> impl Responder {
+        let use_case = DeleteWebhookSubscriptionUseCase::new(PostgresWebhookRepository::new(
+            pool.get_ref().clone(),
+        ));
+
+        match use_case.execute(subscription_id.into_inner()).await {
+            Ok(()) => HttpResponse::NoContent().finish(),
+            Err(e) => Self::error_response(e, "Error deleting webhook subscription"),
+        }
+    }
+
+    /// List the deliveries of a subscription
+    pub async fn list_deliveries(
+        _: AdminPrincipal,
+        pool: web::Data<sqlx::PgPool>,
+        subscription_id: web::Path<Uuid>,
+        query: web::Query<WebhookDeliveriesQuery>,
+    ) -> impl Responder {
+        let use_case = ListWebhookDeliveriesUseCase::new(PostgresWebhookRepository::new(
+            pool.get_ref().clone(),
+        ));
+
+        match use_case
+            .execute((subscription_id.into_inner(), query.limit))
+            .await
+        {
+            Ok(deliveries) => HttpResponse::Ok().json(WebhookDeliveriesListResponse { deliveries }),
+            Err(e) => Self::error_response(e, "Error listing webhook deliveries"),
+        }
+    }
+
+    /// Queue a delivery again
+    pub async fn redeliver(
+        _: AdminPrincipal,
+        pool: web::Data<sqlx::PgPool>,
+        path: web::Path<(Uuid, Uuid)>,
+    ) -> impl Responder {
+        let use_case =
+            RedeliverWebhookUseCase::new(PostgresWebhookRepository::new(pool.get_ref().clone()));
+
+        match use_case.execute(path.into_inner()).await {
+            Ok(delivery) => HttpResponse::Accepted().json(delivery),
+            Err(e) => Self::error_response(e, "Error redelivering webhook"),
+        }
+    }
+
+    fn error_response(e: ApplicationError, context: &str) -> HttpResponse {
+        match e {
+            ApplicationError::NotFound => HttpResponse::NotFound().json(json!({
+                "error": "Webhook subscription or delivery not found"
+            })),
+            ApplicationError::Validation(msg) => {
+                HttpResponse::BadRequest().json(json!({ "error": msg }))
+            }
+            e => {
+                error!("{}: {:?}", context, e);
+                HttpResponse::InternalServerError().json(json!({
+                    "error": "Internal server error"
+                }))
+            }
+        }
+    }
+}
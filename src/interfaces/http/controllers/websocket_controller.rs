@@ -0,0 +1,213 @@
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse, Responder};
+use actix_ws::{CloseCode, CloseReason, Closed, Message, MessageStream, Session};
+use log::{info, warn};
+use serde_json::json;
+use std::{
+    collections::HashSet,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::sync::broadcast::{error::RecvError, Receiver};
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    domain::entities::live_update::{LiveUpdate, Topic},
+    infrastructure::{events::BroadcastUpdateBus, shutdown::Shutdown},
+    interfaces::{
+        http::{
+            requests::websocket_requests::{ClientMessage, WebSocketQuery},
+            responses::websocket_responses::ServerMessage,
+        },
+        middleware::auth::{Principal, TokenValidator},
+    },
+};
+
+pub struct WebSocketController;
+
+/// How often the server pings the client
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+/// Connections that sent nothing for this long, pongs included, are closed
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(10);
+/// Most topics a single connection may subscribe to
+const MAX_TOPICS: usize = 100;
+
+/// The caller of the handshake, from the `Authorization` header or the `token` query,
+/// or why it was refused
+fn authenticate(
+    req: &HttpRequest,
+    validator: &TokenValidator,
+    query: &WebSocketQuery,
+) -> Result<Principal, &'static str> {
+    if let Some(principal) = req.extensions().get::<Principal>() {
+        return Ok(*principal);
+    }
+
+    match &query.token {
+        Some(token) => validator
+            .validate(token)
+            .ok_or("Invalid or expired bearer token"),
+        None => Err("Authentication required"),
+    }
+}
+
+/// Applies a client message to the subscriptions and returns the reply
+fn handle_text<'a>(
+    text: &str,
+    topics: &mut HashSet<Topic>,
+    principal: Principal,
+) -> ServerMessage<'a> {
+    let message = match serde_json::from_str::<ClientMessage>(text) {
+        Ok(message) => message,
+        Err(e) => return ServerMessage::error(format!("Invalid message: {}", e)),
+    };
+
+    match message {
+        ClientMessage::Subscribe { topic } => {
+            let parsed = match topic.parse::<Topic>() {
+                Ok(parsed) => parsed,
+                Err(msg) => return ServerMessage::error(msg),
+            };
+            if parsed == Topic::Orders && !principal.is_admin {
+                return ServerMessage::error("Only administrators may subscribe to orders");
+            }
+            if !topics.contains(&parsed) && topics.len() >= MAX_TOPICS {
+                return ServerMessage::error(format!(
+                    "A connection can follow at most {} topics",
+                    MAX_TOPICS
+                ));
+            }
+            topics.insert(parsed);
+            ServerMessage::Subscribed { topic }
+        }
+        ClientMessage::Unsubscribe { topic } => match topic.parse::<Topic>() {
+            Ok(parsed) => {
+                topics.remove(&parsed);
+                ServerMessage::Unsubscribed { topic }
+            }
+            Err(msg) => ServerMessage::error(msg),
+        },
+    }
+}
+
+async fn send(session: &mut Session, message: &ServerMessage<'_>) -> Result<(), Closed> {
+    session
+        .text(serde_json::to_string(message).unwrap_or_default())
+        .await
+}
+
+/// Serves one connection until either side closes it, the client stops answering or
+/// the server shuts down
+async fn run(
+    mut session: Session,
+    mut messages: MessageStream,
+    mut updates: Receiver<Arc<LiveUpdate>>,
+    principal: Principal,
+    draining: CancellationToken,
+) {
+    let mut topics = HashSet::new();
+    let mut last_seen = Instant::now();
+    let mut heartbeat = actix_web::rt::time::interval(HEARTBEAT_INTERVAL);
+
+    let reason = loop {
+        tokio::select! {
+            message = messages.recv() => {
+                last_seen = Instant::now();
+                let sent = match message {
+                    Some(Ok(Message::Text(text))) => {
+                        send(&mut session, &handle_text(&text, &mut topics, principal)).await
+                    }
+                    Some(Ok(Message::Binary(_))) => {
+                        send(&mut session, &ServerMessage::error("Messages must be JSON text")).await
+                    }
+                    Some(Ok(Message::Ping(bytes))) => session.pong(&bytes).await,
+                    Some(Ok(Message::Close(reason))) => break reason,
+                    Some(Ok(_)) => Ok(()),
+                    Some(Err(e)) => {
+                        warn!("WebSocket protocol error for {}: {}", principal.user_id, e);
+                        break Some(CloseCode::Protocol.into());
+                    }
+                    None => break None,
+                };
+                if sent.is_err() {
+                    break None;
+                }
+            }
+            update = updates.recv() => {
+                let sent = match update {
+                    Ok(update) => {
+                        let topic = update.topic();
+                        if !topics.contains(&topic) {
+                            continue;
+                        }
+                        send(&mut session, &ServerMessage::update(topic, &update)).await
+                    }
+                    Err(RecvError::Lagged(missed)) => {
+                        let error = format!(
+                            "Missed {} updates, reload the subscribed resources",
+                            missed
+                        );
+                        send(&mut session, &ServerMessage::error(error)).await
+                    }
+                    Err(RecvError::Closed) => break Some(CloseCode::Away.into()),
+                };
+                if sent.is_err() {
+                    break None;
+                }
+            }
+            _ = draining.cancelled() => {
+                break Some(CloseReason {
+                    code: CloseCode::Away,
+                    description: Some("Server shutting down".to_string()),
+                });
+            }
+            _ = heartbeat.tick() => {
+                if last_seen.elapsed() > CLIENT_TIMEOUT {
+                    info!("Closing WebSocket of {}: heartbeat timed out", principal.user_id);
+                    break Some(CloseReason {
+                        code: CloseCode::Policy,
+                        description: Some("Heartbeat timed out".to_string()),
+                    });
+                }
+                if session.ping(b"").await.is_err() {
+                    break None;
+                }
+            }
+        }
+    };
+
+    let _ = session.close(reason).await;
+}
+
+impl WebSocketController {
+    /// Upgrade to a WebSocket streaming live updates of the subscribed topics
+    pub async fn connect(
+        req: HttpRequest,
+        body: web::Payload,
+        bus: web::Data<BroadcastUpdateBus>,
+        validator: web::Data<TokenValidator>,
+        shutdown: web::Data<Shutdown>,
+        query: web::Query<WebSocketQuery>,
+    ) -> impl Responder {
+        let principal = match authenticate(&req, &validator, &query) {
+            Ok(principal) => principal,
+            Err(msg) => return HttpResponse::Unauthorized().json(json!({ "error": msg })),
+        };
+
+        let (response, session, messages) = match actix_ws::handle(&req, body) {
+            Ok(handshake) => handshake,
+            Err(e) => return e.error_response(),
+        };
+
+        // Subscribe before answering so no update after the handshake is lost
+        let updates = bus.subscribe();
+        actix_web::rt::spawn(run(
+            session,
+            messages,
+            updates,
+            principal,
+            shutdown.draining(),
+        ));
+
+        response
+    }
+}
@@ -0,0 +1,21 @@
+use actix_web::{dev::Payload, error, web, Error, FromRequest, HttpMessage, HttpRequest};
+use sqlx::PgPool;
+use std::future::{ready, Ready};
+
+use crate::infrastructure::persistence::postgres::Database;
+
+/// Extracts where a write runs: the transaction an atomic batch put in the request
+/// extensions, otherwise the server's pool
+impl FromRequest for Database {
+    type Error = Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let database = req.extensions().get::<Database>().cloned().or_else(|| {
+            req.app_data::<web::Data<PgPool>>()
+                .map(|pool| Database::from(pool.get_ref().clone()))
+        });
+
+        ready(database.ok_or_else(|| error::ErrorInternalServerError("database not configured")))
+    }
+}
@@ -0,0 +1,350 @@
+//! Streaming file exports. Rows are encoded as they arrive from the database and sent
+//! as a chunked response, so memory use does not grow with the size of the export.
+
+use std::cell::RefCell;
+use std::io::Write;
+use std::rc::Rc;
+
+use actix_web::{http::header, web::Bytes, HttpResponse};
+use futures::{Stream, StreamExt};
+use log::error;
+use serde::Serialize;
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipWriter};
+
+use crate::application::error::ApplicationError;
+
+/// Rows encoded per response chunk
+const ROWS_PER_CHUNK: usize = 256;
+
+const CSV_CONTENT_TYPE: &str = "text/csv; charset=utf-8";
+const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+const XLSX_CONTENT_TYPE: &str = "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Ndjson,
+    Xlsx,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ExportFormatError {
+    /// `?format=` names a format that does not exist
+    Unknown(String),
+    /// The `Accept` header rules out every format
+    NotAcceptable,
+}
+
+impl ExportFormat {
+    /// Picks the format from `?format=`, falling back to the `Accept` header and then CSV
+    pub fn negotiate(param: Option<&str>, accept: Option<&str>) -> Result<Self, ExportFormatError> {
+        if let Some(param) = param {
+            return match param.to_ascii_lowercase().as_str() {
+                "csv" => Ok(Self::Csv),
+                "ndjson" | "jsonl" => Ok(Self::Ndjson),
+                "xlsx" => Ok(Self::Xlsx),
+                other => Err(ExportFormatError::Unknown(other.to_string())),
+            };
+        }
+
+        let Some(accept) = accept else {
+            return Ok(Self::Csv);
+        };
+        for media_type in accept.split(',') {
+            let media_type = media_type.split(';').next().unwrap_or_default().trim();
+            match media_type.to_ascii_lowercase().as_str() {
+                "text/csv" | "text/*" | "*/*" => return Ok(Self::Csv),
+                "application/x-ndjson" | "application/ndjson" => return Ok(Self::Ndjson),
+                XLSX_CONTENT_TYPE => return Ok(Self::Xlsx),
+                _ => {}
+            }
+        }
+
+        Err(ExportFormatError::NotAcceptable)
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Csv => CSV_CONTENT_TYPE,
+            Self::Ndjson => NDJSON_CONTENT_TYPE,
+            Self::Xlsx => XLSX_CONTENT_TYPE,
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Ndjson => "ndjson",
+            Self::Xlsx => "xlsx",
+        }
+    }
+}
+
+/// A cell of a tabular export
+pub enum ExportValue {
+    Text(String),
+    /// Decimal representation of a number, written as a numeric cell in spreadsheets
+    Number(String),
+    Empty,
+}
+
+impl ExportValue {
+    fn text(&self) -> &str {
+        match self {
+            Self::Text(value) | Self::Number(value) => value,
+            Self::Empty => "",
+        }
+    }
+}
+
+impl From<Option<String>> for ExportValue {
+    fn from(value: Option<String>) -> Self {
+        value.map_or(Self::Empty, Self::Text)
+    }
+}
+
+/// A row that can be exported. NDJSON uses the serde representation, CSV and XLSX
+/// the flat `columns`/`values` view
+pub trait ExportRecord: Serialize {
+    fn columns() -> &'static [&'static str];
+    fn values(&self) -> Vec<ExportValue>;
+}
+
+#[derive(thiserror::Error, Debug)]
+enum ExportError {
+    #[error("{0}")]
+    Source(#[from] ApplicationError),
+    #[error("Encoding failed: {0}")]
+    Encoding(String),
+}
+
+impl From<std::io::Error> for ExportError {
+    fn from(e: std::io::Error) -> Self {
+        ExportError::Encoding(e.to_string())
+    }
+}
+
+impl From<csv::Error> for ExportError {
+    fn from(e: csv::Error) -> Self {
+        ExportError::Encoding(e.to_string())
+    }
+}
+
+impl From<zip::result::ZipError> for ExportError {
+    fn from(e: zip::result::ZipError) -> Self {
+        ExportError::Encoding(e.to_string())
+    }
+}
+
+/// Streams `rows` as a downloadable `{name}.{extension}` file. Failures after the
+/// first chunk can only abort the transfer, so they are logged here
+pub fn export_response<T, S>(format: ExportFormat, name: &'static str, rows: S) -> HttpResponse
+where
+    T: ExportRecord + 'static,
+    S: Stream<Item = Result<T, ApplicationError>> + 'static,
+{
+    let body = match format {
+        ExportFormat::Csv => csv_body(rows).boxed_local(),
+        ExportFormat::Ndjson => ndjson_body(rows).boxed_local(),
+        ExportFormat::Xlsx => xlsx_body(name, rows).boxed_local(),
+    }
+    .inspect(move |chunk| {
+        if let Err(e) = chunk {
+            error!("Export of {} aborted: {}", name, e);
+        }
+    });
+
+    HttpResponse::Ok()
+        .content_type(format.content_type())
+        .insert_header((header::VARY, header::ACCEPT.as_str()))
+        .insert_header((
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{}.{}\"", name, format.extension()),
+        ))
+        .streaming(body)
+}
+
+fn csv_body<T, S>(rows: S) -> impl Stream<Item = Result<Bytes, ExportError>>
+where
+    T: ExportRecord,
+    S: Stream<Item = Result<T, ApplicationError>>,
+{
+    async_stream::try_stream! {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer.write_record(T::columns())?;
+        yield Bytes::from(writer.into_inner().map_err(|e| e.into_error())?);
+
+        let mut chunks = std::pin::pin!(rows.ready_chunks(ROWS_PER_CHUNK));
+        while let Some(chunk) = chunks.next().await {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            for row in chunk {
+                writer.write_record(row?.values().iter().map(ExportValue::text))?;
+            }
+            yield Bytes::from(writer.into_inner().map_err(|e| e.into_error())?);
+        }
+    }
+}
+
+fn ndjson_body<T, S>(rows: S) -> impl Stream<Item = Result<Bytes, ExportError>>
+where
+    T: ExportRecord,
+    S: Stream<Item = Result<T, ApplicationError>>,
+{
+    async_stream::try_stream! {
+        let mut chunks = std::pin::pin!(rows.ready_chunks(ROWS_PER_CHUNK));
+        while let Some(chunk) = chunks.next().await {
+            let mut buffer = Vec::new();
+            for row in chunk {
+                serde_json::to_writer(&mut buffer, &row?)
+                    .map_err(|e| ExportError::Encoding(e.to_string()))?;
+                buffer.push(b'\n');
+            }
+            yield Bytes::from(buffer);
+        }
+    }
+}
+
+/// Zip output collected between chunks
+#[derive(Clone, Default)]
+struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl SharedBuffer {
+    fn take(&self) -> Bytes {
+        Bytes::from(std::mem::take(&mut *self.0.borrow_mut()))
+    }
+}
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+const XLSX_CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/><Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/></Types>"#;
+
+const XLSX_ROOT_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>"#;
+
+const XLSX_WORKBOOK_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/></Relationships>"#;
+
+const XLSX_SHEET_START: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetData>"#;
+
+const XLSX_SHEET_END: &str = "</sheetData></worksheet>";
+
+fn xlsx_workbook(sheet_name: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets><sheet name="{}" sheetId="1" r:id="rId1"/></sheets></workbook>"#,
+        escape_xml(sheet_name)
+    )
+}
+
+/// Spreadsheet column name of a zero based index: A, B, ..., Z, AA, ...
+fn column_name(mut index: usize) -> String {
+    let mut name = Vec::new();
+    loop {
+        name.push(b'A' + (index % 26) as u8);
+        if index < 26 {
+            break;
+        }
+        index = index / 26 - 1;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap_or_default()
+}
+
+/// Escapes text for XML, dropping control characters XML cannot represent
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn xlsx_row(row_number: usize, values: &[ExportValue]) -> String {
+    let mut xml = format!(r#"<row r="{}">"#, row_number);
+    for (index, value) in values.iter().enumerate() {
+        let reference = format!("{}{}", column_name(index), row_number);
+        match value {
+            ExportValue::Text(text) => xml.push_str(&format!(
+                r#"<c r="{}" t="inlineStr"><is><t xml:space="preserve">{}</t></is></c>"#,
+                reference,
+                escape_xml(text)
+            )),
+            ExportValue::Number(number) => {
+                xml.push_str(&format!(r#"<c r="{}"><v>{}</v></c>"#, reference, number))
+            }
+            ExportValue::Empty => {}
+        }
+    }
+    xml.push_str("</row>");
+    xml
+}
+
+/// A minimal single sheet workbook with inline strings, written through a streaming
+/// zip writer so finished rows leave the server while later ones are still fetched
+fn xlsx_body<T, S>(
+    sheet_name: &'static str,
+    rows: S,
+) -> impl Stream<Item = Result<Bytes, ExportError>>
+where
+    T: ExportRecord,
+    S: Stream<Item = Result<T, ApplicationError>>,
+{
+    async_stream::try_stream! {
+        let buffer = SharedBuffer::default();
+        let mut zip = ZipWriter::new_stream(buffer.clone());
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        let workbook = xlsx_workbook(sheet_name);
+        for (path, content) in [
+            ("[Content_Types].xml", XLSX_CONTENT_TYPES),
+            ("_rels/.rels", XLSX_ROOT_RELS),
+            ("xl/workbook.xml", workbook.as_str()),
+            ("xl/_rels/workbook.xml.rels", XLSX_WORKBOOK_RELS),
+        ] {
+            zip.start_file(path, options)?;
+            zip.write_all(content.as_bytes())?;
+        }
+
+        zip.start_file("xl/worksheets/sheet1.xml", options)?;
+        zip.write_all(XLSX_SHEET_START.as_bytes())?;
+        let header: Vec<ExportValue> = T::columns()
+            .iter()
+            .map(|column| ExportValue::Text(column.to_string()))
+            .collect();
+        zip.write_all(xlsx_row(1, &header).as_bytes())?;
+        yield buffer.take();
+
+        let mut row_number = 1;
+        let mut chunks = std::pin::pin!(rows.ready_chunks(ROWS_PER_CHUNK));
+        while let Some(chunk) = chunks.next().await {
+            for row in chunk {
+                row_number += 1;
+                zip.write_all(xlsx_row(row_number, &row?.values()).as_bytes())?;
+            }
+            yield buffer.take();
+        }
+
+        zip.write_all(XLSX_SHEET_END.as_bytes())?;
+        zip.finish()?;
+        yield buffer.take();
+    }
+}
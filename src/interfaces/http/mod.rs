@@ -1,3 +1,7 @@
 pub mod controllers;
+pub mod database;
+pub mod export;
+pub mod negotiation;
+pub mod projection;
 pub mod requests;
 pub mod responses;
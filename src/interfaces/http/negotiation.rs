@@ -0,0 +1,320 @@
+//! Content negotiation for the JSON API. Handlers keep producing and consuming JSON;
+//! this middleware wraps their responses, re-encoding the body in the format the
+//! `Accept` header prefers, and decodes MessagePack or CBOR request bodies into JSON
+//! before they reach the handler. CSV is a response format only: CSV request bodies
+//! reach handlers untouched, so only handlers reading CSV themselves, like the product
+//! import, accept them and JSON handlers answer 415.
+
+use std::pin::Pin;
+
+use actix_web::{
+    body::{self, BoxBody, MessageBody},
+    dev::{Payload, ServiceRequest, ServiceResponse},
+    error::{JsonPayloadError, PayloadError},
+    http::{
+        header::{self, HeaderValue},
+        Method,
+    },
+    middleware::Next,
+    web::{Bytes, BytesMut},
+    Error, HttpMessage, HttpRequest, HttpResponse,
+};
+use futures::{Stream, StreamExt};
+use serde_json::{json, Value};
+
+/// Largest request body that is transcoded, matching the JSON extractor's default
+const MAX_BODY_BYTES: usize = 2 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BodyFormat {
+    Json,
+    MessagePack,
+    Cbor,
+    /// Only for list responses, one line per item, never decoded from requests
+    Csv,
+}
+
+impl BodyFormat {
+    pub fn media_type(&self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::MessagePack => "application/msgpack",
+            Self::Cbor => "application/cbor",
+            Self::Csv => "text/csv; charset=utf-8",
+        }
+    }
+
+    /// The format a concrete media type names
+    pub fn from_media_type(media_type: &str) -> Option<Self> {
+        match media_type.trim().to_ascii_lowercase().as_str() {
+            "application/json" => Some(Self::Json),
+            "application/msgpack" | "application/x-msgpack" | "application/vnd.msgpack" => {
+                Some(Self::MessagePack)
+            }
+            "application/cbor" => Some(Self::Cbor),
+            "text/csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// Media ranges of an `Accept` header, most preferred first, without refused ones (`q=0`)
+fn media_ranges(accept: &str) -> Vec<String> {
+    let mut ranges: Vec<(String, f32)> = accept
+        .split(',')
+        .filter_map(|range| {
+            let mut parts = range.split(';');
+            let media_type = parts.next()?.trim().to_ascii_lowercase();
+            let quality = parts
+                .filter_map(|param| param.trim().strip_prefix("q="))
+                .find_map(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+            (!media_type.is_empty() && quality > 0.0).then_some((media_type, quality))
+        })
+        .collect();
+    // Stable, so equally weighted ranges keep the client's order
+    ranges.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranges
+        .into_iter()
+        .map(|(media_type, _)| media_type)
+        .collect()
+}
+
+/// Media types some handlers produce themselves: event streams and file exports. When
+/// the client asks for one, the handler decides whether it can answer
+const HANDLER_MEDIA_TYPES: [&str; 3] = [
+    "text/event-stream",
+    "application/x-ndjson",
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+];
+
+/// Whether `Accept` names a media type a handler produces itself
+fn accepts_handler_media_type(accept: Option<&str>) -> bool {
+    accept.is_some_and(|accept| {
+        media_ranges(accept)
+            .iter()
+            .any(|range| HANDLER_MEDIA_TYPES.contains(&range.as_str()))
+    })
+}
+
+/// Picks the response format, `None` meaning nothing acceptable can be produced
+pub fn negotiate(accept: Option<&str>, is_list: bool) -> Option<BodyFormat> {
+    let Some(accept) = accept.filter(|accept| !accept.trim().is_empty()) else {
+        return Some(BodyFormat::Json);
+    };
+
+    media_ranges(accept).iter().find_map(|range| {
+        let format = match range.as_str() {
+            "*/*" | "application/*" => Some(BodyFormat::Json),
+            "text/*" => Some(BodyFormat::Csv),
+            other => BodyFormat::from_media_type(other),
+        };
+        format.filter(|format| *format != BodyFormat::Csv || is_list)
+    })
+}
+
+/// The rows of a list response, shaped like `{ "<items>": [{..}, ..], "total": n }`
+fn list_items(value: &Value) -> Option<&Vec<Value>> {
+    let object = value.as_object()?;
+    if object.len() != 2 || !object.get("total").is_some_and(Value::is_number) {
+        return None;
+    }
+
+    let items = object.values().find_map(Value::as_array)?;
+    items.iter().all(Value::is_object).then_some(items)
+}
+
+/// Renders list items as CSV, nested values as JSON text
+fn to_csv(items: &[Value]) -> Result<Vec<u8>, csv::Error> {
+    let mut columns: Vec<&str> = Vec::new();
+    for item in items.iter().filter_map(Value::as_object) {
+        for key in item.keys() {
+            if !columns.contains(&key.as_str()) {
+                columns.push(key);
+            }
+        }
+    }
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    if !columns.is_empty() {
+        writer.write_record(&columns)?;
+    }
+    for item in items {
+        writer.write_record(columns.iter().map(|column| match item.get(*column) {
+            None | Some(Value::Null) => String::new(),
+            Some(Value::String(text)) => text.clone(),
+            Some(other) => other.to_string(),
+        }))?;
+    }
+
+    writer.into_inner().map_err(|e| e.into_error().into())
+}
+
+fn encode(format: BodyFormat, value: &Value) -> Result<Vec<u8>, String> {
+    match format {
+        BodyFormat::Json => serde_json::to_vec(value).map_err(|e| e.to_string()),
+        BodyFormat::MessagePack => rmp_serde::to_vec_named(value).map_err(|e| e.to_string()),
+        BodyFormat::Cbor => {
+            let mut buffer = Vec::new();
+            ciborium::into_writer(value, &mut buffer).map_err(|e| e.to_string())?;
+            Ok(buffer)
+        }
+        BodyFormat::Csv => match list_items(value) {
+            Some(items) => to_csv(items).map_err(|e| e.to_string()),
+            None => Err("Only list responses can be rendered as CSV".to_string()),
+        },
+    }
+}
+
+fn decode(format: BodyFormat, data: &[u8]) -> Result<Value, String> {
+    match format {
+        BodyFormat::MessagePack => rmp_serde::from_slice(data).map_err(|e| e.to_string()),
+        BodyFormat::Cbor => ciborium::from_reader(data).map_err(|e| e.to_string()),
+        BodyFormat::Json | BodyFormat::Csv => {
+            serde_json::from_slice(data).map_err(|e| e.to_string())
+        }
+    }
+}
+
+fn media_type_of(headers: &header::HeaderMap) -> Option<String> {
+    headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .map(|value| value.trim().to_ascii_lowercase())
+}
+
+fn error_body(message: impl Into<String>) -> Value {
+    json!({ "error": message.into() })
+}
+
+fn not_acceptable() -> HttpResponse {
+    HttpResponse::NotAcceptable().json(error_body(
+        "Responses are available as application/json, application/msgpack, application/cbor or, for lists, text/csv",
+    ))
+}
+
+async fn read_payload(mut payload: Payload) -> Result<Option<BytesMut>, PayloadError> {
+    let mut data = BytesMut::new();
+    while let Some(chunk) = payload.next().await {
+        let chunk = chunk?;
+        if data.len() + chunk.len() > MAX_BODY_BYTES {
+            return Ok(None);
+        }
+        data.extend_from_slice(&chunk);
+    }
+    Ok(Some(data))
+}
+
+/// Replaces a MessagePack or CBOR request body with its JSON equivalent
+async fn decode_request(req: &mut ServiceRequest, format: BodyFormat) -> Result<(), HttpResponse> {
+    let data = match read_payload(req.take_payload()).await {
+        Ok(Some(data)) => data,
+        Ok(None) => {
+            return Err(HttpResponse::PayloadTooLarge().json(error_body(format!(
+                "Request body exceeds {} bytes",
+                MAX_BODY_BYTES
+            ))))
+        }
+        Err(e) => return Err(HttpResponse::BadRequest().json(error_body(e.to_string()))),
+    };
+
+    let json = decode(format, &data)
+        .and_then(|value| serde_json::to_vec(&value).map_err(|e| e.to_string()))
+        .map_err(|e| {
+            HttpResponse::BadRequest().json(error_body(format!("Invalid request body: {}", e)))
+        })?;
+
+    let headers = req.headers_mut();
+    headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("application/json"),
+    );
+    headers.insert(header::CONTENT_LENGTH, HeaderValue::from(json.len()));
+
+    let body: Pin<Box<dyn Stream<Item = Result<Bytes, PayloadError>>>> =
+        Box::pin(futures::stream::once(async move { Ok(Bytes::from(json)) }));
+    req.set_payload(Payload::from(body));
+    Ok(())
+}
+
+/// Middleware negotiating request and response body formats for JSON handlers
+pub async fn content_negotiation(
+    mut req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<BoxBody>, Error> {
+    let request_format = media_type_of(req.headers())
+        .as_deref()
+        .and_then(BodyFormat::from_media_type);
+    if let Some(format @ (BodyFormat::MessagePack | BodyFormat::Cbor)) = request_format {
+        if let Err(response) = decode_request(&mut req, format).await {
+            return Ok(req.into_response(response));
+        }
+    }
+
+    let accept = req
+        .headers()
+        .get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    let handler_media_type = accepts_handler_media_type(accept.as_deref());
+    // Refuse before the handler runs, so nothing is changed for an unreadable answer.
+    // Only reads can answer with a list, so writes never get CSV
+    let may_be_list = matches!(*req.method(), Method::GET | Method::HEAD);
+    if negotiate(accept.as_deref(), may_be_list).is_none() && !handler_media_type {
+        return Ok(req.into_response(not_acceptable()));
+    }
+    let mut res = next.call(req).await?.map_into_boxed_body();
+
+    // Only JSON bodies are negotiated, files, exports and images pass through
+    if media_type_of(res.headers()).as_deref() != Some("application/json") {
+        return Ok(res);
+    }
+    res.headers_mut()
+        .append(header::VARY, HeaderValue::from_static("Accept"));
+    if negotiate(accept.as_deref(), false) == Some(BodyFormat::Json) {
+        return Ok(res);
+    }
+
+    let (http_req, response) = res.into_parts();
+    let (mut response, body) = response.into_parts();
+    let data = body::to_bytes(body)
+        .await
+        .map_err(|_| actix_web::error::ErrorInternalServerError("Unreadable response body"))?;
+    let value: Value = serde_json::from_slice(&data)?;
+
+    let encoded = match negotiate(accept.as_deref(), list_items(&value).is_some()) {
+        Some(BodyFormat::Json) => Ok((BodyFormat::Json, data.to_vec())),
+        Some(format) => encode(format, &value).map(|encoded| (format, encoded)),
+        // Errors of handlers producing their own media type stay JSON
+        None if handler_media_type => Ok((BodyFormat::Json, data.to_vec())),
+        None => Err("No acceptable format".to_string()),
+    };
+
+    let response = match encoded {
+        Ok((format, encoded)) => {
+            response.headers_mut().insert(
+                header::CONTENT_TYPE,
+                HeaderValue::from_static(format.media_type()),
+            );
+            response.set_body(BoxBody::new(encoded))
+        }
+        Err(_) => not_acceptable(),
+    };
+
+    Ok(ServiceResponse::new(http_req, response))
+}
+
+/// Rejects JSON bodies sent with another content type as 415 instead of 400
+pub fn json_error_handler(err: JsonPayloadError, _req: &HttpRequest) -> Error {
+    let response = match &err {
+        JsonPayloadError::ContentType => HttpResponse::UnsupportedMediaType().json(error_body(
+            "Request bodies must be application/json, application/msgpack or application/cbor",
+        )),
+        JsonPayloadError::OverflowKnownLength { .. } | JsonPayloadError::Overflow { .. } => {
+            HttpResponse::PayloadTooLarge().json(error_body(err.to_string()))
+        }
+        _ => HttpResponse::BadRequest().json(error_body(err.to_string())),
+    };
+    actix_web::error::InternalError::from_response(err, response).into()
+}
@@ -0,0 +1,167 @@
+//! Sparse fieldsets (`?fields=`) and embedded relations (`?include=`) for read endpoints.
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// Fields and relations a resource exposes to `?fields=` and `?include=`
+pub struct ProjectionSpec {
+    pub fields: &'static [&'static str],
+    pub includes: &'static [&'static str],
+}
+
+pub const PRODUCT_PROJECTION: ProjectionSpec = ProjectionSpec {
+    fields: &[
+        "id",
+        "sku",
+        "name",
+        "description",
+        "category",
+        "price",
+        "currency",
+        "created_at",
+        "updated_at",
+        "images",
+    ],
+    // The category is a plain field and products have no variants, so the price
+    // history is the only relation to embed
+    includes: &["price_history"],
+};
+
+pub const USER_PROJECTION: ProjectionSpec = ProjectionSpec {
+    fields: &["id", "email", "username", "created_at", "updated_at"],
+    includes: &[],
+};
+
+/// The fields and relations a client asked for
+#[derive(Debug, Default)]
+pub struct Projection {
+    /// `None` keeps every field
+    fields: Option<Vec<String>>,
+    include: Vec<String>,
+}
+
+/// Comma separated names, blanks dropped
+fn names(list: Option<&str>) -> Vec<String> {
+    list.unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn check(names: &[String], allowed: &[&str], kind: &str) -> Result<(), String> {
+    match names.iter().find(|name| !allowed.contains(&name.as_str())) {
+        Some(unknown) if allowed.is_empty() => Err(format!(
+            "Unknown {} '{}', none are available",
+            kind, unknown
+        )),
+        Some(unknown) => Err(format!(
+            "Unknown {} '{}', expected one of: {}",
+            kind,
+            unknown,
+            allowed.join(", ")
+        )),
+        None => Ok(()),
+    }
+}
+
+impl Projection {
+    /// Validates `?fields=` and `?include=` against the resource's whitelist
+    pub fn parse(
+        spec: &ProjectionSpec,
+        fields: Option<&str>,
+        include: Option<&str>,
+    ) -> Result<Self, String> {
+        let include = names(include);
+        check(&include, spec.includes, "include")?;
+
+        let fields = match fields {
+            Some(_) => {
+                let fields = names(fields);
+                check(&fields, spec.fields, "field")?;
+                if fields.is_empty() {
+                    return Err("At least one field must be selected".to_string());
+                }
+                Some(fields)
+            }
+            None => None,
+        };
+
+        Ok(Self { fields, include })
+    }
+
+    /// Whether `field` ends up in the response, so its data has to be loaded
+    pub fn wants(&self, field: &str) -> bool {
+        self.fields
+            .as_ref()
+            .is_none_or(|fields| fields.iter().any(|name| name == field))
+    }
+
+    pub fn includes(&self, relation: &str) -> bool {
+        self.include.iter().any(|name| name == relation)
+    }
+
+    /// Serializes `item`, keeping the selected fields and included relations
+    pub fn apply<T: Serialize>(&self, item: &T) -> Value {
+        let value = serde_json::to_value(item).unwrap_or(Value::Null);
+        match (value, &self.fields) {
+            (Value::Object(object), Some(_)) => Value::Object(
+                object
+                    .into_iter()
+                    .filter(|(key, _)| self.wants(key) || self.includes(key))
+                    .collect::<Map<_, _>>(),
+            ),
+            (value, _) => value,
+        }
+    }
+
+    /// A list response of projected items, shaped like the unprojected one
+    pub fn apply_list<T: Serialize>(&self, key: &str, items: &[T]) -> Value {
+        let items: Vec<Value> = items.iter().map(|item| self.apply(item)).collect();
+        let total = items.len();
+        let mut list = Map::new();
+        list.insert(key.to_string(), Value::Array(items));
+        list.insert("total".to_string(), Value::from(total));
+        Value::Object(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn rejects_unknown_includes() {
+        let error = Projection::parse(&PRODUCT_PROJECTION, None, Some("price_history,variants"))
+            .unwrap_err();
+        assert_eq!(
+            error,
+            "Unknown include 'variants', expected one of: price_history"
+        );
+
+        let error = Projection::parse(&USER_PROJECTION, None, Some("orders")).unwrap_err();
+        assert_eq!(error, "Unknown include 'orders', none are available");
+    }
+
+    #[test]
+    fn rejects_unknown_and_empty_fields() {
+        assert!(Projection::parse(&PRODUCT_PROJECTION, Some("id,stock"), None).is_err());
+        assert!(Projection::parse(&PRODUCT_PROJECTION, Some(" , "), None).is_err());
+    }
+
+    #[test]
+    fn keeps_selected_fields_and_included_relations() {
+        let projection =
+            Projection::parse(&PRODUCT_PROJECTION, Some("id, name"), Some("price_history"))
+                .unwrap();
+        let item = json!({ "id": 1, "name": "A", "price": 10, "price_history": [] });
+
+        assert_eq!(
+            projection.apply(&item),
+            json!({ "id": 1, "name": "A", "price_history": [] })
+        );
+        assert!(!projection.wants("price"));
+    }
+}
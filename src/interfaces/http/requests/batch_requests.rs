@@ -0,0 +1,31 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BatchRequest {
+    /// Run all operations in one transaction, rolled back when any of them fails.
+    /// Only product and user writes can take part, reads and images cannot.
+    #[serde(default)]
+    #[schema(example = true)]
+    pub atomic: bool,
+    /// Operations, executed in order
+    pub operations: Vec<BatchOperation>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BatchOperation {
+    /// HTTP method
+    #[schema(example = "PUT")]
+    pub method: String,
+    /// Path of an `/api/v1` endpoint, with its query string
+    #[schema(example = "/api/v1/products/123e4567-e89b-12d3-a456-426614174000")]
+    pub path: String,
+    /// JSON body of the operation
+    #[schema(value_type = Option<Object>, example = json!({"price": "899.99"}))]
+    pub body: Option<serde_json::Value>,
+    /// Extra request headers, such as `Accept-Currency`. Operations run as the caller of
+    /// the batch, an `Authorization` header here is ignored
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+}
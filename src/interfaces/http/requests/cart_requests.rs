@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct QuoteCartQuery {
+    /// Discount code to try on the cart
+    #[param(example = "SUMMER10")]
+    pub code: Option<String>,
+}
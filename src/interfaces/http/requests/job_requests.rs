@@ -0,0 +1,20 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+use crate::domain::entities::job::JobStatus;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct JobsQuery {
+    /// Only jobs in this status
+    #[param(value_type = Option<String>, example = "dead")]
+    pub status: Option<JobStatus>,
+    /// Only jobs on this queue
+    #[param(example = "media")]
+    pub queue: Option<String>,
+    /// Only jobs of this kind
+    #[param(example = "product_image.remove_files")]
+    pub kind: Option<String>,
+    /// Jobs to return, newest first (default 50, at most 200)
+    #[param(example = 50)]
+    pub limit: Option<i64>,
+}
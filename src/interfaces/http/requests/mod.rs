@@ -1,2 +1,7 @@
+pub mod batch_requests;
+pub mod cart_requests;
+pub mod job_requests;
 pub mod product_requests;
 pub mod user_requests;
+pub mod webhook_requests;
+pub mod websocket_requests;
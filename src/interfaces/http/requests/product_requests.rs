@@ -1,18 +1,36 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{
+    openapi::{
+        schema::{KnownFormat, ObjectBuilder, Schema, SchemaFormat, SchemaType},
+        RefOr,
+    },
+    IntoParams, ToSchema,
+};
+use uuid::Uuid;
+
+use crate::domain::entities::{product::CreateProductDto, product_bulk::BulkUpdateProductDto};
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CreateProductRequest {
+    /// Merchant stock keeping unit, unique when present
+    #[schema(example = "IPH-14-PRO-128")]
+    pub sku: Option<String>,
     /// The name of the product
     #[schema(example = "iPhone 14 Pro")]
     pub name: String,
     /// Detailed description of the product
     #[schema(example = "Latest iPhone model with dynamic island")]
     pub description: String,
+    /// Category the product is listed under
+    #[schema(example = "smartphones")]
+    pub category: Option<String>,
     /// The price of the product
     #[schema(example = "999.99")]
     pub price: Decimal, // Change from f64 to Decimal
+    /// ISO 4217 currency of the price, USD when absent
+    #[schema(example = "USD")]
+    pub currency: Option<String>,
     /// Initial stock quantity
     #[schema(example = "100")]
     pub stock: i32,
@@ -20,12 +38,18 @@ pub struct CreateProductRequest {
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UpdateProductRequest {
+    /// Updated stock keeping unit
+    #[schema(example = "IPH-14-PRO-MAX-256")]
+    pub sku: Option<String>,
     /// Updated name of the product
     #[schema(example = "iPhone 14 Pro Max")]
     pub name: Option<String>,
     /// Updated description of the product
     #[schema(example = "Updated description for iPhone")]
     pub description: Option<String>,
+    /// Updated category of the product
+    #[schema(example = "smartphones")]
+    pub category: Option<String>,
     /// Updated price of the product
     #[schema(example = "1099.99")]
     pub price: Option<Decimal>, // Change from Option<f64> to Option<Decimal>
@@ -33,3 +57,113 @@ pub struct UpdateProductRequest {
     #[schema(example = "50")]
     pub stock: Option<i32>,
 }
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct GetProductQuery {
+    /// Return the price that was valid at this moment (RFC 3339)
+    #[param(value_type = Option<String>, example = "2024-02-16T00:00:00Z")]
+    pub at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Convert the price into this ISO 4217 currency, overrides `Accept-Currency`
+    #[param(example = "EUR")]
+    pub currency: Option<String>,
+    /// Comma separated fields to return, all when absent
+    #[param(example = "id,name,price")]
+    pub fields: Option<String>,
+    /// Comma separated relations to embed, only `price_history`; others are rejected
+    #[param(example = "price_history")]
+    pub include: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ListProductsQuery {
+    /// Convert prices into this ISO 4217 currency, overrides `Accept-Currency`
+    #[param(example = "EUR")]
+    pub currency: Option<String>,
+    /// Comma separated fields to return for each product, all when absent
+    #[param(example = "id,name,price")]
+    pub fields: Option<String>,
+    /// Comma separated relations to embed in each product, only `price_history`;
+    /// others are rejected
+    #[param(example = "price_history")]
+    pub include: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct UploadImageQuery {
+    /// Make the uploaded image the primary image
+    #[param(example = true)]
+    pub primary: Option<bool>,
+}
+
+/// Multipart form accepted by the image upload endpoint. The upload is read as a
+/// multipart stream, so the form only exists as its schema
+pub struct UploadImageForm;
+
+impl<'s> ToSchema<'s> for UploadImageForm {
+    fn schema() -> (&'s str, RefOr<Schema>) {
+        let file = ObjectBuilder::new()
+            .schema_type(SchemaType::String)
+            .format(Some(SchemaFormat::KnownFormat(KnownFormat::Binary)))
+            .description(Some("JPEG, PNG, GIF or WebP file"));
+        let form = ObjectBuilder::new()
+            .description(Some("Multipart form accepted by the image upload endpoint"))
+            .property("file", file)
+            .required("file");
+
+        ("UploadImageForm", form.into())
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ImportProductsQuery {
+    /// Validate and report without saving anything
+    #[param(example = true)]
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ExportProductsQuery {
+    /// File format: csv, ndjson or xlsx. Overrides `Accept`, CSV when neither is given
+    #[param(example = "csv")]
+    pub format: Option<String>,
+    /// Convert prices into this ISO 4217 currency, overrides `Accept-Currency`
+    #[param(example = "EUR")]
+    pub currency: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ProductEventsQuery {
+    /// Resume after this event, for clients that cannot send `Last-Event-ID`
+    #[param(example = 42)]
+    pub last_event_id: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BulkCreateProductsRequest {
+    /// All-or-nothing: one failed item rolls back the others
+    #[serde(default)]
+    #[schema(example = false)]
+    pub atomic: bool,
+    /// Products to create
+    pub items: Vec<CreateProductDto>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BulkUpdateProductsRequest {
+    /// All-or-nothing: one failed item rolls back the others
+    #[serde(default)]
+    #[schema(example = false)]
+    pub atomic: bool,
+    /// Products to update, each with its id and the fields to change
+    pub items: Vec<BulkUpdateProductDto>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BulkDeleteProductsRequest {
+    /// All-or-nothing: one failed item rolls back the others
+    #[serde(default)]
+    #[schema(example = false)]
+    pub atomic: bool,
+    /// Products to delete
+    pub ids: Vec<Uuid>,
+}
@@ -1,5 +1,5 @@
 use serde::Deserialize;
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateUserRequest {
@@ -26,3 +26,17 @@ pub struct UpdateUserRequest {
     #[schema(example = "newpassword123")]
     pub password: Option<String>,
 }
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ExportUsersQuery {
+    /// File format: csv, ndjson or xlsx. Overrides `Accept`, CSV when neither is given
+    #[param(example = "csv")]
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct UserFieldsQuery {
+    /// Comma separated fields to return, all when absent
+    #[param(example = "id,username")]
+    pub fields: Option<String>,
+}
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct WebhookDeliveriesQuery {
+    /// Deliveries to return, newest first (default 50, at most 200)
+    #[param(example = 50)]
+    pub limit: Option<i64>,
+}
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct WebSocketQuery {
+    /// Bearer token, for clients that cannot set `Authorization` on the handshake
+    pub token: Option<String>,
+}
+
+/// A message from a WebSocket client
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    /// Start receiving updates of a topic, `product:{id}` or `orders`
+    Subscribe { topic: String },
+    /// Stop receiving updates of a topic
+    Unsubscribe { topic: String },
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BatchOperationResult {
+    /// Position of the operation in the request
+    #[schema(example = 0)]
+    pub index: usize,
+    /// HTTP status the operation answered with
+    #[schema(example = 200)]
+    pub status: u16,
+    /// Response body, JSON when the endpoint answered with JSON
+    #[schema(value_type = Object)]
+    pub body: serde_json::Value,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BatchResponse {
+    /// Whether the operations ran in one transaction
+    #[schema(example = true)]
+    pub atomic: bool,
+    /// Whether the changes were kept, always true for non-atomic batches
+    #[schema(example = true)]
+    pub committed: bool,
+    /// One result per operation, in request order
+    pub results: Vec<BatchOperationResult>,
+}
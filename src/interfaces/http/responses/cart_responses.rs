@@ -0,0 +1,162 @@
+use crate::domain::{
+    entities::cart::{PricedCart, PricedCartItem, StockWarning},
+    services::promotion_engine::{AppliedPromotion, CodeRejection, PromotionOutcome},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CartItemResponse {
+    /// Product's unique identifier
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub product_id: uuid::Uuid,
+    /// Product name
+    #[schema(example = "Awesome Product")]
+    pub product_name: String,
+    /// Quantity in the cart
+    #[schema(example = 2)]
+    pub quantity: i32,
+    /// Current unit price of the product
+    #[schema(example = "99.99")]
+    pub unit_price: rust_decimal::Decimal,
+    /// Previous unit price, present when the price changed since the item was added
+    #[schema(example = "89.99")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_unit_price: Option<rust_decimal::Decimal>,
+    /// Unit price multiplied by quantity
+    #[schema(example = "199.98")]
+    pub line_total: rust_decimal::Decimal,
+    /// Stock availability warning, if the requested quantity cannot be fulfilled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stock_warning: Option<StockWarning>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CartResponse {
+    /// Cart's unique identifier
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub id: uuid::Uuid,
+    /// Owner of the cart, absent for anonymous carts
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<uuid::Uuid>,
+    /// Token to access an anonymous cart, absent for user carts
+    #[schema(example = "5f0c6b1e-8f4a-4d6e-9a4b-2c1d3e4f5a6b")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cart_token: Option<uuid::Uuid>,
+    /// Items in the cart
+    pub items: Vec<CartItemResponse>,
+    /// Sum of all line totals
+    #[schema(example = "199.98")]
+    pub subtotal: rust_decimal::Decimal,
+    /// ISO 4217 currency of every amount in the cart, absent while it is empty
+    #[schema(example = "USD")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// Whether any item price changed since it was added
+    #[schema(example = false)]
+    pub prices_changed: bool,
+    /// Whether any item cannot be fulfilled from current stock
+    #[schema(example = false)]
+    pub has_stock_warnings: bool,
+    /// Cart last update timestamp
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AppliedPromotionResponse {
+    /// Promotion's unique identifier
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub promotion_id: uuid::Uuid,
+    /// Promotion name
+    #[schema(example = "Summer sale")]
+    pub name: String,
+    /// Discount code, absent for automatic promotions
+    #[schema(example = "SUMMER10")]
+    pub code: Option<String>,
+    /// Amount taken off
+    #[schema(example = "10.00")]
+    pub discount: rust_decimal::Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CartQuoteResponse {
+    /// The priced cart
+    pub cart: CartResponse,
+    /// Promotions applied to the cart
+    pub promotions: Vec<AppliedPromotionResponse>,
+    /// Total discount
+    #[schema(example = "10.00")]
+    pub discount: rust_decimal::Decimal,
+    /// Amount to pay after discounts
+    #[schema(example = "189.98")]
+    pub total: rust_decimal::Decimal,
+    /// Why the entered discount code was not applied
+    pub code_rejection: Option<CodeRejection>,
+}
+
+impl From<PricedCartItem> for CartItemResponse {
+    fn from(item: PricedCartItem) -> Self {
+        Self {
+            product_id: item.product_id,
+            product_name: item.product_name,
+            quantity: item.quantity,
+            unit_price: item.unit_price,
+            previous_unit_price: item.previous_unit_price,
+            line_total: item.line_total,
+            stock_warning: item.stock_warning,
+        }
+    }
+}
+
+impl From<PricedCart> for CartResponse {
+    fn from(priced: PricedCart) -> Self {
+        let cart = priced.cart;
+        Self {
+            id: cart.id,
+            user_id: cart.user_id,
+            cart_token: cart.user_id.is_none().then_some(cart.token),
+            prices_changed: priced
+                .items
+                .iter()
+                .any(|item| item.previous_unit_price.is_some()),
+            has_stock_warnings: priced.items.iter().any(|item| item.stock_warning.is_some()),
+            items: priced
+                .items
+                .into_iter()
+                .map(CartItemResponse::from)
+                .collect(),
+            subtotal: priced.subtotal,
+            currency: priced.currency,
+            updated_at: cart.updated_at,
+        }
+    }
+}
+
+impl From<AppliedPromotion> for AppliedPromotionResponse {
+    fn from(promotion: AppliedPromotion) -> Self {
+        Self {
+            promotion_id: promotion.promotion_id,
+            name: promotion.name,
+            code: promotion.code,
+            discount: promotion.discount,
+        }
+    }
+}
+
+impl From<(PricedCart, PromotionOutcome)> for CartQuoteResponse {
+    fn from((priced, outcome): (PricedCart, PromotionOutcome)) -> Self {
+        Self {
+            cart: CartResponse::from(priced),
+            promotions: outcome
+                .applied
+                .into_iter()
+                .map(AppliedPromotionResponse::from)
+                .collect(),
+            discount: outcome.discount,
+            total: outcome.total,
+            code_rejection: outcome.code_rejection,
+        }
+    }
+}
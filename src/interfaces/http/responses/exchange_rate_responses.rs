@@ -0,0 +1,48 @@
+use crate::domain::entities::exchange_rate::ExchangeRate;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ExchangeRateResponse {
+    /// Currency converted from
+    #[schema(example = "USD")]
+    pub base_currency: String,
+    /// Currency converted to
+    #[schema(example = "EUR")]
+    pub quote_currency: String,
+    /// Units of the quote currency per unit of the base currency
+    #[schema(example = "0.92")]
+    pub rate: rust_decimal::Decimal,
+    /// When the rate was last set
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ExchangeRatesListResponse {
+    /// List of exchange rates
+    pub rates: Vec<ExchangeRateResponse>,
+    /// Total number of exchange rates
+    #[schema(example = 4)]
+    pub total: i64,
+}
+
+impl From<ExchangeRate> for ExchangeRateResponse {
+    fn from(rate: ExchangeRate) -> Self {
+        Self {
+            base_currency: rate.base_currency,
+            quote_currency: rate.quote_currency,
+            rate: rate.rate,
+            updated_at: rate.updated_at,
+        }
+    }
+}
+
+impl From<Vec<ExchangeRate>> for ExchangeRatesListResponse {
+    fn from(rates: Vec<ExchangeRate>) -> Self {
+        Self {
+            total: rates.len() as i64,
+            rates: rates.into_iter().map(ExchangeRateResponse::from).collect(),
+        }
+    }
+}
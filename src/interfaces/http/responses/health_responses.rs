@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::entities::health::HealthStatus;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct LivenessResponse {
+    /// Always up while the process answers
+    pub status: HealthStatus,
+    /// Set once the server started shutting down
+    pub shutting_down: bool,
+    #[schema(example = 3600)]
+    pub uptime_seconds: u64,
+}
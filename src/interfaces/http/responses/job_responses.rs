@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::entities::job::Job;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct JobsListResponse {
+    /// Jobs, newest first
+    pub jobs: Vec<Job>,
+    pub total: i64,
+}
+
+impl From<Vec<Job>> for JobsListResponse {
+    fn from(jobs: Vec<Job>) -> Self {
+        Self {
+            total: jobs.len() as i64,
+            jobs,
+        }
+    }
+}
@@ -1,3 +1,14 @@
+pub mod batch_responses;
+pub mod cart_responses;
 pub mod error_responses;
+pub mod exchange_rate_responses;
+pub mod health_responses;
+pub mod job_responses;
+pub mod order_responses;
+pub mod payment_responses;
 pub mod product_responses;
+pub mod promotion_responses;
+pub mod scheduler_responses;
 pub mod user_responses;
+pub mod webhook_responses;
+pub mod websocket_responses;
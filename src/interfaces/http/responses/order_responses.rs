@@ -0,0 +1,76 @@
+use crate::domain::entities::order::{OrderItem, OrderStatus, OrderWithItems};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OrderItemResponse {
+    /// Product's unique identifier
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub product_id: uuid::Uuid,
+    /// Product name at the time of ordering
+    #[schema(example = "Awesome Product")]
+    pub product_name: String,
+    /// Ordered quantity
+    #[schema(example = 2)]
+    pub quantity: i32,
+    /// Unit price at the time of ordering
+    #[schema(example = "99.99")]
+    pub unit_price: rust_decimal::Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OrderResponse {
+    /// Order's unique identifier
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub id: uuid::Uuid,
+    /// User who placed the order
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub user_id: uuid::Uuid,
+    /// Current order status
+    pub status: OrderStatus,
+    /// Ordered items
+    pub items: Vec<OrderItemResponse>,
+    /// Discount granted by promotions
+    #[schema(example = "10.00")]
+    pub discount: rust_decimal::Decimal,
+    /// Total amount to be paid
+    #[schema(example = "199.98")]
+    pub total: rust_decimal::Decimal,
+    /// ISO 4217 currency of every amount in the order
+    #[schema(example = "USD")]
+    pub currency: String,
+    /// Order creation timestamp
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Order last update timestamp
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<OrderItem> for OrderItemResponse {
+    fn from(item: OrderItem) -> Self {
+        Self {
+            product_id: item.product_id,
+            product_name: item.product_name,
+            quantity: item.quantity,
+            unit_price: item.unit_price,
+        }
+    }
+}
+
+impl From<OrderWithItems> for OrderResponse {
+    fn from(order: OrderWithItems) -> Self {
+        let OrderWithItems { order, items } = order;
+        Self {
+            id: order.id,
+            user_id: order.user_id,
+            status: order.status,
+            items: items.into_iter().map(OrderItemResponse::from).collect(),
+            discount: order.discount,
+            total: order.total,
+            currency: order.currency,
+            created_at: order.created_at,
+            updated_at: order.updated_at,
+        }
+    }
+}
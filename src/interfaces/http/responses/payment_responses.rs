@@ -0,0 +1,49 @@
+use crate::domain::entities::payment::{Payment, PaymentStatus};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PaymentResponse {
+    /// Payment's unique identifier
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub id: uuid::Uuid,
+    /// Order being paid
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub order_id: uuid::Uuid,
+    /// Payment provider
+    #[schema(example = "fake")]
+    pub provider: String,
+    /// Provider payment intent identifier
+    #[schema(example = "fake_pi_123e4567e89b12d3a456426614174000")]
+    pub intent_id: String,
+    /// Amount to be charged
+    #[schema(example = "199.98")]
+    pub amount: rust_decimal::Decimal,
+    /// ISO 4217 currency of the amount
+    #[schema(example = "USD")]
+    pub currency: String,
+    /// Current payment status
+    pub status: PaymentStatus,
+    /// Payment creation timestamp
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Payment last update timestamp
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<Payment> for PaymentResponse {
+    fn from(payment: Payment) -> Self {
+        Self {
+            id: payment.id,
+            order_id: payment.order_id,
+            provider: payment.provider,
+            intent_id: payment.intent_id,
+            amount: payment.amount,
+            currency: payment.currency,
+            status: payment.status,
+            created_at: payment.created_at,
+            updated_at: payment.updated_at,
+        }
+    }
+}
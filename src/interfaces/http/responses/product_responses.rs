@@ -1,4 +1,12 @@
-use crate::domain::entities::product::Product;
+use crate::domain::{
+    entities::{
+        product::Product,
+        product_image::{ProductImage, THUMBNAIL_SIZES},
+        product_price::ProductPrice,
+    },
+    gateways::BlobStorage,
+};
+use crate::interfaces::http::export::{ExportRecord, ExportValue};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -7,21 +15,73 @@ pub struct ProductResponse {
     /// Product's unique identifier
     #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
     pub id: uuid::Uuid,
+    /// Merchant stock keeping unit
+    #[schema(example = "IPH-14-PRO-128")]
+    pub sku: Option<String>,
     /// Product name
     #[schema(example = "Awesome Product")]
     pub name: String,
     /// Product description
     #[schema(example = "This is an awesome product")]
     pub description: String,
+    /// Product category
+    #[schema(example = "gadgets")]
+    pub category: Option<String>,
     /// Product price
     #[schema(example = "99.99")]
     pub price: rust_decimal::Decimal,
+    /// ISO 4217 currency of the price
+    #[schema(example = "USD")]
+    pub currency: String,
     /// Product creation timestamp
     #[schema(example = "2024-02-16T00:00:00Z")]
     pub created_at: chrono::DateTime<chrono::Utc>,
     /// Product last update timestamp
     #[schema(example = "2024-02-16T00:00:00Z")]
     pub updated_at: chrono::DateTime<chrono::Utc>,
+    /// Product images in display order
+    pub images: Vec<ProductImageResponse>,
+    /// Price history, present with `?include=price_history`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price_history: Option<Vec<ProductPriceResponse>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProductImageResponse {
+    /// Image's unique identifier
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub id: uuid::Uuid,
+    /// Display order, starting at 0
+    #[schema(example = 0)]
+    pub position: i32,
+    /// Whether this is the product's main image
+    #[schema(example = true)]
+    pub is_primary: bool,
+    /// MIME type of the original
+    #[schema(example = "image/jpeg")]
+    pub content_type: String,
+    /// Width of the original in pixels
+    #[schema(example = 1200)]
+    pub width: i32,
+    /// Height of the original in pixels
+    #[schema(example = 800)]
+    pub height: i32,
+    /// URL of the original
+    #[schema(
+        example = "/media/products/123e4567-e89b-12d3-a456-426614174000/9b2f6a1e-0c1d-4f7a-8e3b-5d6c7a8b9c0d/original.jpg"
+    )]
+    pub url: String,
+    /// Thumbnail URLs keyed by size (small, medium, large)
+    pub thumbnails: std::collections::BTreeMap<String, String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProductImagesListResponse {
+    /// Images in display order
+    pub images: Vec<ProductImageResponse>,
+    /// Total number of images
+    #[schema(example = 3)]
+    pub total: i64,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -33,15 +93,94 @@ pub struct ProductsListResponse {
     pub total: i64,
 }
 
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProductPriceResponse {
+    /// Price during this period
+    #[schema(example = "99.99")]
+    pub price: rust_decimal::Decimal,
+    /// Start of the period (inclusive)
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub effective_from: chrono::DateTime<chrono::Utc>,
+    /// End of the period (exclusive), absent while open-ended
+    #[schema(example = "2024-03-01T00:00:00Z")]
+    pub effective_to: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PriceHistoryResponse {
+    /// Product's unique identifier
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub product_id: uuid::Uuid,
+    /// Past, current and scheduled prices in chronological order
+    pub prices: Vec<ProductPriceResponse>,
+}
+
 impl From<Product> for ProductResponse {
     fn from(product: Product) -> Self {
         Self {
             id: product.id,
+            sku: product.sku,
             name: product.name,
             description: product.description,
+            category: product.category,
             price: product.price,
+            currency: product.currency,
             created_at: product.created_at,
             updated_at: product.updated_at,
+            images: Vec::new(),
+            price_history: None,
+        }
+    }
+}
+
+impl ProductResponse {
+    /// Product with its images, whose URLs are resolved against `storage`
+    pub fn with_images(
+        product: Product,
+        images: Vec<ProductImage>,
+        storage: &dyn BlobStorage,
+    ) -> Self {
+        Self {
+            images: images
+                .into_iter()
+                .map(|image| ProductImageResponse::new(image, storage))
+                .collect(),
+            ..Self::from(product)
+        }
+    }
+}
+
+impl ProductImageResponse {
+    pub fn new(image: ProductImage, storage: &dyn BlobStorage) -> Self {
+        Self {
+            url: storage.url(&image.storage_key(None)),
+            thumbnails: THUMBNAIL_SIZES
+                .iter()
+                .map(|(name, _)| {
+                    (
+                        name.to_string(),
+                        storage.url(&image.storage_key(Some(name))),
+                    )
+                })
+                .collect(),
+            id: image.id,
+            position: image.position,
+            is_primary: image.is_primary,
+            content_type: image.content_type,
+            width: image.width,
+            height: image.height,
+        }
+    }
+}
+
+impl ProductImagesListResponse {
+    pub fn new(images: Vec<ProductImage>, storage: &dyn BlobStorage) -> Self {
+        Self {
+            total: images.len() as i64,
+            images: images
+                .into_iter()
+                .map(|image| ProductImageResponse::new(image, storage))
+                .collect(),
         }
     }
 }
@@ -54,3 +193,95 @@ impl From<Vec<Product>> for ProductsListResponse {
         }
     }
 }
+
+impl From<Vec<ProductResponse>> for ProductsListResponse {
+    fn from(products: Vec<ProductResponse>) -> Self {
+        Self {
+            total: products.len() as i64,
+            products,
+        }
+    }
+}
+
+impl From<ProductPrice> for ProductPriceResponse {
+    fn from(price: ProductPrice) -> Self {
+        Self {
+            price: price.price,
+            effective_from: price.effective_from,
+            effective_to: price.effective_to,
+        }
+    }
+}
+
+impl PriceHistoryResponse {
+    pub fn new(product_id: uuid::Uuid, prices: Vec<ProductPrice>) -> Self {
+        Self {
+            product_id,
+            prices: prices.into_iter().map(ProductPriceResponse::from).collect(),
+        }
+    }
+}
+
+/// Flat product row used by exports
+#[derive(Debug, Serialize)]
+pub struct ProductExportRow {
+    pub id: uuid::Uuid,
+    pub sku: Option<String>,
+    pub name: String,
+    pub description: String,
+    pub category: Option<String>,
+    pub price: rust_decimal::Decimal,
+    pub currency: String,
+    pub stock: i32,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<Product> for ProductExportRow {
+    fn from(product: Product) -> Self {
+        Self {
+            id: product.id,
+            sku: product.sku,
+            name: product.name,
+            description: product.description,
+            category: product.category,
+            price: product.price,
+            currency: product.currency,
+            stock: product.stock,
+            created_at: product.created_at,
+            updated_at: product.updated_at,
+        }
+    }
+}
+
+impl ExportRecord for ProductExportRow {
+    fn columns() -> &'static [&'static str] {
+        &[
+            "id",
+            "sku",
+            "name",
+            "description",
+            "category",
+            "price",
+            "currency",
+            "stock",
+            "created_at",
+            "updated_at",
+        ]
+    }
+
+    fn values(&self) -> Vec<ExportValue> {
+        vec![
+            ExportValue::Text(self.id.to_string()),
+            self.sku.clone().into(),
+            ExportValue::Text(self.name.clone()),
+            ExportValue::Text(self.description.clone()),
+            self.category.clone().into(),
+            ExportValue::Number(self.price.to_string()),
+            ExportValue::Text(self.currency.clone()),
+            ExportValue::Number(self.stock.to_string()),
+            ExportValue::Text(self.created_at.to_rfc3339()),
+            ExportValue::Text(self.updated_at.to_rfc3339()),
+        ]
+    }
+}
@@ -0,0 +1,101 @@
+use crate::domain::entities::promotion::{Promotion, PromotionKind};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PromotionResponse {
+    /// Promotion's unique identifier
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub id: uuid::Uuid,
+    /// Name shown to customers
+    #[schema(example = "Summer sale")]
+    pub name: String,
+    /// Discount code, absent for automatic promotions
+    #[schema(example = "SUMMER10")]
+    pub code: Option<String>,
+    /// How the discount is calculated
+    pub kind: PromotionKind,
+    /// Percentage or amount, depending on the kind
+    #[schema(example = "10")]
+    pub value: rust_decimal::Decimal,
+    /// Units to buy before the discounted units apply
+    #[schema(example = 2)]
+    pub buy_quantity: Option<i32>,
+    /// Units discounted per `buy_quantity` units bought
+    #[schema(example = 1)]
+    pub get_quantity: Option<i32>,
+    /// Cart subtotal required before the promotion applies
+    #[schema(example = "50.00")]
+    pub min_order_value: Option<rust_decimal::Decimal>,
+    /// ISO 4217 currency of the fixed amount and minimum order value
+    #[schema(example = "USD")]
+    pub currency: Option<String>,
+    /// Products the promotion applies to
+    pub product_ids: Vec<uuid::Uuid>,
+    /// Product categories the promotion applies to
+    #[schema(example = json!(["smartphones"]))]
+    pub categories: Vec<String>,
+    /// Times a single user may redeem the promotion
+    #[schema(example = 1)]
+    pub usage_limit_per_user: Option<i32>,
+    /// Start of the validity window
+    #[schema(example = "2024-06-01T00:00:00Z")]
+    pub starts_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// End of the validity window
+    #[schema(example = "2024-09-01T00:00:00Z")]
+    pub ends_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Whether the promotion can be applied
+    #[schema(example = true)]
+    pub active: bool,
+    /// Promotion creation timestamp
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Promotion last update timestamp
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PromotionsListResponse {
+    /// List of promotions
+    pub promotions: Vec<PromotionResponse>,
+    /// Total number of promotions
+    #[schema(example = 3)]
+    pub total: i64,
+}
+
+impl From<Promotion> for PromotionResponse {
+    fn from(promotion: Promotion) -> Self {
+        Self {
+            id: promotion.id,
+            name: promotion.name,
+            code: promotion.code,
+            kind: promotion.kind,
+            value: promotion.value,
+            buy_quantity: promotion.buy_quantity,
+            get_quantity: promotion.get_quantity,
+            min_order_value: promotion.min_order_value,
+            currency: promotion.currency,
+            product_ids: promotion.product_ids,
+            categories: promotion.categories,
+            usage_limit_per_user: promotion.usage_limit_per_user,
+            starts_at: promotion.starts_at,
+            ends_at: promotion.ends_at,
+            active: promotion.active,
+            created_at: promotion.created_at,
+            updated_at: promotion.updated_at,
+        }
+    }
+}
+
+impl From<Vec<Promotion>> for PromotionsListResponse {
+    fn from(promotions: Vec<Promotion>) -> Self {
+        Self {
+            total: promotions.len() as i64,
+            promotions: promotions
+                .into_iter()
+                .map(PromotionResponse::from)
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::entities::scheduled_task::ScheduledTask;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ScheduledTasksListResponse {
+    /// Tasks by name
+    pub tasks: Vec<ScheduledTask>,
+    pub total: i64,
+}
+
+impl From<Vec<ScheduledTask>> for ScheduledTasksListResponse {
+    fn from(tasks: Vec<ScheduledTask>) -> Self {
+        Self {
+            total: tasks.len() as i64,
+            tasks,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::domain::entities::user::User;
+use crate::interfaces::http::export::{ExportRecord, ExportValue};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -50,3 +51,19 @@ impl From<Vec<User>> for UsersListResponse {
         }
     }
 }
+
+impl ExportRecord for UserResponse {
+    fn columns() -> &'static [&'static str] {
+        &["id", "email", "username", "created_at", "updated_at"]
+    }
+
+    fn values(&self) -> Vec<ExportValue> {
+        vec![
+            ExportValue::Text(self.id.to_string()),
+            ExportValue::Text(self.email.clone()),
+            ExportValue::Text(self.username.clone()),
+            ExportValue::Text(self.created_at.to_rfc3339()),
+            ExportValue::Text(self.updated_at.to_rfc3339()),
+        ]
+    }
+}
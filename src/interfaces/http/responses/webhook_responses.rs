@@ -0,0 +1,89 @@
+use crate::domain::entities::webhook::{WebhookDelivery, WebhookSubscription};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookSubscriptionResponse {
+    /// Subscription's unique identifier
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    pub id: uuid::Uuid,
+    /// Endpoint the events are posted to
+    #[schema(example = "https://partner.example.com/hooks/catalog")]
+    pub url: String,
+    /// Events delivered to the endpoint
+    #[schema(example = json!(["product.created", "product.updated"]))]
+    pub event_types: Vec<String>,
+    /// Signing key, only returned when the subscription is created
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "whsec_2f1c9a7e4b")]
+    pub secret: Option<String>,
+    /// Whether events are delivered
+    #[schema(example = true)]
+    pub active: bool,
+    /// Failed attempts since the last successful delivery
+    #[schema(example = 0)]
+    pub consecutive_failures: i32,
+    /// When the subscription was disabled for failing
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub disabled_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Subscription creation timestamp
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Subscription last update timestamp
+    #[schema(example = "2024-02-16T00:00:00Z")]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookSubscriptionsListResponse {
+    /// List of subscriptions
+    pub subscriptions: Vec<WebhookSubscriptionResponse>,
+    /// Total number of subscriptions
+    #[schema(example = 2)]
+    pub total: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDeliveriesListResponse {
+    /// Deliveries, newest first
+    pub deliveries: Vec<WebhookDelivery>,
+}
+
+impl WebhookSubscriptionResponse {
+    /// The response to creating a subscription, the only one showing its secret
+    pub fn with_secret(subscription: WebhookSubscription) -> Self {
+        let secret = subscription.secret.clone();
+        Self {
+            secret: Some(secret),
+            ..Self::from(subscription)
+        }
+    }
+}
+
+impl From<WebhookSubscription> for WebhookSubscriptionResponse {
+    fn from(subscription: WebhookSubscription) -> Self {
+        Self {
+            id: subscription.id,
+            url: subscription.url,
+            event_types: subscription.event_types,
+            secret: None,
+            active: subscription.active,
+            consecutive_failures: subscription.consecutive_failures,
+            disabled_at: subscription.disabled_at,
+            created_at: subscription.created_at,
+            updated_at: subscription.updated_at,
+        }
+    }
+}
+
+impl From<Vec<WebhookSubscription>> for WebhookSubscriptionsListResponse {
+    fn from(subscriptions: Vec<WebhookSubscription>) -> Self {
+        Self {
+            total: subscriptions.len() as i64,
+            subscriptions: subscriptions
+                .into_iter()
+                .map(WebhookSubscriptionResponse::from)
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use serde::Serialize;
+
+use crate::domain::entities::live_update::{LiveUpdate, Topic};
+
+/// A message to a WebSocket client
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage<'a> {
+    Subscribed {
+        topic: String,
+    },
+    Unsubscribed {
+        topic: String,
+    },
+    /// A change on a subscribed topic
+    Update {
+        topic: String,
+        update: &'a LiveUpdate,
+    },
+    /// A rejected message, or updates the connection missed
+    Error {
+        error: String,
+    },
+}
+
+impl<'a> ServerMessage<'a> {
+    pub fn update(topic: Topic, update: &'a LiveUpdate) -> Self {
+        Self::Update {
+            topic: topic.to_string(),
+            update,
+        }
+    }
+
+    pub fn error(error: impl Into<String>) -> Self {
+        Self::Error {
+            error: error.into(),
+        }
+    }
+}
@@ -1,11 +1,119 @@
+use crate::config::AuthConfig;
 use actix_web::{
-    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
-    Error, HttpMessage,
+    dev::{forward_ready, Payload, Service, ServiceRequest, ServiceResponse, Transform},
+    error::InternalError,
+    http::header,
+    Error, FromRequest, HttpMessage, HttpRequest, HttpResponse,
 };
 use futures::future::LocalBoxFuture;
+use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
+use serde::Deserialize;
+use serde_json::json;
 use std::future::{ready, Ready};
+use uuid::Uuid;
 
-pub struct Auth;
+/// The authenticated caller, present in the request extensions when a valid
+/// bearer token was sent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Principal {
+    pub user_id: Uuid,
+    pub is_admin: bool,
+}
+
+impl Principal {
+    /// Whether the caller may see or change the given user
+    pub fn can_access_user(&self, user_id: Uuid) -> bool {
+        self.is_admin || self.user_id == user_id
+    }
+}
+
+/// Guards an administrator-only endpoint, answering 401 without a token and 403 for
+/// other users. Handlers that need the caller take the `Principal` from the request
+#[derive(Debug, Clone, Copy)]
+pub struct AdminPrincipal;
+
+impl FromRequest for AdminPrincipal {
+    type Error = Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let result = match req.extensions().get::<Principal>() {
+            Some(principal) if principal.is_admin => Ok(Self),
+            Some(_) => Err(InternalError::from_response(
+                "administrator required",
+                HttpResponse::Forbidden().json(json!({"error": "Administrator access required"})),
+            )
+            .into()),
+            None => Err(InternalError::from_response(
+                "authentication required",
+                HttpResponse::Unauthorized().json(json!({"error": "Authentication required"})),
+            )
+            .into()),
+        };
+
+        ready(result)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Claims {
+    sub: Uuid,
+    #[serde(default)]
+    role: Option<String>,
+}
+
+/// Validates HS256 bearer tokens issued by the identity provider
+#[derive(Clone)]
+pub struct TokenValidator {
+    key: Option<DecodingKey>,
+    validation: Validation,
+}
+
+impl TokenValidator {
+    pub fn new(config: &AuthConfig) -> Self {
+        Self {
+            key: config
+                .jwt_secret
+                .as_deref()
+                .map(|secret| DecodingKey::from_secret(secret.as_bytes())),
+            validation: Validation::new(Algorithm::HS256),
+        }
+    }
+
+    /// The principal a token stands for, `None` if it is invalid, expired or no
+    /// secret is configured
+    pub fn validate(&self, token: &str) -> Option<Principal> {
+        let key = self.key.as_ref()?;
+        let claims = decode::<Claims>(token, key, &self.validation).ok()?.claims;
+
+        Some(Principal {
+            user_id: claims.sub,
+            is_admin: claims.role.as_deref() == Some("admin"),
+        })
+    }
+
+    /// Token of an `Authorization: Bearer` header value
+    pub fn bearer(value: &str) -> Option<&str> {
+        value
+            .strip_prefix("Bearer ")
+            .map(str::trim)
+            .filter(|token| !token.is_empty())
+    }
+}
+
+/// Resolves the caller of a request. Requests without a token stay anonymous and
+/// the endpoints decide what they allow, an invalid token is rejected with 401
+pub struct Auth {
+    validator: TokenValidator,
+}
+
+impl Auth {
+    pub fn new(config: &AuthConfig) -> Self {
+        Self {
+            validator: TokenValidator::new(config),
+        }
+    }
+}
 
 impl<S, B> Transform<S, ServiceRequest> for Auth
 where
@@ -20,12 +128,16 @@ where
     type Future = Ready<Result<Self::Transform, Self::InitError>>;
 
     fn new_transform(&self, service: S) -> Self::Future {
-        ready(Ok(AuthMiddleware { service }))
+        ready(Ok(AuthMiddleware {
+            service,
+            validator: self.validator.clone(),
+        }))
     }
 }
 
 pub struct AuthMiddleware<S> {
     service: S,
+    validator: TokenValidator,
 }
 
 impl<S, B> Service<ServiceRequest> for AuthMiddleware<S>
@@ -41,7 +153,28 @@ where
     forward_ready!(service);
 
     fn call(&self, req: ServiceRequest) -> Self::Future {
-        // Authentication logic here
+        let token = req
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(TokenValidator::bearer)
+            .map(str::to_string);
+
+        if let Some(token) = token {
+            match self.validator.validate(&token) {
+                Some(principal) => {
+                    req.extensions_mut().insert(principal);
+                }
+                None => {
+                    let response = HttpResponse::Unauthorized()
+                        .json(json!({"error": "Invalid or expired bearer token"}));
+                    return Box::pin(async move {
+                        Err(InternalError::from_response("invalid bearer token", response).into())
+                    });
+                }
+            }
+        }
+
         Box::pin(self.service.call(req))
     }
 }
@@ -1,21 +1,71 @@
 use actix_web::{
+    body::{self, BoxBody, EitherBody, MessageBody},
     dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
-    Error,
+    error::InternalError,
+    http::header::{self, HeaderMap, HeaderName, HeaderValue},
+    Error, HttpMessage, HttpResponse,
 };
 use futures::future::LocalBoxFuture;
-use log::info;
+use opentelemetry::{global, propagation::Extractor};
+use serde_json::Value;
+use std::fmt;
 use std::future::{ready, Ready};
 use std::time::Instant;
+use tracing::{field::Empty, info, info_span, Span};
+use tracing_actix_web::{DefaultRootSpanBuilder, RootSpanBuilder};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use uuid::Uuid;
 
+/// Correlates the log lines and the response of one request, taken from the
+/// caller's `X-Request-Id` when it is well-formed and generated otherwise
+#[derive(Debug, Clone)]
+pub struct RequestId(String);
+
+impl RequestId {
+    pub const HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+    fn from_headers(headers: &HeaderMap) -> Self {
+        headers
+            .get(&Self::HEADER)
+            .and_then(|value| value.to_str().ok())
+            .filter(|id| {
+                (1..=128).contains(&id.len())
+                    && id
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+            })
+            .map(|id| Self(id.to_string()))
+            .unwrap_or_else(|| Self(Uuid::new_v4().to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        // Incoming ids were checked to be header-safe, generated ones are UUIDs
+        HeaderValue::from_str(&self.0).expect("Request ids are valid header values")
+    }
+}
+
+impl fmt::Display for RequestId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Logs one line per request and returns its [`RequestId`] in the `X-Request-Id`
+/// header and in JSON error bodies. Goes inside `TracingLogger` with [`RequestSpan`],
+/// which assigns the id, so the line is logged within the request's span.
 pub struct RequestLogger;
 
 impl<S, B> Transform<S, ServiceRequest> for RequestLogger
 where
     S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
     S::Future: 'static,
-    B: 'static,
+    B: MessageBody + 'static,
 {
-    type Response = ServiceResponse<B>;
+    type Response = ServiceResponse<EitherBody<B>>;
     type Error = Error;
     type InitError = ();
     type Transform = RequestLoggerMiddleware<S>;
@@ -34,9 +84,9 @@ impl<S, B> Service<ServiceRequest> for RequestLoggerMiddleware<S>
 where
     S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
     S::Future: 'static,
-    B: 'static,
+    B: MessageBody + 'static,
 {
-    type Response = ServiceResponse<B>;
+    type Response = ServiceResponse<EitherBody<B>>;
     type Error = Error;
     type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
 
@@ -46,22 +96,137 @@ where
         let start = Instant::now();
         let method = req.method().clone();
         let path = req.path().to_owned();
+        let request_id = req
+            .extensions()
+            .get::<RequestId>()
+            .cloned()
+            .unwrap_or_else(|| RequestId::from_headers(req.headers()));
 
         let fut = self.service.call(req);
 
         Box::pin(async move {
-            let res = fut.await?;
-            let duration = start.elapsed();
+            let result: Result<Self::Response, Error> = match fut.await {
+                Ok(res) if res.status().is_client_error() || res.status().is_server_error() => {
+                    let (req, res) = res.into_parts();
+                    let res = with_request_id(res.map_into_boxed_body(), &request_id).await;
+                    Ok(ServiceResponse::new(req, res).map_into_right_body())
+                }
+                Ok(mut res) => {
+                    res.headers_mut()
+                        .insert(RequestId::HEADER, request_id.header_value());
+                    Ok(res.map_into_left_body())
+                }
+                // Failures are rendered here rather than by actix so they carry the id as well
+                Err(e) => {
+                    let res = with_request_id(e.error_response(), &request_id).await;
+                    Err(InternalError::from_response(e, res).into())
+                }
+            };
 
+            let status = match &result {
+                Ok(res) => res.status(),
+                Err(e) => e.as_response_error().status_code(),
+            }
+            .as_u16();
+            let elapsed_ms = start.elapsed().as_millis() as u64;
             info!(
-                "{} {} {} {}ms",
-                method,
-                path,
-                res.status(),
-                duration.as_millis()
+                http.status_code = status,
+                elapsed_ms, "{} {} {} {}ms", method, path, status, elapsed_ms
             );
 
-            Ok(res)
+            result
         })
     }
 }
+
+/// Sets `X-Request-Id` on an error response and adds `request_id` to its body
+/// when that is a JSON object
+async fn with_request_id(
+    mut res: HttpResponse<BoxBody>,
+    request_id: &RequestId,
+) -> HttpResponse<BoxBody> {
+    res.headers_mut()
+        .insert(RequestId::HEADER, request_id.header_value());
+    let is_json = res
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"));
+    if !is_json {
+        return res;
+    }
+
+    let (res, body) = res.into_parts();
+    let body = match body::to_bytes(body).await {
+        Ok(bytes) => match serde_json::from_slice::<Value>(&bytes) {
+            Ok(Value::Object(mut error)) => {
+                error.insert("request_id".into(), request_id.as_str().into());
+                BoxBody::new(Value::Object(error).to_string())
+            }
+            _ => BoxBody::new(bytes),
+        },
+        Err(_) => BoxBody::new(()),
+    };
+    res.set_body(body)
+}
+
+/// Root span of a request for `TracingLogger`, like its default one but assigning
+/// our [`RequestId`] rather than an id of its own
+pub struct RequestSpan;
+
+impl RootSpanBuilder for RequestSpan {
+    fn on_request_start(request: &ServiceRequest) -> Span {
+        let request_id = RequestId::from_headers(request.headers());
+        request.extensions_mut().insert(request_id.clone());
+        let route = request
+            .match_pattern()
+            .unwrap_or_else(|| "unmatched".to_string());
+        let method = request.method();
+        let connection = request.connection_info();
+        let user_agent = request
+            .headers()
+            .get(header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or("");
+
+        let span = info_span!(
+            "HTTP request",
+            otel.name = %format!("{} {}", method, route),
+            otel.kind = "server",
+            otel.status_code = Empty,
+            http.method = %method,
+            http.route = %route,
+            http.scheme = %connection.scheme(),
+            http.host = %connection.host(),
+            http.client_ip = %connection.realip_remote_addr().unwrap_or(""),
+            http.user_agent = %user_agent,
+            // Without the query string, it may carry tokens
+            http.target = %request.uri().path(),
+            http.status_code = Empty,
+            exception.message = Empty,
+            exception.details = Empty,
+            request_id = %request_id,
+        );
+        span.set_parent(global::get_text_map_propagator(|propagator| {
+            propagator.extract(&HeaderExtractor(request.headers()))
+        }));
+        span
+    }
+
+    fn on_request_end<B: MessageBody>(span: Span, outcome: &Result<ServiceResponse<B>, Error>) {
+        DefaultRootSpanBuilder::on_request_end(span, outcome);
+    }
+}
+
+/// Reads W3C `traceparent` from actix headers
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(HeaderName::as_str).collect()
+    }
+}
@@ -1,3 +1,4 @@
 pub mod auth;
 pub mod error;
 pub mod logging;
+pub mod metrics;
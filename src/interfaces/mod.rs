@@ -1,3 +1,5 @@
 pub mod api;
+pub mod graphql;
+pub mod grpc;
 pub mod http;
 pub mod middleware;
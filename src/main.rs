@@ -5,35 +5,207 @@ mod infrastructure;
 mod interfaces;
 
 use actix_web::{web, App, HttpServer};
-use log::info;
+use log::{error, info, warn};
 use sqlx::PgPool;
+use std::net::SocketAddr;
+use tracing_actix_web::TracingLogger;
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
+use crate::config::media::MediaBackend;
 use crate::config::AppConfig;
+use crate::infrastructure::events::{BroadcastEventBus, BroadcastUpdateBus, ProductEventFeed};
+use crate::infrastructure::health::{
+    DatabaseHealthCheck, HealthRegistry, SchemaHealthCheck, StorageHealthCheck,
+};
+use crate::infrastructure::jobs::{spawn_job_workers, JobRegistry, RemoveImageFilesHandler};
+use crate::infrastructure::scheduler::Scheduler;
+use crate::infrastructure::shutdown::Shutdown;
+use crate::infrastructure::storage::ConfiguredStorage;
+use crate::infrastructure::tasks::{
+    spawn_live_product_updates, spawn_outbox_relay, spawn_webhook_delivery, ActivatePricesTask,
+    PruneJobsTask, PruneOutboxTask, PruneProductEventsTask,
+};
 use crate::interfaces::api::docs::ApiDoc;
-use crate::interfaces::api::routes::configure_routes;
+use crate::interfaces::api::routes::{configure_graphql, configure_routes};
+use crate::interfaces::graphql::build_schema;
+use crate::interfaces::grpc;
+use crate::interfaces::middleware::auth::{Auth, TokenValidator};
+use crate::interfaces::middleware::logging::{RequestLogger, RequestSpan};
+use crate::interfaces::middleware::metrics::RequestMetrics;
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     // Initialize application configuration
     let config = AppConfig::new().await;
+    let telemetry = config.telemetry;
 
     let server_url = format!("{}:{}", config.env.server_host, config.env.server_port);
     info!("Server running at http://{}", server_url);
+    let grpc_addr: SocketAddr = format!("{}:{}", config.env.server_host, config.env.grpc_port)
+        .parse()
+        .expect("SERVER_HOST and GRPC_PORT must form a socket address");
 
     // Get the database pool
     let db_pool = config.db.pool;
+    let payment_config = config.payment;
+    let media_config = config.media;
+    let storage = ConfiguredStorage::new(&media_config);
+    let auth_config = config.auth;
+    let health_config = config.health;
+    let shutdown_config = config.shutdown;
+    let graphiql = !config.env.is_production();
+    let updates = BroadcastUpdateBus::new();
+    let domain_events = BroadcastEventBus::new();
+    let token_validator = TokenValidator::new(&auth_config);
+    let schema = build_schema(db_pool.clone(), storage.clone());
+    let health = HealthRegistry::new(health_config.check_timeout)
+        .register(DatabaseHealthCheck::new(db_pool.clone()))
+        .register(SchemaHealthCheck::new(db_pool.clone()))
+        .register(StorageHealthCheck::new(storage.clone()));
+
+    let shutdown = Shutdown::new();
+
+    // Start background tasks
+    spawn_outbox_relay(
+        db_pool.clone(),
+        &config.outbox,
+        domain_events.clone(),
+        &shutdown,
+    );
+    spawn_live_product_updates(&domain_events, updates.clone(), &shutdown);
+    spawn_webhook_delivery(db_pool.clone(), &shutdown);
+    spawn_job_workers(
+        db_pool.clone(),
+        JobRegistry::new()
+            .queue("default", 4)
+            .queue("media", 2)
+            .register(RemoveImageFilesHandler::new(storage.clone())),
+        &shutdown,
+    );
+    let product_events = ProductEventFeed::spawn(db_pool.clone(), &shutdown);
+
+    // Start scheduled tasks, schedules are cron expressions with seconds in UTC
+    Scheduler::new(db_pool.clone())
+        .task("*/30 * * * * *", ActivatePricesTask::new(db_pool.clone()))
+        .task("0 * * * * *", PruneProductEventsTask::new(db_pool.clone()))
+        .task("0 0 * * * *", PruneOutboxTask::new(db_pool.clone()))
+        .task("0 30 3 * * *", PruneJobsTask::new(db_pool.clone()))
+        .spawn(&shutdown);
+
+    // Start gRPC server on its own port
+    info!("gRPC server running at {}", grpc_addr);
+    let (stop_grpc, grpc_stopped) = tokio::sync::oneshot::channel::<()>();
+    let grpc_server = grpc::serve(
+        grpc_addr,
+        db_pool.clone(),
+        token_validator.clone(),
+        health.clone(),
+        async {
+            let _ = grpc_stopped.await;
+        },
+    );
 
     // Start HTTP server
-    HttpServer::new(move || {
+    let app_health = health.clone();
+    let app_shutdown = shutdown.clone();
+    let app_pool = db_pool.clone();
+    let http_server = HttpServer::new(move || {
         info!("Configuring application routes...");
         App::new()
-            .app_data(web::Data::new(db_pool.clone()))
-            .wrap(actix_web::middleware::Logger::default())
+            .app_data(web::Data::new(app_pool.clone()))
+            .app_data(web::Data::new(payment_config.clone()))
+            .app_data(web::Data::new(media_config.clone()))
+            .app_data(web::Data::new(storage.clone()))
+            .app_data(web::Data::new(schema.clone()))
+            .app_data(web::Data::new(product_events.clone()))
+            .app_data(web::Data::new(updates.clone()))
+            .app_data(web::Data::new(token_validator.clone()))
+            .app_data(web::Data::new(app_health.clone()))
+            .app_data(web::Data::new(app_shutdown.clone()))
+            .wrap(Auth::new(&auth_config))
+            .wrap(RequestMetrics)
+            .wrap(RequestLogger)
+            .wrap(TracingLogger::<RequestSpan>::new())
             .configure(configure_routes)
+            .configure(|cfg| configure_graphql(cfg, graphiql))
+            .configure(|cfg| serve_local_media(cfg, &media_config))
     })
     .bind(&server_url)?
-    .run()
-    .await
+    .disable_signals()
+    .shutdown_timeout(shutdown_config.drain_timeout.as_secs())
+    .run();
+
+    // On SIGTERM/SIGINT readiness and gRPC health fail first, then the HTTP server stops accepting
+    // connections and drains in-flight requests, and the gRPC server stops along with it
+    let server_handle = http_server.handle();
+    let http_handle = http_server.handle();
+    let draining = shutdown.clone();
+    actix_web::rt::spawn(async move {
+        shutdown_signal().await;
+        info!("Shutdown signal received, failing readiness");
+        health.begin_shutdown();
+        actix_web::rt::time::sleep(shutdown_config.readiness_delay).await;
+        info!("Draining in-flight requests");
+        draining.begin_draining();
+        server_handle.stop(true).await;
+    });
+
+    // Either server stopping, normally or with an error, stops the other one, so the
+    // shutdown sequence below always runs
+    let (http_result, grpc_result) = tokio::join!(
+        async {
+            let result = http_server.await;
+            let _ = stop_grpc.send(());
+            result
+        },
+        async {
+            let result = grpc_server.await;
+            if let Err(e) = &result {
+                error!("gRPC server failed: {}", e);
+                http_handle.stop(true).await;
+            }
+            result.map_err(std::io::Error::other)
+        }
+    );
+
+    // Then background tasks finish their current work before the pool closes
+    info!("Stopping background tasks");
+    if !shutdown.stop(shutdown_config.task_timeout).await {
+        warn!(
+            "Background tasks still running after {}s, stopping anyway",
+            shutdown_config.task_timeout.as_secs()
+        );
+    }
+    db_pool.close().await;
+    telemetry.shutdown().await;
+    info!("Shutdown complete");
+
+    http_result.and(grpc_result)
+}
+
+/// Resolves on SIGINT, or SIGTERM on Unix
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        let mut terminate =
+            tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+                .expect("Failed to install the SIGTERM handler");
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => {}
+            _ = terminate.recv() => {}
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = tokio::signal::ctrl_c().await;
+}
+
+/// Serves locally stored product images, other backends serve them themselves
+fn serve_local_media(cfg: &mut web::ServiceConfig, media: &config::MediaConfig) {
+    match &media.backend {
+        MediaBackend::Local { dir } if media.base_url.starts_with('/') => {
+            cfg.service(actix_files::Files::new(&media.base_url, dir));
+        }
+        _ => {}
+    }
 }